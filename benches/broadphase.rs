@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use glam::Vec2;
+use shapes2d::broadphase;
+use shapes2d::prelude::{Intersects, Rectangle};
+
+// Scattered boxes which mostly overlap only their neighbors, as in a typical scene
+fn scattered_boxes(count: usize) -> Vec<Rectangle> {
+    let mut seed = 0x2545_f491_u32;
+    let mut random = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        (seed % 10_000) as f32 / 100.
+    };
+    (0..count)
+        .map(|_| {
+            let min = Vec2 {
+                x: random(),
+                y: random(),
+            };
+            Rectangle::new_coordinates(
+                min,
+                min + Vec2 {
+                    x: random() / 20.,
+                    y: random() / 20.,
+                },
+            )
+        })
+        .collect()
+}
+
+fn brute_force(boxes: &[Rectangle]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for i in 0..boxes.len() {
+        for j in i + 1..boxes.len() {
+            if boxes[i].intersects(&boxes[j]) {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+fn overlapping_pairs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("overlapping_pairs");
+    for count in [100, 500, 2000] {
+        let boxes = scattered_boxes(count);
+        group.bench_with_input(
+            BenchmarkId::new("sweep_and_prune", count),
+            &boxes,
+            |b, boxes| b.iter(|| broadphase::overlapping_pairs(black_box(boxes))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("brute_force", count),
+            &boxes,
+            |b, boxes| b.iter(|| brute_force(black_box(boxes))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, overlapping_pairs);
+criterion_main!(benches);
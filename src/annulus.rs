@@ -0,0 +1,310 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single [`Annulus`], a ring between two circles sharing a `center`, in 2d space
+///
+/// The `inner_radius` is never larger than the `outer_radius`, the radii are swapped when they are given the
+/// other way around. An [`Annulus`] with an `inner_radius` of `0.` has no hole and is the same as a
+/// [`Circle`](crate::prelude::Circle).
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::Annulus;
+///
+/// // the area of effect of a shockwave
+/// let ring = Annulus::new(Vec2::ZERO, 4., 5.);
+///
+/// assert!(ring.contains_point(Vec2 { x: 0., y: 4.5 }));
+/// assert!(!ring.contains_point(Vec2 { x: 1., y: 1. }));
+/// assert!(!ring.contains_point(Vec2 { x: 6., y: 0. }));
+/// ```
+///
+/// Without a hole, an [`Annulus`] acts exactly like a [`Circle`](crate::prelude::Circle).
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Annulus, Bounded, Circle, ClosestPoint, Contains, Shape2d, SignedDistance};
+///
+/// let annulus = Annulus::new(Vec2::ONE, 0., 2.);
+/// let circle = Circle::new(Vec2::ONE, 2.);
+///
+/// assert_eq!(Shape2d::area(&annulus), Shape2d::area(&circle));
+/// assert_eq!(annulus.perimeter(), circle.perimeter());
+/// assert_eq!(annulus.centroid(), circle.centroid());
+/// assert_eq!(Bounded::bounding_box(&annulus), Bounded::bounding_box(&circle));
+/// for x in -4..=6 {
+///     for y in -4..=6 {
+///         let point = Vec2 { x: x as f32 * 0.5, y: y as f32 * 0.5 };
+///         assert_eq!(annulus.contains(point), circle.contains(point));
+///         assert_eq!(annulus.closest_point(point), circle.closest_point(point));
+///         assert_eq!(annulus.signed_distance(point), circle.signed_distance(point));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Annulus {
+    center: Vec2,
+    inner_radius: f32,
+    outer_radius: f32,
+}
+
+// ##########
+// Constructors
+// ##########
+impl Annulus {
+    /// Creates a new [`Annulus`] between an `inner_radius` and an `outer_radius`, swapping them if needed
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// let annulus = Annulus::new(Vec2::ZERO, 3., 1.);
+    ///
+    /// assert_eq!(annulus.inner_radius(), 1.);
+    /// assert_eq!(annulus.outer_radius(), 3.);
+    /// ```
+    pub fn new(center: Vec2, inner_radius: f32, outer_radius: f32) -> Self {
+        Self {
+            center,
+            inner_radius: inner_radius.min(outer_radius),
+            outer_radius: inner_radius.max(outer_radius),
+        }
+    }
+
+    /// Creates a new [`Annulus`] of a `thickness` centered on a circle of `radius`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// let annulus = Annulus::new_thickness(Vec2::ZERO, 2., 1.);
+    ///
+    /// assert_eq!(annulus.inner_radius(), 1.5);
+    /// assert_eq!(annulus.outer_radius(), 2.5);
+    /// assert_eq!(annulus.thickness(), 1.);
+    /// ```
+    pub fn new_thickness(center: Vec2, radius: f32, thickness: f32) -> Self {
+        let half = thickness.abs() * 0.5;
+        Self::new(center, radius - half, radius + half)
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl Annulus {
+    /// Get the `center` of the [`Annulus`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// assert_eq!(Annulus::new(Vec2::ONE, 1., 2.).center(), Vec2::ONE);
+    /// ```
+    pub fn center(&self) -> Vec2 {
+        self.center
+    }
+
+    /// Get the `inner_radius` of the [`Annulus`], the radius of its hole
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// assert_eq!(Annulus::new(Vec2::ZERO, 1., 2.).inner_radius(), 1.);
+    /// ```
+    pub fn inner_radius(&self) -> f32 {
+        self.inner_radius
+    }
+
+    /// Get the `outer_radius` of the [`Annulus`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// assert_eq!(Annulus::new(Vec2::ZERO, 1., 2.).outer_radius(), 2.);
+    /// ```
+    pub fn outer_radius(&self) -> f32 {
+        self.outer_radius
+    }
+
+    /// Get the `thickness` of the [`Annulus`], the distance between its radii
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// assert_eq!(Annulus::new(Vec2::ZERO, 1., 2.5).thickness(), 1.5);
+    /// ```
+    pub fn thickness(&self) -> f32 {
+        self.outer_radius - self.inner_radius
+    }
+
+    /// Set a new `center` for the [`Annulus`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// let mut annulus = Annulus::new(Vec2::ZERO, 1., 2.);
+    /// annulus.set_center(Vec2::ONE);
+    ///
+    /// assert_eq!(annulus.center(), Vec2::ONE);
+    /// ```
+    pub fn set_center(&mut self, center: Vec2) {
+        self.center = center;
+    }
+
+    /// Set a new `inner_radius` for the [`Annulus`], swapping the radii if it is larger than the `outer_radius`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// let mut annulus = Annulus::new(Vec2::ZERO, 1., 2.);
+    /// annulus.set_inner_radius(0.5);
+    /// assert_eq!(annulus.inner_radius(), 0.5);
+    ///
+    /// annulus.set_inner_radius(3.);
+    /// assert_eq!(annulus.inner_radius(), 2.);
+    /// assert_eq!(annulus.outer_radius(), 3.);
+    /// ```
+    pub fn set_inner_radius(&mut self, inner_radius: f32) {
+        *self = Self::new(self.center, inner_radius, self.outer_radius);
+    }
+
+    /// Set a new `outer_radius` for the [`Annulus`], swapping the radii if it is smaller than the `inner_radius`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// let mut annulus = Annulus::new(Vec2::ZERO, 1., 2.);
+    /// annulus.set_outer_radius(4.);
+    /// assert_eq!(annulus.outer_radius(), 4.);
+    ///
+    /// annulus.set_outer_radius(0.5);
+    /// assert_eq!(annulus.inner_radius(), 0.5);
+    /// assert_eq!(annulus.outer_radius(), 1.);
+    /// ```
+    pub fn set_outer_radius(&mut self, outer_radius: f32) {
+        *self = Self::new(self.center, self.inner_radius, outer_radius);
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl Annulus {
+    /// Get the `area` of the [`Annulus`], the area of the outer circle less the area of the hole
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// assert_eq!(Annulus::new(Vec2::ZERO, 1., 2.).area(), 3. * PI);
+    /// ```
+    pub fn area(&self) -> f32 {
+        std::f32::consts::PI
+            * (self.outer_radius * self.outer_radius - self.inner_radius * self.inner_radius)
+    }
+
+    /// Checks if `point` lies between the radii of the [`Annulus`], including on either circle
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// let annulus = Annulus::new(Vec2::ZERO, 1., 2.);
+    ///
+    /// assert!(annulus.contains_point(Vec2 { x: 1., y: 0. }));
+    /// assert!(annulus.contains_point(Vec2 { x: 0., y: -2. }));
+    /// assert!(!annulus.contains_point(Vec2 { x: 0.5, y: 0. }));
+    /// assert!(!annulus.contains_point(Vec2 { x: 2., y: 2. }));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let distance_squared = point.distance_squared(self.center);
+        distance_squared >= self.inner_radius * self.inner_radius
+            && distance_squared <= self.outer_radius * self.outer_radius
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`Annulus`], which is the box around its outer circle
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// let rect = Annulus::new(Vec2::ONE, 1., 2.).bounding_box();
+    ///
+    /// assert_eq!(rect.min(), Vec2 { x: -1., y: -1. });
+    /// assert_eq!(rect.max(), Vec2 { x: 3., y: 3. });
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let radius = Vec2::splat(self.outer_radius);
+        Rectangle::new_coordinates(self.center - radius, self.center + radius)
+    }
+
+    /// Get a [`Mesh`] approximating the [`Annulus`] with a strip of `2 * segments` triangles around the ring,
+    /// see [`Mesh::from_annulus`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Annulus;
+    ///
+    /// let mesh = Annulus::new(Vec2::ZERO, 1., 2.).to_mesh(16);
+    ///
+    /// assert_eq!(mesh.vertex_count(), 32);
+    /// assert_eq!(mesh.triangle_count(), 32);
+    /// ```
+    #[cfg(feature = "mesh")]
+    pub fn to_mesh(&self, segments: u32) -> Mesh {
+        Mesh::from_annulus(self, segments)
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `center`, `inner_radius` and `outer_radius` of two [`Annulus`] within `epsilon`
+    Annulus { center, inner_radius, outer_radius }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for Annulus {
+    fn default() -> Self {
+        Self {
+            center: Vec2::ZERO,
+            inner_radius: 0.5,
+            outer_radius: 1.,
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for Annulus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Annulus {{ center: {}, inner_radius: {}, outer_radius: {} }}",
+            self.center, self.inner_radius, self.outer_radius
+        )
+    }
+}
@@ -0,0 +1,519 @@
+use std::{f32::consts::TAU, fmt::Display};
+
+use glam::Vec2;
+
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single circular [`Arc`] in 2d space
+///
+/// An [`Arc`] is the part of the edge of a circle swept from its `start_angle` through its `sweep`. Angles
+/// are in radians, counter-clockwise from the `+x` axis. A positive `sweep` turns counter-clockwise and a
+/// negative `sweep` turns clockwise.
+///
+/// The `start_angle` is always kept between `0.` and `TAU`, and the `sweep` between `-TAU` and `TAU`, a
+/// whole circle either way.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use std::f32::consts::{FRAC_PI_2, PI, TAU};
+///
+/// use glam::Vec2;
+/// use shapes2d::prelude::Arc;
+///
+/// let arc = Arc::new(Vec2::ZERO, 2., -FRAC_PI_2, PI);
+/// assert_eq!(arc.start_angle(), 3. * FRAC_PI_2);
+/// assert_eq!(arc.sweep(), PI);
+/// assert_abs_diff_eq!(arc.end_point(), Vec2 { x: 0., y: 2. }, epsilon = 1e-6);
+///
+/// let full = Arc::new(Vec2::ZERO, 1., 0., 3. * TAU);
+/// assert_eq!(full.sweep(), TAU);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Arc {
+    center: Vec2,
+    radius: f32,
+    start_angle: f32,
+    sweep: f32,
+}
+
+// ##########
+// Constructors
+// ##########
+impl Arc {
+    /// Creates a new [`Arc`] turning `sweep` radians from `start_angle`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let arc = Arc::new(Vec2::ZERO, 1., 0., FRAC_PI_2);
+    ///
+    /// assert_eq!(arc.start_point(), Vec2::X);
+    /// assert_abs_diff_eq!(arc.end_point(), Vec2::Y, epsilon = 1e-6);
+    /// ```
+    pub fn new(center: Vec2, radius: f32, start_angle: f32, sweep: f32) -> Self {
+        Self {
+            center,
+            radius,
+            start_angle: normalize_angle(start_angle),
+            sweep: sweep.clamp(-TAU, TAU),
+        }
+    }
+
+    /// Creates a new [`Arc`] turning counter-clockwise from `start_angle` to `end_angle`
+    ///
+    /// The angles may be any number of turns apart, only where they point matters, so equal angles make an
+    /// [`Arc`] without any sweep.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// // across the seam at 0
+    /// let arc = Arc::new_angles(Vec2::ZERO, 1., 3. * FRAC_PI_2, FRAC_PI_2);
+    /// assert_abs_diff_eq!(arc.sweep(), PI, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(arc.point_at(0.5), Vec2::X, epsilon = 1e-6);
+    ///
+    /// // counter-clockwise the long way around
+    /// let arc = Arc::new_angles(Vec2::ZERO, 1., FRAC_PI_2, 0.);
+    /// assert_abs_diff_eq!(arc.sweep(), 3. * FRAC_PI_2, epsilon = 1e-6);
+    /// ```
+    pub fn new_angles(center: Vec2, radius: f32, start_angle: f32, end_angle: f32) -> Self {
+        Self::new(
+            center,
+            radius,
+            start_angle,
+            normalize_angle(end_angle - start_angle),
+        )
+    }
+
+    /// Creates a new [`Arc`] from `start` to `end` passing through `through`
+    ///
+    /// The [`Arc`] turns whichever way passes through `through`. Returns `None` if the points are on a single
+    /// line, as no circle passes through them.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let over = Arc::new_points(Vec2::X, Vec2::Y, -Vec2::X).unwrap();
+    /// assert!(over.center().length() < 1e-6);
+    /// assert_abs_diff_eq!(over.radius(), 1., epsilon = 1e-6);
+    /// assert_abs_diff_eq!(over.sweep(), PI, epsilon = 1e-6);
+    ///
+    /// // under turns clockwise
+    /// let under = Arc::new_points(Vec2::X, -Vec2::Y, -Vec2::X).unwrap();
+    /// assert_abs_diff_eq!(under.sweep(), -PI, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(under.point_at(0.5), -Vec2::Y, epsilon = 1e-6);
+    ///
+    /// assert!(Arc::new_points(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }).is_none());
+    /// ```
+    pub fn new_points(start: Vec2, through: Vec2, end: Vec2) -> Option<Self> {
+        let (b, c) = (through - start, end - start);
+        let denominator = 2. * b.perp_dot(c);
+        if denominator == 0. || !denominator.is_finite() {
+            return None;
+        }
+        let offset = Vec2 {
+            x: c.y * b.length_squared() - b.y * c.length_squared(),
+            y: b.x * c.length_squared() - c.x * b.length_squared(),
+        } / denominator;
+        let center = start + offset;
+
+        let angle = |point: Vec2| (point - center).y.atan2((point - center).x);
+        let start_angle = angle(start);
+        let counter_clockwise = normalize_angle(angle(end) - start_angle);
+        let sweep = if normalize_angle(angle(through) - start_angle) <= counter_clockwise {
+            counter_clockwise
+        } else {
+            counter_clockwise - TAU
+        };
+        Some(Self::new(center, offset.length(), start_angle, sweep))
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl Arc {
+    /// Get the `center` of the circle the [`Arc`] is a part of
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let arc = Arc::new(Vec2::ONE, 1., 0., 1.);
+    ///
+    /// assert_eq!(arc.center(), Vec2::ONE);
+    /// ```
+    pub fn center(&self) -> Vec2 {
+        self.center
+    }
+
+    /// Get the `radius` of the [`Arc`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let arc = Arc::new(Vec2::ZERO, 2., 0., 1.);
+    ///
+    /// assert_eq!(arc.radius(), 2.);
+    /// ```
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Get the angle the [`Arc`] starts at, between `0.` and `TAU`
+    ///
+    /// ```
+    /// use std::f32::consts::{PI, TAU};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// assert_eq!(Arc::new(Vec2::ZERO, 1., -PI, 1.).start_angle(), PI);
+    /// assert_eq!(Arc::new(Vec2::ZERO, 1., TAU, 1.).start_angle(), 0.);
+    /// ```
+    pub fn start_angle(&self) -> f32 {
+        self.start_angle
+    }
+
+    /// Get the angle the [`Arc`] turns through, counter-clockwise when positive
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let arc = Arc::new(Vec2::ZERO, 1., 0., -1.);
+    ///
+    /// assert_eq!(arc.sweep(), -1.);
+    /// ```
+    pub fn sweep(&self) -> f32 {
+        self.sweep
+    }
+
+    /// Get the angle the [`Arc`] ends at, between `0.` and `TAU`
+    ///
+    /// ```
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// assert_eq!(Arc::new(Vec2::ZERO, 1., PI, FRAC_PI_2).end_angle(), 3. * FRAC_PI_2);
+    /// assert_eq!(Arc::new(Vec2::ZERO, 1., FRAC_PI_2, -PI).end_angle(), 3. * FRAC_PI_2);
+    /// ```
+    pub fn end_angle(&self) -> f32 {
+        normalize_angle(self.start_angle + self.sweep)
+    }
+
+    /// Get the point the [`Arc`] starts at
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let arc = Arc::new(Vec2::ONE, 2., PI, 1.);
+    ///
+    /// assert_abs_diff_eq!(arc.start_point(), Vec2 { x: -1., y: 1. }, epsilon = 1e-6);
+    /// ```
+    pub fn start_point(&self) -> Vec2 {
+        self.point_at(0.)
+    }
+
+    /// Get the point the [`Arc`] ends at
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let arc = Arc::new(Vec2::ONE, 2., 0., PI);
+    ///
+    /// assert_abs_diff_eq!(arc.end_point(), Vec2 { x: -1., y: 1. }, epsilon = 1e-6);
+    /// ```
+    pub fn end_point(&self) -> Vec2 {
+        self.point_at(1.)
+    }
+
+    /// Set a new `center` for the [`Arc`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let mut arc = Arc::new(Vec2::ZERO, 1., 0., 1.);
+    /// arc.set_center(Vec2::ONE);
+    ///
+    /// assert_eq!(arc.center(), Vec2::ONE);
+    /// ```
+    pub fn set_center(&mut self, center: Vec2) {
+        self.center = center;
+    }
+
+    /// Set a new `radius` for the [`Arc`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let mut arc = Arc::new(Vec2::ZERO, 1., 0., 1.);
+    /// arc.set_radius(3.);
+    ///
+    /// assert_eq!(arc.radius(), 3.);
+    /// ```
+    pub fn set_radius(&mut self, radius: f32) {
+        self.radius = radius;
+    }
+
+    /// Set a new `start_angle` for the [`Arc`], keeping its `sweep`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, TAU};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let mut arc = Arc::new(Vec2::ZERO, 1., 0., 1.);
+    /// arc.set_start_angle(TAU + FRAC_PI_2);
+    ///
+    /// assert_abs_diff_eq!(arc.start_angle(), FRAC_PI_2, epsilon = 1e-6);
+    /// assert_eq!(arc.sweep(), 1.);
+    /// ```
+    pub fn set_start_angle(&mut self, start_angle: f32) {
+        self.start_angle = normalize_angle(start_angle);
+    }
+
+    /// Set a new `sweep` for the [`Arc`], keeping its `start_angle`
+    ///
+    /// ```
+    /// use std::f32::consts::TAU;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let mut arc = Arc::new(Vec2::ZERO, 1., 0., 1.);
+    /// arc.set_sweep(-10.);
+    ///
+    /// assert_eq!(arc.sweep(), -TAU);
+    /// ```
+    pub fn set_sweep(&mut self, sweep: f32) {
+        self.sweep = sweep.clamp(-TAU, TAU);
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl Arc {
+    /// Get the `length` of the [`Arc`]
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// assert_eq!(Arc::new(Vec2::ZERO, 2., 0., PI).length(), 2. * PI);
+    /// assert_eq!(Arc::new(Vec2::ZERO, 2., 0., -PI).length(), 2. * PI);
+    /// ```
+    pub fn length(&self) -> f32 {
+        self.radius * self.sweep.abs()
+    }
+
+    /// Get the point `t` of the way along the [`Arc`], clamping `t` between `0.` and `1.`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let arc = Arc::new(Vec2::ZERO, 1., 0., -PI);
+    ///
+    /// assert_abs_diff_eq!(arc.point_at(0.5), -Vec2::Y, epsilon = 1e-6);
+    /// assert_eq!(arc.point_at(-1.), arc.start_point());
+    /// assert_eq!(arc.point_at(2.), arc.end_point());
+    /// ```
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        self.center
+            + Vec2::from_angle(self.start_angle + self.sweep * t.clamp(0., 1.)) * self.radius
+    }
+
+    /// Checks if the direction at `angle` from the `center` passes through the [`Arc`]
+    ///
+    /// `angle` may be any number of turns around, and both ends of the [`Arc`] are included.
+    ///
+    /// ```
+    /// use std::f32::consts::{FRAC_PI_2, PI, TAU};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// // from 350 to 10 degrees, across the seam at 0
+    /// let arc = Arc::new(Vec2::ZERO, 1., (-10_f32).to_radians(), 20_f32.to_radians());
+    /// assert!(arc.contains_angle(0.));
+    /// assert!(arc.contains_angle(TAU));
+    /// assert!(arc.contains_angle(5_f32.to_radians()));
+    /// assert!(arc.contains_angle(355_f32.to_radians()));
+    /// assert!(!arc.contains_angle(PI));
+    ///
+    /// // the same part of the circle, swept clockwise
+    /// let clockwise = Arc::new(Vec2::ZERO, 1., 10_f32.to_radians(), (-20_f32).to_radians());
+    /// assert!(clockwise.contains_angle(0.));
+    /// assert!(clockwise.contains_angle(-5_f32.to_radians()));
+    /// assert!(!clockwise.contains_angle(FRAC_PI_2));
+    /// ```
+    pub fn contains_angle(&self, angle: f32) -> bool {
+        let turned = if self.sweep >= 0. {
+            normalize_angle(angle - self.start_angle)
+        } else {
+            normalize_angle(self.start_angle - angle)
+        };
+        self.sweep.abs() >= TAU || turned <= self.sweep.abs()
+    }
+
+    /// Get the points along the [`Arc`] from its start to its end, split into `segments` equal pieces
+    ///
+    /// There are `segments + 1` points, and always at least 2.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// let arc = Arc::new(Vec2::ZERO, 1., 0., PI);
+    /// let points = arc.to_polyline(2);
+    ///
+    /// assert_eq!(points.len(), 3);
+    /// assert_eq!(points[0], Vec2::X);
+    /// assert_abs_diff_eq!(points[1], Vec2::Y, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(points[2], -Vec2::X, epsilon = 1e-6);
+    /// ```
+    pub fn to_polyline(&self, segments: u32) -> Vec<Vec2> {
+        let segments = segments.max(1);
+        (0..=segments)
+            .map(|i| self.point_at(i as f32 / segments as f32))
+            .collect()
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`Arc`]
+    ///
+    /// The box holds both ends, and each side of the circle the [`Arc`] passes through.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Arc;
+    ///
+    /// // a quarter arc only holds its ends
+    /// let quarter = Arc::new(Vec2::ZERO, 2., 0., FRAC_PI_2).bounding_box();
+    /// assert_abs_diff_eq!(quarter.min(), Vec2::ZERO, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(quarter.max(), Vec2 { x: 2., y: 2. }, epsilon = 1e-6);
+    ///
+    /// // an arc across the seam at 0 reaches the right side of the circle
+    /// let seam = Arc::new(Vec2::ZERO, 2., 3. * FRAC_PI_2, PI).bounding_box();
+    /// assert_abs_diff_eq!(seam.min(), Vec2 { x: 0., y: -2. }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(seam.max(), Vec2 { x: 2., y: 2. }, epsilon = 1e-6);
+    ///
+    /// // the other way around it reaches the left side instead
+    /// let left = Arc::new(Vec2::ZERO, 2., 3. * FRAC_PI_2, -PI).bounding_box();
+    /// assert_abs_diff_eq!(left.min(), Vec2 { x: -2., y: -2. }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(left.max(), Vec2 { x: 0., y: 2. }, epsilon = 1e-6);
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let (start, end) = (self.start_point(), self.end_point());
+        let (mut min, mut max) = (start.min(end), start.max(end));
+        for (i, side) in [Vec2::X, Vec2::Y, -Vec2::X, -Vec2::Y]
+            .into_iter()
+            .enumerate()
+        {
+            if self.contains_angle(i as f32 * std::f32::consts::FRAC_PI_2) {
+                let point = self.center + side * self.radius;
+                min = min.min(point);
+                max = max.max(point);
+            }
+        }
+        Rectangle::new_coordinates(min, max)
+    }
+}
+
+// ##########
+// Helpers
+// ##########
+// The same direction as `angle`, between `0.` and `TAU`
+fn normalize_angle(angle: f32) -> f32 {
+    let angle = angle.rem_euclid(TAU);
+    // a tiny negative angle rounds up to a whole turn
+    if angle >= TAU {
+        0.
+    } else {
+        angle
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `center`, `radius`, `start_angle` and `sweep` of two [`Arc`]s within `epsilon`
+    ///
+    /// The `start_angle` is kept in `[0, TAU)`, so start angles just either side of `0.` are not close.
+    Arc { center, radius, start_angle, sweep }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for Arc {
+    fn default() -> Self {
+        Self {
+            center: Vec2::ZERO,
+            radius: 1.,
+            start_angle: 0.,
+            sweep: std::f32::consts::FRAC_PI_2,
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for Arc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Arc {{ center: {}, radius: {}, start_angle: {}, sweep: {} }}",
+            self.center(),
+            self.radius(),
+            self.start_angle(),
+            self.sweep()
+        )
+    }
+}
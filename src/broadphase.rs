@@ -0,0 +1,79 @@
+use crate::prelude::Rectangle;
+
+// ##########
+// Operations
+// ##########
+/// Get the index pairs of the `boxes` which touch or overlap
+///
+/// Uses sweep and prune along the x axis: the boxes are sorted by their minimum x, and each is only checked
+/// against the earlier boxes whose x range it still reaches. Each pair is returned once as `(i, j)` with
+/// `i < j`, and the pairs are sorted. When every box overlaps every other, all `n * (n - 1) / 2` pairs are
+/// returned, at the cost of the brute force check.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::broadphase;
+/// use shapes2d::prelude::{Intersects, Rectangle};
+///
+/// let boxes = vec![
+///     Rectangle::new(0., 0., 2., 2.),
+///     Rectangle::new(5., 0., 6., 1.),
+///     Rectangle::new(1., 1., 3., 3.),
+///     Rectangle::new(1., 5., 6., 6.),
+///     Rectangle::new(3., 3., 4., 4.),
+/// ];
+/// assert_eq!(broadphase::overlapping_pairs(&boxes), vec![(0, 2), (2, 4)]);
+///
+/// // agrees with checking every pair over several hundred pseudo random boxes
+/// let mut seed = 0x2545_f491_u32;
+/// let mut random = move || {
+///     seed ^= seed << 13;
+///     seed ^= seed >> 17;
+///     seed ^= seed << 5;
+///     (seed % 10_000) as f32 / 100.
+/// };
+/// for _ in 0..4 {
+///     let boxes: Vec<Rectangle> = (0..300)
+///         .map(|_| {
+///             let min = Vec2 { x: random(), y: random() };
+///             Rectangle::new_coordinates(min, min + Vec2 { x: random() / 10., y: random() / 10. })
+///         })
+///         .collect();
+///
+///     let mut brute_force = Vec::new();
+///     for i in 0..boxes.len() {
+///         for j in i + 1..boxes.len() {
+///             if boxes[i].intersects(&boxes[j]) {
+///                 brute_force.push((i, j));
+///             }
+///         }
+///     }
+///     assert!(!brute_force.is_empty());
+///     assert_eq!(broadphase::overlapping_pairs(&boxes), brute_force);
+/// }
+///
+/// // every box overlapping every other
+/// let boxes: Vec<Rectangle> = (0..100).map(|i| Rectangle::new(i as f32 / 100., 0., 2., 1.)).collect();
+/// assert_eq!(broadphase::overlapping_pairs(&boxes).len(), 100 * 99 / 2);
+/// ```
+pub fn overlapping_pairs(boxes: &[Rectangle]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..boxes.len()).collect();
+    order.sort_by(|a, b| boxes[*a].min_x().total_cmp(&boxes[*b].min_x()));
+
+    // The boxes whose x range may still reach the boxes yet to be swept
+    let mut active: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+    for index in order {
+        let rect = &boxes[index];
+        active.retain(|other| boxes[*other].max_x() >= rect.min_x());
+        for &other in &active {
+            if boxes[other].min_y() <= rect.max_y() && rect.min_y() <= boxes[other].max_y() {
+                pairs.push((other.min(index), other.max(index)));
+            }
+        }
+        active.push(index);
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
@@ -0,0 +1,459 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+use crate::traits::{closest_point_on_segment, segment_distance};
+
+/// Represents a single [`Capsule`], or stadium, in 2d space
+///
+/// A [`Capsule`] holds every point within `radius` of the segment from `start` to `end`: a rectangle along the
+/// segment with a half circle capping each end. A [`Capsule`] whose `start` and `end` are the same is a circle.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::Capsule;
+///
+/// // a character standing 2 units tall
+/// let player = Capsule::new_vertical(Vec2 { x: 0., y: 1. }, 2., 0.5);
+///
+/// assert_eq!(player.start(), Vec2 { x: 0., y: 0.5 });
+/// assert_eq!(player.end(), Vec2 { x: 0., y: 1.5 });
+/// assert!(player.contains_point(Vec2::ZERO));
+/// assert!(!player.contains_point(Vec2 { x: 0., y: 2.1 }));
+/// ```
+///
+/// With no length, a [`Capsule`] acts like a [`Circle`](crate::prelude::Circle).
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Capsule, Circle, ClosestPoint, Contains, Shape2d, SignedDistance};
+///
+/// let capsule = Capsule::new(Vec2::ONE, Vec2::ONE, 2.);
+/// let circle = Circle::new(Vec2::ONE, 2.);
+///
+/// assert_eq!(Shape2d::area(&capsule), Shape2d::area(&circle));
+/// assert_eq!(Shape2d::perimeter(&capsule), Shape2d::perimeter(&circle));
+/// for point in [Vec2::ZERO, Vec2 { x: 3., y: 1. }, Vec2 { x: -4., y: 5. }] {
+///     assert_eq!(capsule.contains(point), circle.contains(point));
+///     assert_eq!(capsule.closest_point(point), circle.closest_point(point));
+///     assert_eq!(capsule.signed_distance(point), circle.signed_distance(point));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule {
+    start: Vec2,
+    end: Vec2,
+    radius: f32,
+}
+
+// ##########
+// Constructors
+// ##########
+impl Capsule {
+    /// Creates a new [`Capsule`] around the segment from `start` to `end`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let capsule = Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, 1.);
+    ///
+    /// assert_eq!(capsule.length(), 4.);
+    /// assert_eq!(capsule.radius(), 1.);
+    /// ```
+    pub fn new(start: Vec2, end: Vec2, radius: f32) -> Self {
+        Self { start, end, radius }
+    }
+
+    /// Creates a new [`Capsule`] around a [`Line`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Capsule, Line};
+    ///
+    /// let line = Line::new(Vec2::ZERO, Vec2::ONE);
+    /// let capsule = Capsule::new_line(&line, 0.5);
+    ///
+    /// assert_eq!(capsule.segment(), line);
+    /// ```
+    #[cfg(feature = "line")]
+    pub fn new_line(line: &Line, radius: f32) -> Self {
+        Self::new(line.origin(), line.end(), radius)
+    }
+
+    /// Creates a new upright [`Capsule`] `height` tall from the bottom of one cap to the top of the other
+    ///
+    /// A `height` less than twice the `radius` gives a circle.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let capsule = Capsule::new_vertical(Vec2::ZERO, 4., 1.);
+    /// assert_eq!(capsule.start(), Vec2 { x: 0., y: -1. });
+    /// assert_eq!(capsule.end(), Vec2 { x: 0., y: 1. });
+    ///
+    /// let short = Capsule::new_vertical(Vec2::ZERO, 1., 1.);
+    /// assert_eq!(short.length(), 0.);
+    /// ```
+    pub fn new_vertical(center: Vec2, height: f32, radius: f32) -> Self {
+        let half = Vec2 {
+            x: 0.,
+            y: (height * 0.5 - radius).max(0.),
+        };
+        Self::new(center - half, center + half, radius)
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl Capsule {
+    /// Get the `start` of the segment inside the [`Capsule`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// assert_eq!(Capsule::new(Vec2::ZERO, Vec2::ONE, 1.).start(), Vec2::ZERO);
+    /// ```
+    pub fn start(&self) -> Vec2 {
+        self.start
+    }
+
+    /// Get the `end` of the segment inside the [`Capsule`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// assert_eq!(Capsule::new(Vec2::ZERO, Vec2::ONE, 1.).end(), Vec2::ONE);
+    /// ```
+    pub fn end(&self) -> Vec2 {
+        self.end
+    }
+
+    /// Get the `radius` of the [`Capsule`], the distance from its segment to its edge
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// assert_eq!(Capsule::new(Vec2::ZERO, Vec2::ONE, 2.).radius(), 2.);
+    /// ```
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// Get the `center` of the [`Capsule`], halfway along its segment
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// assert_eq!(Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 2. }, 1.).center(), Vec2 { x: 2., y: 1. });
+    /// ```
+    pub fn center(&self) -> Vec2 {
+        (self.start + self.end) * 0.5
+    }
+
+    /// Get the `length` of the segment inside the [`Capsule`], not counting its caps
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// assert_eq!(Capsule::new(Vec2::ZERO, Vec2 { x: 3., y: 4. }, 1.).length(), 5.);
+    /// ```
+    pub fn length(&self) -> f32 {
+        self.start.distance(self.end)
+    }
+
+    /// Get the segment inside the [`Capsule`] as a [`Line`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Capsule, Line};
+    ///
+    /// let capsule = Capsule::new(Vec2::ZERO, Vec2::ONE, 1.);
+    ///
+    /// assert_eq!(capsule.segment(), Line::new(Vec2::ZERO, Vec2::ONE));
+    /// ```
+    #[cfg(feature = "line")]
+    pub fn segment(&self) -> Line {
+        Line::new(self.start, self.end)
+    }
+
+    /// Set a new `start` for the segment inside the [`Capsule`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let mut capsule = Capsule::new(Vec2::ZERO, Vec2::ONE, 1.);
+    /// capsule.set_start(Vec2 { x: -1., y: -1. });
+    ///
+    /// assert_eq!(capsule.start(), Vec2 { x: -1., y: -1. });
+    /// ```
+    pub fn set_start(&mut self, start: Vec2) {
+        self.start = start;
+    }
+
+    /// Set a new `end` for the segment inside the [`Capsule`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let mut capsule = Capsule::new(Vec2::ZERO, Vec2::ONE, 1.);
+    /// capsule.set_end(Vec2 { x: 2., y: 2. });
+    ///
+    /// assert_eq!(capsule.end(), Vec2 { x: 2., y: 2. });
+    /// ```
+    pub fn set_end(&mut self, end: Vec2) {
+        self.end = end;
+    }
+
+    /// Set a new `radius` for the [`Capsule`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let mut capsule = Capsule::new(Vec2::ZERO, Vec2::ONE, 1.);
+    /// capsule.set_radius(0.25);
+    ///
+    /// assert_eq!(capsule.radius(), 0.25);
+    /// ```
+    pub fn set_radius(&mut self, radius: f32) {
+        self.radius = radius;
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl Capsule {
+    /// Get the `area` of the [`Capsule`], the rectangle along its segment plus the circle made by its two caps
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// assert_eq!(Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, 1.).area(), 8. + PI);
+    /// assert_eq!(Capsule::new(Vec2::ONE, Vec2::ONE, 2.).area(), 4. * PI);
+    /// ```
+    pub fn area(&self) -> f32 {
+        2. * self.radius * self.length() + std::f32::consts::PI * self.radius * self.radius
+    }
+
+    /// Get the `perimeter` of the [`Capsule`], its two straight sides plus the circle made by its two caps
+    ///
+    /// ```
+    /// use std::f32::consts::TAU;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// assert_eq!(Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, 1.).perimeter(), 8. + TAU);
+    /// ```
+    pub fn perimeter(&self) -> f32 {
+        2. * self.length() + std::f32::consts::TAU * self.radius
+    }
+
+    /// Get the point of the segment inside the [`Capsule`] closest to `point`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let capsule = Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, 1.);
+    ///
+    /// assert_eq!(capsule.closest_point_on_segment(Vec2 { x: 2., y: 5. }), Vec2 { x: 2., y: 0. });
+    /// assert_eq!(capsule.closest_point_on_segment(Vec2 { x: 7., y: 5. }), Vec2 { x: 4., y: 0. });
+    /// ```
+    pub fn closest_point_on_segment(&self, point: Vec2) -> Vec2 {
+        closest_point_on_segment(point, self.start, self.end)
+    }
+
+    /// Checks if `point` is within `radius` of the segment inside the [`Capsule`], including on its edge
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let capsule = Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, 1.);
+    ///
+    /// // the straight section
+    /// assert!(capsule.contains_point(Vec2 { x: 2., y: 1. }));
+    /// assert!(!capsule.contains_point(Vec2 { x: 2., y: 1.01 }));
+    ///
+    /// // the end caps are round, so the corners of the rectangle around the caps are outside
+    /// assert!(capsule.contains_point(Vec2 { x: -1., y: 0. }));
+    /// assert!(capsule.contains_point(Vec2 { x: 4.7, y: 0.7 }));
+    /// assert!(!capsule.contains_point(Vec2 { x: 4.8, y: 0.8 }));
+    /// assert!(!capsule.contains_point(Vec2 { x: -0.8, y: -0.8 }));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.distance_squared(self.closest_point_on_segment(point)) <= self.radius * self.radius
+    }
+
+    /// Checks if the [`Capsule`] overlaps a [`Circle`], including touching
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Capsule, Circle};
+    ///
+    /// let capsule = Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, 1.);
+    ///
+    /// assert!(capsule.intersects_circle(&Circle::new(Vec2 { x: 2., y: 2. }, 1.)));
+    /// assert!(capsule.intersects_circle(&Circle::new(Vec2 { x: 6., y: 0. }, 1.)));
+    /// assert!(!capsule.intersects_circle(&Circle::new(Vec2 { x: 5.5, y: 1.5 }, 1.)));
+    /// ```
+    #[cfg(feature = "circle")]
+    pub fn intersects_circle(&self, circle: &Circle) -> bool {
+        let reach = self.radius + circle.radius();
+        circle
+            .center()
+            .distance_squared(self.closest_point_on_segment(circle.center()))
+            <= reach * reach
+    }
+
+    /// Checks if the [`Capsule`] overlaps another [`Capsule`], including touching
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let capsule = Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, 1.);
+    ///
+    /// // parallel segments, touching and apart
+    /// assert!(capsule.intersects_capsule(&Capsule::new(Vec2 { x: 1., y: 2. }, Vec2 { x: 5., y: 2. }, 1.)));
+    /// assert!(!capsule.intersects_capsule(&Capsule::new(Vec2 { x: 1., y: 2.5 }, Vec2 { x: 5., y: 2.5 }, 1.)));
+    ///
+    /// // crossing segments always overlap, however thin
+    /// assert!(capsule.intersects_capsule(&Capsule::new(Vec2 { x: 2., y: -3. }, Vec2 { x: 2., y: 3. }, 0.)));
+    ///
+    /// // separated segments, end to end
+    /// assert!(capsule.intersects_capsule(&Capsule::new(Vec2 { x: 6., y: 0. }, Vec2 { x: 9., y: 3. }, 1.)));
+    /// assert!(!capsule.intersects_capsule(&Capsule::new(Vec2 { x: 6.5, y: 0. }, Vec2 { x: 9., y: 3. }, 1.)));
+    /// ```
+    pub fn intersects_capsule(&self, other: &Capsule) -> bool {
+        segment_distance(self.start, self.end, other.start, other.end) <= self.radius + other.radius
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`Capsule`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Capsule;
+    ///
+    /// let rect = Capsule::new(Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 2. }, 1.).bounding_box();
+    ///
+    /// assert_eq!(rect.min(), Vec2 { x: -1., y: -1. });
+    /// assert_eq!(rect.max(), Vec2 { x: 5., y: 3. });
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let radius = Vec2::splat(self.radius);
+        Rectangle::new_coordinates(
+            self.start.min(self.end) - radius,
+            self.start.max(self.end) + radius,
+        )
+    }
+
+    /// Creates a [`Polygon`] approximating the [`Capsule`] with `cap_segments` edges around each cap, wound
+    /// counter-clockwise
+    ///
+    /// The cap around `end` comes first, starting on the right of the segment looking from `start` to `end`. A
+    /// [`Capsule`] with no length is a circle of `2 * cap_segments` coordinates. A `cap_segments` below 2 is
+    /// clamped to 2.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Capsule, Winding};
+    ///
+    /// let capsule = Capsule::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, 1.);
+    /// let polygon = capsule.to_polygon(32);
+    ///
+    /// assert_eq!(polygon.len(), 66);
+    /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+    /// assert_abs_diff_eq!(polygon.area(), capsule.area(), epsilon = 0.01);
+    ///
+    /// let circle = Capsule::new(Vec2::ZERO, Vec2::ZERO, 1.).to_polygon(32);
+    /// assert_eq!(circle.len(), 64);
+    /// assert_abs_diff_eq!(circle.area(), PI, epsilon = 0.01);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn to_polygon(&self, cap_segments: u32) -> Polygon {
+        let cap_segments = cap_segments.max(2);
+        let direction = (self.end - self.start).try_normalize();
+        // a circle would repeat the coordinates where its caps meet
+        let steps = if direction.is_some() {
+            cap_segments + 1
+        } else {
+            cap_segments
+        };
+        let right = -direction.unwrap_or(Vec2::X).perp();
+        let cap = move |center: Vec2, from: Vec2| {
+            (0..steps).map(move |i| {
+                let angle = i as f32 * std::f32::consts::PI / cap_segments as f32;
+                center + Vec2::from_angle(angle).rotate(from) * self.radius
+            })
+        };
+        cap(self.end, right)
+            .chain(cap(self.start, -right))
+            .collect()
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `start`, `end` and `radius` of two [`Capsule`]s within `epsilon`, so a reversed [`Capsule`] is
+    /// not close
+    Capsule { start, end, radius }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for Capsule {
+    fn default() -> Self {
+        Self {
+            start: Vec2 { x: 0., y: -0.5 },
+            end: Vec2 { x: 0., y: 0.5 },
+            radius: 0.5,
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for Capsule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Capsule {{ start: {}, end: {}, radius: {} }}",
+            self.start, self.end, self.radius
+        )
+    }
+}
@@ -2,162 +2,332 @@ use std::fmt::Display;
 
 use glam::Vec2;
 
-/// Represents a single [`Circle`] in 2d space
-pub struct Circle {
-    center: Vec2,
-    radius: f32,
-}
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
 
-// ##########
-// Constructors
-// ##########
-impl Circle {
-    /// Creates a new [`Circle`] with a `radius`
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Circle;
-    ///
-    /// let circle = Circle::new(Vec2::ZERO, 1.);
-    ///
-    /// assert_eq!(circle.diameter(), 2.);
-    /// ```
-    pub fn new(center: Vec2, radius: f32) -> Self {
-        Self { center, radius }
-    }
-
-    /// Creates a new [`Circle`] with a `diameter`
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Circle;
-    ///
-    /// let circle = Circle::new_diameter(Vec2::ZERO, 2.);
-    ///
-    /// assert_eq!(circle.radius(), 1.);
-    /// ```
-    pub fn new_diameter(center: Vec2, diameter: f32) -> Self {
-        Self::new(center, diameter * 0.5)
-    }
-}
+// Defines the `Circle` for one precision, here with `f32` and in `crate::double` with `f64`, where the
+// shape and `Vec2` names in the bodies refer to the `f64` types
+macro_rules! impl_circle {
+    ($(#[$meta:meta])* $circle:ident, $float:ident; $imports:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $circle {
+            center: Vec2,
+            radius: $float,
+        }
 
-// ##########
-// Getters/Setters
-// ##########
-impl Circle {
-    /// Get the `center` of the [`Circle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Circle;
-    ///
-    /// let circle = Circle::new(Vec2::ZERO, 1.);
-    /// let center = circle.center();
-    ///
-    /// assert_eq!(center, Vec2::ZERO);
-    /// ```
-    pub fn center(&self) -> Vec2 {
-        self.center
-    }
-
-    /// Get the `radius` of the [`Circle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Circle;
-    ///
-    /// let circle = Circle::new(Vec2::ZERO, 1.);
-    /// let radius = circle.radius();
-    ///
-    /// assert_eq!(radius, 1.);
-    /// ```
-    pub fn radius(&self) -> f32 {
-        self.radius
-    }
-
-    /// Get the `diameter` of the [`Circle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Circle;
-    ///
-    /// let circle = Circle::new(Vec2::ZERO, 1.);
-    /// let diameter = circle.diameter();
-    ///
-    /// assert_eq!(diameter, 2.);
-    /// ```
-    pub fn diameter(&self) -> f32 {
-        self.radius * 2.
-    }
-
-    /// Set a new `center` for the [`Circle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Circle;
-    ///
-    /// let mut circle = Circle::new(Vec2::ZERO, 1.);
-    /// circle.set_center(Vec2::ONE);
-    ///
-    /// assert_eq!(circle.center(), Vec2::ONE);
-    /// ```
-    pub fn set_center(&mut self, center: Vec2) {
-        self.center = center;
-    }
-
-    /// Set a new `radius` for the [`Circle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Circle;
-    ///
-    /// let mut circle = Circle::new(Vec2::ZERO, 1.);
-    /// circle.set_radius(2.);
-    ///
-    /// assert_eq!(circle.diameter(), 4.);
-    /// ```
-    pub fn set_radius(&mut self, radius: f32) {
-        self.radius = radius;
-    }
-
-    /// Set a new `diameter` for the [`Circle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Circle;
-    ///
-    /// let mut circle = Circle::new(Vec2::ZERO, 1.);
-    /// circle.set_diameter(4.);
-    ///
-    /// assert_eq!(circle.radius(), 2.);
-    /// ```
-    pub fn set_diameter(&mut self, diameter: f32) {
-        self.radius = diameter * 0.5;
-    }
-}
+        // ##########
+        // Constructors
+        // ##########
+        impl Circle {
+            /// Creates a new [`Circle`] with a `radius`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let circle = Circle::new(Vec2::ZERO, 1.);
+            ///
+            /// assert_eq!(circle.diameter(), 2.);
+            /// ```
+            pub fn new(center: Vec2, radius: $float) -> Self {
+                Self { center, radius }
+            }
 
-// ##########
-// Default impl
-// ##########
-impl Default for Circle {
-    fn default() -> Self {
-        Self {
-            center: Vec2::ZERO,
-            radius: 1.,
+            /// Creates a new [`Circle`] with a `diameter`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let circle = Circle::new_diameter(Vec2::ZERO, 2.);
+            ///
+            /// assert_eq!(circle.radius(), 1.);
+            /// ```
+            pub fn new_diameter(center: Vec2, diameter: $float) -> Self {
+                Self::new(center, diameter * 0.5)
+            }
+        }
+
+        // ##########
+        // Getters/Setters
+        // ##########
+        impl Circle {
+            /// Get the `center` of the [`Circle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let circle = Circle::new(Vec2::ZERO, 1.);
+            /// let center = circle.center();
+            ///
+            /// assert_eq!(center, Vec2::ZERO);
+            /// ```
+            pub fn center(&self) -> Vec2 {
+                self.center
+            }
+
+            /// Get the `radius` of the [`Circle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let circle = Circle::new(Vec2::ZERO, 1.);
+            /// let radius = circle.radius();
+            ///
+            /// assert_eq!(radius, 1.);
+            /// ```
+            pub fn radius(&self) -> $float {
+                self.radius
+            }
+
+            /// Get the `diameter` of the [`Circle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let circle = Circle::new(Vec2::ZERO, 1.);
+            /// let diameter = circle.diameter();
+            ///
+            /// assert_eq!(diameter, 2.);
+            /// ```
+            pub fn diameter(&self) -> $float {
+                self.radius * 2.
+            }
+
+            /// Set a new `center` for the [`Circle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut circle = Circle::new(Vec2::ZERO, 1.);
+            /// circle.set_center(Vec2::ONE);
+            ///
+            /// assert_eq!(circle.center(), Vec2::ONE);
+            /// ```
+            pub fn set_center(&mut self, center: Vec2) {
+                self.center = center;
+            }
+
+            /// Set a new `radius` for the [`Circle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut circle = Circle::new(Vec2::ZERO, 1.);
+            /// circle.set_radius(2.);
+            ///
+            /// assert_eq!(circle.diameter(), 4.);
+            /// ```
+            pub fn set_radius(&mut self, radius: $float) {
+                self.radius = radius;
+            }
+
+            /// Set a new `diameter` for the [`Circle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut circle = Circle::new(Vec2::ZERO, 1.);
+            /// circle.set_diameter(4.);
+            ///
+            /// assert_eq!(circle.radius(), 2.);
+            /// ```
+            pub fn set_diameter(&mut self, diameter: $float) {
+                self.radius = diameter * 0.5;
+            }
+        }
+
+        // ##########
+        // Operations
+        // ##########
+        impl Circle {
+            /// Get a [`Mesh`] approximating the [`Circle`] with a fan of `segments` triangles, see [`Mesh::from_circle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Circle::new(Vec2::ZERO, 1.).to_mesh(16);
+            ///
+            /// assert_eq!(mesh.vertex_count(), 17);
+            /// assert_eq!(mesh.triangle_count(), 16);
+            /// ```
+            #[cfg(feature = "mesh")]
+            pub fn to_mesh(&self, segments: u32) -> Mesh {
+                Mesh::from_circle(self, segments)
+            }
+
+            /// Creates a [`Polygon`] approximating the [`Circle`] with `segments` edges, wound counter-clockwise
+            ///
+            /// The first coordinate is to the right of the `center`. A `segments` below 3 is clamped to 3.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let circle = Circle::new(Vec2::ZERO, 1.);
+            /// let polygon = circle.to_polygon(4);
+            ///
+            /// assert_eq!(polygon.len(), 4);
+            /// assert_eq!(polygon.coordinate(0), Some(Vec2 { x: 1., y: 0. }));
+            /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+            /// assert_abs_diff_eq!(polygon.area(), 2., epsilon = 1e-5);
+            ///
+            /// assert_abs_diff_eq!(circle.to_polygon(256).area(), 3.1416, epsilon = 0.001);
+            /// ```
+            #[cfg(feature = "polygon")]
+            pub fn to_polygon(&self, segments: u32) -> Polygon {
+                let segments = segments.max(3);
+                (0..segments)
+                    .map(|i| {
+                        let angle = i as $float * std::$float::consts::TAU / segments as $float;
+                        self.center + Vec2::from_angle(angle) * self.radius
+                    })
+                    .collect()
+            }
+
+            /// Get the earliest time in `[0, 1]` at which the [`Circle`], moving by `velocity` over one step,
+            /// first touches `line`, or `None` if it never does within the step
+            ///
+            /// The [`Circle`] can hit the face of the [`Line`] or one of its ends. A [`Circle`] which already
+            /// touches the [`Line`] returns `Some(0.)`.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let wall = Line::new(Vec2 { x: 5., y: -2. }, Vec2 { x: 5., y: 2. });
+            ///
+            /// // head on
+            /// let circle = Circle::new(Vec2::ZERO, 1.);
+            /// assert_eq!(circle.sweep_against_line(Vec2 { x: 8., y: 0. }, &wall), Some(0.5));
+            /// // not far enough this step
+            /// assert_eq!(circle.sweep_against_line(Vec2 { x: 3., y: 0. }, &wall), None);
+            /// // moving away
+            /// assert_eq!(circle.sweep_against_line(Vec2 { x: -8., y: 0. }, &wall), None);
+            ///
+            /// // grazing the top end of the wall
+            /// let circle = Circle::new(Vec2 { x: 0., y: 2.6 }, 1.);
+            /// let t = circle.sweep_against_line(Vec2 { x: 10., y: 0. }, &wall).unwrap();
+            /// assert_abs_diff_eq!(t, 0.42, epsilon = 1e-5);
+            /// // passing just above it
+            /// let circle = Circle::new(Vec2 { x: 0., y: 3.1 }, 1.);
+            /// assert_eq!(circle.sweep_against_line(Vec2 { x: 10., y: 0. }, &wall), None);
+            ///
+            /// // moving parallel to the wall, beside it and then into its end
+            /// let circle = Circle::new(Vec2 { x: 3., y: -6. }, 1.);
+            /// assert_eq!(circle.sweep_against_line(Vec2 { x: 0., y: 8. }, &wall), None);
+            /// let circle = Circle::new(Vec2 { x: 5., y: -6. }, 1.);
+            /// assert_eq!(circle.sweep_against_line(Vec2 { x: 0., y: 8. }, &wall), Some(0.375));
+            ///
+            /// // fast enough to cross the whole wall within the step
+            /// let circle = Circle::new(Vec2 { x: 0., y: 1. }, 0.5);
+            /// assert_eq!(circle.sweep_against_line(Vec2 { x: 45., y: 0. }, &wall), Some(0.1));
+            ///
+            /// // already touching
+            /// let circle = Circle::new(Vec2 { x: 4., y: 0. }, 1.);
+            /// assert_eq!(circle.sweep_against_line(Vec2 { x: -1., y: 0. }, &wall), Some(0.));
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn sweep_against_line(&self, velocity: Vec2, line: &Line) -> Option<$float> {
+                let (start, end) = (line.origin(), line.end());
+                let direction = end - start;
+                let length_squared = direction.length_squared();
+
+                let closest = if length_squared == 0. {
+                    start
+                } else {
+                    let t = ((self.center - start).dot(direction) / length_squared).clamp(0., 1.);
+                    start + direction * t
+                };
+                if self.center.distance_squared(closest) <= self.radius * self.radius {
+                    return Some(0.);
+                }
+
+                let mut earliest: Option<$float> = None;
+                let mut consider = |t: $float| {
+                    if (0. ..=1.).contains(&t) && earliest.is_none_or(|earliest| t < earliest) {
+                        earliest = Some(t);
+                    }
+                };
+
+                // The face, where the center reaches `radius` from the line while between its ends
+                if length_squared > 0. {
+                    let normal = direction.perp() / length_squared.sqrt();
+                    let distance = normal.dot(self.center - start);
+                    let approach = normal.dot(velocity);
+                    if distance.abs() >= self.radius && distance * approach < 0. {
+                        let t = (distance.abs() - self.radius) / approach.abs();
+                        let along = direction.dot(self.center + velocity * t - start);
+                        if (0. ..=length_squared).contains(&along) {
+                            consider(t);
+                        }
+                    }
+                }
+
+                // The ends, where the center reaches `radius` from either end
+                let speed_squared = velocity.length_squared();
+                if speed_squared > 0. {
+                    for point in [start, end] {
+                        let offset = self.center - point;
+                        let half_b = offset.dot(velocity);
+                        let c = offset.length_squared() - self.radius * self.radius;
+                        let discriminant = half_b * half_b - speed_squared * c;
+                        if discriminant >= 0. {
+                            consider((-half_b - discriminant.sqrt()) / speed_squared);
+                        }
+                    }
+                }
+
+                earliest
+            }
+        }
+
+        // ##########
+        // Default impl
+        // ##########
+        impl Default for Circle {
+            fn default() -> Self {
+                Self {
+                    center: Vec2::ZERO,
+                    radius: 1.,
+                }
+            }
         }
-    }
+
+        // ##########
+        // Display impl
+        // ##########
+        impl Display for Circle {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    concat!(stringify!($circle), " {{ center: {}, radius: {} }}"),
+                    self.center(),
+                    self.radius()
+                )
+            }
+        }
+    };
 }
+pub(crate) use impl_circle;
+
+impl_circle!(
+    /// Represents a single [`Circle`] in 2d space
+    Circle,
+    f32;
+    "use glam::Vec2;\nuse shapes2d::prelude::*;"
+);
 
 // ##########
-// Display impl
+// approx impl
 // ##########
-impl Display for Circle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Circle {{ center: {}, radius: {} }}",
-            self.center(),
-            self.radius()
-        )
-    }
-}
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `center` and `radius` of two [`Circle`]s within `epsilon`
+    Circle { center, radius }
+);
@@ -0,0 +1,458 @@
+use std::{
+    f32::consts::{PI, TAU},
+    fmt::Display,
+};
+
+use glam::Vec2;
+
+use crate::prelude::Arc;
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single [`CircularSegment`] in 2d space, the region of a circle cut off by a chord
+///
+/// The segment is described by the circle's `center` and `radius`, the `direction` angle pointing from the
+/// `center` towards the middle of the segment's [`Arc`], and the `half_angle` the [`Arc`] spreads to either side
+/// of the `direction`. The `direction` always picks which side of the chord is the segment, so the same chord
+/// gives the small cap with one `direction` and the rest of the circle with the opposite one.
+///
+/// The `half_angle` is clamped between `0.`, an empty sliver, and `PI`, the whole circle.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use std::f32::consts::{FRAC_PI_2, PI};
+///
+/// use glam::Vec2;
+/// use shapes2d::prelude::CircularSegment;
+///
+/// // a tank of radius 1 filled to a depth of 0.5
+/// let fill = CircularSegment::new(Vec2::ZERO, 1., -FRAC_PI_2, PI / 3.);
+///
+/// assert_abs_diff_eq!(fill.height(), 0.5, epsilon = 1e-6);
+/// assert!(fill.contains_point(Vec2 { x: 0., y: -0.9 }));
+/// assert!(!fill.contains_point(Vec2 { x: 0., y: -0.4 }));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CircularSegment {
+    arc: Arc,
+}
+
+// ##########
+// Constructors
+// ##########
+impl CircularSegment {
+    /// Creates a new [`CircularSegment`] whose [`Arc`] spreads `half_angle` to either side of `direction`
+    ///
+    /// ```
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// // the top half of the circle
+    /// let half = CircularSegment::new(Vec2::ZERO, 2., FRAC_PI_2, FRAC_PI_2);
+    ///
+    /// assert!(half.contains_point(Vec2 { x: 0., y: 1. }));
+    /// assert!(!half.contains_point(Vec2 { x: 0., y: -1. }));
+    /// assert_eq!(CircularSegment::new(Vec2::ZERO, 2., 0., 10.).half_angle(), PI);
+    /// ```
+    pub fn new(center: Vec2, radius: f32, direction: f32, half_angle: f32) -> Self {
+        let half_angle = half_angle.clamp(0., PI);
+        Self {
+            arc: Arc::new(center, radius, direction - half_angle, 2. * half_angle),
+        }
+    }
+
+    /// Creates a new [`CircularSegment`] from the part of `circle` to the left of `line`, looking from its
+    /// `origin` towards its `end`
+    ///
+    /// The `line` is extended past its ends to cut all the way across the `circle`. Reverse the `line` to get
+    /// the other side. Returns [`None`] if the `line` has no length or does not cross the `circle`.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, CircularSegment, Line};
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 2.);
+    /// let line = Line::new(Vec2 { x: 5., y: 1. }, Vec2 { x: 4., y: 1. });
+    ///
+    /// // looking along -x, the left is below the line
+    /// let below = CircularSegment::new_circle_line(&circle, &line).unwrap();
+    /// assert!(below.contains_point(Vec2 { x: 0., y: 0.5 }));
+    /// assert!(!below.contains_point(Vec2 { x: 0., y: 1.5 }));
+    ///
+    /// let reversed = Line::new(line.end(), line.origin());
+    /// let above = CircularSegment::new_circle_line(&circle, &reversed).unwrap();
+    /// assert!(above.contains_point(Vec2 { x: 0., y: 1.5 }));
+    /// assert_abs_diff_eq!(below.area() + above.area(), std::f32::consts::PI * 4., epsilon = 1e-5);
+    ///
+    /// assert_eq!(CircularSegment::new_circle_line(&circle, &Line::new(Vec2 { x: 3., y: 0. }, Vec2 { x: 3., y: 1. })), None);
+    /// ```
+    #[cfg(all(feature = "circle", feature = "line"))]
+    pub fn new_circle_line(circle: &Circle, line: &Line) -> Option<Self> {
+        let left = line.direction().perp().try_normalize()?;
+        let offset = (line.origin() - circle.center()).dot(left);
+        if offset.abs() >= circle.radius() {
+            return None;
+        }
+
+        Some(Self::new(
+            circle.center(),
+            circle.radius(),
+            left.y.atan2(left.x),
+            (offset / circle.radius()).acos(),
+        ))
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl CircularSegment {
+    /// Get the [`Arc`] along the curved edge of the [`CircularSegment`], turning counter-clockwise
+    ///
+    /// ```
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let segment = CircularSegment::new(Vec2::ZERO, 1., FRAC_PI_2, FRAC_PI_2);
+    ///
+    /// assert_eq!(segment.arc().start_angle(), 0.);
+    /// assert_eq!(segment.arc().sweep(), PI);
+    /// ```
+    pub fn arc(&self) -> Arc {
+        self.arc
+    }
+
+    /// Get the `center` of the circle the [`CircularSegment`] is cut from
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// assert_eq!(CircularSegment::new(Vec2::ONE, 1., 0., 1.).center(), Vec2::ONE);
+    /// ```
+    pub fn center(&self) -> Vec2 {
+        self.arc.center()
+    }
+
+    /// Get the `radius` of the circle the [`CircularSegment`] is cut from
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// assert_eq!(CircularSegment::new(Vec2::ZERO, 2., 0., 1.).radius(), 2.);
+    /// ```
+    pub fn radius(&self) -> f32 {
+        self.arc.radius()
+    }
+
+    /// Get the angle pointing from the `center` towards the middle of the [`CircularSegment`], between `0.` and
+    /// `TAU`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let segment = CircularSegment::new(Vec2::ZERO, 1., -FRAC_PI_2, 1.);
+    ///
+    /// assert_abs_diff_eq!(segment.direction(), 3. * FRAC_PI_2, epsilon = 1e-6);
+    /// ```
+    pub fn direction(&self) -> f32 {
+        (self.arc.start_angle() + self.half_angle()).rem_euclid(TAU)
+    }
+
+    /// Get the angle the [`Arc`] spreads to either side of the `direction`, half of the angle it subtends
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// assert_eq!(CircularSegment::new(Vec2::ZERO, 1., 0., 0.5).half_angle(), 0.5);
+    /// ```
+    pub fn half_angle(&self) -> f32 {
+        self.arc.sweep() * 0.5
+    }
+
+    /// Get the chord cutting off the [`CircularSegment`], from the start to the end of its [`Arc`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let chord = CircularSegment::new(Vec2::ZERO, 1., FRAC_PI_2, FRAC_PI_2).chord();
+    ///
+    /// assert_abs_diff_eq!(chord.origin(), Vec2 { x: 1., y: 0. }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(chord.end(), Vec2 { x: -1., y: 0. }, epsilon = 1e-6);
+    /// ```
+    #[cfg(feature = "line")]
+    pub fn chord(&self) -> Line {
+        Line::new(self.arc.start_point(), self.arc.end_point())
+    }
+
+    /// Set a new `center` for the [`CircularSegment`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let mut segment = CircularSegment::new(Vec2::ZERO, 1., 0., 1.);
+    /// segment.set_center(Vec2::ONE);
+    ///
+    /// assert_eq!(segment.center(), Vec2::ONE);
+    /// ```
+    pub fn set_center(&mut self, center: Vec2) {
+        self.arc.set_center(center);
+    }
+
+    /// Set a new `radius` for the [`CircularSegment`], keeping its angles
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let mut segment = CircularSegment::new(Vec2::ZERO, 1., 0., 1.);
+    /// segment.set_radius(3.);
+    ///
+    /// assert_eq!(segment.radius(), 3.);
+    /// ```
+    pub fn set_radius(&mut self, radius: f32) {
+        self.arc.set_radius(radius);
+    }
+
+    /// Set a new `direction` for the [`CircularSegment`], keeping its `half_angle`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let mut segment = CircularSegment::new(Vec2::ZERO, 1., 0., 1.);
+    /// segment.set_direction(PI);
+    ///
+    /// assert_abs_diff_eq!(segment.direction(), PI, epsilon = 1e-6);
+    /// assert!(segment.contains_point(Vec2 { x: -0.9, y: 0. }));
+    /// ```
+    pub fn set_direction(&mut self, direction: f32) {
+        self.arc.set_start_angle(direction - self.half_angle());
+    }
+
+    /// Set a new `half_angle` for the [`CircularSegment`], keeping its `direction`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let mut segment = CircularSegment::new(Vec2::ZERO, 1., 1., 0.5);
+    /// segment.set_half_angle(-1.);
+    /// assert_eq!(segment.half_angle(), 0.);
+    ///
+    /// segment.set_half_angle(PI);
+    /// assert_abs_diff_eq!(segment.area(), PI, epsilon = 1e-6);
+    /// ```
+    pub fn set_half_angle(&mut self, half_angle: f32) {
+        *self = Self::new(self.center(), self.radius(), self.direction(), half_angle);
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl CircularSegment {
+    /// Get the `area` of the [`CircularSegment`], `r² * (θ - sin(θ)) / 2` where `θ` is the angle its [`Arc`]
+    /// subtends
+    ///
+    /// Thin slivers use a series expansion, so the `area` shrinks smoothly to `0.` instead of losing its
+    /// precision to cancellation.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let half = CircularSegment::new(Vec2::ZERO, 2., FRAC_PI_2, FRAC_PI_2);
+    /// assert_abs_diff_eq!(half.area(), PI * 4. / 2., epsilon = 1e-6);
+    ///
+    /// // thinner slivers always have less area, all the way down to nothing
+    /// let mut previous = half.area();
+    /// for step in 1..=100 {
+    ///     let half_angle = FRAC_PI_2 * (1. - step as f32 / 100.).powi(3);
+    ///     let area = CircularSegment::new(Vec2::ZERO, 2., 0., half_angle).area();
+    ///     assert!(area < previous);
+    ///     assert!(area >= 0.);
+    ///     previous = area;
+    /// }
+    /// assert_eq!(previous, 0.);
+    /// ```
+    pub fn area(&self) -> f32 {
+        let theta = self.arc.sweep();
+        // theta - sin(theta) cancels for small angles, so use the start of its taylor series there
+        let difference = if theta < 0.25 {
+            let theta3 = theta * theta * theta;
+            theta3 / 6. - theta3 * theta * theta / 120. + theta3 * theta3 * theta / 5040.
+        } else {
+            theta - theta.sin()
+        };
+        0.5 * self.radius() * self.radius() * difference
+    }
+
+    /// Get the length of the [`Arc`] along the curved edge of the [`CircularSegment`]
+    ///
+    /// ```
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// assert_eq!(CircularSegment::new(Vec2::ZERO, 2., 0., FRAC_PI_2).arc_length(), 2. * PI);
+    /// ```
+    pub fn arc_length(&self) -> f32 {
+        self.arc.length()
+    }
+
+    /// Get the `height` of the [`CircularSegment`], the distance from the middle of its chord to the middle of its
+    /// [`Arc`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// assert_abs_diff_eq!(CircularSegment::new(Vec2::ZERO, 2., 0., FRAC_PI_2).height(), 2., epsilon = 1e-6);
+    /// assert_eq!(CircularSegment::new(Vec2::ZERO, 2., 0., PI).height(), 4.);
+    /// ```
+    pub fn height(&self) -> f32 {
+        self.radius() * (1. - self.half_angle().cos())
+    }
+
+    /// Checks if `point` is inside or on the edge of the [`CircularSegment`]
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// // the cap to the right of x = 1
+    /// let cap = CircularSegment::new(Vec2::ZERO, 2., 0., PI / 3.);
+    /// assert!(cap.contains_point(Vec2 { x: 1.5, y: 0. }));
+    /// assert!(!cap.contains_point(Vec2 { x: 0.9, y: 0. }));
+    /// assert!(!cap.contains_point(Vec2 { x: 1.5, y: 1.5 }));
+    ///
+    /// // the rest of the circle to the left of x = 1
+    /// let rest = CircularSegment::new(Vec2::ZERO, 2., PI, 2. * PI / 3.);
+    /// assert!(rest.contains_point(Vec2 { x: 0.9, y: 0. }));
+    /// assert!(!rest.contains_point(Vec2 { x: 1.5, y: 0. }));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let offset = point - self.center();
+        offset.length_squared() <= self.radius() * self.radius()
+            && offset.dot(Vec2::from_angle(self.direction()))
+                >= self.radius() * self.half_angle().cos()
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`CircularSegment`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CircularSegment;
+    ///
+    /// let half = CircularSegment::new(Vec2::ZERO, 2., FRAC_PI_2, FRAC_PI_2).bounding_box();
+    ///
+    /// assert_abs_diff_eq!(half.min(), Vec2 { x: -2., y: 0. }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(half.max(), Vec2 { x: 2., y: 2. }, epsilon = 1e-6);
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        // the chord joins the ends of the arc, so the arc's box holds it too
+        self.arc.bounding_box()
+    }
+
+    /// Creates a [`Polygon`] approximating the [`CircularSegment`] with `segments` edges along its [`Arc`], wound
+    /// counter-clockwise
+    ///
+    /// The chord closes the [`Polygon`] from the last coordinate back to the first. A whole circle leaves out the
+    /// repeated coordinate where its [`Arc`] meets itself. A `segments` below 1, or below 3 for a whole circle, is
+    /// clamped.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{CircularSegment, Winding};
+    ///
+    /// let half = CircularSegment::new(Vec2::ZERO, 1., FRAC_PI_2, FRAC_PI_2);
+    /// let polygon = half.to_polygon(64);
+    ///
+    /// assert_eq!(polygon.len(), 65);
+    /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+    /// assert_abs_diff_eq!(polygon.area(), PI / 2., epsilon = 1e-3);
+    /// assert_eq!(CircularSegment::new(Vec2::ZERO, 1., 0., PI).to_polygon(16).len(), 16);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn to_polygon(&self, segments: u32) -> Polygon {
+        if self.arc.sweep() >= TAU {
+            let segments = segments.max(3);
+            return (0..segments)
+                .map(|i| self.arc.point_at(i as f32 / segments as f32))
+                .collect();
+        }
+        self.arc.to_polyline(segments).into_iter().collect()
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the [`Arc`] two [`CircularSegment`]s are cut from within `epsilon`
+    CircularSegment { arc }
+);
+
+// ##########
+// Display impl
+// ##########
+impl Display for CircularSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CircularSegment {{ center: {}, radius: {}, direction: {}, half_angle: {} }}",
+            self.center(),
+            self.radius(),
+            self.direction(),
+            self.half_angle()
+        )
+    }
+}
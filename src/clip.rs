@@ -0,0 +1,61 @@
+use glam::Vec2;
+
+// Defines the clipping shared between shapes for one precision, here with `f32` and in `crate::double` with
+// `f64`, where the `Vec2` name in the bodies refers to `DVec2`
+macro_rules! impl_clip {
+    ($float:ident) => {
+        // A single step of Sutherland-Hodgman clipping, keeping the parts of `input` to the left of the edge
+        // from `start` to `end` (or to the right when `orientation` is negative). A `closed` ring of points
+        // also clips its edge from the last point back to the first, which an open chain of points doesn't have.
+        pub(crate) fn clip_against_edge(
+            input: &[Vec2],
+            closed: bool,
+            start: Vec2,
+            end: Vec2,
+            orientation: $float,
+            output: &mut Vec<Vec2>,
+        ) {
+            let edge = end - start;
+            let side = |point: Vec2| edge.perp_dot(point - start) * orientation;
+
+            for (index, current) in input.iter().enumerate() {
+                let current_side = side(*current);
+                if closed || index > 0 {
+                    let previous = input[(index + input.len() - 1) % input.len()];
+                    let previous_side = side(previous);
+                    if (current_side >= 0. && previous_side < 0.)
+                        || (current_side < 0. && previous_side > 0.)
+                    {
+                        output.push(
+                            previous.lerp(*current, previous_side / (previous_side - current_side)),
+                        );
+                    }
+                }
+                if current_side >= 0. {
+                    output.push(*current);
+                }
+            }
+        }
+    };
+}
+#[cfg(feature = "polygon")]
+pub(crate) use impl_clip;
+
+impl_clip!(f32);
+
+// The part of the segment to the left of the edge from `start` to `end`, or `None` when it lies wholly to the
+// right. A segment only touching the edge is clipped down to that one point.
+#[cfg(any(feature = "rectangle", all(feature = "half_plane", feature = "line")))]
+pub(crate) fn clip_segment_against_edge(
+    segment: [Vec2; 2],
+    start: Vec2,
+    end: Vec2,
+) -> Option<[Vec2; 2]> {
+    let mut clipped = Vec::with_capacity(2);
+    clip_against_edge(&segment, false, start, end, 1., &mut clipped);
+    match clipped[..] {
+        [] => None,
+        [point] => Some([point, point]),
+        [start, end, ..] => Some([start, end]),
+    }
+}
@@ -0,0 +1,596 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "polyline")]
+use crate::prelude::Polyline;
+#[cfg(feature = "quadratic_bezier")]
+use crate::prelude::QuadraticBezier;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+// The deepest a curve is halved while measuring its length
+const MAX_LENGTH_DEPTH: u32 = 16;
+// The most segments a curve is flattened into
+#[cfg(feature = "polyline")]
+const MAX_FLATTEN_SEGMENTS: f32 = 1024.;
+
+/// Represents a single cubic Bézier curve in 2d space
+///
+/// The curve leaves `start` heading towards `control1`, and arrives at `end` heading away from `control2`. It is
+/// parameterized by `t` from `0.` at `start` to `1.` at `end`, which is not proportional to the distance along
+/// the curve. Unlike a [`QuadraticBezier`](crate::prelude::QuadraticBezier), a [`CubicBezier`] can bend both
+/// ways, stop at a cusp, or loop over itself.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::CubicBezier;
+///
+/// let curve = CubicBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 2. }, Vec2 { x: 2., y: -2. }, Vec2 { x: 3., y: 0. });
+///
+/// assert_eq!(curve.point_at(0.5), Vec2 { x: 1.5, y: 0. });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    start: Vec2,
+    control1: Vec2,
+    control2: Vec2,
+    end: Vec2,
+}
+
+// ##########
+// Constructors
+// ##########
+impl CubicBezier {
+    /// Creates a new [`CubicBezier`] from `start` to `end`, pulled towards `control1` then `control2`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let curve = CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X);
+    ///
+    /// assert_eq!(curve.point_at(0.), Vec2::ZERO);
+    /// assert_eq!(curve.point_at(1.), Vec2::X);
+    /// ```
+    pub fn new(start: Vec2, control1: Vec2, control2: Vec2, end: Vec2) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+
+    /// Creates a new [`CubicBezier`] tracing exactly the same path as a [`QuadraticBezier`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{CubicBezier, QuadraticBezier};
+    ///
+    /// let quadratic = QuadraticBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 3. }, Vec2 { x: 4., y: 1. });
+    /// let cubic = CubicBezier::from_quadratic(&quadratic);
+    ///
+    /// for i in 0..=20 {
+    ///     let t = i as f32 / 20.;
+    ///     assert_abs_diff_eq!(cubic.point_at(t), quadratic.point_at(t), epsilon = 1e-5);
+    ///     assert_abs_diff_eq!(cubic.tangent_at(t), quadratic.tangent_at(t), epsilon = 1e-5);
+    /// }
+    /// ```
+    #[cfg(feature = "quadratic_bezier")]
+    pub fn from_quadratic(quadratic: &QuadraticBezier) -> Self {
+        let start = quadratic.start();
+        let control = quadratic.control();
+        let end = quadratic.end();
+        Self::new(
+            start,
+            start + (control - start) * (2. / 3.),
+            end + (control - end) * (2. / 3.),
+            end,
+        )
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl CubicBezier {
+    /// Get the `start` of the [`CubicBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// assert_eq!(CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X).start(), Vec2::ZERO);
+    /// ```
+    pub fn start(&self) -> Vec2 {
+        self.start
+    }
+
+    /// Get the first control point of the [`CubicBezier`], the one the curve leaves `start` towards
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// assert_eq!(CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X).control1(), Vec2::Y);
+    /// ```
+    pub fn control1(&self) -> Vec2 {
+        self.control1
+    }
+
+    /// Get the second control point of the [`CubicBezier`], the one the curve arrives at `end` from
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// assert_eq!(CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X).control2(), Vec2::ONE);
+    /// ```
+    pub fn control2(&self) -> Vec2 {
+        self.control2
+    }
+
+    /// Get the `end` of the [`CubicBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// assert_eq!(CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X).end(), Vec2::X);
+    /// ```
+    pub fn end(&self) -> Vec2 {
+        self.end
+    }
+
+    /// Set the `start` of the [`CubicBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let mut curve = CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X);
+    /// curve.set_start(Vec2::NEG_X);
+    ///
+    /// assert_eq!(curve.point_at(0.), Vec2::NEG_X);
+    /// ```
+    pub fn set_start(&mut self, start: Vec2) {
+        self.start = start;
+    }
+
+    /// Set the first control point of the [`CubicBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let mut curve = CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X);
+    /// curve.set_control1(Vec2::X);
+    ///
+    /// assert_eq!(curve.tangent_at(0.), Vec2 { x: 3., y: 0. });
+    /// ```
+    pub fn set_control1(&mut self, control1: Vec2) {
+        self.control1 = control1;
+    }
+
+    /// Set the second control point of the [`CubicBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let mut curve = CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X);
+    /// curve.set_control2(Vec2::ZERO);
+    ///
+    /// assert_eq!(curve.tangent_at(1.), Vec2 { x: 3., y: 0. });
+    /// ```
+    pub fn set_control2(&mut self, control2: Vec2) {
+        self.control2 = control2;
+    }
+
+    /// Set the `end` of the [`CubicBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let mut curve = CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X);
+    /// curve.set_end(Vec2::ONE);
+    ///
+    /// assert_eq!(curve.point_at(1.), Vec2::ONE);
+    /// ```
+    pub fn set_end(&mut self, end: Vec2) {
+        self.end = end;
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl CubicBezier {
+    /// Get the point at `t` along the [`CubicBezier`], clamping `t` between `0.` and `1.`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let curve = CubicBezier::new(Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 2., y: 0. });
+    ///
+    /// assert_eq!(curve.point_at(0.5), Vec2 { x: 1., y: 1.5 });
+    /// assert_eq!(curve.point_at(-1.), curve.start());
+    /// assert_eq!(curve.point_at(2.), curve.end());
+    /// ```
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        let t = t.clamp(0., 1.);
+        let s = 1. - t;
+        self.start * (s * s * s)
+            + self.control1 * (3. * s * s * t)
+            + self.control2 * (3. * s * t * t)
+            + self.end * (t * t * t)
+    }
+
+    /// Get the tangent at `t` along the [`CubicBezier`], clamping `t` between `0.` and `1.`
+    ///
+    /// The tangent is the derivative of the curve, so its length is how fast the point moves as `t` changes. It
+    /// is `(0., 0.)` where the curve stops, such as at a cusp.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let curve = CubicBezier::new(Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 2., y: 0. });
+    /// assert_eq!(curve.tangent_at(0.), Vec2 { x: 0., y: 6. });
+    /// assert_eq!(curve.tangent_at(0.5), Vec2 { x: 3., y: 0. });
+    ///
+    /// // a cusp, where the curve stops and turns back on itself
+    /// let cusp = CubicBezier::new(Vec2::ZERO, Vec2::ONE, Vec2::Y, Vec2::X);
+    /// assert_eq!(cusp.tangent_at(0.5), Vec2::ZERO);
+    /// ```
+    pub fn tangent_at(&self, t: f32) -> Vec2 {
+        let t = t.clamp(0., 1.);
+        let s = 1. - t;
+        (self.control1 - self.start) * (3. * s * s)
+            + (self.control2 - self.control1) * (6. * s * t)
+            + (self.end - self.control2) * (3. * t * t)
+    }
+
+    /// Splits the [`CubicBezier`] at `t` into two curves that together trace the same path, clamping `t`
+    /// between `0.` and `1.`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let curve = CubicBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 2. }, Vec2 { x: 2., y: -2. }, Vec2 { x: 3., y: 0. });
+    /// let (first, second) = curve.split_at(0.4);
+    ///
+    /// // the pieces re-join where the curve was split, heading the same way
+    /// assert_eq!(first.start(), curve.start());
+    /// assert_eq!(first.end(), second.start());
+    /// assert_eq!(second.end(), curve.end());
+    /// assert_abs_diff_eq!(first.end(), curve.point_at(0.4), epsilon = 1e-6);
+    /// assert_abs_diff_eq!(first.tangent_at(1.).normalize(), second.tangent_at(0.).normalize(), epsilon = 1e-6);
+    ///
+    /// // and follow the original curve
+    /// for i in 0..=10 {
+    ///     let t = i as f32 / 10.;
+    ///     assert_abs_diff_eq!(first.point_at(t), curve.point_at(0.4 * t), epsilon = 1e-5);
+    ///     assert_abs_diff_eq!(second.point_at(t), curve.point_at(0.4 + 0.6 * t), epsilon = 1e-5);
+    /// }
+    /// ```
+    pub fn split_at(&self, t: f32) -> (Self, Self) {
+        let t = t.clamp(0., 1.);
+        let a = self.start.lerp(self.control1, t);
+        let b = self.control1.lerp(self.control2, t);
+        let c = self.control2.lerp(self.end, t);
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        let middle = ab.lerp(bc, t);
+        (
+            Self::new(self.start, a, ab, middle),
+            Self::new(middle, bc, c, self.end),
+        )
+    }
+
+    /// Get the [`CubicBezier`] tracing the same path from `end` back to `start`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let curve = CubicBezier::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X);
+    /// let reversed = curve.reversed();
+    ///
+    /// assert_eq!(reversed.start(), curve.end());
+    /// assert_eq!(reversed.point_at(0.25), curve.point_at(0.75));
+    /// ```
+    pub fn reversed(&self) -> Self {
+        Self::new(self.end, self.control2, self.control1, self.start)
+    }
+
+    /// Get the length of the [`CubicBezier`], within `tolerance` of the true length
+    ///
+    /// The curve is halved until each piece is close enough to a straight line, to a depth of 16 halvings.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// // a quarter of a circle, approximated to within `0.03%` of its radius
+    /// let k = 0.5519150244935105;
+    /// let curve = CubicBezier::new(Vec2::X, Vec2 { x: 1., y: k }, Vec2 { x: k, y: 1. }, Vec2::Y);
+    /// assert_abs_diff_eq!(curve.length(1e-4), std::f32::consts::FRAC_PI_2, epsilon = 1e-3);
+    ///
+    /// // a straight curve is as long as its chord
+    /// let line = CubicBezier::new(Vec2::ZERO, Vec2::X, Vec2 { x: 2., y: 0. }, Vec2 { x: 3., y: 0. });
+    /// assert_eq!(line.length(1e-3), 3.);
+    /// ```
+    pub fn length(&self, tolerance: f32) -> f32 {
+        subdivided_length(self, tolerance, MAX_LENGTH_DEPTH)
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`CubicBezier`]
+    ///
+    /// The curve may be well inside of the [`Rectangle`] around its `start`, controls and `end`, as only the
+    /// turning points of the curve are used.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// // an S-curve, `y = 6t(1 - t)(1 - 2t)`, turning at `t = (3 ± √3) / 6`
+    /// let curve = CubicBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 2. }, Vec2 { x: 2., y: -2. }, Vec2 { x: 3., y: 0. });
+    /// let rect = curve.bounding_box();
+    /// let peak = 3f32.sqrt() / 3.;
+    ///
+    /// assert_abs_diff_eq!(rect.min(), Vec2 { x: 0., y: -peak }, epsilon = 1e-5);
+    /// assert_abs_diff_eq!(rect.max(), Vec2 { x: 3., y: peak }, epsilon = 1e-5);
+    ///
+    /// // a curve that loops over itself, past its start and end
+    /// let curve = CubicBezier::new(Vec2::ZERO, Vec2 { x: 4., y: 2. }, Vec2 { x: -3., y: 2. }, Vec2::X);
+    /// let rect = curve.bounding_box();
+    ///
+    /// assert!(rect.min().x < 0. && rect.max().x > 1.);
+    /// for i in 0..=1000 {
+    ///     let point = curve.point_at(i as f32 / 1000.);
+    ///     assert!(point.cmpge(rect.min() - 1e-6).all() && point.cmple(rect.max() + 1e-6).all());
+    /// }
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let mut min = self.start.min(self.end);
+        let mut max = self.start.max(self.end);
+
+        // the curve only turns back where the tangent is `0.` along an axis, which is a quadratic in `t`
+        let a = self.control1 - self.start;
+        let b = self.control2 - self.control1;
+        let c = self.end - self.control2;
+        let quadratic = a - 2. * b + c;
+        let linear = 2. * (b - a);
+        for (quadratic, linear, constant) in
+            [(quadratic.x, linear.x, a.x), (quadratic.y, linear.y, a.y)]
+        {
+            for t in quadratic_roots(quadratic, linear, constant)
+                .into_iter()
+                .flatten()
+            {
+                if t > 0. && t < 1. {
+                    let point = self.point_at(t);
+                    min = min.min(point);
+                    max = max.max(point);
+                }
+            }
+        }
+
+        Rectangle::new_coordinates(min, max)
+    }
+
+    /// Creates a [`Polyline`] following the [`CubicBezier`], never more than `tolerance` away from it
+    ///
+    /// The curve is split into equal steps of `t`, as few as keep every point of the [`Polyline`] within
+    /// `tolerance` of the curve and every point of the curve within `tolerance` of the [`Polyline`], which also
+    /// holds through cusps and loops. The curve is never split into more than 1024 segments, which only limits
+    /// a `tolerance` that is tiny next to the size of the curve, or one that is not positive.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// let curves = [
+    ///     CubicBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 2. }, Vec2 { x: 2., y: -2. }, Vec2 { x: 3., y: 0. }),
+    ///     // a cusp
+    ///     CubicBezier::new(Vec2::ZERO, Vec2::ONE, Vec2::Y, Vec2::X),
+    ///     // a loop
+    ///     CubicBezier::new(Vec2::ZERO, Vec2 { x: 4., y: 2. }, Vec2 { x: -3., y: 2. }, Vec2::X),
+    /// ];
+    ///
+    /// for curve in curves {
+    ///     for tolerance in [0.5, 0.1, 0.01] {
+    ///         let polyline = curve.flatten(tolerance);
+    ///
+    ///         assert_eq!(polyline.coordinate(0), Some(curve.start()));
+    ///         assert_eq!(polyline.coordinate(polyline.len() - 1), Some(curve.end()));
+    ///         for i in 0..=1000 {
+    ///             let point = curve.point_at(i as f32 / 1000.);
+    ///             assert!(polyline.distance_to_point(point).unwrap() <= tolerance + 1e-5);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // a straight curve needs a single segment
+    /// let line = CubicBezier::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }, Vec2 { x: 3., y: 3. });
+    /// assert_eq!(line.flatten(0.1).len(), 2);
+    /// ```
+    #[cfg(feature = "polyline")]
+    pub fn flatten(&self, tolerance: f32) -> Polyline {
+        // Wang's formula, a piece of the curve spanning `h` of `t` strays at most `3. / 4. * bend * h * h` from
+        // its chord, and the chord at most as far from the piece
+        let bend = (self.start - 2. * self.control1 + self.control2)
+            .length()
+            .max((self.control1 - 2. * self.control2 + self.end).length());
+        let segments = if tolerance > 0. {
+            (0.75 * bend / tolerance)
+                .sqrt()
+                .ceil()
+                .clamp(1., MAX_FLATTEN_SEGMENTS) as u32
+        } else {
+            MAX_FLATTEN_SEGMENTS as u32
+        };
+
+        let mut polyline: Polyline = (0..segments)
+            .map(|i| self.point_at(i as f32 / segments as f32))
+            .collect();
+        polyline.push(self.end);
+        polyline
+    }
+
+    /// Get the `t` of the point of the [`CubicBezier`] closest to `point`
+    ///
+    /// The curve is sampled to find a starting guess, which is then refined with Newton's method.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::CubicBezier;
+    ///
+    /// // an S-curve along `x = 3t`
+    /// let curve = CubicBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 2. }, Vec2 { x: 2., y: -2. }, Vec2 { x: 3., y: 0. });
+    ///
+    /// assert_eq!(curve.closest_t(Vec2 { x: -1., y: 0. }), 0.);
+    /// assert_eq!(curve.closest_t(Vec2 { x: 4., y: 1. }), 1.);
+    ///
+    /// // on the curve, and off to the side along the normal there
+    /// let t = 0.3;
+    /// let normal = curve.tangent_at(t).perp().normalize();
+    /// assert_abs_diff_eq!(curve.closest_t(curve.point_at(t)), t, epsilon = 1e-5);
+    /// assert_abs_diff_eq!(curve.closest_t(curve.point_at(t) + normal * 0.1), t, epsilon = 1e-4);
+    /// ```
+    pub fn closest_t(&self, point: Vec2) -> f32 {
+        const SAMPLES: u32 = 32;
+        const ITERATIONS: u32 = 8;
+
+        let distance = |t: f32| self.point_at(t).distance_squared(point);
+        let mut best = (1..=SAMPLES)
+            .map(|i| i as f32 / SAMPLES as f32)
+            .fold(0., |best, t| {
+                if distance(t) < distance(best) {
+                    t
+                } else {
+                    best
+                }
+            });
+
+        // Newton's method on the slope of the squared distance
+        for _ in 0..ITERATIONS {
+            let offset = self.point_at(best) - point;
+            let tangent = self.tangent_at(best);
+            let slope = tangent.length_squared() + offset.dot(self.acceleration_at(best));
+            if slope <= 0. {
+                break;
+            }
+            let next = (best - offset.dot(tangent) / slope).clamp(0., 1.);
+            if distance(next) > distance(best) {
+                break;
+            }
+            let converged = (next - best).abs() < 1e-7;
+            best = next;
+            if converged {
+                break;
+            }
+        }
+        best
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the four control points of two [`CubicBezier`] curves in order within `epsilon`, so a reversed curve is
+    /// not close
+    CubicBezier { start, control1, control2, end }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for CubicBezier {
+    fn default() -> Self {
+        Self {
+            start: Vec2::ZERO,
+            control1: Vec2 { x: 0., y: 1. },
+            control2: Vec2 { x: 1., y: 1. },
+            end: Vec2 { x: 1., y: 0. },
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for CubicBezier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CubicBezier {{ start: {}, control1: {}, control2: {}, end: {} }}",
+            self.start(),
+            self.control1(),
+            self.control2(),
+            self.end()
+        )
+    }
+}
+
+// ##########
+// Helpers
+// ##########
+impl CubicBezier {
+    // The second derivative of the curve at `t`
+    fn acceleration_at(&self, t: f32) -> Vec2 {
+        let t = t.clamp(0., 1.);
+        (self.control2 - 2. * self.control1 + self.start) * (6. * (1. - t))
+            + (self.end - 2. * self.control2 + self.control1) * (6. * t)
+    }
+}
+
+// The real roots of `quadratic * t * t + linear * t + constant`, falling back to the linear root when the curve
+// is too flat for the quadratic term to matter
+#[cfg(feature = "rectangle")]
+fn quadratic_roots(quadratic: f32, linear: f32, constant: f32) -> [Option<f32>; 2] {
+    if quadratic.abs() <= f32::EPSILON * (linear.abs() + constant.abs()) {
+        if linear == 0. {
+            return [None, None];
+        }
+        return [Some(-constant / linear), None];
+    }
+    let discriminant = linear * linear - 4. * quadratic * constant;
+    if discriminant < 0. {
+        return [None, None];
+    }
+    let root = discriminant.sqrt();
+    [
+        Some((-linear + root) / (2. * quadratic)),
+        Some((-linear - root) / (2. * quadratic)),
+    ]
+}
+
+// The length of `curve` within `tolerance`, which lies between its chord and the length of its control polygon
+fn subdivided_length(curve: &CubicBezier, tolerance: f32, depth: u32) -> f32 {
+    let chord = curve.start.distance(curve.end);
+    let hull = curve.start.distance(curve.control1)
+        + curve.control1.distance(curve.control2)
+        + curve.control2.distance(curve.end);
+    if hull - chord <= tolerance || depth == 0 {
+        return (chord + hull) / 2.;
+    }
+    let (first, second) = curve.split_at(0.5);
+    subdivided_length(&first, tolerance / 2., depth - 1)
+        + subdivided_length(&second, tolerance / 2., depth - 1)
+}
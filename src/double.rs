@@ -0,0 +1,768 @@
+#[cfg(any(feature = "circle", feature = "rectangle"))]
+use glam::DVec2;
+
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "ellipse")]
+use crate::prelude::Ellipse;
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "point")]
+use crate::prelude::Point;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "ray")]
+use crate::prelude::Ray;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "triangle")]
+use crate::prelude::Triangle;
+
+// ##########
+// DPoint
+// ##########
+#[cfg(feature = "point")]
+mod point {
+    use std::fmt::Display;
+
+    use glam::DVec2 as Vec2;
+
+    use self::DPoint as Point;
+
+    crate::point::impl_point!(
+        /// Represents a single [`Point`](crate::prelude::Point) in 2d space with `f64` precision
+        DPoint,
+        f64;
+        "use glam::DVec2 as Vec2;\nuse shapes2d::prelude::{DPoint as Point, *};"
+    );
+}
+#[cfg(feature = "point")]
+pub use point::DPoint;
+
+#[cfg(feature = "point")]
+impl DPoint {
+    /// Get the [`Point`] with `f32` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::DPoint;
+    ///
+    /// assert_eq!(DPoint::new(DVec2 { x: 1.5, y: 2. }).to_f32().coordinate(), Vec2 { x: 1.5, y: 2. });
+    /// ```
+    pub fn to_f32(&self) -> Point {
+        Point::new(self.coordinate().as_vec2())
+    }
+}
+
+#[cfg(feature = "point")]
+impl Point {
+    /// Get the [`DPoint`] with `f64` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::Point;
+    ///
+    /// assert_eq!(Point::new(Vec2 { x: 1.5, y: 2. }).to_f64().coordinate(), DVec2 { x: 1.5, y: 2. });
+    /// ```
+    pub fn to_f64(&self) -> DPoint {
+        DPoint::new(self.coordinate().as_dvec2())
+    }
+}
+
+// ##########
+// DLine
+// ##########
+#[cfg(feature = "line")]
+mod line {
+    use std::fmt::Display;
+
+    use glam::DVec2 as Vec2;
+
+    use self::DLine as Line;
+
+    crate::line::impl_line!(
+        /// Represents a [`Line`](crate::prelude::Line) in 2d space with `f64` precision
+        DLine,
+        f64;
+        "use glam::DVec2 as Vec2;\nuse shapes2d::prelude::{DLine as Line, *};"
+    );
+}
+#[cfg(feature = "line")]
+pub use line::DLine;
+
+#[cfg(feature = "line")]
+impl DLine {
+    /// Get the [`Line`] with `f32` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::DLine;
+    ///
+    /// let line = DLine::new(DVec2::ZERO, DVec2::ONE).to_f32();
+    ///
+    /// assert_eq!((line.origin(), line.end()), (Vec2::ZERO, Vec2::ONE));
+    /// ```
+    pub fn to_f32(&self) -> Line {
+        Line::new(self.origin().as_vec2(), self.end().as_vec2())
+    }
+}
+
+#[cfg(feature = "line")]
+impl Line {
+    /// Get the [`DLine`] with `f64` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::Line;
+    ///
+    /// let line = Line::new(Vec2::ZERO, Vec2::ONE).to_f64();
+    ///
+    /// assert_eq!((line.origin(), line.end()), (DVec2::ZERO, DVec2::ONE));
+    /// ```
+    pub fn to_f64(&self) -> DLine {
+        DLine::new(self.origin().as_dvec2(), self.end().as_dvec2())
+    }
+}
+
+// ##########
+// DRay
+// ##########
+#[cfg(feature = "ray")]
+mod ray {
+    use std::fmt::Display;
+
+    use glam::DVec2 as Vec2;
+
+    use self::DRay as Ray;
+
+    crate::ray::impl_ray!(
+        /// Represents a [`Ray`](crate::prelude::Ray) in 2d space with `f64` precision
+        DRay,
+        f64;
+        "use glam::DVec2 as Vec2;\nuse shapes2d::prelude::{DRay as Ray, *};"
+    );
+}
+#[cfg(feature = "ray")]
+pub use ray::DRay;
+
+#[cfg(feature = "ray")]
+impl DRay {
+    /// Get the [`Ray`] with `f32` precision
+    ///
+    /// The direction is normalized again, as it may be off by a rounding error in `f32`.
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::DRay;
+    ///
+    /// let ray = DRay::new_direction(DVec2 { x: 1.5, y: 2. }, DVec2::Y).to_f32();
+    ///
+    /// assert_eq!((ray.origin(), ray.direction()), (Vec2 { x: 1.5, y: 2. }, Vec2::Y));
+    /// ```
+    pub fn to_f32(&self) -> Ray {
+        Ray::new_direction(self.origin().as_vec2(), self.direction().as_vec2())
+    }
+}
+
+#[cfg(feature = "ray")]
+impl Ray {
+    /// Get the [`DRay`] with `f64` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::Ray;
+    ///
+    /// let ray = Ray::new_direction(Vec2 { x: 1.5, y: 2. }, Vec2::Y).to_f64();
+    ///
+    /// assert_eq!((ray.origin(), ray.direction()), (DVec2 { x: 1.5, y: 2. }, DVec2::Y));
+    /// ```
+    pub fn to_f64(&self) -> DRay {
+        DRay::new_direction(self.origin().as_dvec2(), self.direction().as_dvec2())
+    }
+}
+
+// ##########
+// DCircle
+// ##########
+#[cfg(feature = "circle")]
+mod circle {
+    use std::fmt::Display;
+
+    use glam::DVec2 as Vec2;
+
+    use self::DCircle as Circle;
+    #[cfg(feature = "line")]
+    use super::DLine as Line;
+    #[cfg(feature = "mesh")]
+    use super::DMesh as Mesh;
+    #[cfg(feature = "polygon")]
+    use super::DPolygon as Polygon;
+
+    crate::circle::impl_circle!(
+        /// Represents a single [`Circle`](crate::prelude::Circle) in 2d space with `f64` precision
+        DCircle,
+        f64;
+        "use glam::DVec2 as Vec2;\nuse shapes2d::prelude::{DCircle as Circle, *};\n# #[cfg(feature = \"line\")]\n# use shapes2d::prelude::DLine as Line;\n# #[cfg(feature = \"mesh\")]\n# use shapes2d::prelude::DMesh as Mesh;\n# #[cfg(feature = \"polygon\")]\n# use shapes2d::prelude::DPolygon as Polygon;"
+    );
+}
+#[cfg(feature = "circle")]
+pub use circle::DCircle;
+
+#[cfg(feature = "circle")]
+impl DCircle {
+    /// Get the area of the [`DCircle`]
+    ///
+    /// ```
+    /// use glam::DVec2;
+    /// use shapes2d::prelude::DCircle;
+    ///
+    /// assert_eq!(DCircle::new(DVec2::ZERO, 2.).area(), std::f64::consts::PI * 4.);
+    /// ```
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius() * self.radius()
+    }
+
+    /// Get the perimeter of the [`DCircle`]
+    ///
+    /// ```
+    /// use glam::DVec2;
+    /// use shapes2d::prelude::DCircle;
+    ///
+    /// assert_eq!(DCircle::new(DVec2::ZERO, 2.).perimeter(), std::f64::consts::TAU * 2.);
+    /// ```
+    pub fn perimeter(&self) -> f64 {
+        std::f64::consts::TAU * self.radius()
+    }
+
+    /// Checks if `point` is inside of the [`DCircle`] or on its boundary
+    ///
+    /// ```
+    /// use glam::DVec2;
+    /// use shapes2d::prelude::DCircle;
+    ///
+    /// let circle = DCircle::new(DVec2::ZERO, 2.);
+    ///
+    /// assert!(circle.contains(DVec2 { x: 2., y: 0. }));
+    /// assert!(!circle.contains(DVec2 { x: 2., y: 0.1 }));
+    /// ```
+    pub fn contains(&self, point: DVec2) -> bool {
+        self.center().distance_squared(point) <= self.radius() * self.radius()
+    }
+
+    /// Checks if the [`DCircle`] touches or overlaps `other`
+    ///
+    /// ```
+    /// use glam::DVec2;
+    /// use shapes2d::prelude::DCircle;
+    ///
+    /// let circle = DCircle::new(DVec2::ZERO, 2.);
+    ///
+    /// assert!(circle.intersects(&DCircle::new(DVec2 { x: 3., y: 0. }, 1.)));
+    /// assert!(!circle.intersects(&DCircle::new(DVec2 { x: 3.1, y: 0. }, 1.)));
+    /// ```
+    pub fn intersects(&self, other: &DCircle) -> bool {
+        let radii = self.radius() + other.radius();
+        self.center().distance_squared(other.center()) <= radii * radii
+    }
+
+    /// Get the [`Circle`] with `f32` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::DCircle;
+    ///
+    /// let circle = DCircle::new(DVec2::ONE, 2.).to_f32();
+    ///
+    /// assert_eq!((circle.center(), circle.radius()), (Vec2::ONE, 2.));
+    /// ```
+    pub fn to_f32(&self) -> Circle {
+        Circle::new(self.center().as_vec2(), self.radius() as f32)
+    }
+}
+
+#[cfg(feature = "circle")]
+impl Circle {
+    /// Get the [`DCircle`] with `f64` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::Circle;
+    ///
+    /// let circle = Circle::new(Vec2::ONE, 2.).to_f64();
+    ///
+    /// assert_eq!((circle.center(), circle.radius()), (DVec2::ONE, 2.));
+    /// ```
+    pub fn to_f64(&self) -> DCircle {
+        DCircle::new(self.center().as_dvec2(), self.radius() as f64)
+    }
+}
+
+// ##########
+// DEllipse
+// ##########
+#[cfg(feature = "ellipse")]
+mod ellipse {
+    use std::fmt::Display;
+
+    use glam::DVec2 as Vec2;
+
+    use self::DEllipse as Ellipse;
+    #[cfg(feature = "mesh")]
+    use super::DMesh as Mesh;
+    #[cfg(feature = "polygon")]
+    use super::DPolygon as Polygon;
+
+    crate::ellipse::impl_ellipse!(
+        /// Represents a single [`Ellipse`](crate::prelude::Ellipse) in 2d space with `f64` precision
+        DEllipse,
+        f64;
+        "use glam::DVec2 as Vec2;\nuse shapes2d::prelude::{DEllipse as Ellipse, *};\n# #[cfg(feature = \"mesh\")]\n# use shapes2d::prelude::DMesh as Mesh;\n# #[cfg(feature = \"polygon\")]\n# use shapes2d::prelude::DPolygon as Polygon;"
+    );
+}
+#[cfg(feature = "ellipse")]
+pub use ellipse::DEllipse;
+
+#[cfg(feature = "ellipse")]
+impl DEllipse {
+    /// Get the [`Ellipse`] with `f32` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::DEllipse;
+    ///
+    /// let ellipse = DEllipse::new_rotated(DVec2::ONE, 2., 1., 0.5).to_f32();
+    ///
+    /// assert_eq!(
+    ///     (ellipse.center(), ellipse.radius_major(), ellipse.radius_minor(), ellipse.rotation()),
+    ///     (Vec2::ONE, 2., 1., 0.5)
+    /// );
+    /// ```
+    pub fn to_f32(&self) -> Ellipse {
+        Ellipse::new_rotated(
+            self.center().as_vec2(),
+            self.radius_major() as f32,
+            self.radius_minor() as f32,
+            self.rotation() as f32,
+        )
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl Ellipse {
+    /// Get the [`DEllipse`] with `f64` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// let ellipse = Ellipse::new_rotated(Vec2::ONE, 2., 1., 0.5).to_f64();
+    ///
+    /// assert_eq!(
+    ///     (ellipse.center(), ellipse.radius_major(), ellipse.radius_minor(), ellipse.rotation()),
+    ///     (DVec2::ONE, 2., 1., 0.5)
+    /// );
+    /// ```
+    pub fn to_f64(&self) -> DEllipse {
+        DEllipse::new_rotated(
+            self.center().as_dvec2(),
+            f64::from(self.radius_major()),
+            f64::from(self.radius_minor()),
+            f64::from(self.rotation()),
+        )
+    }
+}
+
+// ##########
+// DRectangle
+// ##########
+#[cfg(feature = "rectangle")]
+mod rectangle {
+    use std::fmt::Display;
+
+    use glam::DVec2 as Vec2;
+
+    use self::DRectangle as Rectangle;
+    #[cfg(feature = "mesh")]
+    use super::DMesh as Mesh;
+
+    crate::rectangle::impl_rectangle!(
+        /// Represents a single [`Rectangle`](crate::prelude::Rectangle) in 2d space with `f64` precision
+        DRectangle,
+        DSweepHit,
+        f64;
+        "use glam::DVec2 as Vec2;\nuse shapes2d::prelude::{DRectangle as Rectangle, DSweepHit as SweepHit, *};\n# #[cfg(feature = \"mesh\")]\n# use shapes2d::prelude::DMesh as Mesh;"
+    );
+}
+#[cfg(feature = "rectangle")]
+pub use rectangle::{DRectangle, DSweepHit};
+
+#[cfg(feature = "rectangle")]
+impl DRectangle {
+    /// Get the area of the [`DRectangle`]
+    ///
+    /// ```
+    /// use shapes2d::prelude::DRectangle;
+    ///
+    /// assert_eq!(DRectangle::new(1., 2., 4., 6.).area(), 12.);
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    /// Get the perimeter of the [`DRectangle`]
+    ///
+    /// ```
+    /// use shapes2d::prelude::DRectangle;
+    ///
+    /// assert_eq!(DRectangle::new(1., 2., 4., 6.).perimeter(), 14.);
+    /// ```
+    pub fn perimeter(&self) -> f64 {
+        (self.width() + self.height()) * 2.
+    }
+
+    /// Checks if `point` is inside of the [`DRectangle`] or on its boundary
+    ///
+    /// ```
+    /// use glam::DVec2;
+    /// use shapes2d::prelude::DRectangle;
+    ///
+    /// let rect = DRectangle::new(0., 0., 2., 1.);
+    ///
+    /// assert!(rect.contains(DVec2 { x: 2., y: 0.5 }));
+    /// assert!(!rect.contains(DVec2 { x: 2.5, y: 0.5 }));
+    /// ```
+    pub fn contains(&self, point: DVec2) -> bool {
+        point.cmpge(self.min()).all() && point.cmple(self.max()).all()
+    }
+
+    /// Checks if the [`DRectangle`] touches or overlaps `other`
+    ///
+    /// ```
+    /// use shapes2d::prelude::DRectangle;
+    ///
+    /// let rect = DRectangle::new(0., 0., 2., 1.);
+    ///
+    /// assert!(rect.intersects(&DRectangle::new(2., 1., 3., 3.)));
+    /// assert!(!rect.intersects(&DRectangle::new(2.5, 0., 3., 3.)));
+    /// ```
+    pub fn intersects(&self, other: &DRectangle) -> bool {
+        self.min().cmple(other.max()).all() && other.min().cmple(self.max()).all()
+    }
+
+    /// Get the [`Rectangle`] with `f32` precision
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::DRectangle;
+    ///
+    /// let rect = DRectangle::new(0., 0., 2., 1.).to_f32();
+    ///
+    /// assert_eq!((rect.min(), rect.max()), (Vec2::ZERO, Vec2 { x: 2., y: 1. }));
+    /// ```
+    pub fn to_f32(&self) -> Rectangle {
+        Rectangle::new_coordinates(self.min().as_vec2(), self.max().as_vec2())
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Rectangle {
+    /// Get the [`DRectangle`] with `f64` precision
+    ///
+    /// Large coordinates lose precision in `f32`, so convert before moving a shape far from the origin.
+    ///
+    /// ```
+    /// use glam::DVec2;
+    /// use shapes2d::prelude::{DRectangle, Rectangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 2., 1.).to_f64();
+    /// assert_eq!((rect.min(), rect.max()), (DVec2::ZERO, DVec2 { x: 2., y: 1. }));
+    ///
+    /// // a metre wide box a hundred thousand kilometres away
+    /// assert_eq!(Rectangle::new(1e8, 0., 1e8 + 1., 1.).width(), 0.);
+    /// assert_eq!(DRectangle::new(1e8, 0., 1e8 + 1., 1.).width(), 1.);
+    /// ```
+    pub fn to_f64(&self) -> DRectangle {
+        DRectangle::new_coordinates(self.min().as_dvec2(), self.max().as_dvec2())
+    }
+}
+
+// ##########
+// DTriangle
+// ##########
+#[cfg(feature = "triangle")]
+mod triangle {
+    use std::fmt::Display;
+
+    use glam::{DVec2 as Vec2, DVec3 as Vec3};
+
+    use self::DTriangle as Triangle;
+    #[cfg(feature = "circle")]
+    use super::DCircle as Circle;
+    #[cfg(feature = "line")]
+    use super::DLine as Line;
+    #[cfg(feature = "mesh")]
+    use super::DMesh as Mesh;
+    #[cfg(feature = "rectangle")]
+    use super::DRectangle as Rectangle;
+    use crate::prelude::Winding;
+
+    crate::triangle::impl_triangle!(
+        /// Represents a single [`Triangle`](crate::prelude::Triangle) in 2d space with `f64` precision
+        DTriangle,
+        f64;
+        "use glam::{DVec2 as Vec2, DVec3 as Vec3};\nuse shapes2d::prelude::{DTriangle as Triangle, *};\n# #[cfg(feature = \"circle\")]\n# use shapes2d::prelude::DCircle as Circle;\n# #[cfg(feature = \"line\")]\n# use shapes2d::prelude::DLine as Line;\n# #[cfg(feature = \"mesh\")]\n# use shapes2d::prelude::DMesh as Mesh;\n# #[cfg(feature = \"rectangle\")]\n# use shapes2d::prelude::DRectangle as Rectangle;\n# use std::f64::consts::{FRAC_PI_2, FRAC_PI_3, PI};"
+    );
+}
+#[cfg(feature = "triangle")]
+pub use triangle::DTriangle;
+
+#[cfg(feature = "triangle")]
+impl DTriangle {
+    /// Get the perimeter of the [`DTriangle`]
+    ///
+    /// ```
+    /// use glam::DVec2;
+    /// use shapes2d::prelude::DTriangle;
+    ///
+    /// let triangle = DTriangle::new(DVec2::ZERO, DVec2 { x: 3., y: 0. }, DVec2 { x: 0., y: 4. });
+    ///
+    /// assert_eq!(triangle.perimeter(), 12.);
+    /// ```
+    pub fn perimeter(&self) -> f64 {
+        self.coordinate1().distance(self.coordinate2())
+            + self.coordinate2().distance(self.coordinate3())
+            + self.coordinate3().distance(self.coordinate1())
+    }
+
+    /// Get the [`Triangle`] with `f32` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::DTriangle;
+    ///
+    /// let triangle = DTriangle::new(DVec2::ZERO, DVec2::X, DVec2::Y).to_f32();
+    ///
+    /// assert_eq!(triangle.vertices(), [Vec2::ZERO, Vec2::X, Vec2::Y]);
+    /// ```
+    pub fn to_f32(&self) -> Triangle {
+        let [a, b, c] = self.vertices().map(|vertex| vertex.as_vec2());
+        Triangle::new(a, b, c)
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl Triangle {
+    /// Get the [`DTriangle`] with `f64` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::Triangle;
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2::X, Vec2::Y).to_f64();
+    ///
+    /// assert_eq!(triangle.vertices(), [DVec2::ZERO, DVec2::X, DVec2::Y]);
+    /// ```
+    pub fn to_f64(&self) -> DTriangle {
+        let [a, b, c] = self.vertices().map(|vertex| vertex.as_dvec2());
+        DTriangle::new(a, b, c)
+    }
+}
+
+// ##########
+// DPolygon
+// ##########
+#[cfg(feature = "polygon")]
+// The triangulation is shared with `PolygonWithHoles`, which only uses the `f32` one
+#[cfg_attr(not(any(feature = "mesh", feature = "rand")), allow(dead_code))]
+mod polygon {
+    use std::fmt::Display;
+
+    use glam::DVec2 as Vec2;
+
+    use self::DPolygon as Polygon;
+    use self::DPolygonDefect as PolygonDefect;
+    #[cfg(feature = "rand")]
+    use self::DPolygonSampler as PolygonSampler;
+    #[cfg(feature = "line")]
+    use self::DSplitResult as SplitResult;
+    #[cfg(feature = "circle")]
+    use super::DCircle as Circle;
+    #[cfg(feature = "line")]
+    use super::DLine as Line;
+    #[cfg(feature = "mesh")]
+    use super::DMesh as Mesh;
+    #[cfg(feature = "rectangle")]
+    use super::DRectangle as Rectangle;
+    #[cfg(feature = "triangle")]
+    use super::DTriangle as Triangle;
+    use crate::prelude::Winding;
+
+    crate::clip::impl_clip!(f64);
+
+    crate::polygon::impl_polygon!(
+        /// Represents a single [`Polygon`](crate::prelude::Polygon) in 2d space with `f64` precision
+        DPolygon,
+        DPolygonDefect,
+        DSplitResult,
+        DPolygonSampler,
+        f64;
+        "use glam::DVec2 as Vec2;\nuse shapes2d::prelude::{DPolygon as Polygon, DPolygonDefect as PolygonDefect, *};\n# #[cfg(feature = \"circle\")]\n# use shapes2d::prelude::DCircle as Circle;\n# #[cfg(feature = \"line\")]\n# use shapes2d::prelude::{DLine as Line, DSplitResult as SplitResult};\n# #[cfg(feature = \"mesh\")]\n# use shapes2d::prelude::DMesh as Mesh;\n# #[cfg(feature = \"rectangle\")]\n# use shapes2d::prelude::DRectangle as Rectangle;\n# #[cfg(feature = \"triangle\")]\n# use shapes2d::prelude::DTriangle as Triangle;\n# #[cfg(feature = \"rand\")]\n# use shapes2d::prelude::DPolygonSampler as PolygonSampler;"
+    );
+}
+#[cfg(all(feature = "polygon", feature = "rand"))]
+pub use polygon::DPolygonSampler;
+#[cfg(all(feature = "polygon", feature = "line"))]
+pub use polygon::DSplitResult;
+#[cfg(feature = "polygon")]
+pub use polygon::{DPolygon, DPolygonDefect};
+
+#[cfg(feature = "polygon")]
+impl DPolygon {
+    /// Get the [`Polygon`] with `f32` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::DPolygon;
+    ///
+    /// let polygon = DPolygon::new(vec![DVec2::ZERO, DVec2::X, DVec2::Y]).to_f32();
+    ///
+    /// assert_eq!(polygon.coordinates(), &[Vec2::ZERO, Vec2::X, Vec2::Y]);
+    /// ```
+    pub fn to_f32(&self) -> Polygon {
+        Polygon::new(
+            self.coordinates()
+                .iter()
+                .map(|coordinate| coordinate.as_vec2())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Polygon {
+    /// Get the [`DPolygon`] with `f64` precision
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::Polygon;
+    ///
+    /// let polygon = Polygon::new(vec![Vec2::ZERO, Vec2::X, Vec2::Y]).to_f64();
+    ///
+    /// assert_eq!(polygon.coordinates(), &[DVec2::ZERO, DVec2::X, DVec2::Y]);
+    /// ```
+    pub fn to_f64(&self) -> DPolygon {
+        DPolygon::new(
+            self.coordinates()
+                .iter()
+                .map(|coordinate| coordinate.as_dvec2())
+                .collect(),
+        )
+    }
+}
+
+// ##########
+// DMesh
+// ##########
+#[cfg(feature = "mesh")]
+mod mesh {
+    use std::{collections::HashMap, fmt::Display};
+
+    use glam::{DAffine2 as Affine2, DVec2 as Vec2, DVec2};
+
+    use self::DMesh as Mesh;
+    #[cfg(feature = "polygon")]
+    use super::polygon::triangulate_polygon;
+    #[cfg(feature = "circle")]
+    use super::DCircle as Circle;
+    #[cfg(feature = "ellipse")]
+    use super::DEllipse as Ellipse;
+    #[cfg(feature = "polygon")]
+    use super::DPolygon as Polygon;
+    #[cfg(feature = "rectangle")]
+    use super::DRectangle as Rectangle;
+    #[cfg(feature = "triangle")]
+    use super::DTriangle as Triangle;
+    use crate::mesh::INFINITE;
+    use crate::prelude::{MeshDefect, TriangulationError};
+
+    crate::mesh::impl_mesh!(
+        /// Represents a triangle [`Mesh`](crate::prelude::Mesh) in 2d space with `f64` precision
+        ///
+        /// The colors keep `f32` precision, the same as in a [`Mesh`](crate::prelude::Mesh).
+        DMesh,
+        f64;
+        "use glam::{DAffine2 as Affine2, DVec2 as Vec2};\nuse shapes2d::prelude::{DMesh as Mesh, *};\n# #[cfg(feature = \"circle\")]\n# use shapes2d::prelude::DCircle as Circle;\n# #[cfg(feature = \"ellipse\")]\n# use shapes2d::prelude::DEllipse as Ellipse;\n# #[cfg(feature = \"polygon\")]\n# use shapes2d::prelude::DPolygon as Polygon;\n# #[cfg(feature = \"rectangle\")]\n# use shapes2d::prelude::DRectangle as Rectangle;\n# #[cfg(feature = \"triangle\")]\n# use shapes2d::prelude::DTriangle as Triangle;"
+    );
+}
+#[cfg(feature = "mesh")]
+pub use mesh::DMesh;
+
+#[cfg(feature = "mesh")]
+impl DMesh {
+    /// Get the [`Mesh`] with `f32` precision
+    ///
+    /// The indices and colors are kept as they are, even when they aren't valid.
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::DMesh;
+    ///
+    /// let mut mesh = DMesh::new(vec![DVec2::ZERO, DVec2::X, DVec2::Y], vec![0, 1, 2]).unwrap();
+    /// mesh.fill_color([1., 0., 0., 1.]);
+    /// let mesh = mesh.to_f32();
+    ///
+    /// assert_eq!(mesh.positions(), &[Vec2::ZERO, Vec2::X, Vec2::Y]);
+    /// assert_eq!(mesh.indices(), &[0, 1, 2]);
+    /// assert_eq!(mesh.colors(), Some(&[[1., 0., 0., 1.]; 3][..]));
+    /// ```
+    pub fn to_f32(&self) -> Mesh {
+        let mut mesh = Mesh::empty();
+        *mesh.positions_mut() = self
+            .positions()
+            .iter()
+            .map(|position| position.as_vec2())
+            .collect();
+        *mesh.indices_mut() = self.indices().to_vec();
+        if let Some(colors) = self.colors() {
+            // Through `colors_mut`, as `set_colors` would panic on colors not matching the vertices
+            mesh.fill_color(Mesh::DEFAULT_COLOR);
+            *mesh.colors_mut().unwrap() = colors.to_vec();
+        }
+        mesh
+    }
+}
+
+#[cfg(feature = "mesh")]
+impl Mesh {
+    /// Get the [`DMesh`] with `f64` precision
+    ///
+    /// The indices and colors are kept as they are, even when they aren't valid.
+    ///
+    /// ```
+    /// use glam::{DVec2, Vec2};
+    /// use shapes2d::prelude::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![Vec2::ZERO, Vec2::X, Vec2::Y], vec![0, 1, 2]).unwrap().to_f64();
+    ///
+    /// assert_eq!(mesh.positions(), &[DVec2::ZERO, DVec2::X, DVec2::Y]);
+    /// assert_eq!(mesh.indices(), &[0, 1, 2]);
+    /// assert_eq!(mesh.colors(), None);
+    /// ```
+    pub fn to_f64(&self) -> DMesh {
+        let mut mesh = DMesh::empty();
+        *mesh.positions_mut() = self
+            .positions()
+            .iter()
+            .map(|position| position.as_dvec2())
+            .collect();
+        *mesh.indices_mut() = self.indices().to_vec();
+        if let Some(colors) = self.colors() {
+            // Through `colors_mut`, as `set_colors` would panic on colors not matching the vertices
+            mesh.fill_color(DMesh::DEFAULT_COLOR);
+            *mesh.colors_mut().unwrap() = colors.to_vec();
+        }
+        mesh
+    }
+}
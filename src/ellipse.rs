@@ -2,9 +2,305 @@ use std::fmt::Display;
 
 use glam::Vec2;
 
-/// Represents a single [`Ellipse`] in 2d space
-pub struct Ellipse {
-    center: Vec2,
-    radius_major: f32,
-    radius_minor: f32,
-}
\ No newline at end of file
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+// Defines the `Ellipse` for one precision, here with `f32` and in `crate::double` with `f64`, where the
+// shape and `Vec2` names in the bodies refer to the `f64` types
+macro_rules! impl_ellipse {
+    ($(#[$meta:meta])* $ellipse:ident, $float:ident; $imports:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $ellipse {
+            center: Vec2,
+            radius_major: $float,
+            radius_minor: $float,
+            rotation: $float,
+        }
+
+        // ##########
+        // Constructors
+        // ##########
+        impl Ellipse {
+            /// Creates a new [`Ellipse`] with a `radius_major` along the x axis and a `radius_minor` along the y axis
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+            ///
+            /// assert_eq!(ellipse.radius_major(), 2.);
+            /// assert_eq!(ellipse.radius_minor(), 1.);
+            /// assert_eq!(ellipse.rotation(), 0.);
+            /// ```
+            pub fn new(center: Vec2, radius_major: $float, radius_minor: $float) -> Self {
+                Self::new_rotated(center, radius_major, radius_minor, 0.)
+            }
+
+            /// Creates a new [`Ellipse`] with its major axis turned counter-clockwise by `rotation` radians from the
+            /// x axis
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            #[doc = concat!(" let ellipse = Ellipse::new_rotated(Vec2::ZERO, 2., 1., std::", stringify!($float), "::consts::FRAC_PI_2);")]
+            ///
+            /// // the major axis now runs up the y axis
+            /// let [major, minor] = ellipse.axes();
+            /// assert_abs_diff_eq!(major, Vec2::Y, epsilon = 1e-6);
+            /// assert_abs_diff_eq!(minor, Vec2::NEG_X, epsilon = 1e-6);
+            /// ```
+            pub fn new_rotated(center: Vec2, radius_major: $float, radius_minor: $float, rotation: $float) -> Self {
+                Self {
+                    center,
+                    radius_major,
+                    radius_minor,
+                    rotation,
+                }
+            }
+        }
+
+        // ##########
+        // Getters/Setters
+        // ##########
+        impl Ellipse {
+            /// Get the `center` of the [`Ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let ellipse = Ellipse::new(Vec2::ONE, 2., 1.);
+            /// let center = ellipse.center();
+            ///
+            /// assert_eq!(center, Vec2::ONE);
+            /// ```
+            pub fn center(&self) -> Vec2 {
+                self.center
+            }
+
+            /// Get the `radius_major` of the [`Ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+            /// let radius_major = ellipse.radius_major();
+            ///
+            /// assert_eq!(radius_major, 2.);
+            /// ```
+            pub fn radius_major(&self) -> $float {
+                self.radius_major
+            }
+
+            /// Get the `radius_minor` of the [`Ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+            /// let radius_minor = ellipse.radius_minor();
+            ///
+            /// assert_eq!(radius_minor, 1.);
+            /// ```
+            pub fn radius_minor(&self) -> $float {
+                self.radius_minor
+            }
+
+            /// Get the `rotation` of the [`Ellipse`] in radians, counter-clockwise from the x axis to its major axis
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let ellipse = Ellipse::new_rotated(Vec2::ZERO, 2., 1., 0.25);
+            ///
+            /// assert_eq!(ellipse.rotation(), 0.25);
+            /// ```
+            pub fn rotation(&self) -> $float {
+                self.rotation
+            }
+
+            /// Get the unit directions of the major and minor axes of the [`Ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// assert_eq!(Ellipse::new(Vec2::ZERO, 2., 1.).axes(), [Vec2::X, Vec2::Y]);
+            /// ```
+            pub fn axes(&self) -> [Vec2; 2] {
+                let major = Vec2::from_angle(self.rotation);
+                [major, major.perp()]
+            }
+
+            /// Set a new `center` for the [`Ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+            /// ellipse.set_center(Vec2::ONE);
+            ///
+            /// assert_eq!(ellipse.center(), Vec2::ONE);
+            /// ```
+            pub fn set_center(&mut self, center: Vec2) {
+                self.center = center;
+            }
+
+            /// Set a new `radius_major` for the [`Ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+            /// ellipse.set_radius_major(3.);
+            ///
+            /// assert_eq!(ellipse.radius_major(), 3.);
+            /// ```
+            pub fn set_radius_major(&mut self, radius_major: $float) {
+                self.radius_major = radius_major;
+            }
+
+            /// Set a new `radius_minor` for the [`Ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+            /// ellipse.set_radius_minor(0.5);
+            ///
+            /// assert_eq!(ellipse.radius_minor(), 0.5);
+            /// ```
+            pub fn set_radius_minor(&mut self, radius_minor: $float) {
+                self.radius_minor = radius_minor;
+            }
+
+            /// Set a new `rotation` for the [`Ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+            /// ellipse.set_rotation(0.5);
+            ///
+            /// assert_eq!(ellipse.rotation(), 0.5);
+            /// ```
+            pub fn set_rotation(&mut self, rotation: $float) {
+                self.rotation = rotation;
+            }
+        }
+
+        // ##########
+        // Operations
+        // ##########
+        impl Ellipse {
+            /// Get a [`Mesh`] approximating the [`Ellipse`] with a fan of `segments` triangles, see [`Mesh::from_ellipse`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Ellipse::new(Vec2::ZERO, 2., 1.).to_mesh(16);
+            ///
+            /// assert_eq!(mesh.vertex_count(), 17);
+            /// assert_eq!(mesh.triangle_count(), 16);
+            /// ```
+            #[cfg(feature = "mesh")]
+            pub fn to_mesh(&self, segments: u32) -> Mesh {
+                Mesh::from_ellipse(self, segments)
+            }
+
+            /// Creates a [`Polygon`] approximating the [`Ellipse`] with `segments` edges, wound counter-clockwise
+            ///
+            /// The first coordinate is at the end of the major axis, to the right of the `center` when there is no
+            /// `rotation`. A `segments` below 3 is clamped to 3.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+            /// let polygon = ellipse.to_polygon(4);
+            ///
+            /// assert_eq!(polygon.len(), 4);
+            /// assert_eq!(polygon.coordinate(0), Some(Vec2 { x: 2., y: 0. }));
+            /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+            /// assert_abs_diff_eq!(polygon.area(), 4., epsilon = 1e-5);
+            ///
+            /// // the area of the ellipse is `pi * 2. * 1.`
+            /// assert_abs_diff_eq!(ellipse.to_polygon(256).area(), 6.2832, epsilon = 0.01);
+            ///
+            /// // turned a quarter turn, the first coordinate is above the `center`
+            #[doc = concat!(" let turned = Ellipse::new_rotated(Vec2::ZERO, 2., 1., std::", stringify!($float), "::consts::FRAC_PI_2);")]
+            /// assert_abs_diff_eq!(turned.to_polygon(4).coordinate(0).unwrap(), Vec2 { x: 0., y: 2. }, epsilon = 1e-5);
+            /// ```
+            #[cfg(feature = "polygon")]
+            pub fn to_polygon(&self, segments: u32) -> Polygon {
+                let segments = segments.max(3);
+                let radii = Vec2 {
+                    x: self.radius_major,
+                    y: self.radius_minor,
+                };
+                let turn = Vec2::from_angle(self.rotation);
+                (0..segments)
+                    .map(|i| {
+                        let angle = i as $float * std::$float::consts::TAU / segments as $float;
+                        self.center + turn.rotate(Vec2::from_angle(angle) * radii)
+                    })
+                    .collect()
+            }
+        }
+
+        // ##########
+        // Default impl
+        // ##########
+        impl Default for Ellipse {
+            fn default() -> Self {
+                Self {
+                    center: Vec2::ZERO,
+                    radius_major: 2.,
+                    radius_minor: 1.,
+                    rotation: 0.,
+                }
+            }
+        }
+
+        // ##########
+        // Display impl
+        // ##########
+        impl Display for Ellipse {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    concat!(stringify!($ellipse), " {{ center: {}, radius_major: {}, radius_minor: {}, rotation: {} }}"),
+                    self.center(),
+                    self.radius_major(),
+                    self.radius_minor(),
+                    self.rotation()
+                )
+            }
+        }
+    };
+}
+pub(crate) use impl_ellipse;
+
+impl_ellipse!(
+    /// Represents a single [`Ellipse`] in 2d space
+    ///
+    /// The major axis of the [`Ellipse`] is turned counter-clockwise by `rotation` radians from the x axis, and the
+    /// minor axis lies across it. Without a `rotation` they lie along the x and y axes.
+    Ellipse,
+    f32;
+    "use glam::Vec2;\nuse shapes2d::prelude::*;"
+);
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `center`, `radius_major`, `radius_minor` and `rotation` of two [`Ellipse`]s within `epsilon`
+    Ellipse { center, radius_major, radius_minor, rotation }
+);
@@ -0,0 +1,299 @@
+use glam::Vec2;
+
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "point")]
+use crate::prelude::Point;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "triangle")]
+use crate::prelude::Triangle;
+
+// ##########
+// Consts
+// ##########
+/// The most iterations [`distance`] and [`intersects`] will run before returning their best estimate
+///
+/// Curved shapes such as a [`Circle`] can only be approached, never reached exactly, so without a limit
+/// shapes which almost touch could iterate for a very long time.
+pub const MAX_ITERATIONS: u32 = 64;
+
+/// The tolerance used by [`distance`] and [`intersects`]
+///
+/// Iteration stops once a step gets closer by less than this fraction of the squared distance, and shapes
+/// closer than this distance are considered to be touching.
+pub const TOLERANCE: f32 = 1e-5;
+
+// ##########
+// Support
+// ##########
+/// A convex shape which can find its furthest point in a direction
+///
+/// A [`Polygon`] must be convex (see [`Polygon::is_convex`]) for the results of [`distance`] and [`intersects`]
+/// to be meaningful, and an empty [`Polygon`] behaves like a single point at `(0., 0.)`.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::gjk::Support;
+/// use shapes2d::prelude::{Circle, Line, Point, Rectangle, Triangle};
+///
+/// let shapes: Vec<Box<dyn Support>> = vec![
+///     Box::new(Circle::new(Vec2::ZERO, 2.)),
+///     Box::new(Rectangle::new(-1., -1., 3., 1.)),
+///     Box::new(Triangle::new(Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2 { x: 0., y: 1. })),
+///     Box::new(Line::new(Vec2 { x: -4., y: 0. }, Vec2 { x: 2., y: 2. })),
+///     Box::new(Point::new(Vec2 { x: 3., y: 5. })),
+/// ];
+///
+/// let furthest: Vec<Vec2> = shapes.iter().map(|shape| shape.support(Vec2::X)).collect();
+/// assert_eq!(furthest, vec![Vec2 { x: 2., y: 0. }, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 3., y: 5. }]);
+/// ```
+pub trait Support {
+    /// Get the point of the shape furthest along `direction`
+    ///
+    /// `direction` does not need to be normalized. When several points are equally far, any of them may be returned.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::gjk::Support;
+    /// use shapes2d::prelude::{Circle, Polygon};
+    ///
+    /// let circle = Circle::new(Vec2::ONE, 2.);
+    /// assert_eq!(circle.support(Vec2 { x: 0., y: -5. }), Vec2 { x: 1., y: -1. });
+    ///
+    /// let pentagon: Polygon = (0..5).map(|i| Vec2::from_angle(i as f32 * std::f32::consts::TAU / 5.)).collect();
+    /// assert_eq!(pentagon.support(Vec2 { x: 1., y: 0.1 }), Vec2::X);
+    /// ```
+    fn support(&self, direction: Vec2) -> Vec2;
+}
+
+#[cfg(feature = "circle")]
+impl Support for Circle {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        self.center() + direction.normalize_or_zero() * self.radius()
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Support for Rectangle {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        Vec2 {
+            x: if direction.x >= 0. {
+                self.max_x()
+            } else {
+                self.min_x()
+            },
+            y: if direction.y >= 0. {
+                self.max_y()
+            } else {
+                self.min_y()
+            },
+        }
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl Support for Triangle {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        furthest(self.vertices().into_iter(), direction)
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Support for Polygon {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        furthest(self.coordinates().iter().copied(), direction)
+    }
+}
+
+#[cfg(feature = "line")]
+impl Support for Line {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        furthest([self.origin(), self.end()].into_iter(), direction)
+    }
+}
+
+#[cfg(feature = "point")]
+impl Support for Point {
+    fn support(&self, _direction: Vec2) -> Vec2 {
+        self.coordinate()
+    }
+}
+
+// ##########
+// Operations
+// ##########
+/// Get the shortest distance between two convex shapes, which is `0.` when they touch or overlap
+///
+/// Shapes closer than [`TOLERANCE`] are touching. Curved shapes converge on the exact distance, and after
+/// [`MAX_ITERATIONS`] the best estimate so far is returned.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use rand::{Rng, SeedableRng};
+/// use shapes2d::gjk;
+/// use shapes2d::prelude::{Circle, DistanceTo, Line, Point, Rectangle, Triangle};
+///
+/// let rect = Rectangle::new(0., 0., 2., 2.);
+/// assert_eq!(gjk::distance(&rect, &Rectangle::new(5., 6., 7., 8.)), 5.);
+/// assert_eq!(gjk::distance(&rect, &Rectangle::new(1., 1., 3., 3.)), 0.);
+///
+/// let line = Line::new(Vec2 { x: 4., y: -4. }, Vec2 { x: 4., y: 4. });
+/// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2 { x: 0., y: 1. });
+/// assert_eq!(gjk::distance(&triangle, &line), 3.);
+///
+/// // degenerate shapes: points, a circle without a radius, and a flat triangle
+/// let point = Point::new(Vec2 { x: 3., y: 4. });
+/// assert_eq!(gjk::distance(&point, &Point::new(Vec2::ZERO)), 5.);
+/// assert_eq!(gjk::distance(&Circle::new(Vec2::ZERO, 0.), &point), 5.);
+/// let flat = Triangle::new(Vec2 { x: 0., y: 4. }, Vec2 { x: 1., y: 4. }, Vec2 { x: 2., y: 4. });
+/// assert_eq!(gjk::distance(&flat, &rect), 2.);
+///
+/// // agrees with the specialized distances over random circles and rectangles
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(193);
+/// let mut point = || Vec2 { x: rng.gen_range(-4.0..4.), y: rng.gen_range(-4.0..4.) };
+/// for _ in 0..64 {
+///     let (a, b, c, d) = (point(), point(), point(), point());
+///     let circle = Circle::new(a, b.x.abs() / 2.);
+///     let other = Circle::new(b, c.y.abs() / 2.);
+///     let rect = Rectangle::new_coordinates(a.min(c), a.max(c));
+///     let other_rect = Rectangle::new_coordinates(b.min(d), b.max(d));
+///
+///     assert_abs_diff_eq!(gjk::distance(&circle, &other), circle.distance(&other), epsilon = 1e-3);
+///     assert_abs_diff_eq!(gjk::distance(&circle, &other_rect), circle.distance(&other_rect), epsilon = 1e-3);
+///     assert_abs_diff_eq!(gjk::distance(&rect, &other_rect), rect.distance(&other_rect), epsilon = 1e-3);
+/// }
+/// ```
+pub fn distance(a: &(impl Support + ?Sized), b: &(impl Support + ?Sized)) -> f32 {
+    // Support points of the Minkowski difference `a - b`, which contains the origin when the shapes overlap
+    let support = |direction: Vec2| a.support(direction) - b.support(-direction);
+
+    let mut simplex = vec![support(Vec2::X)];
+    let mut closest = simplex[0];
+    for _ in 0..MAX_ITERATIONS {
+        closest = reduce_simplex(&mut simplex);
+        let distance_squared = closest.length_squared();
+        if distance_squared <= TOLERANCE * TOLERANCE {
+            return 0.;
+        }
+
+        let next = support(-closest);
+        if distance_squared - closest.dot(next) <= TOLERANCE * distance_squared {
+            break;
+        }
+        simplex.push(next);
+    }
+    closest.length()
+}
+
+/// Checks if two convex shapes touch or overlap, see [`distance`]
+///
+/// ```
+/// use glam::Vec2;
+/// use rand::{Rng, SeedableRng};
+/// use shapes2d::gjk;
+/// use shapes2d::prelude::{Circle, Intersects, Point, Polygon, Rectangle, Triangle};
+///
+/// let pentagon: Polygon = (0..5).map(|i| Vec2::from_angle(i as f32 * std::f32::consts::TAU / 5.)).collect();
+/// assert!(gjk::intersects(&pentagon, &Point::new(Vec2::ZERO)));
+/// assert!(gjk::intersects(&pentagon, &Circle::new(Vec2 { x: 2., y: 0. }, 1.)));
+/// assert!(!gjk::intersects(&pentagon, &Circle::new(Vec2 { x: 2.5, y: 0. }, 1.)));
+///
+/// // one shape inside another, and shapes which only touch
+/// let rect = Rectangle::new(0., 0., 4., 4.);
+/// assert!(gjk::intersects(&rect, &Circle::new(Vec2::splat(2.), 1.)));
+/// assert!(gjk::intersects(&Circle::new(Vec2::splat(2.), 1.), &rect));
+/// assert!(gjk::intersects(&rect, &Triangle::new(Vec2 { x: 4., y: 0. }, Vec2 { x: 5., y: 0. }, Vec2 { x: 5., y: 1. })));
+/// assert!(gjk::intersects(&rect, &Rectangle::new(4., 4., 5., 5.)));
+///
+/// // agrees with the specialized tests over random circles, rectangles, and triangles
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(193);
+/// let mut point = || Vec2 { x: rng.gen_range(-2.0..2.), y: rng.gen_range(-2.0..2.) };
+/// let (mut touching, mut apart) = (0, 0);
+/// for _ in 0..64 {
+///     let (a, b, c, d, e, f) = (point(), point(), point(), point(), point(), point());
+///     let circle = Circle::new(a, b.x.abs() / 2.);
+///     let other = Circle::new(b, c.y.abs() / 4.);
+///     let rect = Rectangle::new_coordinates(a.min(c), a.max(c));
+///     let other_rect = Rectangle::new_coordinates(d, d + Vec2::splat(0.5));
+///     let triangle = Triangle::new(a, c, e);
+///     let other_triangle = Triangle::new(b, d, f);
+///
+///     assert_eq!(gjk::intersects(&circle, &other), circle.intersects(&other));
+///     assert_eq!(gjk::intersects(&rect, &other_rect), rect.intersects(&other_rect));
+///     assert_eq!(gjk::intersects(&triangle, &other_triangle), triangle.intersects(&other_triangle));
+///     if triangle.intersects(&other_triangle) {
+///         touching += 1;
+///     } else {
+///         apart += 1;
+///     }
+/// }
+/// assert!(touching > 0 && apart > 0);
+/// ```
+pub fn intersects(a: &(impl Support + ?Sized), b: &(impl Support + ?Sized)) -> bool {
+    distance(a, b) == 0.
+}
+
+// ##########
+// Helpers
+// ##########
+// The point furthest along `direction`, or `(0., 0.)` when there are no points
+#[cfg(any(feature = "triangle", feature = "polygon", feature = "line"))]
+fn furthest(points: impl Iterator<Item = Vec2>, direction: Vec2) -> Vec2 {
+    points
+        .max_by(|a, b| a.dot(direction).total_cmp(&b.dot(direction)))
+        .unwrap_or(Vec2::ZERO)
+}
+
+// Reduces the simplex to the smallest set of its points whose hull holds the point closest to the origin,
+// returning that closest point. The newest point is last, and a triangle holding the origin returns `(0., 0.)`.
+fn reduce_simplex(simplex: &mut Vec<Vec2>) -> Vec2 {
+    match simplex.len() {
+        1 => simplex[0],
+        2 => {
+            let (closest, kept) = closest_on_segment(simplex[0], simplex[1]);
+            *simplex = kept;
+            closest
+        }
+        _ => {
+            let [a, b, c] = [simplex[0], simplex[1], simplex[2]];
+            let area = (b - a).perp_dot(c - a);
+            let inside = [(a, b), (b, c), (c, a)]
+                .iter()
+                .all(|(start, end)| (*end - *start).perp_dot(-*start) * area >= 0.);
+            if inside && area != 0. {
+                return Vec2::ZERO;
+            }
+
+            let (closest, kept) = [(a, c), (b, c), (a, b)]
+                .into_iter()
+                .map(|(start, end)| closest_on_segment(start, end))
+                .min_by(|x, y| x.0.length_squared().total_cmp(&y.0.length_squared()))
+                .unwrap_or((c, vec![c]));
+            *simplex = kept;
+            closest
+        }
+    }
+}
+
+// The point closest to the origin on the segment from `start` to `end`, and the end points needed to hold it
+fn closest_on_segment(start: Vec2, end: Vec2) -> (Vec2, Vec<Vec2>) {
+    let direction = end - start;
+    let length_squared = direction.length_squared();
+    if length_squared == 0. {
+        return (end, vec![end]);
+    }
+    let t = -start.dot(direction) / length_squared;
+    if t <= 0. {
+        (start, vec![start])
+    } else if t >= 1. {
+        (end, vec![end])
+    } else {
+        (start + direction * t, vec![start, end])
+    }
+}
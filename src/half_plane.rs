@@ -0,0 +1,435 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "infinite_line")]
+use crate::prelude::InfiniteLine;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+#[cfg(feature = "polygon")]
+use crate::{clip::clip_against_edge, prelude::Polygon};
+#[cfg(feature = "line")]
+use crate::{clip::clip_segment_against_edge, prelude::Line};
+
+/// Represents a single [`HalfPlane`] in 2d space, every point on one side of a boundary line
+///
+/// The boundary passes through `point`, and the `normal` points away from the [`HalfPlane`], out of it. Points on
+/// the boundary are inside. The `normal` is always kept normalized, and a `normal` of `(0., 0.)` gives a
+/// [`HalfPlane`] holding every point.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::HalfPlane;
+///
+/// // everything below `y = 1.`
+/// let below = HalfPlane::new(Vec2::Y, Vec2 { x: 0., y: 5. });
+///
+/// assert!(below.contains_point(Vec2 { x: 100., y: -3. }));
+/// assert!(below.contains_point(Vec2 { x: 100., y: 1. }));
+/// assert!(!below.contains_point(Vec2 { x: 0., y: 2. }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HalfPlane {
+    point: Vec2,
+    normal: Vec2,
+}
+
+// ##########
+// Constructors
+// ##########
+impl HalfPlane {
+    /// Creates a new [`HalfPlane`] with its boundary through `point`, and `normal` pointing out of it
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// let half_plane = HalfPlane::new(Vec2::ONE, Vec2 { x: 3., y: 0. });
+    ///
+    /// assert_eq!(half_plane.normal(), Vec2::X);
+    /// ```
+    pub fn new(point: Vec2, normal: Vec2) -> Self {
+        Self {
+            point,
+            normal: normal.normalize_or_zero(),
+        }
+    }
+
+    /// Creates a new [`HalfPlane`] holding everything to the left of the [`Line`], looking from its origin to its
+    /// end
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{HalfPlane, Line};
+    ///
+    /// let half_plane = HalfPlane::from_line_left(&Line::new(Vec2::ZERO, Vec2::X));
+    ///
+    /// assert!(half_plane.contains_point(Vec2 { x: 5., y: 1. }));
+    /// assert!(!half_plane.contains_point(Vec2 { x: 5., y: -1. }));
+    /// ```
+    #[cfg(feature = "line")]
+    pub fn from_line_left(line: &Line) -> Self {
+        Self::new(line.origin(), -(line.end() - line.origin()).perp())
+    }
+
+    /// Creates a new [`HalfPlane`] holding everything to the right of the [`Line`], looking from its origin to
+    /// its end
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{HalfPlane, Line};
+    ///
+    /// let half_plane = HalfPlane::from_line_right(&Line::new(Vec2::ZERO, Vec2::X));
+    ///
+    /// assert!(half_plane.contains_point(Vec2 { x: 5., y: -1. }));
+    /// assert!(!half_plane.contains_point(Vec2 { x: 5., y: 1. }));
+    /// ```
+    #[cfg(feature = "line")]
+    pub fn from_line_right(line: &Line) -> Self {
+        Self::new(line.origin(), (line.end() - line.origin()).perp())
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl HalfPlane {
+    /// Get a `point` on the boundary of the [`HalfPlane`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// assert_eq!(HalfPlane::new(Vec2::ONE, Vec2::X).point(), Vec2::ONE);
+    /// ```
+    pub fn point(&self) -> Vec2 {
+        self.point
+    }
+
+    /// Get the normalized `normal` of the [`HalfPlane`], pointing out of it
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// assert_eq!(HalfPlane::new(Vec2::ONE, Vec2 { x: 0., y: -2. }).normal(), Vec2::NEG_Y);
+    /// ```
+    pub fn normal(&self) -> Vec2 {
+        self.normal
+    }
+
+    /// Set the `point` the boundary of the [`HalfPlane`] passes through
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// let mut half_plane = HalfPlane::new(Vec2::ZERO, Vec2::X);
+    /// half_plane.set_point(Vec2 { x: 2., y: 0. });
+    ///
+    /// assert!(half_plane.contains_point(Vec2 { x: 1.5, y: 0. }));
+    /// ```
+    pub fn set_point(&mut self, point: Vec2) {
+        self.point = point;
+    }
+
+    /// Set the `normal` of the [`HalfPlane`], which is normalized
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// let mut half_plane = HalfPlane::new(Vec2::ZERO, Vec2::X);
+    /// half_plane.set_normal(Vec2 { x: -4., y: 0. });
+    ///
+    /// assert_eq!(half_plane.normal(), Vec2::NEG_X);
+    /// ```
+    pub fn set_normal(&mut self, normal: Vec2) {
+        self.normal = normal.normalize_or_zero();
+    }
+
+    /// Get the boundary of the [`HalfPlane`] as an [`InfiniteLine`], heading so that the [`HalfPlane`] is on its
+    /// left
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// let half_plane = HalfPlane::new(Vec2::ONE, Vec2::Y);
+    /// let boundary = half_plane.boundary();
+    ///
+    /// assert_eq!(boundary.point(), Vec2::ONE);
+    /// assert_eq!(boundary.direction(), Vec2::NEG_X);
+    /// assert!(boundary.side_of(Vec2::ZERO) > 0.);
+    /// assert!(half_plane.contains_point(Vec2::ZERO));
+    /// ```
+    #[cfg(feature = "infinite_line")]
+    pub fn boundary(&self) -> InfiniteLine {
+        InfiniteLine::new(self.point, self.normal.perp())
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl HalfPlane {
+    /// Get the [`HalfPlane`] on the other side of the same boundary
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// let half_plane = HalfPlane::new(Vec2::ZERO, Vec2::X);
+    ///
+    /// assert_eq!(half_plane.flipped().normal(), Vec2::NEG_X);
+    /// assert!(half_plane.flipped().contains_point(Vec2::ONE));
+    /// ```
+    pub fn flipped(&self) -> Self {
+        Self {
+            point: self.point,
+            normal: -self.normal,
+        }
+    }
+
+    /// Get the signed distance from `point` to the boundary of the [`HalfPlane`], negative inside
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// let half_plane = HalfPlane::new(Vec2::ONE, Vec2::Y);
+    ///
+    /// assert_eq!(half_plane.signed_distance(Vec2 { x: 7., y: 3. }), 2.);
+    /// assert_eq!(half_plane.signed_distance(Vec2 { x: -7., y: 1. }), 0.);
+    /// assert_eq!(half_plane.signed_distance(Vec2 { x: 0., y: -1. }), -2.);
+    /// ```
+    pub fn signed_distance(&self, point: Vec2) -> f32 {
+        (point - self.point).dot(self.normal)
+    }
+
+    /// Checks if a `point` is inside of the [`HalfPlane`], including its boundary
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::HalfPlane;
+    ///
+    /// let half_plane = HalfPlane::new(Vec2::ZERO, Vec2::ONE);
+    ///
+    /// assert!(half_plane.contains_point(Vec2 { x: -3., y: 1. }));
+    /// assert!(half_plane.contains_point(Vec2 { x: 1., y: -1. }));
+    /// assert!(!half_plane.contains_point(Vec2 { x: 0.1, y: 0. }));
+    ///
+    /// // without a normal, every point is inside
+    /// assert!(HalfPlane::new(Vec2::ZERO, Vec2::ZERO).contains_point(Vec2::ONE));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.signed_distance(point) <= 0.
+    }
+
+    /// Get the part of the [`Line`] inside of the [`HalfPlane`], or `None` if it is entirely outside
+    ///
+    /// The clipped [`Line`] keeps the direction of the original.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{HalfPlane, Line};
+    ///
+    /// // everything left of `x = 1.`
+    /// let half_plane = HalfPlane::new(Vec2::X, Vec2::X);
+    ///
+    /// // straddling the boundary, either way round
+    /// let line = Line::new(Vec2 { x: -1., y: 0. }, Vec2 { x: 3., y: 2. });
+    /// assert_eq!(half_plane.clip_line(&line), Some(Line::new(Vec2 { x: -1., y: 0. }, Vec2 { x: 1., y: 1. })));
+    /// let line = Line::new(Vec2 { x: 3., y: 2. }, Vec2 { x: -1., y: 0. });
+    /// assert_eq!(half_plane.clip_line(&line), Some(Line::new(Vec2 { x: 1., y: 1. }, Vec2 { x: -1., y: 0. })));
+    ///
+    /// // entirely inside, and entirely outside
+    /// let line = Line::new(Vec2::ZERO, Vec2::Y);
+    /// assert_eq!(half_plane.clip_line(&line), Some(line));
+    /// assert_eq!(half_plane.clip_line(&Line::new(Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 1. })), None);
+    /// ```
+    #[cfg(feature = "line")]
+    pub fn clip_line(&self, line: &Line) -> Option<Line> {
+        // the inside lies to the left of an edge running along the boundary
+        clip_segment_against_edge(
+            [line.origin(), line.end()],
+            self.point,
+            self.point + self.normal.perp(),
+        )
+        .map(|[origin, end]| Line::new(origin, end))
+    }
+
+    /// Get the part of the [`Polygon`] inside of the [`HalfPlane`], or `None` if less than a triangle is left
+    ///
+    /// This is a single step of Sutherland-Hodgman clipping, see [`Polygon::clip_to_convex`]. A concave
+    /// [`Polygon`] split into several pieces by the boundary is returned as one [`Polygon`] with the pieces joined
+    /// along the boundary.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{HalfPlane, Polygon};
+    ///
+    /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    ///
+    /// // the left half
+    /// let clipped = HalfPlane::new(Vec2::ONE, Vec2::X).clip_polygon(&square).unwrap();
+    /// assert_eq!(clipped.area(), 2.);
+    /// assert_eq!(clipped.winding(), square.winding());
+    ///
+    /// // the lower left corner
+    /// let clipped = HalfPlane::new(Vec2::ONE, Vec2::ONE).clip_polygon(&square).unwrap();
+    /// assert_eq!(clipped.area(), 2.);
+    ///
+    /// // entirely inside, and entirely outside
+    /// assert_eq!(HalfPlane::new(Vec2 { x: 5., y: 0. }, Vec2::X).clip_polygon(&square), Some(square.clone()));
+    /// assert_eq!(HalfPlane::new(Vec2 { x: 5., y: 0. }, Vec2::NEG_X).clip_polygon(&square), None);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn clip_polygon(&self, polygon: &Polygon) -> Option<Polygon> {
+        let mut output = Vec::with_capacity(polygon.len() + 1);
+        // the inside lies to the left of an edge running along the boundary
+        clip_against_edge(
+            polygon.coordinates(),
+            true,
+            self.point,
+            self.point + self.normal.perp(),
+            1.,
+            &mut output,
+        );
+
+        if output.len() < 3 {
+            return None;
+        }
+        Some(Polygon::new(output))
+    }
+
+    /// Get the convex [`Polygon`] holding every point inside of all of the [`HalfPlane`]s, counter-clockwise
+    ///
+    /// Returns `None` when no region is inside of all of them, when the region is unbounded and can't be a
+    /// [`Polygon`], or when it is too thin to have an area. Every pair of boundaries is intersected, so this is
+    /// meant for a handful of [`HalfPlane`]s.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{HalfPlane, Polygon};
+    ///
+    /// // a square from its four sides
+    /// let sides = [
+    ///     HalfPlane::new(Vec2::ZERO, Vec2::NEG_X),
+    ///     HalfPlane::new(Vec2::ZERO, Vec2::NEG_Y),
+    ///     HalfPlane::new(Vec2 { x: 2., y: 2. }, Vec2::X),
+    ///     HalfPlane::new(Vec2 { x: 2., y: 2. }, Vec2::Y),
+    /// ];
+    /// let square = HalfPlane::intersect_all(&sides).unwrap();
+    /// assert_eq!(square.coordinates(), &[Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    ///
+    /// // a redundant side changes nothing, and a diagonal cuts off a corner
+    /// let mut more = sides.to_vec();
+    /// more.push(HalfPlane::new(Vec2 { x: 3., y: 0. }, Vec2::X));
+    /// assert_eq!(HalfPlane::intersect_all(&more), Some(square));
+    /// more.push(HalfPlane::new(Vec2 { x: 2., y: 1. }, Vec2::ONE));
+    /// assert_eq!(HalfPlane::intersect_all(&more).unwrap().area(), 3.5);
+    ///
+    /// // unbounded without its top, and empty when the sides don't overlap
+    /// assert_eq!(HalfPlane::intersect_all(&sides[..3]), None);
+    /// let mut apart = sides.to_vec();
+    /// apart.push(HalfPlane::new(Vec2 { x: 3., y: 0. }, Vec2::NEG_X));
+    /// assert_eq!(HalfPlane::intersect_all(&apart), None);
+    /// assert_eq!(HalfPlane::intersect_all(&[]), None);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn intersect_all(half_planes: &[HalfPlane]) -> Option<Polygon> {
+        // a bounded region has no direction it can head off in forever, and any such direction would run
+        // along one of the boundaries
+        let unbounded = half_planes
+            .iter()
+            .flat_map(|half_plane| [half_plane.normal.perp(), -half_plane.normal.perp()])
+            .any(|direction| {
+                direction != Vec2::ZERO
+                    && half_planes
+                        .iter()
+                        .all(|half_plane| half_plane.normal.dot(direction) <= f32::EPSILON)
+            });
+        if unbounded || half_planes.is_empty() {
+            return None;
+        }
+
+        // the corners of the region are where two boundaries cross, inside of every other half plane
+        let mut corners = Vec::new();
+        for (index, first) in half_planes.iter().enumerate() {
+            for second in &half_planes[index + 1..] {
+                let Some(corner) = boundary_intersection(first, second) else {
+                    continue;
+                };
+                let tolerance = CORNER_TOLERANCE * corner.length().max(1.);
+                if half_planes
+                    .iter()
+                    .all(|half_plane| half_plane.signed_distance(corner) <= tolerance)
+                {
+                    corners.push(corner);
+                }
+            }
+        }
+
+        Polygon::convex_hull(&corners)
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `point` and `normal` of two [`HalfPlane`]s within `epsilon`, so the same [`HalfPlane`] given by
+    /// another `point` on its boundary is not close
+    HalfPlane { point, normal }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for HalfPlane {
+    fn default() -> Self {
+        Self {
+            point: Vec2::ZERO,
+            normal: Vec2::Y,
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for HalfPlane {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HalfPlane {{ point: {}, normal: {} }}",
+            self.point(),
+            self.normal()
+        )
+    }
+}
+
+// ##########
+// Helpers
+// ##########
+// How far outside of a half plane a corner may be, relative to its distance from the origin
+#[cfg(feature = "polygon")]
+const CORNER_TOLERANCE: f32 = 1e-5;
+
+// Where the boundaries of two half planes cross, or `None` if they are parallel
+#[cfg(feature = "polygon")]
+fn boundary_intersection(first: &HalfPlane, second: &HalfPlane) -> Option<Vec2> {
+    let denominator = first.normal.perp_dot(second.normal);
+    if denominator.abs() <= f32::EPSILON {
+        return None;
+    }
+    // solve `normal.dot(corner) = normal.dot(point)` for both
+    let first_offset = first.normal.dot(first.point);
+    let second_offset = second.normal.dot(second.point);
+    Some(Vec2 {
+        x: (first_offset * second.normal.y - second_offset * first.normal.y) / denominator,
+        y: (second_offset * first.normal.x - first_offset * second.normal.x) / denominator,
+    })
+}
@@ -0,0 +1,413 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "ray")]
+use crate::prelude::Ray;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+// How close to parallel two normalized directions may be before they are treated as never crossing
+const PARALLEL_EPSILON: f32 = 1e-6;
+
+/// Represents a single [`InfiniteLine`] in 2d space, through a `point` and reaching forever both ways along its
+/// `direction`
+///
+/// Unlike a [`Line`](crate::prelude::Line), which stops at its origin and end, an [`InfiniteLine`] has no ends.
+/// The `direction` is always kept normalized, and decides which side is the left.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{ClosestPoint, InfiniteLine, Line};
+///
+/// let segment = Line::new(Vec2::ZERO, Vec2::X);
+/// let line = InfiniteLine::from(segment);
+///
+/// // the segment stops at `(1., 0.)`, but the infinite line keeps going
+/// assert_eq!(segment.closest_point(Vec2 { x: 3., y: 1. }), Vec2::X);
+/// assert_eq!(line.project(Vec2 { x: 3., y: 1. }), Vec2 { x: 3., y: 0. });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InfiniteLine {
+    point: Vec2,
+    direction: Vec2,
+}
+
+// ##########
+// Constructors
+// ##########
+impl InfiniteLine {
+    /// Creates a new [`InfiniteLine`] through `point`, along `direction`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::InfiniteLine;
+    ///
+    /// let line = InfiniteLine::new(Vec2::ONE, Vec2 { x: 0., y: 3. });
+    ///
+    /// assert_eq!(line.direction(), Vec2::Y);
+    /// ```
+    pub fn new(point: Vec2, direction: Vec2) -> Self {
+        Self {
+            point,
+            direction: direction.normalize_or_zero(),
+        }
+    }
+
+    /// Creates a new [`InfiniteLine`] through `start` and `end`, heading from `start` towards `end`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::InfiniteLine;
+    ///
+    /// let line = InfiniteLine::new_points(Vec2::ONE, Vec2 { x: 1., y: -4. });
+    ///
+    /// assert_eq!(line.point(), Vec2::ONE);
+    /// assert_eq!(line.direction(), Vec2::NEG_Y);
+    /// ```
+    pub fn new_points(start: Vec2, end: Vec2) -> Self {
+        Self::new(start, end - start)
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl InfiniteLine {
+    /// Get a `point` on the [`InfiniteLine`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::InfiniteLine;
+    ///
+    /// assert_eq!(InfiniteLine::new(Vec2::ONE, Vec2::X).point(), Vec2::ONE);
+    /// ```
+    pub fn point(&self) -> Vec2 {
+        self.point
+    }
+
+    /// Get the normalized `direction` of the [`InfiniteLine`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::InfiniteLine;
+    ///
+    /// assert_eq!(InfiniteLine::new(Vec2::ONE, Vec2 { x: -2., y: 0. }).direction(), Vec2::NEG_X);
+    /// ```
+    pub fn direction(&self) -> Vec2 {
+        self.direction
+    }
+
+    /// Get the normal of the [`InfiniteLine`], the `direction` turned a quarter counter-clockwise to point to its
+    /// left
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::InfiniteLine;
+    ///
+    /// assert_eq!(InfiniteLine::new(Vec2::ONE, Vec2::X).normal(), Vec2::Y);
+    /// ```
+    pub fn normal(&self) -> Vec2 {
+        self.direction.perp()
+    }
+
+    /// Set a `point` the [`InfiniteLine`] passes through
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::InfiniteLine;
+    ///
+    /// let mut line = InfiniteLine::new(Vec2::ZERO, Vec2::X);
+    /// line.set_point(Vec2::Y);
+    ///
+    /// assert_eq!(line.distance_to_point(Vec2::ZERO), 1.);
+    /// ```
+    pub fn set_point(&mut self, point: Vec2) {
+        self.point = point;
+    }
+
+    /// Set the `direction` of the [`InfiniteLine`], which is normalized
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::InfiniteLine;
+    ///
+    /// let mut line = InfiniteLine::new(Vec2::ZERO, Vec2::X);
+    /// line.set_direction(Vec2 { x: 0., y: 5. });
+    ///
+    /// assert_eq!(line.direction(), Vec2::Y);
+    /// ```
+    pub fn set_direction(&mut self, direction: Vec2) {
+        self.direction = direction.normalize_or_zero();
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl InfiniteLine {
+    /// Get which side of the [`InfiniteLine`] `point` is on, as its signed distance from the line
+    ///
+    /// The distance is positive to the left of the `direction`, negative to the right, and `0.` on the line.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::InfiniteLine;
+    ///
+    /// let line = InfiniteLine::new(Vec2::ZERO, Vec2::X);
+    ///
+    /// assert_eq!(line.side_of(Vec2 { x: 10., y: 2. }), 2.);
+    /// assert_eq!(line.side_of(Vec2 { x: -10., y: -3. }), -3.);
+    /// assert_eq!(line.side_of(Vec2 { x: 100., y: 0. }), 0.);
+    /// ```
+    pub fn side_of(&self, point: Vec2) -> f32 {
+        self.direction.perp_dot(point - self.point)
+    }
+
+    /// Get the point on the [`InfiniteLine`] closest to `point`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{ClosestPoint, InfiniteLine, Line};
+    ///
+    /// let line = InfiniteLine::new(Vec2::Y, Vec2::X);
+    /// assert_eq!(line.project(Vec2 { x: 3., y: 5. }), Vec2 { x: 3., y: 1. });
+    ///
+    /// // far past the start of a segment along the same line
+    /// let line = InfiniteLine::new(Vec2::ZERO, Vec2::X);
+    /// let segment = Line::new(Vec2::ZERO, Vec2::X);
+    /// let point = Vec2 { x: -4., y: -2. };
+    /// assert_eq!(line.project(point), Vec2 { x: -4., y: 0. });
+    /// assert_eq!(segment.closest_point(point), Vec2::ZERO);
+    /// ```
+    pub fn project(&self, point: Vec2) -> Vec2 {
+        self.point + self.direction * (point - self.point).dot(self.direction)
+    }
+
+    /// Get the distance from `point` to the closest point on the [`InfiniteLine`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{ClosestPoint, InfiniteLine, Line};
+    ///
+    /// let segment = Line::new(Vec2::ZERO, Vec2 { x: 2., y: 0. });
+    /// let line = InfiniteLine::from(segment);
+    ///
+    /// // beside the segment both agree, but past its end only the infinite line is still close
+    /// assert_eq!(line.distance_to_point(Vec2 { x: 1., y: -3. }), 3.);
+    /// assert_eq!(segment.distance_to(Vec2 { x: 1., y: -3. }), 3.);
+    /// assert_eq!(line.distance_to_point(Vec2 { x: 6., y: 3. }), 3.);
+    /// assert_eq!(segment.distance_to(Vec2 { x: 6., y: 3. }), 5.);
+    /// ```
+    pub fn distance_to_point(&self, point: Vec2) -> f32 {
+        self.side_of(point).abs()
+    }
+
+    /// Get the point where two [`InfiniteLine`]s cross, or `None` if they are parallel
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{InfiniteLine, Intersects, Line};
+    ///
+    /// // two segments that would cross if they were longer
+    /// let horizontal = Line::new(Vec2::ZERO, Vec2::X);
+    /// let vertical = Line::new(Vec2 { x: 3., y: -1. }, Vec2 { x: 3., y: 1. });
+    /// assert!(!horizontal.intersects(&vertical));
+    ///
+    /// let crossing = InfiniteLine::from(horizontal).intersection(&InfiniteLine::from(vertical));
+    /// assert_eq!(crossing, Some(Vec2 { x: 3., y: 0. }));
+    ///
+    /// // parallel lines never meet, even when they are the same line
+    /// let line = InfiniteLine::new(Vec2::ZERO, Vec2::ONE);
+    /// assert_eq!(line.intersection(&InfiniteLine::new(Vec2::X, Vec2 { x: -2., y: -2. })), None);
+    /// assert_eq!(line.intersection(&line), None);
+    /// ```
+    pub fn intersection(&self, other: &InfiniteLine) -> Option<Vec2> {
+        let cross = self.direction.perp_dot(other.direction);
+        if cross.abs() <= PARALLEL_EPSILON {
+            return None;
+        }
+        let t = (other.point - self.point).perp_dot(other.direction) / cross;
+        Some(self.point + self.direction * t)
+    }
+
+    /// Get the point where the [`Line`] crosses the [`InfiniteLine`], or `None` if it doesn't reach it or runs
+    /// parallel to it
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{InfiniteLine, Intersects, Line};
+    ///
+    /// let segment = Line::new(Vec2::ZERO, Vec2::X);
+    /// let line = InfiniteLine::from(segment);
+    ///
+    /// // crossing the line well past the end of the segment
+    /// let crossing = Line::new(Vec2 { x: 5., y: -1. }, Vec2 { x: 7., y: 3. });
+    /// assert_eq!(line.intersection_segment(&crossing), Some(Vec2 { x: 5.5, y: 0. }));
+    /// assert!(!segment.intersects(&crossing));
+    ///
+    /// // stopping short of the line
+    /// let short = Line::new(Vec2 { x: 5., y: 1. }, Vec2 { x: 7., y: 3. });
+    /// assert_eq!(line.intersection_segment(&short), None);
+    ///
+    /// // touching the line with one end
+    /// let touching = Line::new(Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 3. });
+    /// assert_eq!(line.intersection_segment(&touching), Some(Vec2 { x: 2., y: 0. }));
+    /// ```
+    #[cfg(feature = "line")]
+    pub fn intersection_segment(&self, line: &Line) -> Option<Vec2> {
+        let (origin, end) = (line.origin(), line.end());
+        let (origin_side, end_side) = (self.side_of(origin), self.side_of(end));
+        if (origin_side > 0. && end_side > 0.)
+            || (origin_side < 0. && end_side < 0.)
+            || origin_side == end_side
+        {
+            return None;
+        }
+        Some(origin.lerp(end, origin_side / (origin_side - end_side)))
+    }
+
+    /// Get the part of the [`InfiniteLine`] inside of the [`Rectangle`], or `None` if it misses it
+    ///
+    /// The [`Line`] heads the same way as the `direction`, and is a single point where the [`InfiniteLine`] only
+    /// touches a corner. An [`InfiniteLine`] without a `direction` is never clipped.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{InfiniteLine, Line, Rectangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    ///
+    /// let line = InfiniteLine::new(Vec2 { x: 10., y: 1. }, Vec2::NEG_X);
+    /// assert_eq!(line.clip_to_rect(&rect), Some(Line::new(Vec2 { x: 4., y: 1. }, Vec2 { x: 0., y: 1. })));
+    ///
+    /// let diagonal = InfiniteLine::new(Vec2 { x: -1., y: -1. }, Vec2::ONE);
+    /// let clipped = diagonal.clip_to_rect(&rect).unwrap();
+    /// assert_abs_diff_eq!(clipped.origin(), Vec2::ZERO, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(clipped.end(), Vec2 { x: 2., y: 2. }, epsilon = 1e-6);
+    ///
+    /// assert_eq!(InfiniteLine::new(Vec2 { x: 0., y: 3. }, Vec2::X).clip_to_rect(&rect), None);
+    /// ```
+    #[cfg(all(feature = "line", feature = "rectangle"))]
+    pub fn clip_to_rect(&self, rectangle: &Rectangle) -> Option<Line> {
+        if self.direction == Vec2::ZERO {
+            return None;
+        }
+        let min = rectangle.min().min(rectangle.max());
+        let max = rectangle.min().max(rectangle.max());
+
+        // Liang-Barsky, narrowing the distances along the line that lie between each pair of sides
+        let mut near = f32::NEG_INFINITY;
+        let mut far = f32::INFINITY;
+        for (point, direction, min, max) in [
+            (self.point.x, self.direction.x, min.x, max.x),
+            (self.point.y, self.direction.y, min.y, max.y),
+        ] {
+            if direction == 0. {
+                if point < min || point > max {
+                    return None;
+                }
+                continue;
+            }
+            let (a, b) = ((min - point) / direction, (max - point) / direction);
+            near = near.max(a.min(b));
+            far = far.min(a.max(b));
+        }
+
+        if near > far {
+            return None;
+        }
+        Some(Line::new(
+            self.point + self.direction * near,
+            self.point + self.direction * far,
+        ))
+    }
+}
+
+// ##########
+// From impls
+// ##########
+#[cfg(feature = "line")]
+impl From<Line> for InfiniteLine {
+    /// Creates an [`InfiniteLine`] through both ends of a [`Line`], heading from its origin to its end
+    ///
+    /// The ends of the [`Line`] are lost, and a [`Line`] with no length gives an [`InfiniteLine`] without a
+    /// `direction`.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{InfiniteLine, Line};
+    ///
+    /// let line = InfiniteLine::from(Line::new(Vec2::ONE, Vec2 { x: 4., y: 5. }));
+    ///
+    /// assert_eq!(line.point(), Vec2::ONE);
+    /// assert_eq!(line.direction(), Vec2 { x: 0.6, y: 0.8 });
+    /// ```
+    fn from(line: Line) -> Self {
+        Self::new_points(line.origin(), line.end())
+    }
+}
+
+#[cfg(feature = "ray")]
+impl From<Ray> for InfiniteLine {
+    /// Creates an [`InfiniteLine`] along a [`Ray`], through its origin and heading the same way
+    ///
+    /// The origin of the [`Ray`] is no longer an end, so the [`InfiniteLine`] also reaches back behind it.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{InfiniteLine, Ray, SignedDistance};
+    ///
+    /// let ray = Ray::new_direction(Vec2::ZERO, Vec2::X);
+    /// let line = InfiniteLine::from(ray);
+    ///
+    /// assert_eq!(line.direction(), Vec2::X);
+    /// // behind the origin of the ray
+    /// assert_eq!(ray.signed_distance(Vec2 { x: -3., y: 0. }), 3.);
+    /// assert_eq!(line.distance_to_point(Vec2 { x: -3., y: 0. }), 0.);
+    /// ```
+    fn from(ray: Ray) -> Self {
+        Self::new(ray.origin(), ray.direction())
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `point` and normalized `direction` of two [`InfiniteLine`]s within `epsilon`, so the same line
+    /// through another `point` or in the opposite direction is not close
+    InfiniteLine { point, direction }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for InfiniteLine {
+    fn default() -> Self {
+        Self {
+            point: Vec2::ZERO,
+            direction: Vec2::X,
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for InfiniteLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "InfiniteLine {{ point: {}, direction: {} }}",
+            self.point(),
+            self.direction()
+        )
+    }
+}
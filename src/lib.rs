@@ -1,47 +1,655 @@
 #![warn(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "annulus")]
+/// Contains the [`Annulus`] structure and related methods
+pub mod annulus;
+#[cfg(feature = "arc")]
+/// Contains the [`Arc`] structure and related methods
+pub mod arc;
+#[cfg(feature = "rectangle")]
+/// Contains the sweep and prune search for overlapping [`Rectangle`]s
+pub mod broadphase;
+#[cfg(feature = "capsule")]
+/// Contains the [`Capsule`] structure and related methods
+pub mod capsule;
 #[cfg(feature = "circle")]
 /// Contains the [`Circle`] structure and related methods
 pub mod circle;
+#[cfg(feature = "circular_segment")]
+/// Contains the [`CircularSegment`] structure and related methods
+pub mod circular_segment;
+// Holds the clipping shared between shapes, gated on having a shape which clips
+#[cfg(any(
+    feature = "polygon",
+    feature = "rectangle",
+    all(feature = "half_plane", feature = "line")
+))]
+mod clip;
+#[cfg(feature = "cubic_bezier")]
+/// Contains the [`CubicBezier`] structure and related methods
+pub mod cubic_bezier;
+#[cfg(any(
+    feature = "point",
+    feature = "line",
+    feature = "circle",
+    feature = "ellipse",
+    feature = "rectangle",
+    feature = "triangle",
+    feature = "polygon",
+    feature = "mesh",
+    feature = "ray"
+))]
+/// Contains `f64` versions of the shapes for coordinates too large for `f32` precision
+pub mod double;
 #[cfg(feature = "ellipse")]
 /// Contains the [`Ellipse`] structure and related methods
 pub mod ellipse;
+/// Contains the GJK algorithm for the distance between convex shapes
+pub mod gjk;
+#[cfg(feature = "half_plane")]
+/// Contains the [`HalfPlane`] structure and related methods
+pub mod half_plane;
+#[cfg(feature = "infinite_line")]
+/// Contains the [`InfiniteLine`] structure and related methods
+pub mod infinite_line;
 #[cfg(feature = "line")]
 /// Contains the [`Line`] structure and related methods
 pub mod line;
-#[cfg(feature = "polygon")]
-/// Contains the [`Polygon`] structure and related methods
-pub mod polygon;
+#[cfg(feature = "mesh")]
+/// Contains the [`Mesh`] structure and related methods
+pub mod mesh;
+#[cfg(feature = "oriented_rectangle")]
+/// Contains the [`OrientedRectangle`] structure and related methods
+pub mod oriented_rectangle;
 #[cfg(feature = "point")]
 /// Contains the [`Point`] structure and related methods
 pub mod point;
+#[cfg(feature = "polygon")]
+/// Contains the [`Polygon`] structure and related methods
+pub mod polygon;
+#[cfg(feature = "polygon_with_holes")]
+/// Contains the [`PolygonWithHoles`] structure and related methods
+pub mod polygon_with_holes;
+#[cfg(feature = "polyline")]
+/// Contains the [`Polyline`] structure and related methods
+pub mod polyline;
+#[cfg(feature = "quad")]
+/// Contains the [`Quad`] structure and related methods
+pub mod quad;
+#[cfg(feature = "quadratic_bezier")]
+/// Contains the [`QuadraticBezier`] structure and related methods
+pub mod quadratic_bezier;
 #[cfg(feature = "ray")]
 /// Contains the [`Ray`] structure and related methods
 pub mod ray;
 #[cfg(feature = "rectangle")]
 /// Contains the [`Rectangle`] structure and related methods
 pub mod rectangle;
+#[cfg(feature = "regular_polygon")]
+/// Contains the [`RegularPolygon`] structure and related methods
+pub mod regular_polygon;
+#[cfg(feature = "rounded_rectangle")]
+/// Contains the [`RoundedRectangle`] structure and related methods
+pub mod rounded_rectangle;
+#[cfg(feature = "integer")]
+/// Contains the [`Rounding`] enum describing how coordinates become integer coordinates
+pub mod rounding;
+/// Contains the separating axis theorem for convex shapes
+pub mod sat;
+#[cfg(feature = "sector")]
+/// Contains the [`Sector`] structure and related methods
+pub mod sector;
+/// Contains the traits shared between shapes
+pub mod traits;
 #[cfg(feature = "triangle")]
 /// Contains the [`Triangle`] structure and related methods
 pub mod triangle;
+/// Contains the [`Winding`] enum describing the order of a shape's coordinates
+pub mod winding;
+
+#[cfg(any(
+    feature = "point",
+    feature = "line",
+    feature = "ray",
+    feature = "circle",
+    feature = "ellipse",
+    feature = "rectangle",
+    feature = "triangle",
+    feature = "polygon"
+))]
+pub use shape::Shape;
+
+// Holds the `Shape` enum, gated on having at least one shape to hold
+#[cfg(any(
+    feature = "point",
+    feature = "line",
+    feature = "ray",
+    feature = "circle",
+    feature = "ellipse",
+    feature = "rectangle",
+    feature = "triangle",
+    feature = "polygon"
+))]
+mod shape {
+    use std::fmt::Display;
+
+    use glam::{Affine2, Vec2};
+
+    use crate::prelude::*;
+
+    /// Any one of the included shapes, for holding different shapes together
+    ///
+    /// [`Shape`] forwards the common traits to the shape it holds. [`Point`], [`Line`], and [`Ray`]
+    /// enclose no area, so as a [`Shape`]:
+    /// - they have an `area` of `0.`, and only contain the points exactly on them
+    /// - a [`Point`] has a `perimeter` of `0.`, a [`Line`] has a `perimeter` of twice its length (there and
+    ///   back again, like a [`Polygon`] with two coordinates), and a [`Ray`] has an infinite `perimeter`
+    /// - the `centroid` is the coordinate of a [`Point`], the center of a [`Line`], and the origin of a [`Ray`]
+    /// - the bounding box of a [`Ray`] is infinite in the directions it extends
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Contains, Line, Point, Rectangle, Shape, Triangle};
+    ///
+    /// let scene: Vec<Shape> = vec![
+    ///     Circle::new(Vec2::ZERO, 1.).into(),
+    ///     Rectangle::new(0., 0., 4., 2.).into(),
+    ///     Triangle::new(Vec2 { x: 3., y: 1. }, Vec2 { x: 6., y: 1. }, Vec2 { x: 3., y: 4. }).into(),
+    ///     Line::new(Vec2::ZERO, Vec2 { x: 5., y: 5. }).into(),
+    ///     Point::new(Vec2 { x: 3.5, y: 1.5 }).into(),
+    /// ];
+    ///
+    /// let under_cursor: Vec<usize> = (0..scene.len()).filter(|i| scene[*i].contains(Vec2 { x: 3.5, y: 1.5 })).collect();
+    /// assert_eq!(under_cursor, vec![1, 2, 4]);
+    ///
+    /// assert_eq!(scene[0].as_circle().map(|circle| circle.radius()), Some(1.));
+    /// assert!(scene[1].as_circle().is_none());
+    /// assert_eq!(scene[1].as_rectangle().map(|rect| rect.max()), Some(Vec2 { x: 4., y: 2. }));
+    /// assert_eq!(scene[4].to_string(), "Point { coordinate: [3.5, 1.5] }");
+    /// ```
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Shape {
+        /// A [`Point`]
+        #[cfg(feature = "point")]
+        Point(Point),
+        /// A [`Line`]
+        #[cfg(feature = "line")]
+        Line(Line),
+        /// A [`Ray`]
+        #[cfg(feature = "ray")]
+        Ray(Ray),
+        /// A [`Circle`]
+        #[cfg(feature = "circle")]
+        Circle(Circle),
+        /// An [`Ellipse`]
+        #[cfg(feature = "ellipse")]
+        Ellipse(Ellipse),
+        /// A [`Rectangle`]
+        #[cfg(feature = "rectangle")]
+        Rectangle(Rectangle),
+        /// A [`Triangle`]
+        #[cfg(feature = "triangle")]
+        Triangle(Triangle),
+        /// A [`Polygon`]
+        #[cfg(feature = "polygon")]
+        Polygon(Polygon),
+    }
+
+    // Matches every variant of a `Shape`, binding the inner shape to `$inner` for `$body`
+    macro_rules! match_shape {
+        ($shape:expr, $inner:ident => $body:expr) => {
+            match $shape {
+                #[cfg(feature = "point")]
+                Shape::Point($inner) => $body,
+                #[cfg(feature = "line")]
+                Shape::Line($inner) => $body,
+                #[cfg(feature = "ray")]
+                Shape::Ray($inner) => $body,
+                #[cfg(feature = "circle")]
+                Shape::Circle($inner) => $body,
+                #[cfg(feature = "ellipse")]
+                Shape::Ellipse($inner) => $body,
+                #[cfg(feature = "rectangle")]
+                Shape::Rectangle($inner) => $body,
+                #[cfg(feature = "triangle")]
+                Shape::Triangle($inner) => $body,
+                #[cfg(feature = "polygon")]
+                Shape::Polygon($inner) => $body,
+            }
+        };
+    }
+
+    // Implements the accessor for a variant and the `From` conversion into a `Shape`
+    macro_rules! impl_variant {
+        ($feature:literal, $variant:ident, $accessor:ident, $doc:literal) => {
+            #[cfg(feature = $feature)]
+            impl Shape {
+                #[doc = $doc]
+                pub fn $accessor(&self) -> Option<&$variant> {
+                    match self {
+                        Shape::$variant(inner) => Some(inner),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    }
+                }
+            }
+
+            #[cfg(feature = $feature)]
+            impl From<$variant> for Shape {
+                fn from(value: $variant) -> Self {
+                    Shape::$variant(value)
+                }
+            }
+        };
+    }
+
+    // ##########
+    // Getters/Setters
+    // ##########
+    impl_variant!(
+        "point",
+        Point,
+        as_point,
+        "Get the [`Point`] held by the [`Shape`], if it holds one"
+    );
+    impl_variant!(
+        "line",
+        Line,
+        as_line,
+        "Get the [`Line`] held by the [`Shape`], if it holds one"
+    );
+    impl_variant!(
+        "ray",
+        Ray,
+        as_ray,
+        "Get the [`Ray`] held by the [`Shape`], if it holds one"
+    );
+    impl_variant!(
+        "circle",
+        Circle,
+        as_circle,
+        "Get the [`Circle`] held by the [`Shape`], if it holds one"
+    );
+    impl_variant!(
+        "ellipse",
+        Ellipse,
+        as_ellipse,
+        "Get the [`Ellipse`] held by the [`Shape`], if it holds one"
+    );
+    impl_variant!(
+        "rectangle",
+        Rectangle,
+        as_rectangle,
+        "Get the [`Rectangle`] held by the [`Shape`], if it holds one"
+    );
+    impl_variant!(
+        "triangle",
+        Triangle,
+        as_triangle,
+        "Get the [`Triangle`] held by the [`Shape`], if it holds one"
+    );
+    impl_variant!(
+        "polygon",
+        Polygon,
+        as_polygon,
+        "Get the [`Polygon`] held by the [`Shape`], if it holds one"
+    );
+
+    // ##########
+    // Compound
+    // ##########
+    impl Shape {
+        /// Get the total `area` of `shapes`
+        ///
+        /// The areas are summed, so where shapes overlap the overlap is counted once for each shape.
+        ///
+        /// ```
+        /// use glam::Vec2;
+        /// use shapes2d::prelude::{Point, Rectangle, Shape};
+        ///
+        /// let sprite: Vec<Shape> = vec![
+        ///     Rectangle::new(0., 0., 2., 2.).into(),
+        ///     Rectangle::new(1., 1., 3., 3.).into(),
+        ///     Point::new(Vec2::ZERO).into(),
+        /// ];
+        ///
+        /// // the overlap of the rectangles is counted twice
+        /// assert_eq!(Shape::compound_area(&sprite), 8.);
+        /// assert_eq!(Shape::compound_area(&[]), 0.);
+        /// ```
+        pub fn compound_area(shapes: &[Shape]) -> f32 {
+            shapes.iter().map(Shape2d::area).sum()
+        }
+
+        /// Get the `centroid` of `shapes` together, their centroids weighted by their areas
+        ///
+        /// Shapes without area, such as a [`Point`], carry no weight, and where shapes overlap the overlap is
+        /// weighted once for each shape. Returns `None` when the total area is `0.`.
+        ///
+        /// ```
+        /// use approx::assert_abs_diff_eq;
+        /// use glam::Vec2;
+        /// use shapes2d::prelude::{Point, Rectangle, Shape};
+        ///
+        /// // a 2x2 rectangle centered at (1, 1), and a 4x1 rectangle centered at (6, 0.5)
+        /// let sprite: Vec<Shape> = vec![
+        ///     Rectangle::new(0., 0., 2., 2.).into(),
+        ///     Rectangle::new(4., 0., 8., 1.).into(),
+        ///     Point::new(Vec2 { x: 100., y: 100. }).into(),
+        /// ];
+        ///
+        /// // (1, 1) weighted by 4 and (6, 0.5) weighted by 4
+        /// assert_eq!(Shape::compound_centroid(&sprite), Some(Vec2 { x: 3.5, y: 0.75 }));
+        ///
+        /// // (1, 1) weighted by 4 and (6, 0.5) weighted by 8
+        /// let sprite: Vec<Shape> = vec![Rectangle::new(0., 0., 2., 2.).into(), Rectangle::new(4., 0., 8., 2.).into()];
+        /// let centroid = Shape::compound_centroid(&sprite).unwrap();
+        /// assert_abs_diff_eq!(centroid, Vec2 { x: (4. + 6. * 8.) / 12., y: 1. }, epsilon = 1e-6);
+        ///
+        /// assert_eq!(Shape::compound_centroid(&[Point::new(Vec2::ONE).into()]), None);
+        /// assert_eq!(Shape::compound_centroid(&[]), None);
+        /// ```
+        pub fn compound_centroid(shapes: &[Shape]) -> Option<Vec2> {
+            let (weighted, total) = shapes
+                .iter()
+                .map(|shape| (shape, shape.area()))
+                .filter(|(_, area)| *area > 0.)
+                .fold((Vec2::ZERO, 0.), |(weighted, total), (shape, area)| {
+                    (weighted + shape.centroid() * area, total + area)
+                });
+            (total > 0.).then(|| weighted / total)
+        }
+    }
+
+    // ##########
+    // Trait impls
+    // ##########
+    impl Shape2d for Shape {
+        fn area(&self) -> f32 {
+            match self {
+                #[cfg(feature = "point")]
+                Shape::Point(_) => 0.,
+                #[cfg(feature = "line")]
+                Shape::Line(_) => 0.,
+                #[cfg(feature = "ray")]
+                Shape::Ray(_) => 0.,
+                #[cfg(feature = "circle")]
+                Shape::Circle(circle) => circle.area(),
+                #[cfg(feature = "ellipse")]
+                Shape::Ellipse(ellipse) => ellipse.area(),
+                #[cfg(feature = "rectangle")]
+                Shape::Rectangle(rect) => rect.area(),
+                #[cfg(feature = "triangle")]
+                Shape::Triangle(triangle) => Shape2d::area(triangle),
+                #[cfg(feature = "polygon")]
+                Shape::Polygon(polygon) => Shape2d::area(polygon),
+            }
+        }
+
+        fn perimeter(&self) -> f32 {
+            match self {
+                #[cfg(feature = "point")]
+                Shape::Point(_) => 0.,
+                #[cfg(feature = "line")]
+                Shape::Line(line) => 2. * line.origin().distance(line.end()),
+                #[cfg(feature = "ray")]
+                Shape::Ray(_) => f32::INFINITY,
+                #[cfg(feature = "circle")]
+                Shape::Circle(circle) => circle.perimeter(),
+                #[cfg(feature = "ellipse")]
+                Shape::Ellipse(ellipse) => ellipse.perimeter(),
+                #[cfg(feature = "rectangle")]
+                Shape::Rectangle(rect) => rect.perimeter(),
+                #[cfg(feature = "triangle")]
+                Shape::Triangle(triangle) => triangle.perimeter(),
+                #[cfg(feature = "polygon")]
+                Shape::Polygon(polygon) => Shape2d::perimeter(polygon),
+            }
+        }
+
+        fn centroid(&self) -> Vec2 {
+            match self {
+                #[cfg(feature = "point")]
+                Shape::Point(point) => point.coordinate(),
+                #[cfg(feature = "line")]
+                Shape::Line(line) => line.center(),
+                #[cfg(feature = "ray")]
+                Shape::Ray(ray) => ray.origin(),
+                #[cfg(feature = "circle")]
+                Shape::Circle(circle) => circle.centroid(),
+                #[cfg(feature = "ellipse")]
+                Shape::Ellipse(ellipse) => ellipse.centroid(),
+                #[cfg(feature = "rectangle")]
+                Shape::Rectangle(rect) => rect.centroid(),
+                #[cfg(feature = "triangle")]
+                Shape::Triangle(triangle) => Shape2d::centroid(triangle),
+                #[cfg(feature = "polygon")]
+                Shape::Polygon(polygon) => Shape2d::centroid(polygon),
+            }
+        }
+    }
+
+    #[cfg(feature = "rectangle")]
+    impl Bounded for Shape {
+        fn bounding_box(&self) -> Rectangle {
+            match self {
+                #[cfg(feature = "point")]
+                Shape::Point(point) => point.bounding_box(),
+                #[cfg(feature = "line")]
+                Shape::Line(line) => line.bounding_box(),
+                #[cfg(feature = "ray")]
+                Shape::Ray(ray) => {
+                    let (origin, direction) = (ray.origin(), ray.direction());
+                    let extend = |origin: f32, direction: f32, sign: f32| {
+                        if direction * sign > 0. {
+                            f32::INFINITY * sign
+                        } else {
+                            origin
+                        }
+                    };
+                    Rectangle::new(
+                        extend(origin.x, direction.x, -1.),
+                        extend(origin.y, direction.y, -1.),
+                        extend(origin.x, direction.x, 1.),
+                        extend(origin.y, direction.y, 1.),
+                    )
+                }
+                #[cfg(feature = "circle")]
+                Shape::Circle(circle) => circle.bounding_box(),
+                #[cfg(feature = "ellipse")]
+                Shape::Ellipse(ellipse) => ellipse.bounding_box(),
+                Shape::Rectangle(rect) => rect.bounding_box(),
+                #[cfg(feature = "triangle")]
+                Shape::Triangle(triangle) => Bounded::bounding_box(triangle),
+                #[cfg(feature = "polygon")]
+                Shape::Polygon(polygon) => Bounded::bounding_box(polygon),
+            }
+        }
+    }
+
+    impl Contains for Shape {
+        fn contains(&self, point: Vec2) -> bool {
+            match self {
+                #[cfg(feature = "point")]
+                Shape::Point(inner) => inner.coordinate() == point,
+                #[cfg(feature = "line")]
+                Shape::Line(line) => line.signed_distance(point) == 0.,
+                #[cfg(feature = "ray")]
+                Shape::Ray(ray) => ray.signed_distance(point) == 0.,
+                #[cfg(feature = "circle")]
+                Shape::Circle(circle) => circle.contains(point),
+                #[cfg(feature = "ellipse")]
+                Shape::Ellipse(ellipse) => ellipse.contains(point),
+                #[cfg(feature = "rectangle")]
+                Shape::Rectangle(rect) => rect.contains(point),
+                #[cfg(feature = "triangle")]
+                Shape::Triangle(triangle) => triangle.contains(point),
+                #[cfg(feature = "polygon")]
+                Shape::Polygon(polygon) => polygon.contains(point),
+            }
+        }
+    }
+
+    impl SignedDistance for Shape {
+        fn signed_distance(&self, point: Vec2) -> f32 {
+            match_shape!(self, inner => SignedDistance::signed_distance(inner, point))
+        }
+    }
+
+    impl Transformable for Shape {
+        fn transform(&mut self, affine: Affine2) {
+            match_shape!(self, inner => Transformable::transform(inner, affine))
+        }
+    }
+
+    impl Validity for Shape {
+        fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect> {
+            match_shape!(self, inner => Validity::validate(inner, epsilon))
+        }
+    }
+
+    // ##########
+    // Display impl
+    // ##########
+    impl Display for Shape {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match_shape!(self, inner => Display::fmt(inner, f))
+        }
+    }
+}
 
 /// Contains the included shapes
 pub mod prelude {
+    #[cfg(feature = "annulus")]
+    pub use crate::annulus::Annulus;
+    #[cfg(feature = "arc")]
+    pub use crate::arc::Arc;
+    #[cfg(feature = "capsule")]
+    pub use crate::capsule::Capsule;
     #[cfg(feature = "circle")]
     pub use crate::circle::Circle;
+    #[cfg(feature = "circular_segment")]
+    pub use crate::circular_segment::CircularSegment;
+    #[cfg(feature = "cubic_bezier")]
+    pub use crate::cubic_bezier::CubicBezier;
+    #[cfg(feature = "circle")]
+    pub use crate::double::DCircle;
+    #[cfg(feature = "ellipse")]
+    pub use crate::double::DEllipse;
+    #[cfg(feature = "line")]
+    pub use crate::double::DLine;
+    #[cfg(feature = "mesh")]
+    pub use crate::double::DMesh;
+    #[cfg(feature = "point")]
+    pub use crate::double::DPoint;
+    #[cfg(feature = "polygon")]
+    pub use crate::double::DPolygon;
+    #[cfg(feature = "polygon")]
+    pub use crate::double::DPolygonDefect;
+    #[cfg(all(feature = "polygon", feature = "rand"))]
+    pub use crate::double::DPolygonSampler;
+    #[cfg(feature = "ray")]
+    pub use crate::double::DRay;
+    #[cfg(feature = "rectangle")]
+    pub use crate::double::DRectangle;
+    #[cfg(all(feature = "polygon", feature = "line"))]
+    pub use crate::double::DSplitResult;
+    #[cfg(feature = "rectangle")]
+    pub use crate::double::DSweepHit;
+    #[cfg(feature = "triangle")]
+    pub use crate::double::DTriangle;
     #[cfg(feature = "ellipse")]
     pub use crate::ellipse::Ellipse;
+    #[cfg(feature = "half_plane")]
+    pub use crate::half_plane::HalfPlane;
+    #[cfg(feature = "infinite_line")]
+    pub use crate::infinite_line::InfiniteLine;
+    #[cfg(all(feature = "line", feature = "integer"))]
+    pub use crate::line::ILine;
     #[cfg(feature = "line")]
     pub use crate::line::Line;
-    #[cfg(feature = "polygon")]
-    pub use crate::polygon::Polygon;
+    #[cfg(feature = "mesh")]
+    pub use crate::mesh::Mesh;
+    #[cfg(feature = "mesh")]
+    pub use crate::mesh::MeshDefect;
+    #[cfg(feature = "mesh")]
+    pub use crate::mesh::TriangulationError;
+    #[cfg(feature = "oriented_rectangle")]
+    pub use crate::oriented_rectangle::OrientedRectangle;
+    #[cfg(all(feature = "point", feature = "integer"))]
+    pub use crate::point::IPoint;
     #[cfg(feature = "point")]
     pub use crate::point::Point;
+    #[cfg(feature = "polygon")]
+    pub use crate::polygon::Polygon;
+    #[cfg(feature = "polygon")]
+    pub use crate::polygon::PolygonDefect;
+    #[cfg(all(feature = "polygon", feature = "rand"))]
+    pub use crate::polygon::PolygonSampler;
+    #[cfg(all(feature = "polygon", feature = "line"))]
+    pub use crate::polygon::SplitResult;
+    #[cfg(feature = "polygon_with_holes")]
+    pub use crate::polygon_with_holes::HoleDefect;
+    #[cfg(feature = "polygon_with_holes")]
+    pub use crate::polygon_with_holes::PolygonWithHoles;
+    #[cfg(feature = "polyline")]
+    pub use crate::polyline::Polyline;
+    #[cfg(feature = "quad")]
+    pub use crate::quad::Quad;
+    #[cfg(feature = "quadratic_bezier")]
+    pub use crate::quadratic_bezier::QuadraticBezier;
     #[cfg(feature = "ray")]
     pub use crate::ray::Ray;
+    #[cfg(all(feature = "rectangle", feature = "integer"))]
+    pub use crate::rectangle::IRect;
     #[cfg(feature = "rectangle")]
     pub use crate::rectangle::Rectangle;
+    #[cfg(feature = "rectangle")]
+    pub use crate::rectangle::SweepHit;
+    #[cfg(feature = "regular_polygon")]
+    pub use crate::regular_polygon::RegularPolygon;
+    #[cfg(feature = "rounded_rectangle")]
+    pub use crate::rounded_rectangle::RoundedRectangle;
+    #[cfg(feature = "integer")]
+    pub use crate::rounding::Rounding;
+    #[cfg(feature = "sector")]
+    pub use crate::sector::Sector;
+    pub use crate::traits::BoundaryNormal;
+    #[cfg(feature = "rectangle")]
+    pub use crate::traits::Bounded;
+    pub use crate::traits::ClosestPoint;
+    pub use crate::traits::ContactManifold;
+    pub use crate::traits::Contacts;
+    pub use crate::traits::Contains;
+    pub use crate::traits::DistanceTo;
+    pub use crate::traits::Intersects;
+    pub use crate::traits::Penetrates;
+    pub use crate::traits::Penetration;
+    pub use crate::traits::Perimeter;
+    #[cfg(feature = "line")]
+    pub use crate::traits::Reflect;
+    #[cfg(feature = "rand")]
+    pub use crate::traits::SampleShape;
+    pub use crate::traits::Shape2d;
+    pub use crate::traits::ShapeDefect;
+    pub use crate::traits::ShapeLerp;
+    pub use crate::traits::SignedDistance;
+    pub use crate::traits::ToBitsKey;
+    pub use crate::traits::Transformable;
+    pub use crate::traits::Validity;
     #[cfg(feature = "triangle")]
     pub use crate::triangle::Triangle;
+    pub use crate::winding::Winding;
+    #[cfg(any(
+        feature = "point",
+        feature = "line",
+        feature = "ray",
+        feature = "circle",
+        feature = "ellipse",
+        feature = "rectangle",
+        feature = "triangle",
+        feature = "polygon"
+    ))]
+    pub use crate::Shape;
 }
@@ -1,258 +1,484 @@
 use std::fmt::Display;
 
+#[cfg(feature = "integer")]
+use glam::IVec2;
 use glam::Vec2;
 
-/// Represents a [`Line`] in 2d space
-pub struct Line {
-    origin: Vec2,
-    end: Vec2,
+#[cfg(feature = "integer")]
+use crate::prelude::Rounding;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+// Defines the `Line` for one precision, here with `f32` and in `crate::double` with `f64`, where the
+// shape and `Vec2` names in the bodies refer to the `f64` types
+macro_rules! impl_line {
+    ($(#[$meta:meta])* $line:ident, $float:ident; $imports:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $line {
+            origin: Vec2,
+            end: Vec2,
+        }
+
+        // ##########
+        // Constructors
+        // ##########
+        impl Line {
+            /// Creates a new [`Line`] starting at the `origin` and ending at the `end`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let line = Line::new(Vec2::ZERO, Vec2::ONE);
+            ///
+            /// assert_eq!(line.direction(), Vec2::ONE);
+            /// ```
+            pub fn new(origin: Vec2, end: Vec2) -> Self {
+                Self { origin, end }
+            }
+
+            /// Creates a new [`Line`] starting at the `origin` and ending in a `direction`  at a `distance`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let line = Line::new_direction(Vec2::ZERO, Vec2 { x: 1., y: 0. }, 5.);
+            ///
+            /// assert_eq!(line.end(), Vec2 { x: 5., y: 0. });
+            /// ```
+            pub fn new_direction(origin: Vec2, direction: Vec2, distance: $float) -> Self {
+                Self {
+                    origin,
+                    end: origin + direction.normalize_or_zero() * distance,
+                }
+            }
+        }
+
+        // ##########
+        // Getters/Setters
+        // ##########
+        impl Line {
+            /// Get the origin for the [`Line`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let line = Line::new(Vec2::ZERO, Vec2::ONE);
+            /// let origin = line.origin();
+            ///
+            /// assert_eq!(origin, Vec2::ZERO);
+            /// ```
+            pub fn origin(&self) -> Vec2 {
+                self.origin
+            }
+
+            /// Get the end of the [`Line`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let line = Line::new(Vec2::ZERO, Vec2::ONE);
+            /// let end = line.end();
+            ///
+            /// assert_eq!(end, Vec2::ONE);
+            /// ```
+            pub fn end(&self) -> Vec2 {
+                self.end
+            }
+
+            /// Set the origin of the [`Line`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut line = Line::new(Vec2::ZERO, Vec2::ONE);
+            /// line.set_origin(Vec2::NEG_ONE);
+            ///
+            /// assert_eq!(line.origin(), Vec2::NEG_ONE);
+            /// ```
+            pub fn set_origin(&mut self, origin: Vec2) {
+                self.origin = origin;
+            }
+
+            /// Set the end of the [`Line`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut line = Line::new(Vec2::ZERO, Vec2::ONE);
+            /// line.set_end(Vec2::NEG_ONE);
+            ///
+            /// assert_eq!(line.end(), Vec2::NEG_ONE);
+            /// ```
+            pub fn set_end(&mut self, offset: Vec2) {
+                self.end = offset;
+            }
+        }
+
+        // ##########
+        // Attributes
+        // ##########
+        impl Line {
+            /// Get the center of the [`Line`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut line = Line::new(Vec2::ZERO, Vec2::ONE);
+            /// let center = line.center();
+            ///
+            /// assert_eq!(center, Vec2 { x: 0.5, y: 0.5 });
+            /// ```
+            pub fn center(&self) -> Vec2 {
+                (self.origin() + self.end()) * 0.5
+            }
+
+            /// Get the non-normalized direction of the [`Line`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut line = Line::new(Vec2::ZERO, Vec2 { x: 2., y: 0. });
+            /// let direction = line.direction();
+            ///
+            /// assert_eq!(direction, Vec2 { x: 2., y: 0. });
+            /// ```
+            pub fn direction(&self) -> Vec2 {
+                self.end() - self.origin()
+            }
+
+            /// Get the length of the [`Line`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut line = Line::new(Vec2::ZERO, Vec2 { x: 2., y: 0. });
+            /// let length = line.length();
+            ///
+            /// assert_eq!(length, 2.);
+            ///
+            /// let diagonal = Line::new(Vec2 { x: 1., y: 1. }, Vec2 { x: -2., y: -3. });
+            /// assert_eq!(diagonal.length(), 5.);
+            /// ```
+            pub fn length(&self) -> $float {
+                self.direction().length()
+            }
+        }
+
+        // ##########
+        // Consts
+        // ##########
+        impl Line {
+            /// A basic wrapper to set the [`Line`] to origin `(0., 0.)` and the end to `(0., 1.)`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let line1 = Line::UP;
+            /// let line2 = Line::new(Vec2::ZERO, Vec2 { x: 0., y: 1. });
+            ///
+            /// assert_eq!(line1.origin(), line2.origin());
+            /// assert_eq!(line1.end(), line2.end());
+            /// ```
+            pub const UP: Self = Self {
+                origin: Vec2::ZERO,
+                end: Vec2 { x: 0., y: 1. },
+            };
+
+            /// A basic wrapper to set the [`Line`] to origin `(0., 0.)` and the end to `(1., 0.)`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let line1 = Line::RIGHT;
+            /// let line2 = Line::new(Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            ///
+            /// assert_eq!(line1.origin(), line2.origin());
+            /// assert_eq!(line1.end(), line2.end());
+            /// ```
+            pub const RIGHT: Self = Self {
+                origin: Vec2::ZERO,
+                end: Vec2 { x: 1., y: 0. },
+            };
+
+            /// A basic wrapper to set the [`Line`] to origin `(0., 0.)` and the end to `(0., -1.)`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let line1 = Line::DOWN;
+            /// let line2 = Line::new(Vec2::ZERO, Vec2 { x: 0., y: -1. });
+            ///
+            /// assert_eq!(line1.origin(), line2.origin());
+            /// assert_eq!(line1.end(), line2.end());
+            /// ```
+            pub const DOWN: Self = Self {
+                origin: Vec2::ZERO,
+                end: Vec2 { x: 0., y: -1. },
+            };
+
+            /// A basic wrapper to set the [`Line`] to origin `(0., 0.)` and the end to `(-1., 0.)`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let line1 = Line::LEFT;
+            /// let line2 = Line::new(Vec2::ZERO, Vec2 { x: -1., y: 0. });
+            ///
+            /// assert_eq!(line1.origin(), line2.origin());
+            /// assert_eq!(line1.end(), line2.end());
+            /// ```
+            pub const LEFT: Self = Self {
+                origin: Vec2::ZERO,
+                end: Vec2 { x: -1., y: 0. },
+            };
+        }
+
+        // ##########
+        // Default impl
+        // ##########
+        impl Default for Line {
+            fn default() -> Self {
+                Self {
+                    origin: Vec2::ZERO,
+                    end: Vec2::ONE,
+                }
+            }
+        }
+
+        // ##########
+        // Display impl
+        // ##########
+        impl Display for Line {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    concat!(stringify!($line), " {{ origin: {}, end: {} }}"),
+                    self.origin(),
+                    self.end()
+                )
+            }
+        }
+    };
 }
+pub(crate) use impl_line;
+
+impl_line!(
+    /// Represents a [`Line`] in 2d space
+    Line,
+    f32;
+    "use glam::Vec2;\nuse shapes2d::prelude::*;"
+);
 
 // ##########
-// Constructors
+// approx impl
 // ##########
-impl Line {
-    /// Creates a new [`Line`] starting at the `origin` and ending at the `end`
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
-    ///
-    /// let line = Line::new(Vec2::ZERO, Vec2::ONE);
-    ///
-    /// assert_eq!(line.direction(), Vec2::ONE);
-    /// ```
-    pub fn new(origin: Vec2, end: Vec2) -> Self {
-        Self { origin, end }
-    }
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `origin` and `end` of two [`Line`]s within `epsilon`, so a reversed [`Line`] is not close
+    Line { origin, end }
+);
+
+// ##########
+// ILine
+// ##########
+/// Represents a [`Line`] between two cells of an integer grid
+#[cfg(feature = "integer")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ILine {
+    origin: IVec2,
+    end: IVec2,
+}
 
-    /// Creates a new [`Line`] starting at the `origin` and ending in a `direction`  at a `distance`
+#[cfg(feature = "integer")]
+impl ILine {
+    /// Creates a new [`ILine`] starting at the `origin` and ending at the `end`
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::ILine;
     ///
-    /// let line = Line::new_direction(Vec2::ZERO, Vec2 { x: 1., y: 0. }, 5.);
+    /// let line = ILine::new(IVec2::ZERO, IVec2 { x: 4, y: 2 });
     ///
-    /// assert_eq!(line.end(), Vec2 { x: 5., y: 0. });
+    /// assert_eq!(line.end(), IVec2 { x: 4, y: 2 });
     /// ```
-    pub fn new_direction(origin: Vec2, direction: Vec2, distance: f32) -> Self {
-        Self {
-            origin,
-            end: origin + direction.normalize_or_zero() * distance,
-        }
+    pub fn new(origin: IVec2, end: IVec2) -> Self {
+        Self { origin, end }
     }
-}
 
-// ##########
-// Getters/Setters
-// ##########
-impl Line {
-    /// Get the origin for the [`Line`]
+    /// Get the origin for the [`ILine`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
-    ///
-    /// let line = Line::new(Vec2::ZERO, Vec2::ONE);
-    /// let origin = line.origin();
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::ILine;
     ///
-    /// assert_eq!(origin, Vec2::ZERO);
+    /// assert_eq!(ILine::new(IVec2::ZERO, IVec2::ONE).origin(), IVec2::ZERO);
     /// ```
-    pub fn origin(&self) -> Vec2 {
+    pub fn origin(&self) -> IVec2 {
         self.origin
     }
 
-    /// Get the end of the [`Line`]
+    /// Get the end for the [`ILine`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::ILine;
     ///
-    /// let line = Line::new(Vec2::ZERO, Vec2::ONE);
-    /// let end = line.end();
-    ///
-    /// assert_eq!(end, Vec2::ONE);
+    /// assert_eq!(ILine::new(IVec2::ZERO, IVec2::ONE).end(), IVec2::ONE);
     /// ```
-    pub fn end(&self) -> Vec2 {
+    pub fn end(&self) -> IVec2 {
         self.end
     }
 
-    /// Set the origin of the [`Line`]
+    /// Set the origin for the [`ILine`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::ILine;
     ///
-    /// let mut line = Line::new(Vec2::ZERO, Vec2::ONE);
-    /// line.set_origin(Vec2::NEG_ONE);
+    /// let mut line = ILine::new(IVec2::ZERO, IVec2::ONE);
+    /// line.set_origin(IVec2::NEG_ONE);
     ///
-    /// assert_eq!(line.origin(), Vec2::NEG_ONE);
+    /// assert_eq!(line.origin(), IVec2::NEG_ONE);
     /// ```
-    pub fn set_origin(&mut self, origin: Vec2) {
+    pub fn set_origin(&mut self, origin: IVec2) {
         self.origin = origin;
     }
 
-    /// Set the end of the [`Line`]
+    /// Set the end for the [`ILine`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::ILine;
     ///
-    /// let mut line = Line::new(Vec2::ZERO, Vec2::ONE);
-    /// line.set_end(Vec2::NEG_ONE);
+    /// let mut line = ILine::new(IVec2::ZERO, IVec2::ONE);
+    /// line.set_end(IVec2::NEG_ONE);
     ///
-    /// assert_eq!(line.end(), Vec2::NEG_ONE);
+    /// assert_eq!(line.end(), IVec2::NEG_ONE);
     /// ```
-    pub fn set_end(&mut self, offset: Vec2) {
-        self.end = offset;
+    pub fn set_end(&mut self, end: IVec2) {
+        self.end = end;
     }
-}
 
-// ##########
-// Attributes
-// ##########
-impl Line {
-    /// Get the center of the [`Line`]
+    /// Get the number of cells the [`ILine`] passes through, see [`ILine::cells`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
-    ///
-    /// let mut line = Line::new(Vec2::ZERO, Vec2::ONE);
-    /// let center = line.center();
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::ILine;
     ///
-    /// assert_eq!(center, Vec2 { x: 0.5, y: 0.5 });
+    /// assert_eq!(ILine::new(IVec2::ZERO, IVec2 { x: 3, y: -1 }).cell_count(), 4);
+    /// assert_eq!(ILine::new(IVec2::ONE, IVec2::ONE).cell_count(), 1);
     /// ```
-    pub fn center(&self) -> Vec2 {
-        (self.origin() + self.end()) * 0.5
+    pub fn cell_count(&self) -> u32 {
+        let delta = self.end - self.origin;
+        delta.x.unsigned_abs().max(delta.y.unsigned_abs()) + 1
     }
 
-    /// Get the non-normalized direction of the [`Line`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
+    /// Get the cells the [`ILine`] passes through from `origin` to `end`, using Bresenham's algorithm
     ///
-    /// let mut line = Line::new(Vec2::ZERO, Vec2 { x: 2., y: 0. });
-    /// let direction = line.direction();
+    /// Both `origin` and `end` are included, and each step moves to one of the eight neighboring cells.
+    /// Reversing the [`ILine`] may choose different cells where the line passes exactly between two.
     ///
-    /// assert_eq!(direction, Vec2 { x: 2., y: 0. });
     /// ```
-    pub fn direction(&self) -> Vec2 {
-        self.end() - self.origin()
-    }
-
-    /// Get the length of the [`Line`]
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::ILine;
     ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
+    /// let cells: Vec<IVec2> = ILine::new(IVec2::ZERO, IVec2 { x: 3, y: 0 }).cells().collect();
+    /// assert_eq!(cells, vec![IVec2 { x: 0, y: 0 }, IVec2 { x: 1, y: 0 }, IVec2 { x: 2, y: 0 }, IVec2 { x: 3, y: 0 }]);
     ///
-    /// let mut line = Line::new(Vec2::ZERO, Vec2 { x: 2., y: 0. });
-    /// let length = line.length();
+    /// let cells: Vec<IVec2> = ILine::new(IVec2::ZERO, IVec2 { x: -4, y: 2 }).cells().collect();
+    /// assert_eq!(
+    ///     cells,
+    ///     vec![IVec2 { x: 0, y: 0 }, IVec2 { x: -1, y: 1 }, IVec2 { x: -2, y: 1 }, IVec2 { x: -3, y: 2 }, IVec2 { x: -4, y: 2 }],
+    /// );
     ///
-    /// assert_eq!(length, 2.);
+    /// // a single cell
+    /// assert_eq!(ILine::new(IVec2::ONE, IVec2::ONE).cells().collect::<Vec<_>>(), vec![IVec2::ONE]);
     /// ```
-    pub fn length(&self) -> f32 {
-        // TODO: is this right?
-        self.direction().max_element()
-    }
-}
+    pub fn cells(&self) -> impl Iterator<Item = IVec2> {
+        let (origin, end) = (self.origin, self.end);
+        let step = IVec2 {
+            x: if end.x < origin.x { -1 } else { 1 },
+            y: if end.y < origin.y { -1 } else { 1 },
+        };
+        // Widened so that the error term can't overflow for lines across the whole grid
+        let dx = (end.x as i64 - origin.x as i64).abs();
+        let dy = -(end.y as i64 - origin.y as i64).abs();
+        let mut error = dx + dy;
 
-// ##########
-// Consts
-// ##########
-impl Line {
-    /// A basic wrapper to set the [`Line`] to origin `(0., 0.)` and the end to `(0., 1.)`
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
-    ///
-    /// let line1 = Line::UP;
-    /// let line2 = Line::new(Vec2::ZERO, Vec2 { x: 0., y: 1. });
-    ///
-    /// assert_eq!(line1.origin(), line2.origin());
-    /// assert_eq!(line1.end(), line2.end());
-    /// ```
-    pub const UP: Self = Self {
-        origin: Vec2::ZERO,
-        end: Vec2 { x: 0., y: 1. },
-    };
+        let mut next = Some(origin);
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = if current == end {
+                None
+            } else {
+                let mut cell = current;
+                let doubled = error * 2;
+                if doubled >= dy {
+                    error += dy;
+                    cell.x += step.x;
+                }
+                if doubled <= dx {
+                    error += dx;
+                    cell.y += step.y;
+                }
+                Some(cell)
+            };
+            Some(current)
+        })
+    }
 
-    /// A basic wrapper to set the [`Line`] to origin `(0., 0.)` and the end to `(1., 0.)`
+    /// Get the [`Line`] between the same coordinates
     ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
-    ///
-    /// let line1 = Line::RIGHT;
-    /// let line2 = Line::new(Vec2::ZERO, Vec2 { x: 1., y: 0. });
-    ///
-    /// assert_eq!(line1.origin(), line2.origin());
-    /// assert_eq!(line1.end(), line2.end());
-    /// ```
-    pub const RIGHT: Self = Self {
-        origin: Vec2::ZERO,
-        end: Vec2 { x: 1., y: 0. },
-    };
-
-    /// A basic wrapper to set the [`Line`] to origin `(0., 0.)` and the end to `(0., -1.)`
+    /// The conversion is exact for coordinates within `±2^24`, beyond which `f32` can't hold every integer.
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
+    /// use glam::{IVec2, Vec2};
+    /// use shapes2d::prelude::ILine;
     ///
-    /// let line1 = Line::DOWN;
-    /// let line2 = Line::new(Vec2::ZERO, Vec2 { x: 0., y: -1. });
+    /// let line = ILine::new(IVec2::ZERO, IVec2 { x: 4, y: 2 }).to_line();
     ///
-    /// assert_eq!(line1.origin(), line2.origin());
-    /// assert_eq!(line1.end(), line2.end());
+    /// assert_eq!((line.origin(), line.end()), (Vec2::ZERO, Vec2 { x: 4., y: 2. }));
     /// ```
-    pub const DOWN: Self = Self {
-        origin: Vec2::ZERO,
-        end: Vec2 { x: 0., y: -1. },
-    };
+    pub fn to_line(&self) -> Line {
+        Line::new(self.origin.as_vec2(), self.end.as_vec2())
+    }
+}
 
-    /// A basic wrapper to set the [`Line`] to origin `(0., 0.)` and the end to `(-1., 0.)`
+#[cfg(feature = "integer")]
+impl Line {
+    /// Get the [`ILine`] with both coordinates rounded by `rounding`
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Line;
+    /// use glam::{IVec2, Vec2};
+    /// use shapes2d::prelude::{Line, Rounding};
     ///
-    /// let line1 = Line::LEFT;
-    /// let line2 = Line::new(Vec2::ZERO, Vec2 { x: -1., y: 0. });
+    /// let line = Line::new(Vec2 { x: 0.5, y: 0.2 }, Vec2 { x: 3.7, y: -1.2 });
+    /// let rounded = line.to_iline(Rounding::Nearest);
     ///
-    /// assert_eq!(line1.origin(), line2.origin());
-    /// assert_eq!(line1.end(), line2.end());
+    /// assert_eq!((rounded.origin(), rounded.end()), (IVec2 { x: 1, y: 0 }, IVec2 { x: 4, y: -1 }));
     /// ```
-    pub const LEFT: Self = Self {
-        origin: Vec2::ZERO,
-        end: Vec2 { x: -1., y: 0. },
-    };
+    pub fn to_iline(&self, rounding: Rounding) -> ILine {
+        ILine::new(rounding.round(self.origin), rounding.round(self.end))
+    }
 }
 
-// ##########
-// Default impl
-// ##########
-impl Default for Line {
+#[cfg(feature = "integer")]
+impl Default for ILine {
     fn default() -> Self {
         Self {
-            origin: Vec2::ZERO,
-            end: Vec2::ONE,
+            origin: IVec2::ZERO,
+            end: IVec2::ONE,
         }
     }
 }
 
-// ##########
-// Display impl
-// ##########
-impl Display for Line {
+#[cfg(feature = "integer")]
+impl Display for ILine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Line {{ origin: {}, end: {} }}",
+            "ILine {{ origin: {}, end: {} }}",
             self.origin(),
             self.end()
         )
@@ -0,0 +1,1735 @@
+use std::{collections::HashMap, fmt::Display};
+
+use glam::{Affine2, DVec2, Vec2};
+
+#[cfg(feature = "polygon")]
+use crate::polygon::triangulate_polygon;
+#[cfg(feature = "annulus")]
+use crate::prelude::Annulus;
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "ellipse")]
+use crate::prelude::Ellipse;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "triangle")]
+use crate::prelude::Triangle;
+
+// The index of the super-triangle's corners, infinitely far away, in the triangles of `Mesh::delaunay`
+pub(crate) const INFINITE: usize = usize::MAX;
+
+/// A problem with a [`Mesh`], reported by [`Mesh::validate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MeshDefect {
+    /// A triangle uses an index without a vertex
+    IndexOutOfRange {
+        /// The index of the triangle
+        triangle: usize,
+        /// The vertex index that is out of range
+        index: u32,
+    },
+    /// The indices don't form whole triangles
+    IndicesNotMultipleOfThree,
+    /// A triangle has no area
+    DegenerateTriangle {
+        /// The index of the triangle
+        triangle: usize,
+    },
+    /// The colors don't match the vertices one to one
+    AttributeLengthMismatch,
+}
+
+/// Why a set of points couldn't be triangulated, reported by [`Mesh::delaunay`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriangulationError {
+    /// There are fewer than 3 distinct points
+    TooFewPoints,
+    /// Every point lies on one line, leaving no area to triangulate
+    Collinear,
+}
+
+// Defines the `Mesh` for one precision, here with `f32` and in `crate::double` with `f64`, where the
+// shape and `Vec2` names in the bodies refer to the `f64` types
+macro_rules! impl_mesh {
+    ($(#[$meta:meta])* $mesh:ident, $float:ident; $imports:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $mesh {
+            positions: Vec<Vec2>,
+            indices: Vec<u32>,
+            colors: Option<Vec<[f32; 4]>>,
+        }
+
+        // ##########
+        // Constructors
+        // ##########
+        impl Mesh {
+            /// Creates a new [`Mesh`] from vertex `positions` and triangle `indices` into them
+            ///
+            /// Every three `indices` form a triangle, which should be wound counter-clockwise.
+            /// Returns `None` if the number of `indices` isn't a multiple of 3, or any index is out of bounds.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let positions = vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }];
+            /// let mesh = Mesh::new(positions, vec![0, 1, 2, 0, 2, 3]).unwrap();
+            ///
+            /// assert_eq!(mesh.vertex_count(), 4);
+            /// assert_eq!(mesh.triangle_count(), 2);
+            ///
+            /// assert!(Mesh::new(vec![Vec2::ZERO, Vec2::ONE], vec![0, 1, 2]).is_none());
+            /// assert!(Mesh::new(vec![Vec2::ZERO, Vec2::ONE], vec![0, 1]).is_none());
+            /// ```
+            pub fn new(positions: Vec<Vec2>, indices: Vec<u32>) -> Option<Self> {
+                let in_bounds = indices
+                    .iter()
+                    .all(|index| (*index as usize) < positions.len());
+                if indices.len() % 3 == 0 && in_bounds {
+                    Some(Self {
+                        positions,
+                        indices,
+                        colors: None,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Creates a new [`Mesh`] with no vertices and no triangles
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::empty();
+            ///
+            /// assert_eq!(mesh.vertex_count(), 0);
+            /// assert_eq!(mesh.triangle_count(), 0);
+            /// ```
+            pub fn empty() -> Self {
+                Self {
+                    positions: Vec::new(),
+                    indices: Vec::new(),
+                    colors: None,
+                }
+            }
+
+            /// Creates a new [`Mesh`] combining every [`Mesh`] in `meshes`, see [`Mesh::append`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::merged([
+            ///     Mesh::from_triangles(&[Triangle::default()]),
+            ///     Mesh::empty(),
+            ///     Mesh::from_triangles(&[Triangle::default(), Triangle::default()]),
+            /// ]);
+            ///
+            /// assert_eq!(mesh.vertex_count(), 9);
+            /// assert_eq!(mesh.triangle_count(), 3);
+            /// assert_eq!(mesh.indices(), &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+            /// ```
+            pub fn merged(meshes: impl IntoIterator<Item = Mesh>) -> Self {
+                let mut mesh = Self::empty();
+                mesh.extend(meshes);
+                mesh
+            }
+
+            /// Creates a new [`Mesh`] containing every [`Triangle`] in `triangles`
+            ///
+            /// Each [`Triangle`] gets its own three vertices, no vertices are shared between triangles.
+            /// Clockwise triangles are reversed so that every triangle in the [`Mesh`] is counter-clockwise.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangles = [
+            ///     Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE),
+            ///     Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2::ONE),
+            /// ];
+            /// let mesh = Mesh::from_triangles(&triangles);
+            ///
+            /// assert_eq!(mesh.positions().len(), 6);
+            /// assert_eq!(mesh.indices(), &[0, 1, 2, 3, 4, 5]);
+            ///
+            /// // the second triangle was clockwise
+            /// assert_eq!(mesh.positions()[3..], [Vec2::ZERO, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            ///
+            /// let mut area = 0.;
+            /// for triangle in mesh.indices().chunks_exact(3) {
+            ///     let [a, b, c] = [0, 1, 2].map(|i| mesh.positions()[triangle[i] as usize]);
+            ///     let signed_area = (b - a).perp_dot(c - a) * 0.5;
+            ///     assert!(signed_area > 0.);
+            ///     area += signed_area;
+            /// }
+            #[doc = concat!(" assert_eq!(area, triangles.iter().map(|triangle| triangle.area()).sum::<", stringify!($float), ">());")]
+            /// ```
+            #[cfg(feature = "triangle")]
+            pub fn from_triangles(triangles: &[Triangle]) -> Self {
+                let mut positions = Vec::with_capacity(triangles.len() * 3);
+                let mut indices = Vec::with_capacity(triangles.len() * 3);
+                for triangle in triangles {
+                    let vertices = if triangle.is_clockwise() {
+                        triangle.reversed().vertices()
+                    } else {
+                        triangle.vertices()
+                    };
+                    indices.extend(positions.len() as u32..positions.len() as u32 + 3);
+                    positions.extend(vertices);
+                }
+
+                Self {
+                    positions,
+                    indices,
+                    colors: None,
+                }
+            }
+
+            /// Creates a new [`Mesh`] of two triangles covering a [`Rectangle`]
+            ///
+            /// The four corners are wound counter-clockwise from the minimum corner.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 3., 2.);
+            /// let mesh = Mesh::from_rect(&rect);
+            ///
+            /// assert_eq!(mesh.positions(), &[Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2 { x: 3., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// assert_eq!(mesh.indices(), &[0, 1, 2, 0, 2, 3]);
+            ///
+            /// let mut area = 0.;
+            /// for triangle in mesh.indices().chunks_exact(3) {
+            ///     let [a, b, c] = [0, 1, 2].map(|i| mesh.positions()[triangle[i] as usize]);
+            ///     let signed_area = (b - a).perp_dot(c - a) * 0.5;
+            ///     assert!(signed_area > 0.);
+            ///     area += signed_area;
+            /// }
+            /// assert_eq!(area, rect.width() * rect.height());
+            /// ```
+            #[cfg(feature = "rectangle")]
+            pub fn from_rect(rectangle: &Rectangle) -> Self {
+                let min = rectangle.min().min(rectangle.max());
+                let max = rectangle.min().max(rectangle.max());
+                Self {
+                    positions: vec![
+                        min,
+                        Vec2 { x: max.x, y: min.y },
+                        max,
+                        Vec2 { x: min.x, y: max.y },
+                    ],
+                    indices: vec![0, 1, 2, 0, 2, 3],
+                    colors: None,
+                }
+            }
+
+            /// Creates a new [`Mesh`] containing a [`Triangle`], reversed if it is clockwise
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 0. });
+            /// let mesh = Mesh::from_triangle(&triangle);
+            ///
+            /// assert_eq!(mesh.indices(), &[0, 1, 2]);
+            /// assert_eq!(mesh.positions(), &triangle.reversed().vertices());
+            /// ```
+            #[cfg(feature = "triangle")]
+            pub fn from_triangle(triangle: &Triangle) -> Self {
+                Self::from_triangles(std::slice::from_ref(triangle))
+            }
+
+            /// Creates a new [`Mesh`] approximating a [`Circle`] with a fan of `segments` triangles around its center
+            ///
+            /// The center is the first vertex, followed by `segments` vertices counter-clockwise around the edge,
+            /// starting to the right of the center. A `segments` below 3 is clamped to 3.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let circle = Circle::new(Vec2::ONE, 2.);
+            /// let mesh = Mesh::from_circle(&circle, 64);
+            ///
+            /// assert_eq!(mesh.vertex_count(), 65);
+            /// assert_eq!(mesh.triangle_count(), 64);
+            /// assert!(mesh.indices().iter().all(|index| (*index as usize) < mesh.vertex_count()));
+            ///
+            /// let mut area = 0.;
+            /// for triangle in mesh.indices().chunks_exact(3) {
+            ///     let [a, b, c] = [0, 1, 2].map(|i| mesh.positions()[triangle[i] as usize]);
+            ///     let signed_area = (b - a).perp_dot(c - a) * 0.5;
+            ///     assert!(signed_area > 0.);
+            ///     area += signed_area;
+            /// }
+            /// // a regular polygon with 64 sides covers a little less than the circle
+            #[doc = concat!(" let circle_area = std::", stringify!($float), "::consts::PI * 4.;")]
+            /// assert!(area < circle_area);
+            /// assert!(circle_area - area < 0.03);
+            /// ```
+            #[cfg(feature = "circle")]
+            pub fn from_circle(circle: &Circle, segments: u32) -> Self {
+                Self::fan(circle.center(), Vec2::splat(circle.radius()), 0., segments)
+            }
+
+            /// Creates a new [`Mesh`] approximating an [`Ellipse`] with a fan of `segments` triangles around its center
+            ///
+            /// The center is the first vertex, followed by `segments` vertices counter-clockwise around the edge,
+            /// starting at the end of the major axis. A `segments` below 3 is clamped to 3.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let ellipse = Ellipse::new(Vec2::ZERO, 3., 1.);
+            /// let mesh = Mesh::from_ellipse(&ellipse, 128);
+            ///
+            /// assert_eq!(mesh.vertex_count(), 129);
+            /// assert_eq!(mesh.triangle_count(), 128);
+            /// assert_eq!(mesh.positions()[1], Vec2 { x: 3., y: 0. });
+            ///
+            /// let mut area = 0.;
+            /// for triangle in mesh.indices().chunks_exact(3) {
+            ///     let [a, b, c] = [0, 1, 2].map(|i| mesh.positions()[triangle[i] as usize]);
+            ///     let signed_area = (b - a).perp_dot(c - a) * 0.5;
+            ///     assert!(signed_area > 0.);
+            ///     area += signed_area;
+            /// }
+            #[doc = concat!(" assert_abs_diff_eq!(area, std::", stringify!($float), "::consts::PI * 3., epsilon = 0.01);")]
+            /// ```
+            #[cfg(feature = "ellipse")]
+            pub fn from_ellipse(ellipse: &Ellipse, segments: u32) -> Self {
+                Self::fan(
+                    ellipse.center(),
+                    Vec2 {
+                        x: ellipse.radius_major(),
+                        y: ellipse.radius_minor(),
+                    },
+                    ellipse.rotation(),
+                    segments,
+                )
+            }
+
+            /// Creates a new [`Mesh`] covering a [`Polygon`] by ear clipping
+            ///
+            /// The [`Polygon`] must be simple (see [`Polygon::is_simple`]) and may be concave or clockwise.
+            /// Its coordinates become the vertices, counter-clockwise and without repeated or collinear coordinates.
+            /// A [`Polygon`] with no area gives an empty [`Mesh`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // a clockwise L shape made of three unit squares
+            /// let l_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 0., y: 2. },
+            ///     Vec2 { x: 1., y: 2. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 2., y: 0. },
+            /// ]);
+            /// let mesh = Mesh::from_polygon(&l_shape);
+            ///
+            /// assert_eq!(mesh.vertex_count(), 6);
+            /// assert_eq!(mesh.triangle_count(), 4);
+            /// assert!(mesh.indices().iter().all(|index| (*index as usize) < mesh.vertex_count()));
+            ///
+            /// let mut area = 0.;
+            /// for triangle in mesh.indices().chunks_exact(3) {
+            ///     let [a, b, c] = [0, 1, 2].map(|i| mesh.positions()[triangle[i] as usize]);
+            ///     let signed_area = (b - a).perp_dot(c - a) * 0.5;
+            ///     assert!(signed_area > 0.);
+            ///     area += signed_area;
+            /// }
+            /// assert_eq!(area, l_shape.area());
+            ///
+            /// let line = Polygon::new(vec![Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }]);
+            /// assert_eq!(Mesh::from_polygon(&line).triangle_count(), 0);
+            /// ```
+            #[cfg(feature = "polygon")]
+            pub fn from_polygon(polygon: &Polygon) -> Self {
+                let (positions, triangles) = triangulate_polygon(polygon);
+                if triangles.is_empty() {
+                    return Self::empty();
+                }
+                let indices = triangles
+                    .iter()
+                    .flatten()
+                    .map(|index| *index as u32)
+                    .collect();
+                Self {
+                    positions,
+                    indices,
+                    colors: None,
+                }
+            }
+
+            /// Creates the Delaunay triangulation of `points`, where no point is inside the circumcircle of any triangle
+            ///
+            /// The triangles cover the convex hull of the points, built with the Bowyer-Watson algorithm by adding one
+            /// point at a time. The first triangle sits inside of a super-triangle with its corners infinitely far away,
+            /// so the triangles along the hull are never lost to a super-triangle that was too small. Points within a
+            /// millionth of the largest coordinate (or of `1.` near the origin) of each other are the same point, and
+            /// only one of them becomes a vertex. The vertices keep the order of the `points` they came from.
+            ///
+            /// Points exactly on a circumcircle don't count as inside of it, so where four or more points share a
+            /// circle, as on a grid, whichever triangles were formed first are kept.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_relative_eq;
+            /// use rand::{Rng, SeedableRng};
+            ///
+            /// // no point falls inside the circumcircle of any triangle of a random point set
+            /// let mut rng = rand::rngs::StdRng::seed_from_u64(2024);
+            /// let points: Vec<Vec2> = (0..200)
+            ///     .map(|_| Vec2 { x: rng.gen_range(-50.0..50.), y: rng.gen_range(-50.0..50.) })
+            ///     .collect();
+            /// let mesh = Mesh::delaunay(&points).unwrap();
+            ///
+            /// assert_eq!(mesh.vertex_count(), 200);
+            /// assert_eq!(mesh.validate(), Ok(()));
+            /// for triangle in mesh.triangles() {
+            ///     let center = triangle.circumcenter().unwrap();
+            ///     let radius = center.distance(triangle.coordinate1());
+            ///     assert!(points.iter().all(|point| point.distance(center) >= radius * (1. - 1e-4)));
+            /// }
+            /// let hull = Polygon::convex_hull(&points).unwrap();
+            /// assert_relative_eq!(mesh.area(), hull.area(), max_relative = 1e-5);
+            ///
+            /// // a grid is full of points sharing a circle, and of repeated points
+            #[doc = concat!(" let grid: Vec<Vec2> = (0..200).map(|i| Vec2 { x: (i % 10) as ", stringify!($float), ", y: (i / 10 % 10) as ", stringify!($float), " }).collect();")]
+            /// let mesh = Mesh::delaunay(&grid).unwrap();
+            /// assert_eq!(mesh.vertex_count(), 100);
+            /// assert_eq!(mesh.triangle_count(), 162);
+            /// assert_eq!(mesh.area(), 81.);
+            ///
+            /// assert_eq!(Mesh::delaunay(&[Vec2::ZERO, Vec2::ONE, Vec2::ONE]), Err(TriangulationError::TooFewPoints));
+            /// assert_eq!(
+            ///     Mesh::delaunay(&[Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }]),
+            ///     Err(TriangulationError::Collinear)
+            /// );
+            /// ```
+            pub fn delaunay(points: &[Vec2]) -> Result<Self, TriangulationError> {
+                let extent = points
+                    .iter()
+                    .map(|point| point.abs().max_element())
+                    .fold(0., $float::max);
+                let epsilon = (extent + 1.) * 1e-6;
+
+                let positions = distinct_points(points, epsilon);
+                if positions.len() < 3 {
+                    return Err(TriangulationError::TooFewPoints);
+                }
+
+                // The first triangle is the first point, the point furthest from it, and the point furthest from the
+                // line between those
+                let vertices: Vec<DVec2> = positions
+                    .iter()
+                    .map(|point| DVec2::new(f64::from(point.x), f64::from(point.y)))
+                    .collect();
+                let furthest_from = |distance: &dyn Fn(DVec2) -> f64| {
+                    (0..vertices.len())
+                        .max_by(|a, b| distance(vertices[*a]).total_cmp(&distance(vertices[*b])))
+                        .unwrap_or(0)
+                };
+                let first = 0;
+                let second = furthest_from(&|vertex| vertex.distance_squared(vertices[first]));
+                let direction = (vertices[second] - vertices[first]).normalize_or_zero();
+                let third = furthest_from(&|vertex| direction.perp_dot(vertex - vertices[first]).abs());
+                let side = direction.perp_dot(vertices[third] - vertices[first]);
+                if side.abs() <= f64::from(epsilon) {
+                    return Err(TriangulationError::Collinear);
+                }
+                let (second, third) = if side > 0. {
+                    (second, third)
+                } else {
+                    (third, second)
+                };
+
+                // Each edge of the hull also has a triangle outside of it, with a corner at the super-triangle's corners
+                let mut triangles = vec![
+                    [first, second, third],
+                    [second, first, INFINITE],
+                    [third, second, INFINITE],
+                    [first, third, INFINITE],
+                ];
+                for point in (0..vertices.len()).filter(|point| ![first, second, third].contains(point)) {
+                    let (removed, kept): (Vec<[usize; 3]>, Vec<[usize; 3]>) = triangles
+                        .into_iter()
+                        .partition(|triangle| in_circumcircle(&vertices, *triangle, vertices[point]));
+                    triangles = kept;
+
+                    // The edges of the hole left behind which aren't shared by two removed triangles, each still
+                    // counter-clockwise around the hole, and so around the new point
+                    let edges: Vec<(usize, usize)> = removed
+                        .iter()
+                        .flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)])
+                        .collect();
+                    triangles.extend(
+                        edges
+                            .iter()
+                            .filter(|(a, b)| !edges.contains(&(*b, *a)))
+                            .map(|(a, b)| match (*a, *b) {
+                                (INFINITE, b) => [b, point, INFINITE],
+                                (a, INFINITE) => [point, a, INFINITE],
+                                (a, b) => [a, b, point],
+                            }),
+                    );
+                }
+
+                let indices = triangles
+                    .into_iter()
+                    .filter(|triangle| !triangle.contains(&INFINITE))
+                    .flatten()
+                    .map(|index| index as u32)
+                    .collect();
+                Ok(Self {
+                    positions,
+                    indices,
+                    colors: None,
+                })
+            }
+
+            // A fan of `segments` triangles around `center`, out to an ellipse of `radii` turned by `rotation`
+            #[cfg(any(feature = "circle", feature = "ellipse"))]
+            fn fan(center: Vec2, radii: Vec2, rotation: $float, segments: u32) -> Self {
+                let segments = segments.max(3);
+                let turn = Vec2::from_angle(rotation);
+                let mut positions = Vec::with_capacity(segments as usize + 1);
+                positions.push(center);
+                positions.extend((0..segments).map(|i| {
+                    let angle = i as $float * std::$float::consts::TAU / segments as $float;
+                    center + turn.rotate(Vec2::from_angle(angle) * radii)
+                }));
+                let indices = (0..segments)
+                    .flat_map(|i| [0, i + 1, (i + 1) % segments + 1])
+                    .collect();
+                Self {
+                    positions,
+                    indices,
+                    colors: None,
+                }
+            }
+        }
+
+        // ##########
+        // Getters/Setters
+        // ##########
+        impl Mesh {
+            /// Get the vertex `positions` of the [`Mesh`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// let positions = mesh.positions();
+            ///
+            /// assert_eq!(positions, &Triangle::default().vertices());
+            /// ```
+            pub fn positions(&self) -> &[Vec2] {
+                &self.positions
+            }
+
+            /// Get the triangle `indices` of the [`Mesh`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// let indices = mesh.indices();
+            ///
+            /// assert_eq!(indices, &[0, 1, 2]);
+            /// ```
+            pub fn indices(&self) -> &[u32] {
+                &self.indices
+            }
+
+            /// Get mutable access to the vertex `positions` of the [`Mesh`]
+            ///
+            /// Removing positions can leave indices out of bounds, see [`Mesh::validate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// mesh.positions_mut()[0] = Vec2 { x: 2., y: 2. };
+            ///
+            /// assert_eq!(mesh.positions()[0], Vec2 { x: 2., y: 2. });
+            /// ```
+            pub fn positions_mut(&mut self) -> &mut Vec<Vec2> {
+                &mut self.positions
+            }
+
+            /// Get mutable access to the triangle `indices` of the [`Mesh`]
+            ///
+            /// Nothing stops the indices from going out of bounds or no longer forming whole triangles,
+            /// see [`Mesh::validate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// mesh.indices_mut().swap(1, 2);
+            ///
+            /// assert_eq!(mesh.indices(), &[0, 2, 1]);
+            /// ```
+            pub fn indices_mut(&mut self) -> &mut Vec<u32> {
+                &mut self.indices
+            }
+
+            /// Get an iterator over the triangles of the [`Mesh`]
+            ///
+            /// Triangles with an index outside of the vertices are skipped.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangles = [
+            ///     Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE),
+            ///     Triangle::new(Vec2::ONE, Vec2 { x: 2., y: 1. }, Vec2 { x: 2., y: 2. }),
+            /// ];
+            /// let mesh = Mesh::from_triangles(&triangles);
+            ///
+            /// assert_eq!(mesh.triangles().count(), 2);
+            /// for (triangle, original) in mesh.triangles().zip(&triangles) {
+            ///     assert_eq!(triangle.vertices(), original.vertices());
+            /// }
+            /// ```
+            #[cfg(feature = "triangle")]
+            pub fn triangles(&self) -> impl Iterator<Item = Triangle> + '_ {
+                self.triangle_positions()
+                    .map(|[a, b, c]| Triangle::new(a, b, c))
+            }
+
+            /// Get the triangle at `index` in the [`Mesh`], or `None` if `index` is out of bounds
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }], vec![0, 1, 2, 0, 2, 3]).unwrap();
+            /// let triangle = mesh.triangle(1).unwrap();
+            ///
+            /// assert_eq!(triangle.vertices(), [Vec2::ZERO, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            /// assert!(mesh.triangle(2).is_none());
+            /// ```
+            #[cfg(feature = "triangle")]
+            pub fn triangle(&self, index: usize) -> Option<Triangle> {
+                let start = index.checked_mul(3)?;
+                let indices = self.indices.get(start..start + 3)?;
+                let a = self.positions.get(indices[0] as usize)?;
+                let b = self.positions.get(indices[1] as usize)?;
+                let c = self.positions.get(indices[2] as usize)?;
+                Some(Triangle::new(*a, *b, *c))
+            }
+
+            /// Get the per-vertex RGBA `colors` of the [`Mesh`], or `None` if it has no colors
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// assert!(mesh.colors().is_none());
+            ///
+            /// mesh.fill_color([1., 0., 0., 1.]);
+            /// assert_eq!(mesh.colors(), Some(&[[1., 0., 0., 1.]; 3][..]));
+            /// ```
+            pub fn colors(&self) -> Option<&[[f32; 4]]> {
+                self.colors.as_deref()
+            }
+
+            /// Get mutable access to the per-vertex RGBA `colors` of the [`Mesh`], or `None` if it has no colors
+            ///
+            /// Changing the number of colors leaves them mismatched with the vertices, see [`Mesh::validate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// mesh.fill_color([1., 1., 1., 1.]);
+            /// mesh.colors_mut().unwrap()[0] = [0., 0., 0., 1.];
+            ///
+            /// assert_eq!(mesh.colors().unwrap()[0], [0., 0., 0., 1.]);
+            /// ```
+            pub fn colors_mut(&mut self) -> Option<&mut Vec<[f32; 4]>> {
+                self.colors.as_mut()
+            }
+
+            /// Set new per-vertex RGBA `colors` for the [`Mesh`]
+            ///
+            /// # Panics
+            ///
+            /// Panics if the number of `colors` isn't the `vertex_count`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// mesh.set_colors(vec![[1., 0., 0., 1.], [0., 1., 0., 1.], [0., 0., 1., 1.]]);
+            ///
+            /// assert_eq!(mesh.colors().unwrap()[1], [0., 1., 0., 1.]);
+            /// ```
+            pub fn set_colors(&mut self, colors: Vec<[f32; 4]>) {
+                assert_eq!(
+                    colors.len(),
+                    self.positions.len(),
+                    "a mesh needs exactly one color per vertex"
+                );
+                self.colors = Some(colors);
+            }
+
+            /// Set every vertex of the [`Mesh`] to the RGBA `color`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default(), Triangle::default()]);
+            /// mesh.fill_color([0.5, 0.5, 0.5, 1.]);
+            ///
+            /// assert_eq!(mesh.colors().unwrap().len(), mesh.vertex_count());
+            /// ```
+            pub fn fill_color(&mut self, color: [f32; 4]) {
+                self.colors = Some(vec![color; self.positions.len()]);
+            }
+
+            /// Removes the `colors` from the [`Mesh`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// mesh.fill_color([1., 1., 1., 1.]);
+            /// mesh.clear_colors();
+            ///
+            /// assert!(mesh.colors().is_none());
+            /// ```
+            pub fn clear_colors(&mut self) {
+                self.colors = None;
+            }
+
+            /// Get the triangle `indices` of the [`Mesh`] as `u16`, or `None` if any index doesn't fit, see [`Mesh::fits_u16`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::default()]);
+            ///
+            /// assert_eq!(mesh.indices_u16(), Some(vec![0_u16, 1, 2]));
+            ///
+            /// // merging keeps full indices, they just stop fitting
+            /// let half = || Mesh::new(vec![Vec2::ZERO; 32768], vec![0, 1, 32767]).unwrap();
+            /// let merged = Mesh::merged([half(), half()]);
+            /// assert_eq!(merged.indices_u16(), Some(vec![0, 1, 32767, 32768, 32769, 65535]));
+            ///
+            /// let merged = Mesh::merged([half(), Mesh::from_triangles(&[Triangle::default()]), half()]);
+            /// assert_eq!(merged.indices_u16(), None);
+            /// assert_eq!(merged.indices()[8], 65538);
+            /// ```
+            pub fn indices_u16(&self) -> Option<Vec<u16>> {
+                self.indices
+                    .iter()
+                    .map(|index| u16::try_from(*index).ok())
+                    .collect()
+            }
+
+            /// Consumes the [`Mesh`], returning its vertex `positions` and triangle `indices`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// let (positions, indices) = mesh.into_buffers();
+            ///
+            /// assert_eq!(positions, Triangle::default().vertices());
+            /// assert_eq!(indices, vec![0, 1, 2]);
+            /// ```
+            pub fn into_buffers(self) -> (Vec<Vec2>, Vec<u32>) {
+                (self.positions, self.indices)
+            }
+
+            /// Adds a vertex at `position` to the [`Mesh`], returning its index
+            ///
+            /// When the [`Mesh`] has `colors`, the vertex gets [`Mesh::DEFAULT_COLOR`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::empty();
+            ///
+            /// assert_eq!(mesh.push_vertex(Vec2::ZERO), 0);
+            /// assert_eq!(mesh.push_vertex(Vec2::ONE), 1);
+            /// assert_eq!(mesh.positions(), &[Vec2::ZERO, Vec2::ONE]);
+            /// ```
+            pub fn push_vertex(&mut self, position: Vec2) -> u32 {
+                self.positions.push(position);
+                if let Some(colors) = &mut self.colors {
+                    colors.push(Self::DEFAULT_COLOR);
+                }
+                (self.positions.len() - 1) as u32
+            }
+
+            /// Adds a triangle of the vertices at indices `a`, `b`, and `c` to the [`Mesh`]
+            ///
+            /// The triangle should be wound counter-clockwise.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any index is `>= vertex_count`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::empty();
+            /// let a = mesh.push_vertex(Vec2::ZERO);
+            /// let b = mesh.push_vertex(Vec2 { x: 1., y: 0. });
+            /// let c = mesh.push_vertex(Vec2::ONE);
+            /// mesh.push_triangle(a, b, c);
+            ///
+            /// assert_eq!(mesh.indices(), &[0, 1, 2]);
+            /// assert_eq!(mesh.triangle_count(), 1);
+            /// ```
+            pub fn push_triangle(&mut self, a: u32, b: u32, c: u32) {
+                let vertex_count = self.positions.len();
+                for index in [a, b, c] {
+                    assert!(
+                        (index as usize) < vertex_count,
+                        "index {index} is out of bounds for a mesh with {vertex_count} vertices"
+                    );
+                }
+                self.indices.extend([a, b, c]);
+            }
+        }
+
+        // ##########
+        // Consts
+        // ##########
+        impl Mesh {
+            /// The color given to vertices without one when combining a [`Mesh`] that has `colors`, opaque white
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default()]);
+            /// mesh.fill_color([1., 0., 0., 1.]);
+            /// mesh.append(&Mesh::from_triangles(&[Triangle::default()]));
+            ///
+            /// assert_eq!(mesh.colors().unwrap()[3], Mesh::DEFAULT_COLOR);
+            /// assert_eq!(Mesh::DEFAULT_COLOR, [1., 1., 1., 1.]);
+            /// ```
+            pub const DEFAULT_COLOR: [f32; 4] = [1., 1., 1., 1.];
+        }
+
+        // ##########
+        // Attributes
+        // ##########
+        impl Mesh {
+            /// Get the number of vertices in the [`Mesh`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::default(), Triangle::default()]);
+            ///
+            /// assert_eq!(mesh.vertex_count(), 6);
+            /// ```
+            pub fn vertex_count(&self) -> usize {
+                self.positions.len()
+            }
+
+            /// Get the number of triangles in the [`Mesh`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::default(), Triangle::default()]);
+            ///
+            /// assert_eq!(mesh.triangle_count(), 2);
+            /// ```
+            pub fn triangle_count(&self) -> usize {
+                self.indices.len() / 3
+            }
+
+            /// Get the signed `area` of the [`Mesh`], the sum of the signed areas of its triangles
+            ///
+            /// Counter-clockwise triangles add to the area and clockwise triangles subtract from it, so this
+            /// only matches [`Mesh::area`] when every triangle is wound counter-clockwise.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let positions = vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }];
+            /// let mesh = Mesh::new(positions, vec![0, 1, 2, 0, 2, 3]).unwrap();
+            /// assert_eq!(mesh.signed_area(), 4.);
+            ///
+            /// // the second triangle is flipped
+            /// let positions = vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }];
+            /// let mesh = Mesh::new(positions, vec![0, 1, 2, 0, 3, 2]).unwrap();
+            /// assert_eq!(mesh.signed_area(), 0.);
+            /// assert_eq!(mesh.area(), 4.);
+            /// ```
+            pub fn signed_area(&self) -> $float {
+                self.triangle_positions()
+                    .map(|[a, b, c]| (b - a).perp_dot(c - a) * 0.5)
+                    .sum()
+            }
+
+            /// Get the `area` of the [`Mesh`], the sum of the areas of its triangles
+            ///
+            /// Overlapping triangles are counted once each, and degenerate triangles add nothing.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let circle = Circle::new(Vec2::ZERO, 2.);
+            #[doc = concat!(" let exact = std::", stringify!($float), "::consts::PI * 4.;")]
+            #[doc = concat!(" let mut previous_error = ", stringify!($float), "::INFINITY;")]
+            /// for segments in [8, 32, 128, 512] {
+            ///     let error = exact - Mesh::from_circle(&circle, segments).area();
+            ///     assert!(error >= 0.);
+            ///     assert!(error < previous_error);
+            ///     previous_error = error;
+            /// }
+            /// assert!(previous_error < 1e-3);
+            /// ```
+            pub fn area(&self) -> $float {
+                self.triangle_positions()
+                    .map(|[a, b, c]| (b - a).perp_dot(c - a).abs() * 0.5)
+                    .sum()
+            }
+
+            /// Checks the [`Mesh`] for problems, returning every [`MeshDefect`] found
+            ///
+            /// [`Mesh::new`] and the push helpers already keep the buffers consistent, this catches problems
+            /// introduced by editing the buffers directly. A triangle is degenerate when its area is zero,
+            /// within floating point error relative to the length of its edges.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = || {
+            ///     let positions = vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }];
+            ///     Mesh::new(positions, vec![0, 1, 2, 0, 2, 3]).unwrap()
+            /// };
+            /// assert_eq!(square().validate(), Ok(()));
+            ///
+            /// let mut mesh = square();
+            /// mesh.indices_mut()[4] = 7;
+            /// assert_eq!(mesh.validate(), Err(vec![MeshDefect::IndexOutOfRange { triangle: 1, index: 7 }]));
+            ///
+            /// let mut mesh = square();
+            /// mesh.indices_mut().push(0);
+            /// assert_eq!(mesh.validate(), Err(vec![MeshDefect::IndicesNotMultipleOfThree]));
+            ///
+            /// let mut mesh = square();
+            /// mesh.indices_mut()[5] = 2;
+            /// assert_eq!(mesh.validate(), Err(vec![MeshDefect::DegenerateTriangle { triangle: 1 }]));
+            ///
+            /// let mut mesh = square();
+            /// mesh.fill_color([1., 1., 1., 1.]);
+            /// mesh.colors_mut().unwrap().pop();
+            /// assert_eq!(mesh.validate(), Err(vec![MeshDefect::AttributeLengthMismatch]));
+            /// ```
+            pub fn validate(&self) -> Result<(), Vec<MeshDefect>> {
+                let mut defects = Vec::new();
+                if self.indices.len() % 3 != 0 {
+                    defects.push(MeshDefect::IndicesNotMultipleOfThree);
+                }
+                if let Some(colors) = &self.colors {
+                    if colors.len() != self.positions.len() {
+                        defects.push(MeshDefect::AttributeLengthMismatch);
+                    }
+                }
+
+                for (triangle, indices) in self.indices.chunks_exact(3).enumerate() {
+                    let out_of_range: Vec<u32> = indices
+                        .iter()
+                        .copied()
+                        .filter(|index| *index as usize >= self.positions.len())
+                        .collect();
+                    if !out_of_range.is_empty() {
+                        defects.extend(
+                            out_of_range
+                                .into_iter()
+                                .map(|index| MeshDefect::IndexOutOfRange { triangle, index }),
+                        );
+                        continue;
+                    }
+
+                    let [a, b, c] = [0, 1, 2].map(|i| self.positions[indices[i] as usize]);
+                    let scale = (b - a).length_squared().max((c - a).length_squared());
+                    if (b - a).perp_dot(c - a).abs() <= scale * $float::EPSILON {
+                        defects.push(MeshDefect::DegenerateTriangle { triangle });
+                    }
+                }
+
+                if defects.is_empty() {
+                    Ok(())
+                } else {
+                    Err(defects)
+                }
+            }
+
+            /// Checks if every triangle index of the [`Mesh`] fits in a `u16`, for 16-bit index buffers
+            ///
+            /// Only the indices count, a [`Mesh`] with more than 65536 vertices fits as long as its triangles
+            /// only use the first 65536.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::new(vec![Vec2::ZERO; 65536], vec![0, 1, 65535]).unwrap();
+            /// assert!(mesh.fits_u16());
+            /// assert_eq!(mesh.indices_u16(), Some(vec![0, 1, 65535]));
+            ///
+            /// let last = mesh.push_vertex(Vec2::ONE);
+            /// assert_eq!(last, 65536);
+            /// assert!(mesh.fits_u16());
+            ///
+            /// mesh.push_triangle(0, 1, last);
+            /// assert!(!mesh.fits_u16());
+            /// assert_eq!(mesh.indices_u16(), None);
+            /// ```
+            pub fn fits_u16(&self) -> bool {
+                self.indices.iter().all(|index| *index <= u16::MAX as u32)
+            }
+
+            // The minimum and maximum of every position, or `None` without any vertices
+            fn bounds(&self) -> Option<(Vec2, Vec2)> {
+                let first = *self.positions.first()?;
+                Some(
+                    self.positions
+                        .iter()
+                        .fold((first, first), |(min, max), position| {
+                            (min.min(*position), max.max(*position))
+                        }),
+                )
+            }
+
+            // The triangles which might contain `point`.
+            // This is every triangle, which an acceleration structure could narrow down without changing the results.
+            fn candidate_triangles(&self, _point: Vec2) -> impl Iterator<Item = [Vec2; 3]> + '_ {
+                self.triangle_positions()
+            }
+
+            // The positions of every triangle, skipping any with an index outside of the vertices
+            fn triangle_positions(&self) -> impl Iterator<Item = [Vec2; 3]> + '_ {
+                self.indices.chunks_exact(3).filter_map(|triangle| {
+                    let a = self.positions.get(triangle[0] as usize)?;
+                    let b = self.positions.get(triangle[1] as usize)?;
+                    let c = self.positions.get(triangle[2] as usize)?;
+                    Some([*a, *b, *c])
+                })
+            }
+
+            /// Get the smallest [`Rectangle`] containing every vertex of the [`Mesh`], or `None` if it has no vertices
+            ///
+            /// Every position counts, including vertices that no triangle uses.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// assert!(Mesh::empty().bounding_box().is_none());
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: -1. }, Vec2 { x: 1., y: 3. })]);
+            /// let rect = mesh.bounding_box().unwrap();
+            /// assert_eq!(rect.min(), Vec2 { x: 0., y: -1. });
+            /// assert_eq!(rect.max(), Vec2 { x: 2., y: 3. });
+            ///
+            /// // an unused vertex still counts
+            /// mesh.push_vertex(Vec2 { x: 10., y: 0. });
+            /// assert_eq!(mesh.bounding_box().unwrap().max(), Vec2 { x: 10., y: 3. });
+            /// ```
+            #[cfg(feature = "rectangle")]
+            pub fn bounding_box(&self) -> Option<Rectangle> {
+                let (min, max) = self.bounds()?;
+                Some(Rectangle::new_coordinates(min, max))
+            }
+
+            /// Checks if a `point` is inside of any triangle of the [`Mesh`]
+            ///
+            /// A `point` on the edge of a triangle is inside. Degenerate triangles contain nothing.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // a ring of 16 quads between radius 1 and 2
+            /// let segments = 16;
+            /// let mut positions = Vec::new();
+            /// let mut indices = Vec::new();
+            /// for i in 0..segments {
+            #[doc = concat!("     let direction = Vec2::from_angle(i as ", stringify!($float), " * std::", stringify!($float), "::consts::TAU / segments as ", stringify!($float), ");")]
+            ///     positions.extend([direction, direction * 2.]);
+            ///     let [inner, outer] = [i * 2, i * 2 + 1];
+            ///     let [next_inner, next_outer] = [(i + 1) % segments * 2, (i + 1) % segments * 2 + 1];
+            ///     indices.extend([inner, outer, next_outer, inner, next_outer, next_inner]);
+            /// }
+            /// let ring = Mesh::new(positions, indices).unwrap();
+            ///
+            /// assert!(ring.contains_point(Vec2 { x: 1.5, y: 0. }));
+            /// assert!(ring.contains_point(Vec2 { x: 0., y: -1.5 }));
+            /// assert!(ring.contains_point(Vec2 { x: 2., y: 0. }));
+            /// assert!(!ring.contains_point(Vec2::ZERO));
+            /// assert!(!ring.contains_point(Vec2 { x: 0.5, y: 0.5 }));
+            /// assert!(!ring.contains_point(Vec2 { x: 3., y: 0. }));
+            /// ```
+            pub fn contains_point(&self, point: Vec2) -> bool {
+                match self.bounds() {
+                    Some((min, max)) if point.cmpge(min).all() && point.cmple(max).all() => {}
+                    _ => return false,
+                }
+
+                self.candidate_triangles(point).any(|[a, b, c]| {
+                    if (b - a).perp_dot(c - a) == 0. {
+                        return false;
+                    }
+
+                    let d1 = (b - a).perp_dot(point - a);
+                    let d2 = (c - b).perp_dot(point - b);
+                    let d3 = (a - c).perp_dot(point - c);
+
+                    let has_negative = d1 < 0. || d2 < 0. || d3 < 0.;
+                    let has_positive = d1 > 0. || d2 > 0. || d3 > 0.;
+
+                    !(has_negative && has_positive)
+                })
+            }
+        }
+
+        // ##########
+        // Operations
+        // ##########
+        impl Mesh {
+            /// Adds the vertices and triangles of `other` to the [`Mesh`]
+            ///
+            /// The indices of `other` are offset to point at its vertices after the vertices already in the [`Mesh`].
+            /// When only one of them has `colors`, the vertices of the other get [`Mesh::DEFAULT_COLOR`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if the combined vertices can't all be indexed by a `u32`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE], vec![0, 1, 2]).unwrap();
+            /// let other = Mesh::new(
+            ///     vec![Vec2 { x: 5., y: 0. }, Vec2 { x: 6., y: 0. }, Vec2 { x: 6., y: 1. }, Vec2 { x: 5., y: 1. }],
+            ///     vec![0, 1, 2, 0, 2, 3],
+            /// )
+            /// .unwrap();
+            /// mesh.append(&other);
+            ///
+            /// assert_eq!(mesh.triangle_count(), 3);
+            /// assert_eq!(mesh.indices(), &[0, 1, 2, 3, 4, 5, 3, 5, 6]);
+            /// // every triangle of other still uses only its vertices
+            /// for index in &mesh.indices()[3..] {
+            ///     assert!(mesh.positions()[*index as usize].x >= 5.);
+            /// }
+            ///
+            /// // appending an empty mesh changes nothing
+            /// mesh.append(&Mesh::empty());
+            /// assert_eq!(mesh.vertex_count(), 7);
+            /// assert_eq!(mesh.triangle_count(), 3);
+            ///
+            /// // colors are kept when only one side has them
+            /// let red = [1., 0., 0., 1.];
+            /// let mut colored = Mesh::new(vec![Vec2::ZERO, Vec2::X, Vec2::ONE], vec![0, 1, 2]).unwrap();
+            /// colored.fill_color(red);
+            /// mesh.append(&colored);
+            /// let colors = mesh.colors().unwrap();
+            /// assert_eq!(colors.len(), 10);
+            /// assert!(colors[..7].iter().all(|color| *color == Mesh::DEFAULT_COLOR));
+            /// assert!(colors[7..].iter().all(|color| *color == red));
+            ///
+            /// colored.append(&other);
+            /// let colors = colored.colors().unwrap();
+            /// assert_eq!(colors.len(), 7);
+            /// assert!(colors[..3].iter().all(|color| *color == red));
+            /// assert!(colors[3..].iter().all(|color| *color == Mesh::DEFAULT_COLOR));
+            /// ```
+            pub fn append(&mut self, other: &Mesh) {
+                assert!(
+                    self.positions.len() + other.positions.len() <= u32::MAX as usize + 1,
+                    "a mesh can't hold more vertices than a u32 can index"
+                );
+                if self.colors.is_some() || other.colors.is_some() {
+                    let mut colors = self
+                        .colors
+                        .take()
+                        .unwrap_or_else(|| vec![Self::DEFAULT_COLOR; self.positions.len()]);
+                    match &other.colors {
+                        Some(other_colors) => colors.extend_from_slice(other_colors),
+                        None => colors.resize(colors.len() + other.positions.len(), Self::DEFAULT_COLOR),
+                    }
+                    self.colors = Some(colors);
+                }
+
+                let offset = self.positions.len() as u32;
+                self.positions.extend_from_slice(&other.positions);
+                self.indices
+                    .extend(other.indices.iter().map(|index| index + offset));
+            }
+
+            /// Merges vertices of the [`Mesh`] within `epsilon` of each other into one vertex
+            ///
+            /// Each vertex merges into the lowest indexed earlier vertex within `epsilon` which wasn't merged
+            /// itself, keeping that vertex's position and color. Triangles left with a repeated vertex are removed, and then vertices no
+            /// triangle uses are removed. The remaining vertices keep their order. Triangles with an index
+            /// outside of the vertices are removed too.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::merged([
+            ///     Mesh::from_rect(&Rectangle::new(0., 0., 1., 1.)),
+            ///     Mesh::from_rect(&Rectangle::new(1., 0., 2., 1.)),
+            /// ]);
+            /// mesh.fill_color([1., 0., 0., 1.]);
+            /// mesh.weld(1e-5);
+            ///
+            /// assert_eq!(mesh.vertex_count(), 6);
+            /// assert_eq!(mesh.triangle_count(), 4);
+            /// assert_eq!(mesh.area(), 2.);
+            /// assert_eq!(mesh.colors().unwrap().len(), 6);
+            /// assert_eq!(mesh.validate(), Ok(()));
+            ///
+            /// // a triangle collapsing to a line is removed along with its unused vertex
+            /// let positions = vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 1., y: 1e-7 }];
+            /// let mut mesh = Mesh::new(positions, vec![0, 1, 2, 0, 3, 1]).unwrap();
+            /// mesh.weld(1e-5);
+            ///
+            /// assert_eq!(mesh.positions(), &[Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            /// assert_eq!(mesh.indices(), &[0, 1, 2]);
+            ///
+            /// // the middle vertex is within `epsilon` of both others, and merges into the lowest indexed one
+            /// let positions = vec![
+            ///     Vec2 { x: 2.5, y: 0.5 },
+            ///     Vec2 { x: 0.5, y: 0.5 },
+            ///     Vec2 { x: 1.5, y: 0.5 },
+            ///     Vec2 { x: 1.5, y: 5. },
+            ///     Vec2 { x: 0., y: 5. },
+            /// ];
+            /// let mut mesh = Mesh::new(positions, vec![2, 3, 4]).unwrap();
+            /// mesh.weld(1.);
+            ///
+            /// assert_eq!(mesh.positions()[0], Vec2 { x: 2.5, y: 0.5 });
+            /// assert_eq!(mesh.indices(), &[0, 1, 2]);
+            ///
+            /// // a subnormal epsilon only merges exact duplicates
+            /// let positions = vec![Vec2::ZERO, Vec2::splat(1e30), Vec2::ONE, Vec2::splat(1e30)];
+            /// let mut mesh = Mesh::new(positions, vec![0, 1, 2, 1, 3, 2]).unwrap();
+            #[doc = concat!(" mesh.weld(", stringify!($float), "::MIN_POSITIVE / 2.);")]
+            ///
+            /// assert_eq!(mesh.vertex_count(), 3);
+            /// assert_eq!(mesh.indices(), &[0, 1, 2]);
+            /// ```
+            pub fn weld(&mut self, epsilon: $float) {
+                // bucket vertices into cells of size `epsilon`, so only neighboring cells need comparing.
+                // a tiny `epsilon` saturates the cell coordinates, so neighbors saturate too
+                let cell_of = |position: Vec2| -> (i64, i64) {
+                    if epsilon > 0. {
+                        let cell = (position / epsilon).floor();
+                        (cell.x as i64, cell.y as i64)
+                    } else {
+                        let position = position + Vec2::ZERO;
+                        (position.x.to_bits() as i64, position.y.to_bits() as i64)
+                    }
+                };
+                let neighbors: &[i64] = if epsilon > 0. { &[-1, 0, 1] } else { &[0] };
+
+                let mut cells: HashMap<(i64, i64), Vec<u32>> = HashMap::new();
+                let mut merged_into = Vec::with_capacity(self.positions.len());
+                for (index, position) in self.positions.iter().enumerate() {
+                    let (x, y) = cell_of(*position);
+                    // the lowest matching index, whichever cell it is in, so the result doesn't depend on the
+                    // order the cells are scanned in
+                    let existing = neighbors
+                        .iter()
+                        .flat_map(|dx| neighbors.iter().map(move |dy| (*dx, *dy)))
+                        .filter_map(|(dx, dy)| cells.get(&(x.saturating_add(dx), y.saturating_add(dy))))
+                        .flatten()
+                        .copied()
+                        .filter(|other| self.positions[*other as usize].distance(*position) <= epsilon)
+                        .min();
+                    match existing {
+                        Some(other) => merged_into.push(other),
+                        None => {
+                            cells.entry((x, y)).or_default().push(index as u32);
+                            merged_into.push(index as u32);
+                        }
+                    }
+                }
+
+                let mut indices = Vec::with_capacity(self.indices.len());
+                for triangle in self.indices.chunks_exact(3) {
+                    let [a, b, c] = [0, 1, 2].map(|i| merged_into.get(triangle[i] as usize).copied());
+                    let (Some(a), Some(b), Some(c)) = (a, b, c) else {
+                        continue;
+                    };
+                    if a != b && b != c && c != a {
+                        indices.extend([a, b, c]);
+                    }
+                }
+
+                let mut used = vec![false; self.positions.len()];
+                for index in &indices {
+                    used[*index as usize] = true;
+                }
+                let mut new_index = vec![0; self.positions.len()];
+                let mut kept = 0;
+                for (index, used) in used.iter().enumerate() {
+                    if *used {
+                        new_index[index] = kept;
+                        kept += 1;
+                    }
+                }
+
+                let mut index = 0;
+                self.positions.retain(|_| {
+                    index += 1;
+                    used[index - 1]
+                });
+                if let Some(colors) = &mut self.colors {
+                    let mut index = 0;
+                    colors.retain(|_| {
+                        index += 1;
+                        used.get(index - 1).copied().unwrap_or(false)
+                    });
+                }
+                self.indices = indices
+                    .into_iter()
+                    .map(|index| new_index[index as usize])
+                    .collect();
+            }
+
+            /// Moves every vertex of the [`Mesh`] by `offset`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE)]);
+            /// mesh.translate(Vec2 { x: 2., y: 3. });
+            ///
+            /// assert_eq!(mesh.positions(), &[Vec2 { x: 2., y: 3. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 3., y: 4. }]);
+            /// ```
+            pub fn translate(&mut self, offset: Vec2) {
+                self.transform(Affine2::from_translation(offset));
+            }
+
+            /// Get the [`Mesh`] with every vertex moved by `offset`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE)]);
+            /// let mesh = mesh.translated(Vec2::ONE);
+            ///
+            /// assert_eq!(mesh.positions(), &[Vec2::ONE, Vec2 { x: 2., y: 1. }, Vec2 { x: 2., y: 2. }]);
+            /// ```
+            pub fn translated(mut self, offset: Vec2) -> Self {
+                self.translate(offset);
+                self
+            }
+
+            /// Rotates the [`Mesh`] counter-clockwise by `radians` around `anchor`
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE)]);
+            #[doc = concat!(" mesh.rotate_about(std::", stringify!($float), "::consts::FRAC_PI_2, Vec2::ZERO);")]
+            ///
+            /// let expected = [Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2 { x: -1., y: 1. }];
+            /// for (position, expected) in mesh.positions().iter().zip(expected) {
+            ///     assert_abs_diff_eq!(*position, expected, epsilon = 1e-6);
+            /// }
+            /// ```
+            pub fn rotate_about(&mut self, radians: $float, anchor: Vec2) {
+                self.transform(
+                    Affine2::from_translation(anchor)
+                        * Affine2::from_angle(radians)
+                        * Affine2::from_translation(-anchor),
+                );
+            }
+
+            /// Get the [`Mesh`] rotated counter-clockwise by `radians` around `anchor`
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE)]);
+            #[doc = concat!(" let mesh = mesh.rotated_about(std::", stringify!($float), "::consts::PI, Vec2::ONE);")]
+            ///
+            /// assert_abs_diff_eq!(mesh.positions()[0], Vec2 { x: 2., y: 2. }, epsilon = 1e-6);
+            /// ```
+            pub fn rotated_about(mut self, radians: $float, anchor: Vec2) -> Self {
+                self.rotate_about(radians, anchor);
+                self
+            }
+
+            /// Scales the [`Mesh`] away from `anchor` by `factor` along each axis
+            ///
+            /// A negative factor mirrors the [`Mesh`] along that axis. Mirroring would reverse the winding of
+            /// every triangle, so their indices are reordered to keep them counter-clockwise.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE)]);
+            /// mesh.scale_about(Vec2 { x: -2., y: 1. }, Vec2::ZERO);
+            ///
+            /// assert_eq!(mesh.positions(), &[Vec2::ZERO, Vec2 { x: -2., y: 0. }, Vec2 { x: -2., y: 1. }]);
+            /// assert_eq!(mesh.indices(), &[0, 2, 1]);
+            /// ```
+            pub fn scale_about(&mut self, factor: Vec2, anchor: Vec2) {
+                self.transform(
+                    Affine2::from_translation(anchor)
+                        * Affine2::from_scale(factor)
+                        * Affine2::from_translation(-anchor),
+                );
+            }
+
+            /// Get the [`Mesh`] scaled away from `anchor` by `factor` along each axis, see [`Mesh::scale_about`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE)]);
+            /// let mesh = mesh.scaled_about(Vec2::splat(2.), Vec2::ONE);
+            ///
+            /// assert_eq!(mesh.positions(), &[-Vec2::ONE, Vec2 { x: 1., y: -1. }, Vec2::ONE]);
+            /// ```
+            pub fn scaled_about(mut self, factor: Vec2, anchor: Vec2) -> Self {
+                self.scale_about(factor, anchor);
+                self
+            }
+
+            /// Applies `affine` to every vertex of the [`Mesh`]
+            ///
+            /// When `affine` mirrors the [`Mesh`] (a negative determinant), the indices of every triangle are
+            /// reordered to keep them counter-clockwise.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[
+            ///     Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE),
+            ///     Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 0., y: 1. }),
+            /// ]);
+            /// // mirror across the y axis and move up
+            /// mesh.transform(Affine2::from_translation(Vec2::Y) * Affine2::from_scale(Vec2 { x: -1., y: 1. }));
+            ///
+            /// assert_eq!(mesh.positions()[1], Vec2 { x: -1., y: 1. });
+            /// for triangle in mesh.indices().chunks_exact(3) {
+            ///     let [a, b, c] = [0, 1, 2].map(|i| mesh.positions()[triangle[i] as usize]);
+            ///     assert!((b - a).perp_dot(c - a) > 0.);
+            /// }
+            /// ```
+            pub fn transform(&mut self, affine: Affine2) {
+                for position in &mut self.positions {
+                    *position = affine.transform_point2(*position);
+                }
+                if affine.matrix2.determinant() < 0. {
+                    self.flip_winding();
+                }
+            }
+
+            /// Get the [`Mesh`] with `affine` applied to every vertex, see [`Mesh::transform`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE)]);
+            /// let mesh = mesh.transformed(Affine2::from_scale(Vec2::splat(3.)));
+            ///
+            /// assert_eq!(mesh.positions(), &[Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2::splat(3.)]);
+            /// ```
+            pub fn transformed(mut self, affine: Affine2) -> Self {
+                self.transform(affine);
+                self
+            }
+
+            /// Reverses the winding of every triangle of the [`Mesh`] by swapping its last two indices
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut mesh = Mesh::from_triangles(&[Triangle::default(), Triangle::default()]);
+            /// let area = mesh.signed_area();
+            /// mesh.flip_winding();
+            ///
+            /// assert_eq!(mesh.indices(), &[0, 2, 1, 3, 5, 4]);
+            /// assert_eq!(mesh.signed_area(), -area);
+            /// ```
+            pub fn flip_winding(&mut self) {
+                for triangle in self.indices.chunks_exact_mut(3) {
+                    triangle.swap(1, 2);
+                }
+            }
+
+            /// Reverses only the clockwise triangles of the [`Mesh`], so every triangle is wound counter-clockwise
+            ///
+            /// Degenerate triangles and triangles with an index outside of the vertices are left alone.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let positions = vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }, Vec2 { x: 3., y: 3. }];
+            /// // the second and third triangles are clockwise
+            /// let mut mesh = Mesh::new(positions, vec![0, 1, 2, 0, 3, 2, 2, 3, 4]).unwrap();
+            /// let area = mesh.area();
+            /// assert!(mesh.signed_area() < area);
+            ///
+            /// mesh.make_ccw();
+            ///
+            /// assert_eq!(mesh.indices(), &[0, 1, 2, 0, 2, 3, 2, 4, 3]);
+            /// for triangle in mesh.triangles() {
+            ///     assert!(triangle.signed_area() > 0.);
+            /// }
+            /// assert_eq!(mesh.area(), area);
+            /// assert_eq!(mesh.signed_area(), area);
+            /// ```
+            pub fn make_ccw(&mut self) {
+                let positions = &self.positions;
+                for triangle in self.indices.chunks_exact_mut(3) {
+                    let [Some(a), Some(b), Some(c)] =
+                        [0, 1, 2].map(|i| positions.get(triangle[i] as usize))
+                    else {
+                        continue;
+                    };
+                    if (*b - *a).perp_dot(*c - *a) < 0. {
+                        triangle.swap(1, 2);
+                    }
+                }
+            }
+
+            /// Writes the [`Mesh`] to `writer` as Wavefront OBJ text
+            ///
+            /// Every vertex becomes a `v x y 0.0` line, followed by an `f a b c` line for every triangle
+            /// using the 1-based vertex numbers of OBJ.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_triangles(&[Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2 { x: 0., y: 0.5 })]);
+            /// let mut obj = Vec::new();
+            /// mesh.write_obj(&mut obj).unwrap();
+            ///
+            /// assert_eq!(String::from_utf8(obj).unwrap(), "v 0 0 0.0\nv 1 0 0.0\nv 0 0.5 0.0\nf 1 2 3\n");
+            /// ```
+            pub fn write_obj<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+                for position in &self.positions {
+                    writeln!(writer, "v {} {} 0.0", position.x, position.y)?;
+                }
+                for triangle in self.indices.chunks_exact(3) {
+                    writeln!(
+                        writer,
+                        "f {} {} {}",
+                        triangle[0] as u64 + 1,
+                        triangle[1] as u64 + 1,
+                        triangle[2] as u64 + 1
+                    )?;
+                }
+                Ok(())
+            }
+
+            /// Get the [`Mesh`] as Wavefront OBJ text, see [`Mesh::write_obj`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Mesh::from_circle(&Circle::new(Vec2 { x: 1., y: -2. }, 3.), 12);
+            /// let obj = mesh.to_obj_string();
+            ///
+            /// // read the vertices and faces back
+            /// let mut positions = Vec::new();
+            /// let mut faces = Vec::new();
+            /// for line in obj.lines() {
+            ///     let mut parts = line.split_whitespace();
+            ///     match parts.next() {
+            ///         Some("v") => {
+            #[doc = concat!("             let values: Vec<", stringify!($float), "> = parts.map(|part| part.parse().unwrap()).collect();")]
+            ///             assert_eq!(values[2], 0.);
+            ///             positions.push(Vec2 { x: values[0], y: values[1] });
+            ///         }
+            ///         Some("f") => {
+            ///             let face: Vec<usize> = parts.map(|part| part.parse().unwrap()).collect();
+            ///             assert!(face.iter().all(|number| (1..=positions.len()).contains(number)));
+            ///             faces.push([face[0] - 1, face[1] - 1, face[2] - 1]);
+            ///         }
+            ///         _ => panic!("unexpected line {line}"),
+            ///     }
+            /// }
+            ///
+            /// assert_eq!(positions, mesh.positions());
+            /// assert_eq!(faces.len(), mesh.triangle_count());
+            /// let indices: Vec<u32> = faces.iter().flatten().map(|index| *index as u32).collect();
+            /// assert_eq!(indices, mesh.indices());
+            /// ```
+            pub fn to_obj_string(&self) -> String {
+                let mut obj = Vec::new();
+                self.write_obj(&mut obj)
+                    .expect("writing to a Vec can't fail");
+                String::from_utf8(obj).expect("OBJ text is always valid UTF-8")
+            }
+        }
+
+        // ##########
+        // Extend impl
+        // ##########
+        impl Extend<Mesh> for Mesh {
+            fn extend<T: IntoIterator<Item = Mesh>>(&mut self, iter: T) {
+                for mesh in iter {
+                    self.append(&mesh);
+                }
+            }
+        }
+
+        // ##########
+        // Default impl
+        // ##########
+        impl Default for Mesh {
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+
+        // ##########
+        // Display impl
+        // ##########
+        impl Display for Mesh {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!(stringify!($mesh), " {{ positions: ["))?;
+                for (index, position) in self.positions().iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", position)?;
+                }
+                write!(f, "], indices: {:?} }}", self.indices())
+            }
+        }
+
+        // ##########
+        // Helpers
+        // ##########
+        // One of each group of `points` within `epsilon` of each other, the leftmost, in their original order
+        fn distinct_points(points: &[Vec2], epsilon: $float) -> Vec<Vec2> {
+            let mut order: Vec<usize> = (0..points.len()).collect();
+            order.sort_by(|a, b| points[*a].x.total_cmp(&points[*b].x).then(a.cmp(b)));
+
+            // Only points at most `epsilon` to the left need checking, which are the last few kept
+            let mut kept: Vec<usize> = Vec::with_capacity(points.len());
+            for index in order {
+                let point = points[index];
+                let repeated = kept
+                    .iter()
+                    .rev()
+                    .take_while(|other| points[**other].x >= point.x - epsilon)
+                    .any(|other| points[*other].distance(point) <= epsilon);
+                if !repeated {
+                    kept.push(index);
+                }
+            }
+            kept.sort_unstable();
+            kept.into_iter().map(|index| points[index]).collect()
+        }
+
+        // Checks if `point` is strictly inside the circumcircle of the counter-clockwise `triangle`. With a corner at
+        // infinity the circle becomes everything left of the other two corners, and the edge between them.
+        fn in_circumcircle(vertices: &[DVec2], triangle: [usize; 3], point: DVec2) -> bool {
+            if let [a, b, INFINITE] = triangle {
+                let (a, b) = (vertices[a], vertices[b]);
+                let side = (b - a).perp_dot(point - a);
+                return side > 0.
+                    || (side == 0. && (point - a).dot(b - a) > 0. && (point - b).dot(a - b) > 0.);
+            }
+            let [a, b, c] = triangle.map(|index| vertices[index] - point);
+            let determinant = a.length_squared() * b.perp_dot(c)
+                + b.length_squared() * c.perp_dot(a)
+                + c.length_squared() * a.perp_dot(b);
+            determinant > 0.
+        }
+    };
+}
+pub(crate) use impl_mesh;
+
+impl_mesh!(
+    /// Represents a triangle [`Mesh`] in 2d space
+    ///
+    /// Every three `indices` form a triangle of `positions`, wound counter-clockwise.
+    /// Each vertex may also have an RGBA color, when the [`Mesh`] has `colors`.
+    Mesh,
+    f32;
+    "use glam::{Affine2, Vec2};\nuse shapes2d::prelude::*;"
+);
+
+// ##########
+// Annulus constructor
+// ##########
+impl Mesh {
+    /// Creates a new [`Mesh`] approximating an [`Annulus`] with a strip of `2 * segments` triangles around the ring
+    ///
+    /// The vertices alternate between the outer and inner circles, counter-clockwise starting at angle `0.`, with
+    /// no vertex at the center. A `segments` below 3 is clamped to 3.
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Annulus, Mesh};
+    ///
+    /// let annulus = Annulus::new(Vec2::ZERO, 1., 2.);
+    /// let mesh = Mesh::from_annulus(&annulus, 64);
+    ///
+    /// assert_eq!(mesh.vertex_count(), 128);
+    /// assert_eq!(mesh.triangle_count(), 128);
+    /// assert_eq!(mesh.positions()[..2], [Vec2 { x: 2., y: 0. }, Vec2 { x: 1., y: 0. }]);
+    /// assert!(!mesh.contains_point(Vec2::ZERO));
+    ///
+    /// for triangle in mesh.indices().chunks_exact(3) {
+    ///     let [a, b, c] = [0, 1, 2].map(|i| mesh.positions()[triangle[i] as usize]);
+    ///     assert!((b - a).perp_dot(c - a) > 0.);
+    /// }
+    ///
+    /// // the mesh covers a little less than the ring, and gets closer with more segments
+    /// let mut previous = f32::INFINITY;
+    /// for segments in [8, 16, 32, 64, 128, 256] {
+    ///     let error = annulus.area() - Mesh::from_annulus(&annulus, segments).area();
+    ///     assert!(error > 0.);
+    ///     assert!(error < previous);
+    ///     previous = error;
+    /// }
+    /// assert!(previous < 1e-3 * 3. * PI);
+    /// ```
+    #[cfg(feature = "annulus")]
+    pub fn from_annulus(annulus: &Annulus, segments: u32) -> Self {
+        let segments = segments.max(3);
+        let positions = (0..segments)
+            .flat_map(|i| {
+                let direction =
+                    Vec2::from_angle(i as f32 * std::f32::consts::TAU / segments as f32);
+                [
+                    annulus.center() + direction * annulus.outer_radius(),
+                    annulus.center() + direction * annulus.inner_radius(),
+                ]
+            })
+            .collect();
+        let indices = (0..segments)
+            .flat_map(|i| {
+                let (outer, inner) = (2 * i, 2 * i + 1);
+                let (next_outer, next_inner) =
+                    ((outer + 2) % (2 * segments), (inner + 2) % (2 * segments));
+                [outer, next_outer, inner, inner, next_outer, next_inner]
+            })
+            .collect();
+        Self {
+            positions,
+            indices,
+            colors: None,
+        }
+    }
+}
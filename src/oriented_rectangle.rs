@@ -0,0 +1,526 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single [`OrientedRectangle`] in 2d space, a rectangle turned by a `rotation` about its `center`
+///
+/// The `half_extents` are the distances from the `center` to the sides along each of the turned axes, so an
+/// [`OrientedRectangle`] without a `rotation` covers the same area as a
+/// [`Rectangle`](crate::prelude::Rectangle) from `center - half_extents` to `center + half_extents`.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::OrientedRectangle;
+///
+/// // a plank leaning at 45 degrees
+/// let plank = OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0.5 }, std::f32::consts::FRAC_PI_4);
+///
+/// assert!(plank.contains_point(Vec2 { x: 1., y: 1. }));
+/// assert!(!plank.contains_point(Vec2 { x: 1., y: -1. }));
+/// assert_eq!(plank.area(), 4.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientedRectangle {
+    center: Vec2,
+    half_extents: Vec2,
+    rotation: f32,
+}
+
+// ##########
+// Constructors
+// ##########
+impl OrientedRectangle {
+    /// Creates a new [`OrientedRectangle`] around `center`, reaching `half_extents` along its axes once turned
+    /// counter-clockwise by `rotation` radians
+    ///
+    /// Negative `half_extents` are made positive.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let rect = OrientedRectangle::new(Vec2::ONE, Vec2 { x: -2., y: 1. }, 0.5);
+    ///
+    /// assert_eq!(rect.center(), Vec2::ONE);
+    /// assert_eq!(rect.half_extents(), Vec2 { x: 2., y: 1. });
+    /// assert_eq!(rect.rotation(), 0.5);
+    /// ```
+    pub fn new(center: Vec2, half_extents: Vec2, rotation: f32) -> Self {
+        Self {
+            center,
+            half_extents: half_extents.abs(),
+            rotation,
+        }
+    }
+
+    /// Creates the [`OrientedRectangle`] with the least area which contains every point, or `None` if there
+    /// are fewer than 3 points which aren't all on one line
+    ///
+    /// The smallest rectangle around a set of points always has a side along an edge of their convex hull, so
+    /// rotating calipers walk the edges of [`Polygon::convex_hull`] while tracking the points furthest along
+    /// and across each edge. Points on a single line have a rectangle with no width, and also give `None`.
+    ///
+    /// The `rotation` of the result is between `-π/4` and `π/4`, with the `half_extents` swapped to match.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use rand::{Rng, SeedableRng};
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// // the corners of a turned rectangle recover it
+    /// let rect = OrientedRectangle::new(Vec2 { x: 1., y: 2. }, Vec2 { x: 3., y: 1. }, 0.5);
+    /// let bounding = OrientedRectangle::min_area_bounding(&rect.corners()).unwrap();
+    /// assert_abs_diff_eq!(bounding.center(), rect.center(), epsilon = 1e-5);
+    /// assert_abs_diff_eq!(bounding.half_extents(), rect.half_extents(), epsilon = 1e-5);
+    /// assert_abs_diff_eq!(bounding.rotation(), rect.rotation(), epsilon = 1e-5);
+    ///
+    /// // never larger than the axis aligned box of a random point set
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(54321);
+    /// for _ in 0..20 {
+    ///     let points: Vec<Vec2> = (0..50)
+    ///         .map(|_| Vec2 { x: rng.gen_range(-50.0..50.), y: rng.gen_range(-15.0..15.) })
+    ///         .collect();
+    ///     let bounding = OrientedRectangle::min_area_bounding(&points).unwrap();
+    ///
+    ///     let min = points.iter().fold(points[0], |min, point| min.min(*point));
+    ///     let max = points.iter().fold(points[0], |max, point| max.max(*point));
+    ///     let size = max - min;
+    ///     assert!(bounding.area() <= size.x * size.y * (1. + 1e-5));
+    ///     assert!(points.iter().all(|point| bounding.signed_distance(*point) < 1e-3));
+    /// }
+    ///
+    /// // points on a line have no area
+    /// assert_eq!(OrientedRectangle::min_area_bounding(&[Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }]), None);
+    /// assert_eq!(OrientedRectangle::min_area_bounding(&[Vec2::ZERO, Vec2::ONE]), None);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn min_area_bounding(points: &[Vec2]) -> Option<OrientedRectangle> {
+        let hull = Polygon::convex_hull(points)?;
+        let hull = hull.coordinates();
+        let length = hull.len();
+        let next = |index: usize| (index + 1) % length;
+
+        // The points furthest right of, above and left of each edge, following the edges counter-clockwise
+        let (mut right, mut top, mut left) = (1, 1, 1);
+        let mut best: Option<(f32, OrientedRectangle)> = None;
+        for edge in 0..length {
+            let along = (hull[next(edge)] - hull[edge]).normalize_or_zero();
+            if along == Vec2::ZERO {
+                continue;
+            }
+            let across = along.perp();
+
+            for _ in 0..length {
+                if hull[next(right)].dot(along) <= hull[right].dot(along) {
+                    break;
+                }
+                right = next(right);
+            }
+            if edge == 0 {
+                top = right;
+            }
+            for _ in 0..length {
+                if hull[next(top)].dot(across) <= hull[top].dot(across) {
+                    break;
+                }
+                top = next(top);
+            }
+            if edge == 0 {
+                left = top;
+            }
+            for _ in 0..length {
+                if hull[next(left)].dot(along) >= hull[left].dot(along) {
+                    break;
+                }
+                left = next(left);
+            }
+
+            let (min_along, max_along) = (hull[left].dot(along), hull[right].dot(along));
+            let (min_across, max_across) = (hull[edge].dot(across), hull[top].dot(across));
+            let area = (max_along - min_along) * (max_across - min_across);
+            if best.is_none_or(|(best_area, _)| area < best_area) {
+                let center = along * (min_along + max_along) * 0.5
+                    + across * (min_across + max_across) * 0.5;
+                let half_extents = Vec2 {
+                    x: max_along - min_along,
+                    y: max_across - min_across,
+                } * 0.5;
+                best = Some((area, Self::new_axis(center, half_extents, along)));
+            }
+        }
+        best.map(|(_, rectangle)| rectangle)
+    }
+
+    // Creates an [`OrientedRectangle`] with its first axis along `axis`, turning it by quarter turns to keep the
+    // `rotation` between -π/4 and π/4
+    #[cfg(feature = "polygon")]
+    fn new_axis(center: Vec2, half_extents: Vec2, axis: Vec2) -> Self {
+        let swapped = Vec2 {
+            x: half_extents.y,
+            y: half_extents.x,
+        };
+        let (axis, half_extents) = [
+            (axis, half_extents),
+            (axis.perp(), swapped),
+            (-axis, half_extents),
+            (-axis.perp(), swapped),
+        ]
+        .into_iter()
+        .max_by(|(a, _), (b, _)| a.x.total_cmp(&b.x))
+        .unwrap_or((axis, half_extents));
+        Self::new(center, half_extents, axis.y.atan2(axis.x))
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl OrientedRectangle {
+    /// Get the `center` of the [`OrientedRectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// assert_eq!(OrientedRectangle::new(Vec2::ONE, Vec2::ONE, 1.).center(), Vec2::ONE);
+    /// ```
+    pub fn center(&self) -> Vec2 {
+        self.center
+    }
+
+    /// Get the `half_extents` of the [`OrientedRectangle`], half of its size along each of its axes
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// assert_eq!(OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 3. }, 1.).half_extents(), Vec2 { x: 2., y: 3. });
+    /// ```
+    pub fn half_extents(&self) -> Vec2 {
+        self.half_extents
+    }
+
+    /// Get the `rotation` of the [`OrientedRectangle`] in radians, counter-clockwise
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// assert_eq!(OrientedRectangle::new(Vec2::ZERO, Vec2::ONE, 0.25).rotation(), 0.25);
+    /// ```
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// Get the size of the [`OrientedRectangle`] along each of its axes
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// assert_eq!(OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 3. }, 1.).size(), Vec2 { x: 4., y: 6. });
+    /// ```
+    pub fn size(&self) -> Vec2 {
+        self.half_extents * 2.
+    }
+
+    /// Get the two axes of the [`OrientedRectangle`], the x axis and the y axis turned by its `rotation`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// assert_eq!(OrientedRectangle::new(Vec2::ZERO, Vec2::ONE, 0.).axes(), [Vec2::X, Vec2::Y]);
+    ///
+    /// let [x, y] = OrientedRectangle::new(Vec2::ZERO, Vec2::ONE, std::f32::consts::FRAC_PI_2).axes();
+    /// assert_abs_diff_eq!(x, Vec2::Y, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(y, Vec2::NEG_X, epsilon = 1e-6);
+    /// ```
+    pub fn axes(&self) -> [Vec2; 2] {
+        let x = Vec2::from_angle(self.rotation);
+        [x, x.perp()]
+    }
+
+    /// Set the `center` of the [`OrientedRectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let mut rect = OrientedRectangle::default();
+    /// rect.set_center(Vec2 { x: 3., y: 3. });
+    ///
+    /// assert!(rect.contains_point(Vec2 { x: 3., y: 3. }));
+    /// assert!(!rect.contains_point(Vec2::ZERO));
+    /// ```
+    pub fn set_center(&mut self, center: Vec2) {
+        self.center = center;
+    }
+
+    /// Set the `half_extents` of the [`OrientedRectangle`], which are made positive
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let mut rect = OrientedRectangle::default();
+    /// rect.set_half_extents(Vec2 { x: 1., y: -2. });
+    ///
+    /// assert_eq!(rect.area(), 8.);
+    /// ```
+    pub fn set_half_extents(&mut self, half_extents: Vec2) {
+        self.half_extents = half_extents.abs();
+    }
+
+    /// Set the `rotation` of the [`OrientedRectangle`] in radians, counter-clockwise
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let mut rect = OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0.5 }, 0.);
+    /// assert!(rect.contains_point(Vec2 { x: 1.5, y: 0. }));
+    ///
+    /// rect.set_rotation(std::f32::consts::FRAC_PI_2);
+    /// assert!(!rect.contains_point(Vec2 { x: 1.5, y: 0. }));
+    /// assert!(rect.contains_point(Vec2 { x: 0., y: 1.5 }));
+    /// ```
+    pub fn set_rotation(&mut self, rotation: f32) {
+        self.rotation = rotation;
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl OrientedRectangle {
+    /// Get the area of the [`OrientedRectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// assert_eq!(OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 3. }, 1.).area(), 24.);
+    /// ```
+    pub fn area(&self) -> f32 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    /// Get the perimeter of the [`OrientedRectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// assert_eq!(OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 3. }, 1.).perimeter(), 20.);
+    /// ```
+    pub fn perimeter(&self) -> f32 {
+        let size = self.size();
+        (size.x + size.y) * 2.
+    }
+
+    /// Get the four corners of the [`OrientedRectangle`], counter-clockwise from the corner at the negative end
+    /// of both axes
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let rect = OrientedRectangle::new(Vec2::ONE, Vec2 { x: 2., y: 1. }, 0.);
+    /// assert_eq!(
+    ///     rect.corners(),
+    ///     [Vec2 { x: -1., y: 0. }, Vec2 { x: 3., y: 0. }, Vec2 { x: 3., y: 2. }, Vec2 { x: -1., y: 2. }]
+    /// );
+    ///
+    /// // a quarter turn
+    /// let rect = OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 1. }, std::f32::consts::FRAC_PI_2);
+    /// let expected = [Vec2 { x: 1., y: -2. }, Vec2 { x: 1., y: 2. }, Vec2 { x: -1., y: 2. }, Vec2 { x: -1., y: -2. }];
+    /// for (corner, expected) in rect.corners().iter().zip(expected) {
+    ///     assert_abs_diff_eq!(*corner, expected, epsilon = 1e-6);
+    /// }
+    /// ```
+    pub fn corners(&self) -> [Vec2; 4] {
+        let [x, y] = self.axes();
+        let (x, y) = (x * self.half_extents.x, y * self.half_extents.y);
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+    /// Checks if a `point` is inside of, or on the edge of, the [`OrientedRectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let rect = OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 1. }, 0.);
+    ///
+    /// assert!(rect.contains_point(Vec2 { x: 1.5, y: -0.5 }));
+    /// assert!(rect.contains_point(Vec2 { x: 2., y: 1. }));
+    /// assert!(!rect.contains_point(Vec2 { x: 0.5, y: 1.5 }));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let local = self.local_point(point).abs();
+        local.x <= self.half_extents.x && local.y <= self.half_extents.y
+    }
+
+    /// Get the point inside of, or on the edge of, the [`OrientedRectangle`] closest to `point`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let rect = OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 1. }, 0.);
+    ///
+    /// assert_eq!(rect.closest_point(Vec2 { x: 5., y: 0.5 }), Vec2 { x: 2., y: 0.5 });
+    /// assert_eq!(rect.closest_point(Vec2 { x: -5., y: -5. }), Vec2 { x: -2., y: -1. });
+    /// assert_eq!(rect.closest_point(Vec2 { x: 1., y: 0. }), Vec2 { x: 1., y: 0. });
+    /// ```
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        let local = self
+            .local_point(point)
+            .clamp(-self.half_extents, self.half_extents);
+        let [x, y] = self.axes();
+        self.center + x * local.x + y * local.y
+    }
+
+    /// Get the signed distance from `point` to the edge of the [`OrientedRectangle`]
+    ///
+    /// The distance is negative inside of the [`OrientedRectangle`] and positive outside.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let rect = OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 1. }, 0.);
+    ///
+    /// assert_eq!(rect.signed_distance(Vec2 { x: 1.5, y: 0. }), -0.5);
+    /// assert_eq!(rect.signed_distance(Vec2 { x: 5., y: 5. }), 5.);
+    /// assert_eq!(rect.signed_distance(Vec2 { x: 2., y: 0. }), 0.);
+    /// ```
+    pub fn signed_distance(&self, point: Vec2) -> f32 {
+        let q = self.local_point(point).abs() - self.half_extents;
+        q.max(Vec2::ZERO).length() + q.max_element().min(0.)
+    }
+
+    /// Get the smallest axis aligned [`Rectangle`] containing the [`OrientedRectangle`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::OrientedRectangle;
+    ///
+    /// let rect = OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 1. }, std::f32::consts::FRAC_PI_2);
+    /// let bounds = rect.bounding_box();
+    ///
+    /// assert_abs_diff_eq!(bounds.min(), Vec2 { x: -1., y: -2. }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(bounds.max(), Vec2 { x: 1., y: 2. }, epsilon = 1e-6);
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let [x, y] = self.axes();
+        let extent = x.abs() * self.half_extents.x + y.abs() * self.half_extents.y;
+        Rectangle::new_coordinates(self.center - extent, self.center + extent)
+    }
+
+    /// Creates a counter-clockwise [`Polygon`] from the corners of the [`OrientedRectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{OrientedRectangle, Winding};
+    ///
+    /// let rect = OrientedRectangle::new(Vec2::ZERO, Vec2 { x: 2., y: 1. }, 0.3);
+    /// let polygon = rect.to_polygon();
+    ///
+    /// assert_eq!(polygon.coordinates(), &rect.corners());
+    /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn to_polygon(&self) -> Polygon {
+        Polygon::new(self.corners().to_vec())
+    }
+
+    // The `point` along each of the axes from the `center`
+    fn local_point(&self, point: Vec2) -> Vec2 {
+        let [x, y] = self.axes();
+        let offset = point - self.center;
+        Vec2 {
+            x: offset.dot(x),
+            y: offset.dot(y),
+        }
+    }
+}
+
+// ##########
+// From impls
+// ##########
+#[cfg(feature = "rectangle")]
+impl From<Rectangle> for OrientedRectangle {
+    /// Creates an [`OrientedRectangle`] covering a [`Rectangle`], without a `rotation`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{OrientedRectangle, Rectangle};
+    ///
+    /// let rect = OrientedRectangle::from(Rectangle::new(0., 0., 4., 2.));
+    ///
+    /// assert_eq!(rect.center(), Vec2 { x: 2., y: 1. });
+    /// assert_eq!(rect.half_extents(), Vec2 { x: 2., y: 1. });
+    /// assert_eq!(rect.rotation(), 0.);
+    /// ```
+    fn from(rectangle: Rectangle) -> Self {
+        Self::new(rectangle.center(), rectangle.size() * 0.5, 0.)
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `center`, `half_extents` and `rotation` of two [`OrientedRectangle`]s within `epsilon`, so the
+    /// same rectangle rotated a quarter turn with swapped extents is not close
+    OrientedRectangle { center, half_extents, rotation }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for OrientedRectangle {
+    fn default() -> Self {
+        Self {
+            center: Vec2::ZERO,
+            half_extents: Vec2::ONE,
+            rotation: 0.,
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for OrientedRectangle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OrientedRectangle {{ center: {}, half_extents: {}, rotation: {} }}",
+            self.center(),
+            self.half_extents(),
+            self.rotation()
+        )
+    }
+}
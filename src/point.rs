@@ -1,130 +1,261 @@
 use std::fmt::Display;
 
+#[cfg(feature = "integer")]
+use glam::IVec2;
 use glam::Vec2;
 
-/// Represents a single [`point`] in 2d space
-pub struct Point {
-    coordinate: Vec2,
+#[cfg(feature = "integer")]
+use crate::prelude::Rounding;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+// Defines the `Point` for one precision, here with `f32` and in `crate::double` with `f64`, where the
+// shape and `Vec2` names in the bodies refer to the `f64` types
+macro_rules! impl_point {
+    ($(#[$meta:meta])* $point:ident, $float:ident; $imports:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $point {
+            coordinate: Vec2,
+        }
+
+        // ##########
+        // Constructors
+        // ##########
+        impl Point {
+            /// Creates a new [`Point`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let point = Point::new(Vec2::ZERO);
+            ///
+            /// assert_eq!(point.coordinate(), Vec2::ZERO);
+            /// ```
+            pub fn new(coordinate: Vec2) -> Self {
+                Self { coordinate }
+            }
+        }
+
+        // ##########
+        // Getters/Setters
+        // ##########
+        impl Point {
+            /// Get the coordinate for the [`Point`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let point = Point::new(Vec2::ZERO);
+            /// let coordinate = point.coordinate();
+            ///
+            /// assert_eq!(coordinate, Vec2::ZERO);
+            /// ```
+            pub fn coordinate(&self) -> Vec2 {
+                self.coordinate
+            }
+
+            /// Set the coordinate for the [`Point`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut point = Point::new(Vec2::ZERO);
+            /// point.set_coordinate(Vec2::ONE);
+            ///
+            /// assert_eq!(point.coordinate(), Vec2::ONE);
+            /// ```
+            pub fn set_coordinate(&mut self, coordinate: Vec2) {
+                self.coordinate = coordinate;
+            }
+        }
+
+        // ##########
+        // Consts
+        // ##########
+        impl Point {
+            /// A basic wrapper to set the [`Point`] to coordinate `(0., 0.)`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let point1 = Point::ZERO;
+            /// let point2 = Point::new(Vec2::ZERO);
+            ///
+            /// assert_eq!(point1.coordinate(), point2.coordinate());
+            /// ```
+            pub const ZERO: Self = Self {
+                coordinate: Vec2::ZERO,
+            };
+            /// A basic wrapper to set the [`Point`] to coordinate `(1., 1.)`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let point1 = Point::ONE;
+            /// let point2 = Point::new(Vec2::ONE);
+            ///
+            /// assert_eq!(point1.coordinate(), point2.coordinate());
+            /// ```
+            pub const ONE: Self = Self {
+                coordinate: Vec2::ONE,
+            };
+            /// A basic wrapper to set the [`Point`] to coordinate `(-1., -1.)`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let point1 = Point::NEG_ONE;
+            /// let point2 = Point::new(Vec2::NEG_ONE);
+            ///
+            /// assert_eq!(point1.coordinate(), point2.coordinate());
+            /// ```
+            pub const NEG_ONE: Self = Self {
+                coordinate: Vec2::NEG_ONE,
+            };
+        }
+
+        // ##########
+        // Default impl
+        // ##########
+        impl Default for Point {
+            fn default() -> Self {
+                Self {
+                    coordinate: Vec2::ZERO,
+                }
+            }
+        }
+
+        // ##########
+        // Display impl
+        // ##########
+        impl Display for Point {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    concat!(stringify!($point), " {{ coordinate: {} }}"),
+                    self.coordinate()
+                )
+            }
+        }
+    };
 }
+pub(crate) use impl_point;
+
+impl_point!(
+    /// Represents a single [`point`] in 2d space
+    Point,
+    f32;
+    "use glam::Vec2;\nuse shapes2d::prelude::*;"
+);
 
 // ##########
-// Constructors
+// approx impl
 // ##########
-impl Point {
-    /// Creates a new [`Point`]
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `coordinate` of two [`Point`]s within `epsilon`
+    Point { coordinate }
+);
+
+// ##########
+// IPoint
+// ##########
+/// Represents a single [`Point`] on an integer grid, such as a tile coordinate
+#[cfg(feature = "integer")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IPoint {
+    coordinate: IVec2,
+}
+
+#[cfg(feature = "integer")]
+impl IPoint {
+    /// Creates a new [`IPoint`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Point;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IPoint;
     ///
-    /// let point = Point::new(Vec2::ZERO);
+    /// let point = IPoint::new(IVec2 { x: 3, y: -2 });
     ///
-    /// assert_eq!(point.coordinate(), Vec2::ZERO);
+    /// assert_eq!(point.coordinate(), IVec2 { x: 3, y: -2 });
     /// ```
-    pub fn new(coordinate: Vec2) -> Self {
+    pub fn new(coordinate: IVec2) -> Self {
         Self { coordinate }
     }
-}
 
-// ##########
-// Getters/Setters
-// ##########
-impl Point {
-    /// Get the coordinate for the [`Point`]
+    /// Get the coordinate for the [`IPoint`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Point;
-    ///
-    /// let point = Point::new(Vec2::ZERO);
-    /// let coordinate = point.coordinate();
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IPoint;
     ///
-    /// assert_eq!(coordinate, Vec2::ZERO);
+    /// assert_eq!(IPoint::new(IVec2::ONE).coordinate(), IVec2::ONE);
     /// ```
-    pub fn coordinate(&self) -> Vec2 {
+    pub fn coordinate(&self) -> IVec2 {
         self.coordinate
     }
 
-    /// Set the coordinate for the [`Point`]
+    /// Set the coordinate for the [`IPoint`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Point;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IPoint;
     ///
-    /// let mut point = Point::new(Vec2::ZERO);
-    /// point.set_coordinate(Vec2::ONE);
+    /// let mut point = IPoint::new(IVec2::ZERO);
+    /// point.set_coordinate(IVec2::ONE);
     ///
-    /// assert_eq!(point.coordinate(), Vec2::ONE);
+    /// assert_eq!(point.coordinate(), IVec2::ONE);
     /// ```
-    pub fn set_coordinate(&mut self, coordinate: Vec2) {
+    pub fn set_coordinate(&mut self, coordinate: IVec2) {
         self.coordinate = coordinate;
     }
-}
 
-// ##########
-// Consts
-// ##########
-impl Point {
-    /// A basic wrapper to set the [`Point`] to coordinate `(0., 0.)`
+    /// Get the [`Point`] at the same coordinate
     ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Point;
-    ///
-    /// let point1 = Point::ZERO;
-    /// let point2 = Point::new(Vec2::ZERO);
-    ///
-    /// assert_eq!(point1.coordinate(), point2.coordinate());
-    /// ```
-    pub const ZERO: Self = Self {
-        coordinate: Vec2::ZERO,
-    };
-    /// A basic wrapper to set the [`Point`] to coordinate `(1., 1.)`
+    /// The conversion is exact for coordinates within `±2^24`, beyond which `f32` can't hold every integer.
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Point;
-    ///
-    /// let point1 = Point::ONE;
-    /// let point2 = Point::new(Vec2::ONE);
+    /// use glam::{IVec2, Vec2};
+    /// use shapes2d::prelude::IPoint;
     ///
-    /// assert_eq!(point1.coordinate(), point2.coordinate());
+    /// assert_eq!(IPoint::new(IVec2 { x: 3, y: -2 }).to_point().coordinate(), Vec2 { x: 3., y: -2. });
     /// ```
-    pub const ONE: Self = Self {
-        coordinate: Vec2::ONE,
-    };
-    /// A basic wrapper to set the [`Point`] to coordinate `(-1., -1.)`
+    pub fn to_point(&self) -> Point {
+        Point::new(self.coordinate.as_vec2())
+    }
+}
+
+#[cfg(feature = "integer")]
+impl Point {
+    /// Get the [`IPoint`] with the coordinate rounded by `rounding`
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Point;
+    /// use glam::{IVec2, Vec2};
+    /// use shapes2d::prelude::{Point, Rounding};
     ///
-    /// let point1 = Point::NEG_ONE;
-    /// let point2 = Point::new(Vec2::NEG_ONE);
+    /// let point = Point::new(Vec2 { x: 2.5, y: -0.5 });
     ///
-    /// assert_eq!(point1.coordinate(), point2.coordinate());
+    /// assert_eq!(point.to_ipoint(Rounding::Floor).coordinate(), IVec2 { x: 2, y: -1 });
+    /// assert_eq!(point.to_ipoint(Rounding::Nearest).coordinate(), IVec2 { x: 3, y: -1 });
     /// ```
-    pub const NEG_ONE: Self = Self {
-        coordinate: Vec2::NEG_ONE,
-    };
+    pub fn to_ipoint(&self, rounding: Rounding) -> IPoint {
+        IPoint::new(rounding.round(self.coordinate))
+    }
 }
 
-// ##########
-// Default impl
-// ##########
-impl Default for Point {
+#[cfg(feature = "integer")]
+impl Default for IPoint {
     fn default() -> Self {
         Self {
-            coordinate: Vec2::ZERO,
+            coordinate: IVec2::ZERO,
         }
     }
 }
 
-// ##########
-// Display impl
-// ##########
-impl Display for Point {
+#[cfg(feature = "integer")]
+impl Display for IPoint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Point {{ coordinate: {} }}", self.coordinate())
+        write!(f, "IPoint {{ coordinate: {} }}", self.coordinate())
     }
 }
@@ -2,7 +2,2834 @@ use std::fmt::Display;
 
 use glam::Vec2;
 
-/// Represents a single [`Polygon`] in 2d space
-pub struct Polygon {
-    coordinates: Vec<Vec2>,
+use crate::clip::clip_against_edge;
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "triangle")]
+use crate::prelude::Triangle;
+use crate::prelude::Winding;
+use crate::sat::{self, AxisProject};
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+// Defines the `Polygon` for one precision, here with `f32` and in `crate::double` with `f64`, where the
+// shape and `Vec2` names in the bodies refer to the `f64` types
+macro_rules! impl_polygon {
+    ($(#[$meta:meta])* $polygon:ident, $polygon_defect:ident, $split_result:ident, $polygon_sampler:ident, $float:ident; $imports:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $polygon {
+            coordinates: Vec<Vec2>,
+        }
+
+        /// A problem with a [`Polygon`], reported by [`Polygon::validate`]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum $polygon_defect {
+            /// The [`Polygon`] has fewer than 3 coordinates
+            TooFewVertices,
+            /// The coordinate at the index repeats the coordinate before it
+            DuplicateConsecutiveVertices(usize),
+            /// The coordinate at the index lies on the line between its neighbors
+            CollinearVertex(usize),
+            /// The [`Polygon`] encloses no area
+            ZeroArea,
+            /// The edges starting at the two indices cross or touch at the point
+            SelfIntersection(usize, usize, Vec2),
+        }
+
+        /// The result of [`Polygon::split_by_line`]
+        #[cfg(feature = "line")]
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum $split_result {
+            /// The line doesn't pass through the [`Polygon`], which is returned whole
+            Unsplit(Polygon),
+            /// The pieces of the [`Polygon`] on each side of the line
+            Split {
+                /// The pieces on the left of the line
+                left: Vec<Polygon>,
+                /// The pieces on the right of the line
+                right: Vec<Polygon>,
+            },
+        }
+
+        // ##########
+        // Constructors
+        // ##########
+        impl Polygon {
+            /// Creates a new [`Polygon`] from a list of `coordinates`
+            ///
+            /// The [`Polygon`] is closed, the last coordinate connects back to the first.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            ///
+            /// assert_eq!(polygon.len(), 3);
+            /// assert_eq!(polygon.coordinate(2), Some(Vec2::ONE));
+            /// ```
+            pub fn new(coordinates: Vec<Vec2>) -> Self {
+                Self { coordinates }
+            }
+
+            /// Creates a new [`Polygon`] from the convex hull of `points`, using Andrew's monotone chain algorithm
+            ///
+            /// The hull is counter-clockwise, with duplicate points and collinear points along its edges removed.
+            /// Returns `None` when there are fewer than 3 non-collinear points.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use rand::{Rng, SeedableRng};
+            ///
+            /// // a square with duplicates, interior points, and a point along an edge
+            /// let points = [
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 2., y: 2. },
+            ///     Vec2::ONE,
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 0.5, y: 1.5 },
+            ///     Vec2 { x: 0., y: 2. },
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 1., y: 0. },
+            ///     Vec2 { x: 2., y: 2. },
+            /// ];
+            /// let hull = Polygon::convex_hull(&points).unwrap();
+            ///
+            /// assert_eq!(hull.coordinates(), &[Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// assert_eq!(hull.winding(), Winding::CounterClockwise);
+            ///
+            /// // collinear and too few points
+            /// assert!(Polygon::convex_hull(&[Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }, Vec2::ONE]).is_none());
+            /// assert!(Polygon::convex_hull(&[Vec2::ZERO, Vec2::ONE]).is_none());
+            ///
+            /// // every point is inside or on the hull of a random point set
+            /// let mut rng = rand::rngs::StdRng::seed_from_u64(12345);
+            /// let points: Vec<Vec2> = (0..500)
+            ///     .map(|_| Vec2 { x: rng.gen_range(-50.0..50.), y: rng.gen_range(-50.0..50.) })
+            ///     .collect();
+            /// let hull = Polygon::convex_hull(&points).unwrap();
+            ///
+            /// assert!(hull.is_convex());
+            /// for point in points {
+            ///     assert!(hull.contains_point(point) || hull.coordinates().contains(&point));
+            /// }
+            /// ```
+            pub fn convex_hull(points: &[Vec2]) -> Option<Polygon> {
+                let mut points = points.to_vec();
+                points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+                points.dedup();
+                if points.len() < 3 {
+                    return None;
+                }
+
+                // Keeps only left turns, dropping collinear points
+                fn push_turning_left(chain: &mut Vec<Vec2>, point: Vec2) {
+                    while chain.len() >= 2 {
+                        let a = chain[chain.len() - 2];
+                        let b = chain[chain.len() - 1];
+                        if (b - a).perp_dot(point - a) > 0. {
+                            break;
+                        }
+                        chain.pop();
+                    }
+                    chain.push(point);
+                }
+
+                let mut lower = Vec::new();
+                for point in points.iter() {
+                    push_turning_left(&mut lower, *point);
+                }
+                let mut upper = Vec::new();
+                for point in points.iter().rev() {
+                    push_turning_left(&mut upper, *point);
+                }
+
+                // The last point of each chain is the first point of the other
+                lower.pop();
+                upper.pop();
+                lower.append(&mut upper);
+
+                if lower.len() < 3 {
+                    return None;
+                }
+                Some(Self::new(lower))
+            }
+        }
+
+        // ##########
+        // Getters/Setters
+        // ##########
+        impl Polygon {
+            /// Get the coordinates for the [`Polygon`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            /// let coordinates = polygon.coordinates();
+            ///
+            /// assert_eq!(coordinates, &[Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            /// ```
+            pub fn coordinates(&self) -> &[Vec2] {
+                &self.coordinates
+            }
+
+            /// Get the coordinate at `index` for the [`Polygon`], or `None` if `index` is out of bounds
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            ///
+            /// assert_eq!(polygon.coordinate(1), Some(Vec2 { x: 1., y: 0. }));
+            /// assert_eq!(polygon.coordinate(3), None);
+            /// ```
+            pub fn coordinate(&self, index: usize) -> Option<Vec2> {
+                self.coordinates.get(index).copied()
+            }
+
+            /// Get an iterator over the edges of the [`Polygon`], in order, ending with the edge from the last
+            /// coordinate back to the first
+            ///
+            /// A [`Polygon`] with fewer than two coordinates has no edges, and one with exactly two coordinates
+            /// has a single edge from the first to the second.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 3. }, Vec2 { x: 0., y: 3. }]);
+            /// let edges: Vec<_> = polygon.edges().collect();
+            ///
+            /// assert_eq!(edges.len(), polygon.len());
+            /// assert_eq!(edges[0].origin(), Vec2::ZERO);
+            /// for i in 0..edges.len() {
+            ///     assert_eq!(edges[i].end(), edges[(i + 1) % edges.len()].origin());
+            /// }
+            ///
+            /// assert_eq!(Polygon::new(Vec::new()).edges().count(), 0);
+            /// assert_eq!(Polygon::new(vec![Vec2::ZERO]).edges().count(), 0);
+            /// assert_eq!(Polygon::new(vec![Vec2::ZERO, Vec2::ONE]).edges().count(), 1);
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn edges(&self) -> impl Iterator<Item = Line> + '_ {
+                self.edge_pairs()
+                    .take(self.edge_count())
+                    .map(|(start, end)| Line::new(start, end))
+            }
+
+            /// Get the edge starting at the coordinate at `index` for the [`Polygon`], or `None` if there is no
+            /// such edge
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 3. }]);
+            /// let closing = polygon.edge(2).unwrap();
+            ///
+            /// assert_eq!(closing.origin(), Vec2 { x: 4., y: 3. });
+            /// assert_eq!(closing.end(), Vec2::ZERO);
+            /// assert!(polygon.edge(3).is_none());
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn edge(&self, index: usize) -> Option<Line> {
+                if index >= self.edge_count() {
+                    return None;
+                }
+                let start = self.coordinates[index];
+                let end = self.coordinates[(index + 1) % self.coordinates.len()];
+                Some(Line::new(start, end))
+            }
+
+            /// Set the coordinate at `index` for the [`Polygon`]
+            ///
+            /// Returns the previous coordinate, or `None` without changing the [`Polygon`] if `index` is out of bounds.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            ///
+            /// assert_eq!(polygon.set_coordinate(2, Vec2 { x: 2., y: 2. }), Some(Vec2::ONE));
+            /// assert_eq!(polygon.coordinate(2), Some(Vec2 { x: 2., y: 2. }));
+            /// assert_eq!(polygon.set_coordinate(3, Vec2::ONE), None);
+            /// ```
+            pub fn set_coordinate(&mut self, index: usize, coordinate: Vec2) -> Option<Vec2> {
+                let previous = self.coordinates.get_mut(index)?;
+                Some(std::mem::replace(previous, coordinate))
+            }
+
+            /// Adds a `coordinate` to the end of the [`Polygon`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }]);
+            /// polygon.push(Vec2::ONE);
+            ///
+            /// assert_eq!(polygon.len(), 3);
+            /// assert_eq!(polygon.coordinate(2), Some(Vec2::ONE));
+            /// ```
+            pub fn push(&mut self, coordinate: Vec2) {
+                self.coordinates.push(coordinate);
+            }
+
+            /// Inserts a `coordinate` at `index` in the [`Polygon`], shifting all coordinates after it
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index > len`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut polygon = Polygon::new(vec![Vec2::ZERO, Vec2::ONE]);
+            /// polygon.insert(1, Vec2 { x: 1., y: 0. });
+            ///
+            /// assert_eq!(polygon.coordinates(), &[Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            /// ```
+            pub fn insert(&mut self, index: usize, coordinate: Vec2) {
+                self.coordinates.insert(index, coordinate);
+            }
+
+            /// Removes the coordinate at `index` from the [`Polygon`], shifting all coordinates after it
+            ///
+            /// Returns the removed coordinate, or `None` if `index` is out of bounds.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            ///
+            /// assert_eq!(polygon.remove(1), Some(Vec2 { x: 1., y: 0. }));
+            /// assert_eq!(polygon.coordinates(), &[Vec2::ZERO, Vec2::ONE]);
+            /// assert_eq!(polygon.remove(2), None);
+            /// ```
+            pub fn remove(&mut self, index: usize) -> Option<Vec2> {
+                if index < self.coordinates.len() {
+                    Some(self.coordinates.remove(index))
+                } else {
+                    None
+                }
+            }
+        }
+
+        // ##########
+        // Attributes
+        // ##########
+        impl Polygon {
+            /// Get the number of coordinates in the [`Polygon`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            /// let len = polygon.len();
+            ///
+            /// assert_eq!(len, 3);
+            /// ```
+            pub fn len(&self) -> usize {
+                self.coordinates.len()
+            }
+
+            /// Checks if the [`Polygon`] has no coordinates
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let polygon = Polygon::new(Vec::new());
+            ///
+            /// assert!(polygon.is_empty());
+            /// ```
+            pub fn is_empty(&self) -> bool {
+                self.coordinates.is_empty()
+            }
+
+            /// Get the signed `area` of the [`Polygon`] using the shoelace formula
+            ///
+            /// The area is positive when the coordinates are counter-clockwise and negative when clockwise.
+            /// A [`Polygon`] with fewer than 3 coordinates has no area.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            /// assert_eq!(square.signed_area(), 1.);
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2::ONE, Vec2 { x: 1., y: 0. }]);
+            /// assert_eq!(square.signed_area(), -1.);
+            /// ```
+            pub fn signed_area(&self) -> $float {
+                if self.len() < 3 {
+                    return 0.;
+                }
+                self.edge_pairs()
+                    .map(|(start, end)| start.perp_dot(end))
+                    .sum::<$float>()
+                    * 0.5
+            }
+
+            /// Get the `area` of the [`Polygon`]
+            ///
+            /// A [`Polygon`] with fewer than 3 coordinates has no area.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2::ONE, Vec2 { x: 1., y: 0. }]);
+            /// assert_eq!(square.area(), 1.);
+            ///
+            /// // an L shape made of three unit squares
+            /// let l_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 2. },
+            ///     Vec2 { x: 0., y: 2. },
+            /// ]);
+            /// assert_eq!(l_shape.area(), 3.);
+            ///
+            /// let line = Polygon::new(vec![Vec2::ZERO, Vec2::ONE]);
+            /// assert_eq!(line.area(), 0.);
+            /// ```
+            pub fn area(&self) -> $float {
+                self.signed_area().abs()
+            }
+
+            /// Get the `centroid` of the [`Polygon`], the center of mass of its area
+            ///
+            /// A [`Polygon`] with no area uses the average of its coordinates instead, and an empty [`Polygon`]
+            /// has no centroid.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// // an L shape made of three unit squares
+            /// let l_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 2. },
+            ///     Vec2 { x: 0., y: 2. },
+            /// ]);
+            /// let centroid = l_shape.centroid().unwrap();
+            /// assert_abs_diff_eq!(centroid, Vec2 { x: 5. / 6., y: 5. / 6. }, epsilon = 1e-6);
+            ///
+            /// let line = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 2. }]);
+            /// assert_eq!(line.centroid(), Some(Vec2::ONE));
+            ///
+            /// assert_eq!(Polygon::new(Vec::new()).centroid(), None);
+            /// ```
+            pub fn centroid(&self) -> Option<Vec2> {
+                let origin = *self.coordinates.first()?;
+                let mut weighted = Vec2::ZERO;
+                let mut cross_sum = 0.;
+                let mut cross_magnitude = 0.;
+                for (start, end) in self.edge_pairs() {
+                    let (start, end) = (start - origin, end - origin);
+                    let cross = start.perp_dot(end);
+                    weighted += (start + end) * cross;
+                    cross_sum += cross;
+                    cross_magnitude += cross.abs();
+                }
+
+                if cross_sum.abs() <= cross_magnitude * $float::EPSILON * 4. {
+                    let sum: Vec2 = self.coordinates.iter().sum();
+                    return Some(sum / self.coordinates.len() as $float);
+                }
+                Some(origin + weighted / (3. * cross_sum))
+            }
+
+            /// Get the smallest axis aligned [`Rectangle`] containing the [`Polygon`], or `None` if it has no coordinates
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// assert!(Polygon::new(Vec::new()).bounding_box().is_none());
+            ///
+            /// let point = Polygon::new(vec![Vec2::ONE]);
+            /// let rect = point.bounding_box().unwrap();
+            /// assert_eq!(rect.min(), Vec2::ONE);
+            /// assert_eq!(rect.max(), Vec2::ONE);
+            ///
+            /// let arrow = Polygon::new(vec![
+            ///     Vec2 { x: -2., y: -1. },
+            ///     Vec2 { x: 3., y: 0.5 },
+            ///     Vec2 { x: -2., y: 2. },
+            ///     Vec2 { x: 0., y: 0.5 },
+            /// ]);
+            /// let rect = arrow.bounding_box().unwrap();
+            /// assert_eq!(rect.min(), Vec2 { x: -2., y: -1. });
+            /// assert_eq!(rect.max(), Vec2 { x: 3., y: 2. });
+            /// ```
+            #[cfg(feature = "rectangle")]
+            pub fn bounding_box(&self) -> Option<Rectangle> {
+                let first = *self.coordinates.first()?;
+                let (min, max) = self
+                    .coordinates
+                    .iter()
+                    .fold((first, first), |(min, max), coordinate| {
+                        (min.min(*coordinate), max.max(*coordinate))
+                    });
+                Some(Rectangle::new_coordinates(min, max))
+            }
+
+            /// Get the [`Winding`] order of the [`Polygon`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            /// assert_eq!(square.winding(), Winding::CounterClockwise);
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2::ONE, Vec2 { x: 1., y: 0. }]);
+            /// assert_eq!(square.winding(), Winding::Clockwise);
+            ///
+            /// let line = Polygon::new(vec![Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }]);
+            /// assert_eq!(line.winding(), Winding::Degenerate);
+            /// ```
+            pub fn winding(&self) -> Winding {
+                Winding::from_signed_area(self.signed_area())
+            }
+
+            /// Checks if the coordinates of the [`Polygon`] are ordered clockwise
+            ///
+            /// A degenerate [`Polygon`] is neither clockwise nor counter-clockwise and returns `false`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2::ONE, Vec2 { x: 1., y: 0. }]);
+            ///
+            /// assert!(square.is_clockwise());
+            /// ```
+            pub fn is_clockwise(&self) -> bool {
+                self.winding() == Winding::Clockwise
+            }
+
+            /// Get the `perimeter` of the [`Polygon`], including the edge from the last coordinate back to the first
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            /// assert_eq!(square.perimeter(), 4.);
+            ///
+            /// // there and back again
+            /// let line = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 3., y: 4. }]);
+            /// assert_eq!(line.perimeter(), 10.);
+            /// ```
+            pub fn perimeter(&self) -> $float {
+                self.edge_pairs()
+                    .map(|(start, end)| start.distance(end))
+                    .sum()
+            }
+
+            /// Checks if the [`Polygon`] is convex
+            ///
+            /// Every turn between consecutive edges must be in the same direction, in either winding order.
+            /// Collinear coordinates (near-zero turns) and repeated consecutive coordinates are allowed.
+            /// A [`Polygon`] with fewer than 3 coordinates, one with no area, or one that winds around more
+            /// than once (such as a pentagram) is not convex.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            #[doc = concat!(" let pentagon: Polygon = (0..5).map(|i| Vec2::from_angle(i as ", stringify!($float), " * std::", stringify!($float), "::consts::TAU / 5.)).collect();")]
+            /// assert!(pentagon.is_convex());
+            ///
+            /// let reversed: Polygon = pentagon.coordinates().iter().rev().copied().collect();
+            /// assert!(reversed.is_convex());
+            ///
+            /// let arrow = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 1. }, Vec2 { x: 0., y: 2. }, Vec2::ONE]);
+            /// assert!(!arrow.is_convex());
+            ///
+            /// // a collinear midpoint on the bottom edge and a repeated coordinate
+            /// let square = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 1., y: 0. },
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 2., y: 2. },
+            ///     Vec2 { x: 2., y: 2. },
+            ///     Vec2 { x: 0., y: 2. },
+            /// ]);
+            /// assert!(square.is_convex());
+            ///
+            #[doc = concat!(" let pentagram: Polygon = (0..5).map(|i| Vec2::from_angle(i as ", stringify!($float), " * std::", stringify!($float), "::consts::TAU * 0.4)).collect();")]
+            /// assert!(!pentagram.is_convex());
+            ///
+            /// let line = Polygon::new(vec![Vec2::ZERO, Vec2::ONE]);
+            /// assert!(!line.is_convex());
+            /// ```
+            pub fn is_convex(&self) -> bool {
+                const EPSILON: $float = 1e-6;
+
+                // Skip repeated coordinates so that every edge has a direction
+                let edges: Vec<Vec2> = self
+                    .edge_pairs()
+                    .map(|(start, end)| end - start)
+                    .filter(|edge| *edge != Vec2::ZERO)
+                    .collect();
+                if edges.len() < 3 {
+                    return false;
+                }
+
+                let mut sign = 0.;
+                let mut turning = 0.;
+                for (index, edge) in edges.iter().enumerate() {
+                    let next = edges[(index + 1) % edges.len()];
+                    let cross = edge.perp_dot(next);
+                    turning += cross.atan2(edge.dot(next));
+
+                    if cross.abs() <= EPSILON * edge.length() * next.length() {
+                        continue;
+                    }
+                    if sign == 0. {
+                        sign = cross.signum();
+                    } else if cross.signum() != sign {
+                        return false;
+                    }
+                }
+
+                // A convex polygon turns exactly once around
+                sign != 0. && (turning.abs() - std::$float::consts::TAU).abs() < 1e-3
+            }
+
+            // Each coordinate paired with the next, wrapping around to the first
+            pub(crate) fn edge_pairs(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+                let next = self.coordinates.iter().cycle().skip(1);
+                self.coordinates.iter().copied().zip(next.copied())
+            }
+
+            // The indices, in order, of coordinates closer than `epsilon` to the coordinate before them.
+            // The last coordinate is also compared to the first, so the first coordinate is never repeated.
+            fn repeated_coordinates(&self, epsilon: $float) -> Vec<usize> {
+                let length = self.len();
+                let mut repeated: Vec<usize> = (1..length)
+                    .filter(|&index| {
+                        self.coordinates[index - 1].distance(self.coordinates[index]) < epsilon
+                    })
+                    .collect();
+                if length > 1
+                    && repeated.last() != Some(&(length - 1))
+                    && self.coordinates[length - 1].distance(self.coordinates[0]) < epsilon
+                {
+                    repeated.push(length - 1);
+                }
+                repeated
+            }
+
+            // The number of distinct edges, a closing edge back over the only edge is not counted
+            #[cfg(feature = "line")]
+            fn edge_count(&self) -> usize {
+                match self.coordinates.len() {
+                    0 | 1 => 0,
+                    2 => 1,
+                    len => len,
+                }
+            }
+        }
+
+        // ##########
+        // Operations
+        // ##########
+        impl Polygon {
+            /// Reverses the order of the coordinates of the [`Polygon`], flipping its winding order
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2::ONE, Vec2 { x: 1., y: 0. }]);
+            /// assert_eq!(square.winding(), Winding::Clockwise);
+            /// assert_eq!(square.signed_area(), -1.);
+            ///
+            /// square.reverse();
+            ///
+            /// assert_eq!(square.winding(), Winding::CounterClockwise);
+            /// assert_eq!(square.signed_area(), 1.);
+            /// assert_eq!(square.area(), 1.);
+            /// ```
+            pub fn reverse(&mut self) {
+                self.coordinates.reverse();
+            }
+
+            /// Get a copy of the [`Polygon`] with the order of the coordinates reversed
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2::ONE, Vec2 { x: 1., y: 0. }]);
+            /// let reversed = square.reversed();
+            ///
+            /// assert!(square.is_clockwise());
+            /// assert!(!reversed.is_clockwise());
+            /// assert_eq!(reversed.signed_area(), -square.signed_area());
+            /// assert_eq!(reversed.area(), square.area());
+            /// ```
+            pub fn reversed(&self) -> Polygon {
+                self.coordinates.iter().rev().copied().collect()
+            }
+
+            /// Get a [`Mesh`] covering the [`Polygon`] by ear clipping, see [`Mesh::from_polygon`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Polygon::default().to_mesh();
+            ///
+            /// assert_eq!(mesh.vertex_count(), 4);
+            /// assert_eq!(mesh.triangle_count(), 2);
+            /// ```
+            #[cfg(feature = "mesh")]
+            pub fn to_mesh(&self) -> Mesh {
+                Mesh::from_polygon(self)
+            }
+
+            /// Moves every coordinate of the [`Polygon`] by `offset`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut triangle = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            /// triangle.translate(Vec2 { x: 2., y: -1. });
+            ///
+            /// assert_eq!(triangle.coordinates(), &[Vec2 { x: 2., y: -1. }, Vec2 { x: 3., y: -1. }, Vec2 { x: 3., y: 0. }]);
+            /// ```
+            pub fn translate(&mut self, offset: Vec2) {
+                for coordinate in &mut self.coordinates {
+                    *coordinate += offset;
+                }
+            }
+
+            /// Get a copy of the [`Polygon`] with every coordinate moved by `offset`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            /// let translated = triangle.translated(Vec2::ONE);
+            ///
+            /// assert_eq!(translated.coordinates(), &[Vec2::ONE, Vec2 { x: 2., y: 1. }, Vec2 { x: 2., y: 2. }]);
+            /// assert_eq!(translated.area(), triangle.area());
+            /// ```
+            pub fn translated(&self, offset: Vec2) -> Polygon {
+                self.coordinates
+                    .iter()
+                    .map(|coordinate| *coordinate + offset)
+                    .collect()
+            }
+
+            /// Rotates the [`Polygon`] counter-clockwise by `radians` around `anchor`
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let mut square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            /// let perimeter = square.perimeter();
+            #[doc = concat!(" square.rotate_about(std::", stringify!($float), "::consts::FRAC_PI_2, Vec2::ZERO);")]
+            ///
+            /// assert_abs_diff_eq!(square.coordinate(1).unwrap(), Vec2 { x: 0., y: 1. }, epsilon = 1e-6);
+            /// assert_abs_diff_eq!(square.perimeter(), perimeter, epsilon = 1e-5);
+            /// ```
+            pub fn rotate_about(&mut self, radians: $float, anchor: Vec2) {
+                let rotation = Vec2::from_angle(radians);
+                for coordinate in &mut self.coordinates {
+                    *coordinate = anchor + rotation.rotate(*coordinate - anchor);
+                }
+            }
+
+            /// Get a copy of the [`Polygon`] rotated counter-clockwise by `radians` around `anchor`
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            #[doc = concat!(" let rotated = square.rotated_about(std::", stringify!($float), "::consts::PI, Vec2::ONE);")]
+            ///
+            /// assert_abs_diff_eq!(rotated.coordinate(0).unwrap(), Vec2 { x: 2., y: 2. }, epsilon = 1e-6);
+            /// assert_abs_diff_eq!(rotated.perimeter(), square.perimeter(), epsilon = 1e-5);
+            /// ```
+            pub fn rotated_about(&self, radians: $float, anchor: Vec2) -> Polygon {
+                let mut polygon = Polygon::new(self.coordinates.clone());
+                polygon.rotate_about(radians, anchor);
+                polygon
+            }
+
+            /// Scales the [`Polygon`] away from `anchor` by `factor` along each axis
+            ///
+            /// A negative factor mirrors the [`Polygon`] along that axis.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            /// square.scale_about(Vec2 { x: 3., y: -2. }, Vec2::ZERO);
+            ///
+            /// assert_eq!(square.coordinate(2), Some(Vec2 { x: 3., y: -2. }));
+            /// assert_eq!(square.area(), 6.);
+            /// ```
+            pub fn scale_about(&mut self, factor: Vec2, anchor: Vec2) {
+                for coordinate in &mut self.coordinates {
+                    *coordinate = anchor + (*coordinate - anchor) * factor;
+                }
+            }
+
+            /// Get a copy of the [`Polygon`] scaled away from `anchor` by `factor` along each axis
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE, Vec2 { x: 0., y: 1. }]);
+            /// let scaled = square.scaled_about(Vec2 { x: 2., y: 0.5 }, Vec2::ONE);
+            ///
+            /// assert_eq!(scaled.coordinate(0), Some(Vec2 { x: -1., y: 0.5 }));
+            /// assert_eq!(scaled.area(), square.area() * 2. * 0.5);
+            /// ```
+            pub fn scaled_about(&self, factor: Vec2, anchor: Vec2) -> Polygon {
+                let mut polygon = Polygon::new(self.coordinates.clone());
+                polygon.scale_about(factor, anchor);
+                polygon
+            }
+
+            /// Rotates the [`Polygon`] counter-clockwise by `radians` around its [`Polygon::centroid`]
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let mut arrow = Polygon::new(vec![
+            ///     Vec2 { x: -2., y: -1. },
+            ///     Vec2 { x: 3., y: 0.5 },
+            ///     Vec2 { x: -2., y: 2. },
+            ///     Vec2 { x: 0., y: 0.5 },
+            /// ]);
+            /// let centroid = arrow.centroid().unwrap();
+            /// arrow.rotate(1.);
+            ///
+            /// assert_abs_diff_eq!(arrow.centroid().unwrap(), centroid, epsilon = 1e-5);
+            /// ```
+            pub fn rotate(&mut self, radians: $float) {
+                if let Some(centroid) = self.centroid() {
+                    self.rotate_about(radians, centroid);
+                }
+            }
+
+            /// Get a copy of the [`Polygon`] rotated counter-clockwise by `radians` around its [`Polygon::centroid`]
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            #[doc = concat!(" let rotated = square.rotated(std::", stringify!($float), "::consts::FRAC_PI_2);")]
+            ///
+            /// assert_abs_diff_eq!(rotated.coordinate(0).unwrap(), Vec2 { x: 2., y: 0. }, epsilon = 1e-6);
+            /// assert_abs_diff_eq!(rotated.centroid().unwrap(), Vec2::ONE, epsilon = 1e-6);
+            /// ```
+            pub fn rotated(&self, radians: $float) -> Polygon {
+                let mut polygon = Polygon::new(self.coordinates.clone());
+                polygon.rotate(radians);
+                polygon
+            }
+
+            /// Scales the [`Polygon`] away from its [`Polygon::centroid`] by `factor` along each axis
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// square.scale(Vec2 { x: 2., y: 3. });
+            ///
+            /// assert_eq!(square.coordinate(0), Some(Vec2 { x: -1., y: -2. }));
+            /// assert_eq!(square.area(), 4. * 2. * 3.);
+            /// ```
+            pub fn scale(&mut self, factor: Vec2) {
+                if let Some(centroid) = self.centroid() {
+                    self.scale_about(factor, centroid);
+                }
+            }
+
+            /// Get a copy of the [`Polygon`] scaled away from its [`Polygon::centroid`] by `factor` along each axis
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let triangle = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2 { x: 0., y: 3. }]);
+            /// let scaled = triangle.scaled(Vec2 { x: -0.5, y: 4. });
+            ///
+            /// assert_abs_diff_eq!(scaled.area(), triangle.area() * 2., epsilon = 1e-5);
+            /// assert_abs_diff_eq!(scaled.centroid().unwrap(), triangle.centroid().unwrap(), epsilon = 1e-5);
+            /// ```
+            pub fn scaled(&self, factor: Vec2) -> Polygon {
+                let mut polygon = Polygon::new(self.coordinates.clone());
+                polygon.scale(factor);
+                polygon
+            }
+
+            /// Get a simplified copy of the [`Polygon`] using the Ramer-Douglas-Peucker algorithm
+            ///
+            /// The ring is split at two far apart coordinates (the coordinate farthest from the first, and the
+            /// coordinate farthest from that), and each half is simplified so that every removed coordinate is
+            /// within `tolerance` of the simplified outline. The result keeps at least 3 coordinates when the
+            /// [`Polygon`] has at least 3. A `tolerance` of `0` only removes exactly collinear coordinates.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // a noisy circle
+            /// let circle: Polygon = (0..1000)
+            ///     .map(|i| {
+            ///         let noise = if i % 2 == 0 { 0.001 } else { -0.001 };
+            #[doc = concat!("         Vec2::from_angle(i as ", stringify!($float), " * std::", stringify!($float), "::consts::TAU / 1000.) * (10. + noise)")]
+            ///     })
+            ///     .collect();
+            /// let tolerance = 0.05;
+            /// let simplified = circle.simplified(tolerance);
+            ///
+            /// assert!(simplified.len() < 100);
+            /// assert!(simplified.len() >= 3);
+            ///
+            /// // every removed coordinate is close to the simplified outline
+            /// let distance_to_segment = |point: Vec2, start: Vec2, end: Vec2| {
+            ///     let direction = end - start;
+            ///     let t = ((point - start).dot(direction) / direction.length_squared()).clamp(0., 1.);
+            ///     point.distance(start + direction * t)
+            /// };
+            /// for coordinate in circle.coordinates() {
+            ///     let coordinates = simplified.coordinates();
+            ///     let distance = (0..coordinates.len())
+            ///         .map(|i| distance_to_segment(*coordinate, coordinates[i], coordinates[(i + 1) % coordinates.len()]))
+            #[doc = concat!("         .fold(", stringify!($float), "::INFINITY, ", stringify!($float), "::min);")]
+            ///     assert!(distance <= tolerance);
+            /// }
+            ///
+            /// // a tolerance of 0 only removes collinear coordinates
+            /// let square = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 1., y: 0. },
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 2., y: 2. },
+            ///     Vec2 { x: 0., y: 2. },
+            /// ]);
+            /// let simplified = square.simplified(0.);
+            ///
+            /// assert_eq!(simplified.coordinates(), &[Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// ```
+            pub fn simplified(&self, tolerance: $float) -> Polygon {
+                let length = self.len();
+                if length < 3 {
+                    return self.coordinates.iter().copied().collect();
+                }
+
+                let farthest_from = |from: Vec2| {
+                    (0..length)
+                        .max_by(|a, b| {
+                            let a = self.coordinates[*a].distance_squared(from);
+                            let b = self.coordinates[*b].distance_squared(from);
+                            a.total_cmp(&b)
+                        })
+                        .unwrap_or(0)
+                };
+                let first = farthest_from(self.coordinates[0]);
+                let second = farthest_from(self.coordinates[first]);
+                if first == second {
+                    // Every coordinate is in the same place
+                    return self.coordinates.iter().copied().collect();
+                }
+
+                // Walk each half of the ring from one split coordinate to the other, keeping track of the
+                // original indices so that the result starts in the same place as the original
+                let mut kept = Vec::new();
+                for (from, to) in [(first, second), (second, first)] {
+                    let count = (to + length - from) % length + 1;
+                    let indices: Vec<usize> = (0..count).map(|offset| (from + offset) % length).collect();
+                    let chain: Vec<Vec2> = indices
+                        .iter()
+                        .map(|index| self.coordinates[*index])
+                        .collect();
+                    kept.extend(
+                        simplify_chain(&chain, tolerance)
+                            .into_iter()
+                            .map(|index| indices[index]),
+                    );
+                }
+                kept.sort_unstable();
+                kept.dedup();
+
+                if kept.len() < 3 {
+                    // Keep the coordinate farthest from the split so the result still has an area
+                    let (start, end) = (self.coordinates[first], self.coordinates[second]);
+                    let farthest = (0..length)
+                        .max_by(|a, b| {
+                            let a = distance_to_segment(self.coordinates[*a], start, end);
+                            let b = distance_to_segment(self.coordinates[*b], start, end);
+                            a.total_cmp(&b)
+                        })
+                        .unwrap_or(0);
+                    kept.push(farthest);
+                    kept.sort_unstable();
+                }
+
+                kept.into_iter()
+                    .map(|index| self.coordinates[index])
+                    .collect()
+            }
+
+            /// Get a copy of the [`Polygon`] without repeated or collinear coordinates
+            ///
+            /// Consecutive coordinates closer than `epsilon`, including the last and first, are merged into the
+            /// first of them. Then coordinates within `epsilon` of the line between their neighbors are removed.
+            /// A [`Polygon`] with area keeps at least 3 coordinates, one without area may be left with fewer.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// // a square with every edge split into 5 points and every corner repeated
+            /// let corners = [Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 4. }, Vec2 { x: 0., y: 4. }];
+            /// let mut coordinates = Vec::new();
+            /// for i in 0..4 {
+            ///     let (start, end) = (corners[i], corners[(i + 1) % 4]);
+            ///     coordinates.push(start);
+            ///     coordinates.push(start + Vec2::splat(1e-7));
+            ///     for step in 1..5 {
+            #[doc = concat!("         coordinates.push(start.lerp(end, step as ", stringify!($float), " / 5.));")]
+            ///     }
+            /// }
+            /// let square = Polygon::new(coordinates);
+            /// let cleaned = square.cleaned(1e-4);
+            ///
+            /// assert_eq!(cleaned.coordinates(), &corners);
+            /// assert_abs_diff_eq!(cleaned.area(), square.area(), epsilon = 1e-4);
+            ///
+            /// // a polygon without area may collapse
+            /// let line = Polygon::new(vec![Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }]);
+            /// assert_eq!(line.cleaned(1e-4).len(), 2);
+            /// ```
+            pub fn cleaned(&self, epsilon: $float) -> Polygon {
+                let repeated = self.repeated_coordinates(epsilon);
+                let coordinates = self
+                    .coordinates
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| repeated.binary_search(index).is_err())
+                    .map(|(_, coordinate)| *coordinate)
+                    .collect();
+
+                Polygon::new(remove_collinear(coordinates, epsilon))
+            }
+
+            /// Removes repeated and collinear coordinates from the [`Polygon`], see [`Polygon::cleaned`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut triangle = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 1., y: 0. },
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 0., y: 2. },
+            ///     Vec2::ZERO,
+            /// ]);
+            /// triangle.clean(1e-4);
+            ///
+            /// assert_eq!(triangle.coordinates(), &[Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. }]);
+            /// ```
+            pub fn clean(&mut self, epsilon: $float) {
+                *self = self.cleaned(epsilon);
+            }
+
+            /// Creates a new [`Polygon`] with `count` coordinates spaced evenly along the perimeter of the [`Polygon`]
+            ///
+            /// The first coordinate is kept and the winding order is preserved, other original coordinates
+            /// are only kept if they happen to fall on the spacing. A `count` below 3 is clamped to 3.
+            /// A [`Polygon`] with no perimeter resamples to copies of its first coordinate, and an empty
+            /// [`Polygon`] stays empty.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::abs_diff_eq;
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// let resampled = square.resample(8);
+            ///
+            /// assert_eq!(resampled.len(), 8);
+            /// assert_eq!(resampled.coordinate(0), Some(Vec2::ZERO));
+            /// assert_eq!(resampled.coordinate(1), Some(Vec2 { x: 1., y: 0. }));
+            /// assert_eq!(resampled.winding(), square.winding());
+            #[doc = concat!(" let lengths: Vec<", stringify!($float), "> = resampled.edges().map(|edge| edge.direction().length()).collect();")]
+            /// assert!(lengths.iter().all(|length| abs_diff_eq!(*length, 1., epsilon = 1e-5)));
+            ///
+            /// // corners are cut when they fall between samples, less so with more samples
+            /// let coarse = (square.area() - square.resample(7).area()).abs();
+            /// let fine = (square.area() - square.resample(103).area()).abs();
+            /// assert!(fine < coarse);
+            /// assert!(fine < 0.01);
+            ///
+            /// assert_eq!(square.resample(1).len(), 3);
+            /// let point = Polygon::new(vec![Vec2::ONE, Vec2::ONE]);
+            /// assert_eq!(point.resample(4).coordinates(), &[Vec2::ONE; 4]);
+            /// ```
+            pub fn resample(&self, count: u32) -> Polygon {
+                let Some(&first) = self.coordinates.first() else {
+                    return Polygon::new(Vec::new());
+                };
+                let count = count.max(3);
+                let perimeter = self.perimeter();
+                if perimeter <= 0. {
+                    return Polygon::new(vec![first; count as usize]);
+                }
+
+                let step = perimeter / count as $float;
+                let mut edges = self.edge_pairs();
+                let (mut start, mut end) = edges.next().unwrap_or((first, first));
+                let mut edge_start = 0.;
+                let mut edge_length = start.distance(end);
+                let mut coordinates = Vec::with_capacity(count as usize);
+                for i in 0..count {
+                    let target = i as $float * step;
+                    while target > edge_start + edge_length {
+                        let Some(next) = edges.next() else {
+                            break;
+                        };
+                        edge_start += edge_length;
+                        (start, end) = next;
+                        edge_length = start.distance(end);
+                    }
+                    let t = if edge_length > 0. {
+                        ((target - edge_start) / edge_length).clamp(0., 1.)
+                    } else {
+                        0.
+                    };
+                    coordinates.push(start.lerp(end, t));
+                }
+
+                Polygon::new(coordinates)
+            }
+
+            /// Get a copy of the [`Polygon`] with every edge moved outwards by `distance`
+            ///
+            /// A positive `distance` inflates the [`Polygon`] and a negative `distance` deflates it, regardless of
+            /// the winding order. Adjacent edges are extended until they meet (a miter join). When the miter at
+            /// an outward corner would be more than `miter_limit` times `distance` away from the original
+            #[doc = concat!(" coordinate the corner is cut off instead (a bevel join), so `", stringify!($float), "::INFINITY` always mitres and `1`")]
+            /// always bevels.
+            ///
+            /// When deflating by more than the [`Polygon`] can absorb (such as more than the inradius), edges
+            /// would reverse direction and an empty [`Polygon`] is returned. The same happens when inflating
+            /// a concave [`Polygon`] far enough to close off a notch. A [`Polygon`] without an area also
+            /// returns an empty [`Polygon`].
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            ///
+            /// // mitred corners grow the area by perimeter * distance + 4 * distance^2
+            #[doc = concat!(" let mitred = square.offset(1., ", stringify!($float), "::INFINITY);")]
+            /// assert_eq!(mitred.coordinates(), &[Vec2 { x: -1., y: -1. }, Vec2 { x: 3., y: -1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: -1., y: 3. }]);
+            /// assert_eq!(mitred.area(), 4. + 8. + 4.);
+            ///
+            /// // bevelled corners grow the area by perimeter * distance + 4 * distance^2 / 2
+            /// let bevelled = square.offset(1., 1.);
+            /// assert_eq!(bevelled.len(), 8);
+            /// assert_abs_diff_eq!(bevelled.area(), (4. + 8. + 2.), epsilon = 1e-5);
+            ///
+            /// // the winding order doesn't matter
+            #[doc = concat!(" assert_eq!(square.reversed().offset(1., ", stringify!($float), "::INFINITY).area(), 16.);")]
+            ///
+            /// // deflating too far collapses the square
+            #[doc = concat!(" assert_eq!(square.offset(-0.5, ", stringify!($float), "::INFINITY).area(), 1.);")]
+            #[doc = concat!(" assert!(square.offset(-1.5, ", stringify!($float), "::INFINITY).is_empty());")]
+            ///
+            /// // an L shape with five outward corners and one inward corner
+            /// let l_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 2. },
+            ///     Vec2 { x: 0., y: 2. },
+            /// ]);
+            #[doc = concat!(" assert_abs_diff_eq!(l_shape.offset(0.25, ", stringify!($float), "::INFINITY).area(), 5.25, epsilon = 1e-5);")]
+            #[doc = concat!(" assert_abs_diff_eq!(l_shape.offset(-0.25, ", stringify!($float), "::INFINITY).area(), 1.25, epsilon = 1e-5);")]
+            #[doc = concat!(" assert_abs_diff_eq!(l_shape.reversed().offset(-0.25, ", stringify!($float), "::INFINITY).area(), 1.25, epsilon = 1e-5);")]
+            /// ```
+            pub fn offset(&self, distance: $float, miter_limit: $float) -> Polygon {
+                let mut coordinates = self.coordinates.clone();
+                coordinates.dedup();
+                if coordinates.len() > 1 && coordinates.first() == coordinates.last() {
+                    coordinates.pop();
+                }
+                let length = coordinates.len();
+
+                let orientation = match self.winding() {
+                    Winding::CounterClockwise => 1.,
+                    Winding::Clockwise => -1.,
+                    Winding::Degenerate => return Polygon::new(Vec::new()),
+                };
+                if length < 3 {
+                    return Polygon::new(Vec::new());
+                }
+
+                // The outward normal of the edge starting at each coordinate
+                let normals: Vec<Vec2> = (0..length)
+                    .map(|index| {
+                        let edge = coordinates[(index + 1) % length] - coordinates[index];
+                        -edge.perp().normalize() * orientation
+                    })
+                    .collect();
+
+                // The first and last offset coordinate produced for each original coordinate
+                let mut offset = Vec::with_capacity(length * 2);
+                let mut corners = Vec::with_capacity(length);
+                for index in 0..length {
+                    let coordinate = coordinates[index];
+                    let previous = normals[(index + length - 1) % length];
+                    let next = normals[index];
+
+                    let first = offset.len();
+                    let cosine = previous.dot(next);
+                    let outward = previous.perp_dot(next) * orientation * distance > 0.;
+                    let miter_ratio = (2. / (1. + cosine)).sqrt();
+                    if outward && (miter_ratio > miter_limit || miter_ratio.is_nan()) {
+                        offset.push(coordinate + previous * distance);
+                        offset.push(coordinate + next * distance);
+                    } else {
+                        offset.push(coordinate + (previous + next) * distance / (1. + cosine));
+                    }
+                    corners.push((first, offset.len() - 1));
+                }
+
+                // Any edge that reversed direction means the polygon collapsed
+                for index in 0..length {
+                    let next = (index + 1) % length;
+                    let edge = coordinates[next] - coordinates[index];
+                    let offset_edge = offset[corners[next].0] - offset[corners[index].1];
+                    if edge.dot(offset_edge) <= 0. {
+                        return Polygon::new(Vec::new());
+                    }
+                }
+
+                Polygon::new(offset)
+            }
+
+            /// Checks if the [`Polygon`] is simple, meaning none of its edges cross or touch
+            ///
+            /// See [`Polygon::self_intersections`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let bowtie = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 2. }, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. }]);
+            /// assert!(!bowtie.is_simple());
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// assert!(square.is_simple());
+            /// ```
+            pub fn is_simple(&self) -> bool {
+                self.edge_intersections().is_empty()
+            }
+
+            /// Checks the [`Polygon`] for problems, returning every [`PolygonDefect`] found
+            ///
+            /// Coordinates closer than `epsilon` to the coordinate before them are repeated, coordinates within
+            /// `epsilon` of the line between their neighbors are collinear, and an area no greater than `epsilon`
+            /// counts as no area. These match what [`Polygon::cleaned`] removes. Edges that only meet because
+            /// the repeated coordinates between them have no length are not reported as self-intersections.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// assert_eq!(square.validate(1e-4), Ok(()));
+            ///
+            /// let line = Polygon::new(vec![Vec2::ZERO, Vec2::ONE]);
+            /// assert_eq!(line.validate(1e-4), Err(vec![PolygonDefect::TooFewVertices]));
+            ///
+            /// let repeated = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }]);
+            /// assert_eq!(repeated.validate(1e-4), Err(vec![PolygonDefect::DuplicateConsecutiveVertices(2)]));
+            ///
+            /// let collinear = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }]);
+            /// assert_eq!(collinear.validate(1e-4), Err(vec![PolygonDefect::CollinearVertex(1)]));
+            ///
+            /// let flat = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2 { x: 2., y: 0. }]);
+            /// assert_eq!(flat.validate(1e-4), Err(vec![PolygonDefect::CollinearVertex(1), PolygonDefect::ZeroArea]));
+            ///
+            /// let bowtie = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 2. }]);
+            /// let defects = bowtie.validate(1e-4).unwrap_err();
+            /// assert_eq!(defects.len(), 1);
+            /// match defects[0] {
+            ///     PolygonDefect::SelfIntersection(1, 3, point) => {
+            ///         assert_abs_diff_eq!(point, Vec2 { x: 4. / 3., y: 4. / 3. }, epsilon = 1e-5)
+            ///     }
+            ///     _ => panic!("expected a self intersection"),
+            /// }
+            /// ```
+            pub fn validate(&self, epsilon: $float) -> Result<(), Vec<PolygonDefect>> {
+                let length = self.len();
+                if length < 3 {
+                    return Err(vec![PolygonDefect::TooFewVertices]);
+                }
+
+                let repeated = self.repeated_coordinates(epsilon);
+                let mut defects: Vec<PolygonDefect> = repeated
+                    .iter()
+                    .map(|index| PolygonDefect::DuplicateConsecutiveVertices(*index))
+                    .collect();
+
+                let kept: Vec<usize> = (0..length)
+                    .filter(|index| repeated.binary_search(index).is_err())
+                    .collect();
+                if kept.len() >= 3 {
+                    for (position, index) in kept.iter().enumerate() {
+                        let previous = self.coordinates[kept[(position + kept.len() - 1) % kept.len()]];
+                        let next = self.coordinates[kept[(position + 1) % kept.len()]];
+                        if is_collinear(self.coordinates[*index], previous, next, epsilon) {
+                            defects.push(PolygonDefect::CollinearVertex(*index));
+                        }
+                    }
+                }
+
+                if self.area() <= epsilon {
+                    defects.push(PolygonDefect::ZeroArea);
+                }
+
+                if !self.is_simple() {
+                    // edges with only repeated coordinates between them are adjacent once those are removed
+                    let is_short = |edge: usize| {
+                        self.coordinates[edge % length].distance(self.coordinates[(edge + 1) % length])
+                            < epsilon
+                    };
+                    let is_adjacent =
+                        |a: usize, b: usize| (a + 1..b).all(is_short) || (b + 1..a + length).all(is_short);
+                    defects.extend(
+                        self.edge_intersections()
+                            .into_iter()
+                            .filter(|(a, b, _)| !is_adjacent(*a, *b))
+                            .map(|(a, b, point)| PolygonDefect::SelfIntersection(a, b, point)),
+                    );
+                }
+
+                if defects.is_empty() {
+                    Ok(())
+                } else {
+                    Err(defects)
+                }
+            }
+
+            /// Get every point where two edges of the [`Polygon`] cross or touch
+            ///
+            /// Every pair of non-adjacent edges is checked and reports at most one point. Edges that share a
+            /// coordinate with the next edge are never reported for that shared coordinate, but a coordinate
+            /// touching any non-adjacent edge is reported. Collinear overlapping edges report the point where
+            /// the overlap begins along the earlier edge.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let bowtie = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 2. }, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. }]);
+            /// assert_eq!(bowtie.self_intersections(), vec![Vec2::ONE]);
+            ///
+            /// let u_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 3., y: 0. },
+            ///     Vec2 { x: 3., y: 3. },
+            ///     Vec2 { x: 2., y: 3. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 3. },
+            ///     Vec2 { x: 0., y: 3. },
+            /// ]);
+            /// assert!(u_shape.self_intersections().is_empty());
+            ///
+            /// // the edge from (2, 0) to (1, 0) runs back along the first edge
+            /// let overlapping = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 3., y: 0. },
+            ///     Vec2 { x: 3., y: -1. },
+            ///     Vec2 { x: 2., y: -1. },
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 1., y: 0. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 0., y: 1. },
+            /// ]);
+            /// let intersections = overlapping.self_intersections();
+            /// assert!(!overlapping.is_simple());
+            /// assert!(intersections.contains(&Vec2 { x: 1., y: 0. }));
+            /// assert!(intersections.contains(&Vec2 { x: 2., y: 0. }));
+            /// for point in intersections {
+            ///     assert_eq!(point.y, 0.);
+            /// }
+            /// ```
+            pub fn self_intersections(&self) -> Vec<Vec2> {
+                self.edge_intersections()
+                    .into_iter()
+                    .map(|(_, _, point)| point)
+                    .collect()
+            }
+
+            // Every pair of non-adjacent edges that cross or touch, along with the point where they meet.
+            // Edges are identified by the index of their first coordinate.
+            // This is a brute force O(n^2) search, which a sweep line could replace without changing the results.
+            fn edge_intersections(&self) -> Vec<(usize, usize, Vec2)> {
+                let length = self.len();
+                let mut intersections = Vec::new();
+                for a in 0..length {
+                    for b in a + 2..length {
+                        // The first and last edges are adjacent
+                        if a == 0 && b == length - 1 {
+                            continue;
+                        }
+                        let (a_start, a_end) = (self.coordinates[a], self.coordinates[(a + 1) % length]);
+                        let (b_start, b_end) = (self.coordinates[b], self.coordinates[(b + 1) % length]);
+                        if let Some(point) = segment_intersection(a_start, a_end, b_start, b_end) {
+                            intersections.push((a, b, point));
+                        }
+                    }
+                }
+                intersections
+            }
+
+            /// Get the part of the [`Polygon`] inside of the convex `clip` [`Polygon`], using Sutherland-Hodgman clipping
+            ///
+            /// The result follows the winding order of the [`Polygon`], and is an unchanged copy when the
+            /// [`Polygon`] is entirely inside of `clip`. Returns `None` when `clip` is not convex (see
+            /// [`Polygon::is_convex`]), or when nothing is left after clipping. Clipping a concave [`Polygon`]
+            /// can leave separate pieces connected by zero-width edges along the boundary of `clip`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // a four pointed star, made of a 2x2 square with a spike on each side
+            /// let star = Polygon::new(vec![
+            ///     Vec2 { x: 0., y: 3. },
+            ///     Vec2 { x: -1., y: 1. },
+            ///     Vec2 { x: -3., y: 0. },
+            ///     Vec2 { x: -1., y: -1. },
+            ///     Vec2 { x: 0., y: -3. },
+            ///     Vec2 { x: 1., y: -1. },
+            ///     Vec2 { x: 3., y: 0. },
+            ///     Vec2 { x: 1., y: 1. },
+            /// ]);
+            #[doc = concat!(" let square = |half_size: ", stringify!($float), ", center: Vec2| {")]
+            ///     Polygon::new(vec![
+            ///         center + Vec2 { x: -half_size, y: -half_size },
+            ///         center + Vec2 { x: half_size, y: -half_size },
+            ///         center + Vec2 { x: half_size, y: half_size },
+            ///         center + Vec2 { x: -half_size, y: half_size },
+            ///     ])
+            /// };
+            ///
+            /// // the middle square plus the inner half of each spike
+            /// let clipped = star.clip_to_convex(&square(2., Vec2::ZERO)).unwrap();
+            /// assert_eq!(clipped.area(), 4. + 4. * 1.5);
+            /// assert_eq!(clipped.winding(), star.winding());
+            ///
+            /// // entirely inside
+            /// let clipped = star.clip_to_convex(&square(5., Vec2::ZERO)).unwrap();
+            /// assert_eq!(clipped.coordinates(), star.coordinates());
+            ///
+            /// // entirely outside
+            /// assert!(star.clip_to_convex(&square(1., Vec2 { x: 10., y: 10. })).is_none());
+            ///
+            /// // clipping to a concave polygon isn't supported
+            /// assert!(square(1., Vec2::ZERO).clip_to_convex(&star).is_none());
+            /// ```
+            pub fn clip_to_convex(&self, clip: &Polygon) -> Option<Polygon> {
+                if !clip.is_convex() {
+                    return None;
+                }
+                let orientation = if clip.is_clockwise() { -1. } else { 1. };
+
+                let mut output = self.coordinates.clone();
+                for (clip_start, clip_end) in clip.edge_pairs() {
+                    if clip_start == clip_end {
+                        continue;
+                    }
+                    let input = std::mem::take(&mut output);
+                    clip_against_edge(&input, true, clip_start, clip_end, orientation, &mut output);
+                    if output.is_empty() {
+                        return None;
+                    }
+                }
+
+                if output.len() < 3 {
+                    return None;
+                }
+                Some(Polygon::new(output))
+            }
+
+            /// Projects every coordinate of the [`Polygon`] onto `axis`, returning the `(min, max)` of the projections
+            ///
+            /// The projections are dot products with `axis`, so they are only distances along it when `axis` is
+            #[doc = concat!(" normalized. An empty [`Polygon`] projects to `(", stringify!($float), "::INFINITY, ", stringify!($float), "::NEG_INFINITY)`.")]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 1., y: 3. }]);
+            ///
+            /// assert_eq!(triangle.project_onto_axis(Vec2::X), (0., 4.));
+            /// assert_eq!(triangle.project_onto_axis(Vec2::Y), (0., 3.));
+            /// assert_eq!(triangle.project_onto_axis(Vec2 { x: -1., y: 1. }), (-4., 2.));
+            /// ```
+            pub fn project_onto_axis(&self, axis: Vec2) -> ($float, $float) {
+                self.coordinates
+                    .iter()
+                    .map(|coordinate| coordinate.dot(axis))
+                    .fold(
+                        ($float::INFINITY, $float::NEG_INFINITY),
+                        |(min, max), projection| (min.min(projection), max.max(projection)),
+                    )
+            }
+
+            /// Get the Minkowski sum of the [`Polygon`] and `other`, every point of one added to every point of the other
+            ///
+            /// Both polygons must be convex (see [`Polygon::is_convex`]), otherwise `None` is returned. The sum is
+            /// convex, wound counter-clockwise, and has at most as many coordinates as both inputs together since
+            /// collinear coordinates are merged.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            #[doc = concat!(" let square = |min: Vec2, size: ", stringify!($float), "| {")]
+            ///     Polygon::new(vec![min, min + Vec2::X * size, min + Vec2::ONE * size, min + Vec2::Y * size])
+            /// };
+            ///
+            /// let sum = square(Vec2::ZERO, 1.).minkowski_sum(&square(Vec2::ONE, 2.)).unwrap();
+            /// assert_eq!(sum.coordinates(), square(Vec2::ONE, 3.).coordinates());
+            ///
+            /// // a clockwise triangle
+            /// let triangle = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 1. }, Vec2 { x: 1., y: 0. }]);
+            /// let sum = square(Vec2::ZERO, 2.).minkowski_sum(&triangle).unwrap();
+            /// assert_eq!(
+            ///     sum.coordinates(),
+            ///     &[
+            ///         Vec2::ZERO,
+            ///         Vec2 { x: 3., y: 0. },
+            ///         Vec2 { x: 3., y: 2. },
+            ///         Vec2 { x: 2., y: 3. },
+            ///         Vec2 { x: 0., y: 3. },
+            ///     ]
+            /// );
+            ///
+            /// // the support of the sum in any direction is the sum of the supports
+            #[doc = concat!(" let pentagon: Polygon = (0..5).map(|i| Vec2::from_angle(i as ", stringify!($float), " * std::", stringify!($float), "::consts::TAU / 5.)).collect();")]
+            /// let sum = pentagon.minkowski_sum(&triangle).unwrap();
+            /// assert!(sum.len() <= pentagon.len() + triangle.len());
+            /// for i in 0..12 {
+            #[doc = concat!("     let direction = Vec2::from_angle(i as ", stringify!($float), " * 0.5);")]
+            ///     let support = |polygon: &Polygon| polygon.project_onto_axis(direction).1;
+            ///     assert_abs_diff_eq!(support(&sum), (support(&pentagon) + support(&triangle)), epsilon = 1e-5);
+            /// }
+            ///
+            /// // concave polygons aren't supported
+            /// let l_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 2. },
+            ///     Vec2 { x: 0., y: 2. },
+            /// ]);
+            /// assert!(l_shape.minkowski_sum(&triangle).is_none());
+            /// ```
+            pub fn minkowski_sum(&self, other: &Polygon) -> Option<Polygon> {
+                if !self.is_convex() || !other.is_convex() {
+                    return None;
+                }
+
+                let a = counter_clockwise_ring(self);
+                let b = counter_clockwise_ring(other);
+                let extent = a
+                    .iter()
+                    .chain(b.iter())
+                    .map(|point| point.abs().max_element())
+                    .fold(0., $float::max);
+                let epsilon = (extent + 1.) * 1e-6;
+                let a = lowest_first(remove_collinear(a, epsilon));
+                let b = lowest_first(remove_collinear(b, epsilon));
+
+                // merge the edges of both rings by angle, both start at their lowest coordinate so their edges
+                // are already sorted
+                let (mut i, mut j) = (0, 0);
+                let mut coordinates = Vec::with_capacity(a.len() + b.len());
+                while i < a.len() || j < b.len() {
+                    coordinates.push(a[i % a.len()] + b[j % b.len()]);
+                    let a_edge = a[(i + 1) % a.len()] - a[i % a.len()];
+                    let b_edge = b[(j + 1) % b.len()] - b[j % b.len()];
+                    let cross = a_edge.perp_dot(b_edge);
+                    let advance_a = i < a.len() && (j == b.len() || cross >= 0.);
+                    let advance_b = j < b.len() && (i == a.len() || cross <= 0.);
+                    if advance_a {
+                        i += 1;
+                    }
+                    if advance_b {
+                        j += 1;
+                    }
+                }
+
+                Some(Polygon::new(remove_collinear(coordinates, epsilon)))
+            }
+
+            /// Get the union of the [`Polygon`] and `other`
+            ///
+            /// Both polygons must be simple (see [`Polygon::is_simple`]). Every edge is split where it meets
+            /// the other [`Polygon`], the pieces outside of the other [`Polygon`] are kept, and the kept pieces
+            /// are traced into closed rings. Edges shared by both polygons are kept once when both polygons are
+            /// on the same side of them, and dropped when they are on opposite sides.
+            ///
+            /// Every ring is counter-clockwise, except for holes enclosed by the union, which are returned as
+            /// clockwise rings. When the polygons don't touch, both are returned unchanged, and when one
+            /// [`Polygon`] is inside of the other only the outer [`Polygon`] is returned unchanged.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = |min: Vec2, max: Vec2| Polygon::new(vec![min, Vec2 { x: max.x, y: min.y }, max, Vec2 { x: min.x, y: max.y }]);
+            ///
+            /// // a plus shape
+            /// let horizontal = rect(Vec2 { x: -3., y: -1. }, Vec2 { x: 3., y: 1. });
+            /// let vertical = rect(Vec2 { x: -1., y: -3. }, Vec2 { x: 1., y: 3. });
+            /// let union = horizontal.union(&vertical);
+            ///
+            /// assert_eq!(union.len(), 1);
+            /// assert_eq!(union[0].len(), 12);
+            /// assert_eq!(union[0].area(), 20.);
+            /// assert_eq!(union[0].winding(), Winding::CounterClockwise);
+            ///
+            /// // nested
+            /// let inner = rect(Vec2::ZERO, Vec2 { x: 0.5, y: 0.5 });
+            /// let union = inner.union(&horizontal);
+            ///
+            /// assert_eq!(union.len(), 1);
+            /// assert_eq!(union[0].coordinates(), horizontal.coordinates());
+            ///
+            /// // disjoint
+            /// let far = rect(Vec2 { x: 10., y: 10. }, Vec2 { x: 11., y: 11. });
+            /// let union = horizontal.union(&far);
+            ///
+            /// assert_eq!(union.len(), 2);
+            /// assert_eq!(union[0].coordinates(), horizontal.coordinates());
+            /// assert_eq!(union[1].coordinates(), far.coordinates());
+            ///
+            /// // sharing an edge
+            /// let left = rect(Vec2::ZERO, Vec2 { x: 1., y: 2. });
+            /// let right = rect(Vec2 { x: 1., y: 0. }, Vec2 { x: 2., y: 1. });
+            /// let union = left.union(&right);
+            ///
+            /// assert_eq!(union.len(), 1);
+            /// assert_eq!(union[0].len(), 6);
+            /// assert_eq!(union[0].area(), 3.);
+            /// ```
+            pub fn union(&self, other: &Polygon) -> Vec<Polygon> {
+                boolean_operation(self, other, BooleanOperation::Union)
+            }
+
+            /// Get the parts of the [`Polygon`] not covered by `other`
+            ///
+            /// Uses the same edge splitting and tracing as [`Polygon::union`], so both polygons must be simple.
+            /// The result can be several pieces when `other` cuts the [`Polygon`] apart. Every piece is
+            /// counter-clockwise. When `other` is strictly inside of the [`Polygon`] the result would have a
+            /// hole, which is returned as a clockwise ring after the unchanged [`Polygon`]. When the polygons
+            /// don't touch the [`Polygon`] is returned unchanged, and when the [`Polygon`] is inside of `other`
+            /// nothing is returned.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = |min: Vec2, max: Vec2| Polygon::new(vec![min, Vec2 { x: max.x, y: min.y }, max, Vec2 { x: min.x, y: max.y }]);
+            ///
+            /// // an L shape
+            /// let square = rect(Vec2::ZERO, Vec2 { x: 2., y: 2. });
+            /// let difference = square.difference(&rect(Vec2::ONE, Vec2 { x: 3., y: 3. }));
+            ///
+            /// assert_eq!(difference.len(), 1);
+            /// assert_eq!(difference[0].len(), 6);
+            /// assert_eq!(difference[0].area(), 3.);
+            ///
+            /// // cut in two
+            /// let bar = rect(Vec2::ZERO, Vec2 { x: 3., y: 1. });
+            /// let difference = bar.difference(&rect(Vec2 { x: 1., y: -1. }, Vec2 { x: 2., y: 2. }));
+            ///
+            /// assert_eq!(difference.len(), 2);
+            /// for piece in &difference {
+            ///     assert_eq!(piece.area(), 1.);
+            ///     assert_eq!(piece.winding(), Winding::CounterClockwise);
+            /// }
+            ///
+            /// // not overlapping
+            /// let difference = square.difference(&rect(Vec2 { x: 5., y: 5. }, Vec2 { x: 6., y: 6. }));
+            ///
+            /// assert_eq!(difference.len(), 1);
+            /// assert_eq!(difference[0].coordinates(), square.coordinates());
+            ///
+            /// // a hole
+            /// let difference = square.difference(&rect(Vec2 { x: 0.5, y: 0.5 }, Vec2 { x: 1.5, y: 1.5 }));
+            ///
+            /// assert_eq!(difference.len(), 2);
+            /// assert_eq!(difference[0].coordinates(), square.coordinates());
+            /// assert_eq!(difference[1].winding(), Winding::Clockwise);
+            ///
+            /// // nothing left
+            /// assert!(square.difference(&rect(Vec2 { x: -1., y: -1. }, Vec2 { x: 3., y: 3. })).is_empty());
+            /// ```
+            pub fn difference(&self, other: &Polygon) -> Vec<Polygon> {
+                boolean_operation(self, other, BooleanOperation::Difference)
+            }
+
+            /// Splits the [`Polygon`] along `line`, which is treated as infinite in both directions
+            ///
+            /// The [`Polygon`] must be simple (see [`Polygon::is_simple`]). When `line` passes through its
+            /// interior, the pieces on each side are returned, wound counter-clockwise. A concave [`Polygon`]
+            /// may be cut into more than two pieces. Left is the side `line.direction().perp()` points to.
+            /// Coordinates on `line` belong to the pieces on both sides of it. When `line` misses the
+            /// [`Polygon`], only touches it, or has no length, a copy of the [`Polygon`] is returned unsplit.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::{abs_diff_eq, assert_abs_diff_eq};
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            ///
+            /// // through the middle
+            /// let SplitResult::Split { left, right } = square.split_by_line(&Line::new(Vec2 { x: 1., y: 0. }, Vec2::ONE)) else {
+            ///     panic!("expected a split");
+            /// };
+            /// assert_eq!((left.len(), right.len()), (1, 1));
+            /// assert_eq!(left[0].area(), 2.);
+            /// assert_eq!(right[0].area(), 2.);
+            /// assert!(left[0].coordinates().iter().all(|coordinate| coordinate.x <= 1.));
+            ///
+            /// // through two opposite coordinates
+            /// let SplitResult::Split { left, right } = square.split_by_line(&Line::new(Vec2::ZERO, Vec2::ONE)) else {
+            ///     panic!("expected a split");
+            /// };
+            /// assert_eq!(left[0].len(), 3);
+            /// assert_eq!(right[0].len(), 3);
+            /// assert_eq!(left[0].area(), 2.);
+            ///
+            /// // missing, touching a coordinate, and along an edge
+            /// for line in [
+            ///     Line::new(Vec2 { x: 3., y: 0. }, Vec2 { x: 3., y: 1. }),
+            ///     Line::new(Vec2 { x: 1., y: 3. }, Vec2 { x: 3., y: 1. }),
+            ///     Line::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }),
+            /// ] {
+            ///     let SplitResult::Unsplit(unsplit) = square.split_by_line(&line) else {
+            ///         panic!("expected no split");
+            ///     };
+            ///     assert_eq!(unsplit.coordinates(), square.coordinates());
+            /// }
+            ///
+            /// // a U shape cut across both prongs
+            /// let u_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 3., y: 0. },
+            ///     Vec2 { x: 3., y: 3. },
+            ///     Vec2 { x: 2., y: 3. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 3. },
+            ///     Vec2 { x: 0., y: 3. },
+            /// ]);
+            /// let SplitResult::Split { left, right } = u_shape.split_by_line(&Line::new(Vec2 { x: 0., y: 2. }, Vec2 { x: 1., y: 2. })) else {
+            ///     panic!("expected a split");
+            /// };
+            /// assert_eq!(left.len() + right.len(), 3);
+            /// assert_eq!(left.len(), 2);
+            /// assert!(left.iter().all(|piece| abs_diff_eq!(piece.area(), 1., epsilon = 1e-5)));
+            /// assert_abs_diff_eq!(right[0].area(), 5., epsilon = 1e-5);
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn split_by_line(&self, line: &Line) -> SplitResult {
+                let unsplit = || SplitResult::Unsplit(self.coordinates.iter().copied().collect());
+                let direction = line.direction().normalize_or_zero();
+                let Some(center) = self.centroid() else {
+                    return unsplit();
+                };
+                if direction == Vec2::ZERO {
+                    return unsplit();
+                }
+
+                let extent = self
+                    .coordinates
+                    .iter()
+                    .map(|point| point.abs().max_element())
+                    .fold(0., $float::max);
+                let epsilon = (extent + 1.) * 1e-6;
+                let side = |point: Vec2| direction.perp_dot(point - line.origin());
+                let has_left = self
+                    .coordinates
+                    .iter()
+                    .any(|coordinate| side(*coordinate) > epsilon);
+                let has_right = self
+                    .coordinates
+                    .iter()
+                    .any(|coordinate| side(*coordinate) < -epsilon);
+                if !has_left || !has_right {
+                    return unsplit();
+                }
+
+                // a box on one side of the line, large enough to cover everything on that side
+                let radius = self
+                    .coordinates
+                    .iter()
+                    .map(|coordinate| coordinate.distance(center))
+                    .fold(0., $float::max);
+                let foot = line.origin() + direction * direction.dot(center - line.origin());
+                let size = radius + foot.distance(center) + 1.;
+                let half_plane = |normal: Vec2| {
+                    let along = normal.perp() * size;
+                    Polygon::new(vec![
+                        foot - along,
+                        foot - along + normal * size,
+                        foot + along + normal * size,
+                        foot + along,
+                    ])
+                };
+                let left_normal = direction.perp();
+
+                SplitResult::Split {
+                    left: self.difference(&half_plane(-left_normal)),
+                    right: self.difference(&half_plane(left_normal)),
+                }
+            }
+
+            /// Get the closest point to `point` on the boundary of the [`Polygon`], or `None` if it has no coordinates
+            ///
+            /// When several edges are equally close, such as at a coordinate shared by two edges, the earliest
+            /// edge is used.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // a U shape with a pocket between x = 1 and x = 2
+            /// let polygon = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 3., y: 0. },
+            ///     Vec2 { x: 3., y: 3. },
+            ///     Vec2 { x: 2., y: 3. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 3. },
+            ///     Vec2 { x: 0., y: 3. },
+            /// ]);
+            ///
+            /// // outside near an edge
+            /// assert_eq!(polygon.closest_boundary_point(Vec2 { x: 1.5, y: -2. }), Some(Vec2 { x: 1.5, y: 0. }));
+            /// // outside near a coordinate
+            /// assert_eq!(polygon.closest_boundary_point(Vec2 { x: 4., y: 4. }), Some(Vec2 { x: 3., y: 3. }));
+            /// // in the pocket
+            /// assert_eq!(polygon.closest_boundary_point(Vec2 { x: 1.5, y: 1.2 }), Some(Vec2 { x: 1.5, y: 1. }));
+            /// // inside, closest to the pocket
+            /// assert_eq!(polygon.closest_boundary_point(Vec2 { x: 1.5, y: 0.8 }), Some(Vec2 { x: 1.5, y: 1. }));
+            ///
+            /// assert_eq!(Polygon::new(Vec::new()).closest_boundary_point(Vec2::ZERO), None);
+            /// ```
+            pub fn closest_boundary_point(&self, point: Vec2) -> Option<Vec2> {
+                let mut closest = None;
+                let mut closest_distance = $float::INFINITY;
+                for (start, end) in self.edge_pairs() {
+                    let candidate = closest_point_on_segment(point, start, end);
+                    let distance = candidate.distance_squared(point);
+                    if distance < closest_distance {
+                        closest = Some(candidate);
+                        closest_distance = distance;
+                    }
+                }
+                closest
+            }
+
+            /// Get the signed distance from `point` to the boundary of the [`Polygon`]
+            ///
+            /// The distance is negative inside of the [`Polygon`] (see [`Polygon::contains_point`]) and positive
+            /// outside. A [`Polygon`] with no coordinates is infinitely far away.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 4. }, Vec2 { x: 0., y: 4. }]);
+            ///
+            /// assert_eq!(square.signed_distance(Vec2 { x: 1., y: 2. }), -1.);
+            /// assert_eq!(square.signed_distance(Vec2 { x: 6., y: 2. }), 2.);
+            /// assert_eq!(square.signed_distance(Vec2 { x: 7., y: 8. }), 5.);
+            /// assert_eq!(square.signed_distance(Vec2 { x: 4., y: 2. }), 0.);
+            /// ```
+            pub fn signed_distance(&self, point: Vec2) -> $float {
+                let Some(closest) = self.closest_boundary_point(point) else {
+                    return $float::INFINITY;
+                };
+                let distance = closest.distance(point);
+                if self.contains_point(point) {
+                    -distance
+                } else {
+                    distance
+                }
+            }
+
+            /// Checks if the [`Polygon`] overlaps a [`Circle`]
+            ///
+            /// They overlap when the center of the [`Circle`] is inside of the [`Polygon`], or any edge passes
+            /// within the radius of the center, which also covers a [`Polygon`] entirely inside of the [`Circle`].
+            /// Touching counts as overlapping, and the [`Polygon`] may be concave.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // a U shape with a pocket between x = 1 and x = 3
+            /// let u_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 4., y: 0. },
+            ///     Vec2 { x: 4., y: 4. },
+            ///     Vec2 { x: 3., y: 4. },
+            ///     Vec2 { x: 3., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 4. },
+            ///     Vec2 { x: 0., y: 4. },
+            /// ]);
+            ///
+            /// // nestled in the pocket without touching
+            /// assert!(!u_shape.intersects_circle(&Circle::new(Vec2 { x: 2., y: 2.5 }, 0.9)));
+            /// // overlapping the bottom of the pocket
+            /// assert!(u_shape.intersects_circle(&Circle::new(Vec2 { x: 2., y: 1.5 }, 0.6)));
+            /// // containing the whole polygon
+            /// assert!(u_shape.intersects_circle(&Circle::new(Vec2 { x: 2., y: 2. }, 10.)));
+            /// // center inside of the polygon
+            /// assert!(u_shape.intersects_circle(&Circle::new(Vec2 { x: 0.5, y: 2. }, 0.1)));
+            /// // outside
+            /// assert!(!u_shape.intersects_circle(&Circle::new(Vec2 { x: 6., y: 2. }, 1.)));
+            /// ```
+            #[cfg(feature = "circle")]
+            pub fn intersects_circle(&self, circle: &Circle) -> bool {
+                self.signed_distance(circle.center()) <= circle.radius()
+            }
+
+            /// Checks if a `point` is inside of the [`Polygon`] using the even-odd rule
+            ///
+            /// A ray is cast from the `point` and the [`Polygon`] contains the `point` when the ray crosses
+            /// its edges an odd number of times. This works for concave polygons, and for self-intersecting
+            /// polygons regions that are overlapped an even number of times are outside.
+            /// Points exactly on an edge or coordinate are considered inside.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // a U shape with a pocket between x = 1 and x = 2
+            /// let polygon = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 3., y: 0. },
+            ///     Vec2 { x: 3., y: 3. },
+            ///     Vec2 { x: 2., y: 3. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 3. },
+            ///     Vec2 { x: 0., y: 3. },
+            /// ]);
+            ///
+            /// assert!(polygon.contains_point(Vec2 { x: 0.5, y: 2. }));
+            /// assert!(polygon.contains_point(Vec2 { x: 1.5, y: 0.5 }));
+            /// // in the pocket
+            /// assert!(!polygon.contains_point(Vec2 { x: 1.5, y: 2. }));
+            /// // on a coordinate, and a ray through coordinates
+            /// assert!(polygon.contains_point(Vec2 { x: 2., y: 1. }));
+            /// assert!(polygon.contains_point(Vec2 { x: 0.5, y: 1. }));
+            /// assert!(!polygon.contains_point(Vec2 { x: -1., y: 1. }));
+            /// // collinear with the horizontal edge at the bottom of the pocket
+            /// assert!(polygon.contains_point(Vec2 { x: 1.5, y: 1. }));
+            /// assert!(!polygon.contains_point(Vec2 { x: 4., y: 1. }));
+            /// assert!(!polygon.contains_point(Vec2 { x: 4., y: 3. }));
+            /// // on the boundary
+            /// assert!(polygon.contains_point(Vec2 { x: 3., y: 2. }));
+            /// ```
+            pub fn contains_point(&self, point: Vec2) -> bool {
+                if self
+                    .edge_pairs()
+                    .any(|(start, end)| Self::is_point_on_segment(point, start, end))
+                {
+                    return true;
+                }
+
+                // Each edge is treated as half-open in y so that a ray through a coordinate is only counted
+                // once, and horizontal edges are never counted
+                let mut inside = false;
+                for (start, end) in self.edge_pairs() {
+                    if (start.y > point.y) != (end.y > point.y) {
+                        let x = start.x + (point.y - start.y) / (end.y - start.y) * (end.x - start.x);
+                        if point.x < x {
+                            inside = !inside;
+                        }
+                    }
+                }
+                inside
+            }
+
+            /// Get the winding number of the [`Polygon`] around a `point`
+            ///
+            /// The winding number counts how many times the edges of the [`Polygon`] travel around the `point`,
+            /// positive for counter-clockwise and negative for clockwise. Points outside have a winding number of `0`.
+            /// The winding number of points exactly on an edge depends on the direction of the edge.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// assert_eq!(square.winding_number(Vec2::ONE), 1);
+            /// assert_eq!(square.winding_number(Vec2 { x: 3., y: 1. }), 0);
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 2., y: 0. }]);
+            /// assert_eq!(square.winding_number(Vec2::ONE), -1);
+            ///
+            /// // a pentagram travels around its center twice
+            /// let pentagram: Polygon = (0..5)
+            #[doc = concat!("     .map(|i| Vec2::from_angle(std::", stringify!($float), "::consts::FRAC_PI_2 + i as ", stringify!($float), " * std::", stringify!($float), "::consts::TAU * 0.4))")]
+            ///     .collect();
+            /// assert_eq!(pentagram.winding_number(Vec2::ZERO), 2);
+            /// assert_eq!(pentagram.winding_number(Vec2 { x: 0., y: 0.8 }), 1);
+            /// ```
+            pub fn winding_number(&self, point: Vec2) -> i32 {
+                let mut winding_number = 0;
+                for (start, end) in self.edge_pairs() {
+                    let side = (end - start).perp_dot(point - start);
+                    if start.y <= point.y {
+                        if end.y > point.y && side > 0. {
+                            winding_number += 1;
+                        }
+                    } else if end.y <= point.y && side < 0. {
+                        winding_number -= 1;
+                    }
+                }
+                winding_number
+            }
+
+            /// Checks if a `point` is inside of the [`Polygon`] using the nonzero rule
+            ///
+            /// The [`Polygon`] contains the `point` when its [`Polygon::winding_number`] is not `0`.
+            /// Unlike the even-odd rule used by [`Polygon::contains_point`], regions of a self-intersecting
+            /// [`Polygon`] that are overlapped multiple times in the same direction are inside.
+            /// Points exactly on an edge or coordinate are considered inside.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let pentagram: Polygon = (0..5)
+            #[doc = concat!("     .map(|i| Vec2::from_angle(std::", stringify!($float), "::consts::FRAC_PI_2 + i as ", stringify!($float), " * std::", stringify!($float), "::consts::TAU * 0.4))")]
+            ///     .collect();
+            ///
+            /// // the two rules disagree about the inner pentagon
+            /// assert!(pentagram.contains_point_winding(Vec2::ZERO));
+            /// assert!(!pentagram.contains_point(Vec2::ZERO));
+            ///
+            /// // and agree about the points of the star
+            /// assert!(pentagram.contains_point_winding(Vec2 { x: 0., y: 0.8 }));
+            /// assert!(pentagram.contains_point(Vec2 { x: 0., y: 0.8 }));
+            /// assert!(!pentagram.contains_point_winding(Vec2 { x: 0., y: 1.1 }));
+            /// ```
+            pub fn contains_point_winding(&self, point: Vec2) -> bool {
+                self.edge_pairs()
+                    .any(|(start, end)| Self::is_point_on_segment(point, start, end))
+                    || self.winding_number(point) != 0
+            }
+
+            pub(crate) fn is_point_on_segment(point: Vec2, start: Vec2, end: Vec2) -> bool {
+                let direction = end - start;
+                let offset = point - start;
+                if direction.perp_dot(offset) != 0. {
+                    return false;
+                }
+                let projection = direction.dot(offset);
+                projection >= 0. && projection <= direction.length_squared()
+            }
+        }
+
+        // ##########
+        // Sampling
+        // ##########
+        #[cfg(feature = "rand")]
+        impl Polygon {
+            /// Creates a [`PolygonSampler`] for repeatedly picking uniformly random points inside of the [`Polygon`]
+            ///
+            /// The [`Polygon`] must be simple (see [`Polygon::is_simple`]) and may be concave. It is triangulated
+            /// once here, so prefer this over [`Polygon::sample_interior`] when sampling many points. Returns
+            /// `None` if the [`Polygon`] has no area.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            /// use rand::SeedableRng;
+            ///
+            /// // an L shape made of three unit squares, two of them left of x = 1
+            /// let l_shape = Polygon::new(vec![
+            ///     Vec2::ZERO,
+            ///     Vec2 { x: 2., y: 0. },
+            ///     Vec2 { x: 2., y: 1. },
+            ///     Vec2 { x: 1., y: 1. },
+            ///     Vec2 { x: 1., y: 2. },
+            ///     Vec2 { x: 0., y: 2. },
+            /// ]);
+            /// let sampler = l_shape.sampler().unwrap();
+            /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+            ///
+            /// let samples: Vec<Vec2> = (0..3000).map(|_| sampler.sample(&mut rng)).collect();
+            /// assert!(samples.iter().all(|sample| l_shape.contains_point(*sample)));
+            ///
+            #[doc = concat!(" let left = samples.iter().filter(|sample| sample.x < 1.).count() as ", stringify!($float), " / samples.len() as ", stringify!($float), ";")]
+            /// assert_abs_diff_eq!(left, 2. / 3., epsilon = 0.05);
+            ///
+            /// assert!(Polygon::new(vec![Vec2::ZERO, Vec2::ONE]).sampler().is_none());
+            /// ```
+            pub fn sampler(&self) -> Option<PolygonSampler> {
+                let (ring, indices) = triangulate_polygon(self);
+
+                let mut triangles = Vec::new();
+                let mut cumulative_areas = Vec::new();
+                let mut total_area = 0.;
+                for [a, b, c] in indices {
+                    let triangle = [ring[a], ring[b], ring[c]];
+                    total_area += (triangle[1] - triangle[0])
+                        .perp_dot(triangle[2] - triangle[0])
+                        .abs()
+                        * 0.5;
+                    triangles.push(triangle);
+                    cumulative_areas.push(total_area);
+                }
+
+                if total_area > 0. {
+                    Some(PolygonSampler {
+                        triangles,
+                        cumulative_areas,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Picks a uniformly random point inside of the [`Polygon`], or `None` if it has no area
+            ///
+            /// This triangulates the [`Polygon`] on every call, use [`Polygon::sampler`] to sample repeatedly.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use rand::SeedableRng;
+            ///
+            /// let triangle = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 4. }]);
+            /// let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+            ///
+            /// for _ in 0..100 {
+            ///     assert!(triangle.contains_point(triangle.sample_interior(&mut rng).unwrap()));
+            /// }
+            /// assert!(Polygon::new(Vec::new()).sample_interior(&mut rng).is_none());
+            /// ```
+            pub fn sample_interior<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<Vec2> {
+                self.sampler().map(|sampler| sampler.sample(rng))
+            }
+        }
+
+        /// Picks uniformly random points inside of a triangulated [`Polygon`], see [`Polygon::sampler`]
+        #[cfg(feature = "rand")]
+        #[derive(Debug, Clone)]
+        pub struct $polygon_sampler {
+            triangles: Vec<[Vec2; 3]>,
+            cumulative_areas: Vec<$float>,
+        }
+
+        #[cfg(feature = "rand")]
+        impl PolygonSampler {
+            /// Picks a uniformly random point inside of the sampled [`Polygon`]
+            ///
+            /// A triangle is chosen weighted by its area, then a point is chosen uniformly inside of it.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            /// use rand::SeedableRng;
+            ///
+            /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// let sampler = square.sampler().unwrap();
+            /// let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+            ///
+            /// let mean = (0..4000).map(|_| sampler.sample(&mut rng)).sum::<Vec2>() / 4000.;
+            /// assert_abs_diff_eq!(mean, Vec2::ONE, epsilon = 0.05);
+            /// ```
+            pub fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+                let total_area = self.cumulative_areas.last().copied().unwrap_or(0.);
+                let target = rng.gen::<$float>() * total_area;
+                let index = self
+                    .cumulative_areas
+                    .partition_point(|area| *area <= target)
+                    .min(self.triangles.len() - 1);
+
+                let [a, b, c] = self.triangles[index];
+                let (mut u, mut v) = (rng.gen::<$float>(), rng.gen::<$float>());
+                if u + v > 1. {
+                    (u, v) = (1. - u, 1. - v);
+                }
+                a + (b - a) * u + (c - a) * v
+            }
+        }
+
+        // ##########
+        // Helpers
+        // ##########
+        // The closest point to `point` on the segment from `start` to `end`
+        fn closest_point_on_segment(point: Vec2, start: Vec2, end: Vec2) -> Vec2 {
+            let direction = end - start;
+            let length_squared = direction.length_squared();
+            if length_squared == 0. {
+                return start;
+            }
+            let t = ((point - start).dot(direction) / length_squared).clamp(0., 1.);
+            start + direction * t
+        }
+
+        fn distance_to_segment(point: Vec2, start: Vec2, end: Vec2) -> $float {
+            point.distance(closest_point_on_segment(point, start, end))
+        }
+
+        // The first point where the segments `a_start -> a_end` and `b_start -> b_end` meet, along `a`
+        fn segment_intersection(a_start: Vec2, a_end: Vec2, b_start: Vec2, b_end: Vec2) -> Option<Vec2> {
+            let a = a_end - a_start;
+            let b = b_end - b_start;
+            let offset = b_start - a_start;
+            let denominator = a.perp_dot(b);
+
+            if denominator == 0. {
+                // Parallel segments only meet when they are collinear
+                if offset.perp_dot(a) != 0. || offset.perp_dot(b) != 0. {
+                    return None;
+                }
+                let length_squared = a.length_squared();
+                if length_squared == 0. {
+                    return (closest_point_on_segment(a_start, b_start, b_end) == a_start)
+                        .then_some(a_start);
+                }
+                let t_start = offset.dot(a) / length_squared;
+                let t_end = t_start + b.dot(a) / length_squared;
+                let (t_min, t_max) = (t_start.min(t_end), t_start.max(t_end));
+                if t_max < 0. || t_min > 1. {
+                    return None;
+                }
+                return Some(a_start + a * t_min.max(0.));
+            }
+
+            let t = offset.perp_dot(b) / denominator;
+            let u = offset.perp_dot(a) / denominator;
+            if (0. ..=1.).contains(&t) && (0. ..=1.).contains(&u) {
+                Some(a_start + a * t)
+            } else {
+                None
+            }
+        }
+
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum BooleanOperation {
+            Union,
+            Difference,
+        }
+
+        // Where the midpoint of a split edge lies relative to the other polygon
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum EdgeLocation {
+            Inside,
+            Outside,
+            SharedSameDirection,
+            SharedOppositeDirection,
+        }
+
+        // Splits every edge of both polygons where they meet, keeps the pieces selected by the `operation`,
+        // and traces the kept pieces into rings
+        fn boolean_operation(
+            subject: &Polygon,
+            other: &Polygon,
+            operation: BooleanOperation,
+        ) -> Vec<Polygon> {
+            let copy = |polygon: &Polygon| -> Polygon { polygon.coordinates.iter().copied().collect() };
+            let subject_ring = counter_clockwise_ring(subject);
+            let other_ring = counter_clockwise_ring(other);
+            match (operation, subject_ring.is_empty(), other_ring.is_empty()) {
+                (_, true, true) => return Vec::new(),
+                (BooleanOperation::Union, true, false) => return vec![copy(other)],
+                (BooleanOperation::Difference, true, false) => return Vec::new(),
+                (_, false, true) => return vec![copy(subject)],
+                (_, false, false) => {}
+            }
+
+            let extent = subject_ring
+                .iter()
+                .chain(other_ring.iter())
+                .map(|point| point.abs().max_element())
+                .fold(0., $float::max);
+            let epsilon = (extent + 1.) * 1e-6;
+
+            let (subject_splits, other_splits) = split_points(&subject_ring, &other_ring, epsilon);
+            let touching = subject_splits
+                .iter()
+                .chain(other_splits.iter())
+                .any(|splits| !splits.is_empty())
+                || subject_ring
+                    .iter()
+                    .any(|point| distance_to_ring(*point, &other_ring) <= epsilon)
+                || other_ring
+                    .iter()
+                    .any(|point| distance_to_ring(*point, &subject_ring) <= epsilon);
+
+            if !touching {
+                let subject_inside = contains_ring(&other_ring, subject_ring[0]);
+                let other_inside = contains_ring(&subject_ring, other_ring[0]);
+                match operation {
+                    BooleanOperation::Union if subject_inside => return vec![copy(other)],
+                    BooleanOperation::Union if other_inside => return vec![copy(subject)],
+                    BooleanOperation::Union => return vec![copy(subject), copy(other)],
+                    BooleanOperation::Difference if subject_inside => return Vec::new(),
+                    BooleanOperation::Difference if other_inside => {
+                        let mut hole = other_ring;
+                        hole.reverse();
+                        return vec![copy(subject), Polygon::new(hole)];
+                    }
+                    BooleanOperation::Difference => return vec![copy(subject)],
+                }
+            }
+
+            let mut kept = Vec::new();
+            for (start, end) in split_edges(&subject_ring, &subject_splits) {
+                let keep = matches!(
+                    (operation, locate_edge(start, end, &other_ring, epsilon)),
+                    (_, EdgeLocation::Outside)
+                        | (BooleanOperation::Union, EdgeLocation::SharedSameDirection)
+                        | (
+                            BooleanOperation::Difference,
+                            EdgeLocation::SharedOppositeDirection
+                        )
+                );
+                if keep {
+                    kept.push((start, end));
+                }
+            }
+            for (start, end) in split_edges(&other_ring, &other_splits) {
+                match (operation, locate_edge(start, end, &subject_ring, epsilon)) {
+                    (BooleanOperation::Union, EdgeLocation::Outside) => kept.push((start, end)),
+                    // The parts of `other` inside of `subject` become its new boundary, in reverse
+                    (BooleanOperation::Difference, EdgeLocation::Inside) => kept.push((end, start)),
+                    _ => {}
+                }
+            }
+
+            trace_rings(kept, epsilon)
+        }
+
+        // The coordinates of a polygon without repeated coordinates, ordered counter-clockwise,
+        // or nothing if the polygon has no area
+        fn counter_clockwise_ring(polygon: &Polygon) -> Vec<Vec2> {
+            let mut ring = polygon.coordinates.clone();
+            ring.dedup();
+            if ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            match polygon.winding() {
+                _ if ring.len() < 3 => Vec::new(),
+                Winding::CounterClockwise => ring,
+                Winding::Clockwise => {
+                    ring.reverse();
+                    ring
+                }
+                Winding::Degenerate => Vec::new(),
+            }
+        }
+
+        // Ear clipping triangulation of a simple polygon, returning its counter-clockwise ring without repeated
+        // or collinear coordinates, and counter-clockwise triangles as indices into that ring
+        #[cfg(any(feature = "mesh", feature = "rand", feature = "polygon_with_holes"))]
+        pub(crate) fn triangulate_polygon(polygon: &Polygon) -> (Vec<Vec2>, Vec<[usize; 3]>) {
+            let ring = counter_clockwise_ring(polygon);
+            let extent = ring
+                .iter()
+                .map(|point| point.abs().max_element())
+                .fold(0., $float::max);
+            let ring = remove_collinear(ring, (extent + 1.) * 1e-6);
+            let triangles = triangulate(&ring);
+            (ring, triangles)
+        }
+
+        // Ear clipping triangulation of a simple counter-clockwise ring, returning counter-clockwise triangles
+        // as indices into the ring
+        #[cfg(any(feature = "mesh", feature = "rand", feature = "polygon_with_holes"))]
+        fn triangulate(ring: &[Vec2]) -> Vec<[usize; 3]> {
+            let mut remaining: Vec<usize> = (0..ring.len()).collect();
+            let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2));
+            while remaining.len() > 3 {
+                let len = remaining.len();
+                let corner = |i: usize| {
+                    (
+                        remaining[(i + len - 1) % len],
+                        remaining[i],
+                        remaining[(i + 1) % len],
+                    )
+                };
+                let is_convex = |(previous, current, next): (usize, usize, usize)| {
+                    (ring[current] - ring[previous]).perp_dot(ring[next] - ring[current]) > 0.
+                };
+                let is_ear = |i: usize| {
+                    let (previous, current, next) = corner(i);
+                    is_convex(corner(i))
+                        && remaining.iter().all(|&other| {
+                            let point = ring[other];
+                            other == previous
+                                || other == current
+                                || other == next
+                                || point == ring[previous]
+                                || point == ring[current]
+                                || point == ring[next]
+                                || !is_in_triangle(point, ring[previous], ring[current], ring[next])
+                        })
+                };
+
+                // without an ear the ring isn't simple, clipping any convex corner keeps the progress going
+                let Some(ear) = (0..len)
+                    .find(|&i| is_ear(i))
+                    .or_else(|| (0..len).find(|&i| is_convex(corner(i))))
+                else {
+                    break;
+                };
+                let (previous, current, next) = corner(ear);
+                triangles.push([previous, current, next]);
+                remaining.remove(ear);
+            }
+            if let [a, b, c] = remaining[..] {
+                triangles.push([a, b, c]);
+            }
+            triangles
+        }
+
+        // Checks if `point` is inside or on the counter-clockwise triangle `a`, `b`, `c`
+        #[cfg(any(feature = "mesh", feature = "rand", feature = "polygon_with_holes"))]
+        fn is_in_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+            (b - a).perp_dot(point - a) >= 0.
+                && (c - b).perp_dot(point - b) >= 0.
+                && (a - c).perp_dot(point - c) >= 0.
+        }
+
+        // Rotates a ring to start at its lowest coordinate, the leftmost one on ties
+        fn lowest_first(mut ring: Vec<Vec2>) -> Vec<Vec2> {
+            let lowest = ring
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+                .map_or(0, |(index, _)| index);
+            ring.rotate_left(lowest);
+            ring
+        }
+
+        fn ring_edges(ring: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+            ring.iter()
+                .copied()
+                .zip(ring.iter().cycle().skip(1).copied())
+        }
+
+        fn distance_to_ring(point: Vec2, ring: &[Vec2]) -> $float {
+            ring_edges(ring)
+                .map(|(start, end)| distance_to_segment(point, start, end))
+                .fold($float::INFINITY, $float::min)
+        }
+
+        pub(crate) fn contains_ring(ring: &[Vec2], point: Vec2) -> bool {
+            let mut inside = false;
+            for (start, end) in ring_edges(ring) {
+                if (start.y > point.y) != (end.y > point.y) {
+                    let x = start.x + (point.y - start.y) / (end.y - start.y) * (end.x - start.x);
+                    if point.x < x {
+                        inside = !inside;
+                    }
+                }
+            }
+            inside
+        }
+
+        // The points along each edge of both rings where they meet the other ring, as the distance along the
+        // edge (0 to 1) and the point. Points shared by both rings are exactly equal, snapping to existing
+        // coordinates when within `epsilon` so that the split edges can be traced back together.
+        #[allow(clippy::type_complexity)]
+        fn split_points(
+            a: &[Vec2],
+            b: &[Vec2],
+            epsilon: $float,
+        ) -> (Vec<Vec<($float, Vec2)>>, Vec<Vec<($float, Vec2)>>) {
+            let mut a_splits = vec![Vec::new(); a.len()];
+            let mut b_splits = vec![Vec::new(); b.len()];
+            let parameter = |point: Vec2, start: Vec2, end: Vec2| {
+                let direction = end - start;
+                (point - start).dot(direction) / direction.length_squared()
+            };
+
+            for (a_index, (a_start, a_end)) in ring_edges(a).enumerate() {
+                for (b_index, (b_start, b_end)) in ring_edges(b).enumerate() {
+                    let mut contacts = Vec::new();
+
+                    // Coordinates touching the other edge, which also covers collinear overlaps
+                    for point in [b_start, b_end] {
+                        if distance_to_segment(point, a_start, a_end) <= epsilon {
+                            contacts.push(point);
+                        }
+                    }
+                    for point in [a_start, a_end] {
+                        if distance_to_segment(point, b_start, b_end) <= epsilon {
+                            contacts.push(point);
+                        }
+                    }
+
+                    // Edges crossing away from any coordinate
+                    if contacts.is_empty() {
+                        let a_direction = a_end - a_start;
+                        let b_direction = b_end - b_start;
+                        let denominator = a_direction.perp_dot(b_direction);
+                        if denominator != 0. {
+                            let offset = b_start - a_start;
+                            let t = offset.perp_dot(b_direction) / denominator;
+                            let u = offset.perp_dot(a_direction) / denominator;
+                            if (0. ..=1.).contains(&t) && (0. ..=1.).contains(&u) {
+                                contacts.push(a_start + a_direction * t);
+                            }
+                        }
+                    }
+
+                    for point in contacts {
+                        let t = parameter(point, a_start, a_end);
+                        if point != a_start && point != a_end && t > 0. && t < 1. {
+                            a_splits[a_index].push((t, point));
+                        }
+                        let u = parameter(point, b_start, b_end);
+                        if point != b_start && point != b_end && u > 0. && u < 1. {
+                            b_splits[b_index].push((u, point));
+                        }
+                    }
+                }
+            }
+
+            (a_splits, b_splits)
+        }
+
+        // Every edge of the ring broken up at its split points
+        fn split_edges(ring: &[Vec2], splits: &[Vec<($float, Vec2)>]) -> Vec<(Vec2, Vec2)> {
+            let mut edges = Vec::new();
+            for ((start, end), splits) in ring_edges(ring).zip(splits) {
+                let mut splits = splits.clone();
+                splits.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut previous = start;
+                for point in splits.into_iter().map(|(_, point)| point).chain([end]) {
+                    if point != previous {
+                        edges.push((previous, point));
+                        previous = point;
+                    }
+                }
+            }
+            edges
+        }
+
+        fn locate_edge(start: Vec2, end: Vec2, ring: &[Vec2], epsilon: $float) -> EdgeLocation {
+            let midpoint = (start + end) * 0.5;
+            for (ring_start, ring_end) in ring_edges(ring) {
+                if distance_to_segment(midpoint, ring_start, ring_end) <= epsilon {
+                    return if (end - start).dot(ring_end - ring_start) > 0. {
+                        EdgeLocation::SharedSameDirection
+                    } else {
+                        EdgeLocation::SharedOppositeDirection
+                    };
+                }
+            }
+            if contains_ring(ring, midpoint) {
+                EdgeLocation::Inside
+            } else {
+                EdgeLocation::Outside
+            }
+        }
+
+        // Joins edges end to start into closed rings, dropping collinear coordinates
+        fn trace_rings(edges: Vec<(Vec2, Vec2)>, epsilon: $float) -> Vec<Polygon> {
+            let mut used = vec![false; edges.len()];
+            let mut rings = Vec::new();
+
+            for first in 0..edges.len() {
+                if used[first] {
+                    continue;
+                }
+                used[first] = true;
+                let (start, mut current) = edges[first];
+                let mut ring = vec![start];
+
+                while current != start {
+                    let Some(next) =
+                        (0..edges.len()).find(|index| !used[*index] && edges[*index].0 == current)
+                    else {
+                        break;
+                    };
+                    used[next] = true;
+                    ring.push(current);
+                    current = edges[next].1;
+                }
+                if current != start {
+                    // The edges didn't close into a ring
+                    continue;
+                }
+
+                let ring = remove_collinear(ring, epsilon);
+                if ring.len() >= 3 {
+                    rings.push(Polygon::new(ring));
+                }
+            }
+
+            rings
+        }
+
+        // Checks if `point` is within `epsilon` of the segment between its neighbors `previous` and `next`
+        fn is_collinear(point: Vec2, previous: Vec2, next: Vec2, epsilon: $float) -> bool {
+            distance_to_segment(point, previous, next) <= epsilon
+        }
+
+        fn remove_collinear(mut ring: Vec<Vec2>, epsilon: $float) -> Vec<Vec2> {
+            let mut index = 0;
+            while index < ring.len() && ring.len() >= 3 {
+                let previous = ring[(index + ring.len() - 1) % ring.len()];
+                let next = ring[(index + 1) % ring.len()];
+                if is_collinear(ring[index], previous, next, epsilon) {
+                    ring.remove(index);
+                    index = index.saturating_sub(1);
+                } else {
+                    index += 1;
+                }
+            }
+            ring
+        }
+
+        // Ramer-Douglas-Peucker simplification of an open chain, returning the indices of the kept points
+        // in order, always including both ends
+        pub(crate) fn simplify_chain(chain: &[Vec2], tolerance: $float) -> Vec<usize> {
+            if chain.len() < 3 {
+                return (0..chain.len()).collect();
+            }
+
+            let mut keep = vec![false; chain.len()];
+            keep[0] = true;
+            keep[chain.len() - 1] = true;
+
+            let mut ranges = vec![(0, chain.len() - 1)];
+            while let Some((from, to)) = ranges.pop() {
+                let (start, end) = (chain[from], chain[to]);
+                let mut farthest = from;
+                let mut farthest_distance = 0.;
+                for (index, point) in chain.iter().enumerate().take(to).skip(from + 1) {
+                    let distance = distance_to_segment(*point, start, end);
+                    if distance > farthest_distance {
+                        farthest = index;
+                        farthest_distance = distance;
+                    }
+                }
+
+                if farthest_distance > tolerance {
+                    keep[farthest] = true;
+                    ranges.push((from, farthest));
+                    ranges.push((farthest, to));
+                }
+            }
+
+            (0..chain.len()).filter(|index| keep[*index]).collect()
+        }
+
+        // ##########
+        // FromIterator impl
+        // ##########
+        impl FromIterator<Vec2> for Polygon {
+            /// Creates a new [`Polygon`] from an iterator of coordinates
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let polygon: Polygon = [Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE].into_iter().collect();
+            /// let polygon2 = Polygon::from_iter([Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+            ///
+            /// assert_eq!(polygon.coordinates(), polygon2.coordinates());
+            /// ```
+            fn from_iter<T: IntoIterator<Item = Vec2>>(iter: T) -> Self {
+                Self::new(iter.into_iter().collect())
+            }
+        }
+
+        // ##########
+        // From impls
+        // ##########
+        #[cfg(feature = "rectangle")]
+        impl From<Rectangle> for Polygon {
+            /// Creates a [`Polygon`] from the four corners of a [`Rectangle`], wound counter-clockwise from the
+            /// minimum corner
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 1.);
+            /// let polygon = Polygon::from(Rectangle::new(0., 0., 2., 1.));
+            /// // min and max given the wrong way around
+            /// let flipped = Polygon::from(Rectangle::new(2., 1., 0., 0.));
+            ///
+            /// assert_eq!(polygon.len(), 4);
+            /// assert_eq!(polygon.area(), rect.width() * rect.height());
+            /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+            /// assert_eq!(flipped.coordinates(), polygon.coordinates());
+            /// ```
+            fn from(rectangle: Rectangle) -> Self {
+                let min = rectangle.min().min(rectangle.max());
+                let max = rectangle.min().max(rectangle.max());
+                Self::new(vec![
+                    min,
+                    Vec2 { x: max.x, y: min.y },
+                    max,
+                    Vec2 { x: min.x, y: max.y },
+                ])
+            }
+        }
+
+        #[cfg(feature = "triangle")]
+        impl From<Triangle> for Polygon {
+            /// Creates a [`Polygon`] from the three coordinates of a [`Triangle`], wound counter-clockwise
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // a clockwise triangle
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 3. }, Vec2 { x: 4., y: 0. });
+            /// let area = triangle.area();
+            /// let polygon = Polygon::from(triangle);
+            ///
+            /// assert_eq!(polygon.len(), 3);
+            /// assert_eq!(polygon.area(), area);
+            /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+            /// assert_eq!(polygon.coordinate(0), Some(Vec2::ZERO));
+            /// ```
+            fn from(triangle: Triangle) -> Self {
+                let [coordinate1, coordinate2, coordinate3] = triangle.vertices();
+                if triangle.is_clockwise() {
+                    Self::new(vec![coordinate1, coordinate3, coordinate2])
+                } else {
+                    Self::new(vec![coordinate1, coordinate2, coordinate3])
+                }
+            }
+        }
+
+        // ##########
+        // Default impl
+        // ##########
+        impl Default for Polygon {
+            fn default() -> Self {
+                Self {
+                    coordinates: vec![
+                        Vec2::ZERO,
+                        Vec2 { x: 1., y: 0. },
+                        Vec2::ONE,
+                        Vec2 { x: 0., y: 1. },
+                    ],
+                }
+            }
+        }
+
+        // ##########
+        // Display impl
+        // ##########
+        impl Display for Polygon {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!(stringify!($polygon), " {{ coordinates: ["))?;
+                for (index, coordinate) in self.coordinates().iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", coordinate)?;
+                }
+                write!(f, "] }}")
+            }
+        }
+    };
+}
+pub(crate) use impl_polygon;
+
+impl_polygon!(
+    /// Represents a single [`Polygon`] in 2d space
+    Polygon,
+    PolygonDefect,
+    SplitResult,
+    PolygonSampler,
+    f32;
+    "use glam::Vec2;\nuse shapes2d::prelude::*;"
+);
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the coordinates of two [`Polygon`]s in order within `epsilon`
+    ///
+    /// [`Polygon`]s with a different number of coordinates, or starting from a different coordinate, are never close.
+    Polygon { coordinates[..] }
+);
+
+// ##########
+// Separating axes
+// ##########
+impl Polygon {
+    /// Checks if the [`Polygon`] overlaps `other` using the separating axis theorem
+    ///
+    /// Both polygons must be convex (see [`Polygon::is_convex`]), the result is meaningless otherwise.
+    /// Polygons that only touch along an edge or at a coordinate intersect. An empty [`Polygon`]
+    /// intersects nothing.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polygon;
+    ///
+    /// let pentagon = |center: Vec2| -> Polygon {
+    ///     (0..5).map(|i| center + Vec2::from_angle(i as f32 * std::f32::consts::TAU / 5.)).collect()
+    /// };
+    /// let square = |min: Vec2, size: f32| {
+    ///     Polygon::new(vec![min, min + Vec2::X * size, min + Vec2::ONE * size, min + Vec2::Y * size])
+    /// };
+    ///
+    /// // overlapping
+    /// assert!(pentagon(Vec2::ZERO).intersects_convex(&pentagon(Vec2 { x: 1., y: 0.5 })));
+    /// // touching along an edge
+    /// assert!(square(Vec2::ZERO, 1.).intersects_convex(&square(Vec2 { x: 1., y: 0.5 }, 1.)));
+    /// // touching at a coordinate
+    /// assert!(square(Vec2::ZERO, 1.).intersects_convex(&square(Vec2::ONE, 1.)));
+    /// // one inside the other
+    /// assert!(square(Vec2::ZERO, 4.).intersects_convex(&pentagon(Vec2::ONE * 2.)));
+    /// assert!(pentagon(Vec2::ONE * 2.).intersects_convex(&square(Vec2::ZERO, 4.)));
+    ///
+    /// // separated by a diagonal gap, although their bounding boxes overlap
+    /// let lower = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. }]);
+    /// let upper = Polygon::new(vec![Vec2 { x: 2., y: 0.5 }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0.5, y: 2. }]);
+    /// assert!(!lower.intersects_convex(&upper));
+    /// assert!(!upper.intersects_convex(&lower));
+    /// ```
+    pub fn intersects_convex(&self, other: &Polygon) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return false;
+        }
+
+        let axes = self
+            .separating_axes()
+            .into_iter()
+            .chain(other.separating_axes());
+        sat::intersects(self, other, axes)
+    }
 }
@@ -0,0 +1,606 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+use crate::polygon::{contains_ring, triangulate_polygon};
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+use crate::prelude::{Polygon, Triangle, Winding};
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single [`PolygonWithHoles`] in 2d space, an `outer` [`Polygon`] with `holes` cut out of it
+///
+/// The `outer` ring is kept counter-clockwise and every hole clockwise, whichever way they were given.
+/// Holes are expected to lie inside of the `outer` ring without overlapping each other, which isn't enforced
+/// but can be checked with [`PolygonWithHoles::validate`].
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+///
+/// let outer = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 4. }, Vec2 { x: 0., y: 4. }]);
+/// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 1., y: 3. }]);
+/// let frame = PolygonWithHoles::new(outer, vec![hole]);
+///
+/// assert_eq!(frame.area(), 12.);
+/// assert!(frame.contains_point(Vec2 { x: 0.5, y: 2. }));
+/// assert!(!frame.contains_point(Vec2 { x: 2., y: 2. }));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PolygonWithHoles {
+    outer: Polygon,
+    holes: Vec<Polygon>,
+}
+
+/// A problem with a [`PolygonWithHoles`], reported by [`PolygonWithHoles::validate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoleDefect {
+    /// The hole at the index reaches outside of the `outer` ring
+    OutsideOuter(usize),
+    /// The holes at the two indices overlap
+    Overlapping(usize, usize),
+}
+
+// ##########
+// Constructors
+// ##########
+impl PolygonWithHoles {
+    /// Creates a new [`PolygonWithHoles`] from an `outer` [`Polygon`] and the `holes` inside of it
+    ///
+    /// The `outer` ring is reversed if it is clockwise, and each hole is reversed if it is counter-clockwise.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles, Winding};
+    ///
+    /// // both given counter-clockwise
+    /// let outer = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 4. }, Vec2 { x: 0., y: 4. }]);
+    /// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 1., y: 3. }]);
+    /// let frame = PolygonWithHoles::new(outer, vec![hole]);
+    ///
+    /// assert_eq!(frame.outer().winding(), Winding::CounterClockwise);
+    /// assert_eq!(frame.holes()[0].winding(), Winding::Clockwise);
+    /// ```
+    pub fn new(outer: Polygon, holes: Vec<Polygon>) -> Self {
+        let mut polygon = Self {
+            outer: Polygon::new(Vec::new()),
+            holes: Vec::with_capacity(holes.len()),
+        };
+        polygon.set_outer(outer);
+        for hole in holes {
+            polygon.push_hole(hole);
+        }
+        polygon
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl PolygonWithHoles {
+    /// Get the `outer` [`Polygon`] of the [`PolygonWithHoles`], wound counter-clockwise
+    ///
+    /// ```
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// assert_eq!(PolygonWithHoles::default().outer(), &Polygon::default());
+    /// ```
+    pub fn outer(&self) -> &Polygon {
+        &self.outer
+    }
+
+    /// Get the `holes` of the [`PolygonWithHoles`], each wound clockwise
+    ///
+    /// ```
+    /// use shapes2d::prelude::PolygonWithHoles;
+    ///
+    /// assert!(PolygonWithHoles::default().holes().is_empty());
+    /// ```
+    pub fn holes(&self) -> &[Polygon] {
+        &self.holes
+    }
+
+    /// Set the `outer` [`Polygon`] of the [`PolygonWithHoles`], reversing it if it is clockwise
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles, Winding};
+    ///
+    /// let mut polygon = PolygonWithHoles::default();
+    /// polygon.set_outer(Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 0. }]));
+    ///
+    /// assert_eq!(polygon.area(), 2.);
+    /// assert_eq!(polygon.outer().winding(), Winding::CounterClockwise);
+    /// ```
+    pub fn set_outer(&mut self, outer: Polygon) {
+        self.outer = match outer.winding() {
+            Winding::Clockwise => outer.reversed(),
+            _ => outer,
+        };
+    }
+
+    /// Add a hole to the [`PolygonWithHoles`], reversing it if it is counter-clockwise
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles, Winding};
+    ///
+    /// let mut polygon = PolygonWithHoles::new(Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO), Vec::new());
+    /// polygon.push_hole(Polygon::new(vec![Vec2::ONE, Vec2 { x: 2., y: 1. }, Vec2 { x: 2., y: 2. }]));
+    ///
+    /// assert_eq!(polygon.area(), 15.5);
+    /// assert_eq!(polygon.holes()[0].winding(), Winding::Clockwise);
+    /// ```
+    pub fn push_hole(&mut self, hole: Polygon) {
+        self.holes.push(match hole.winding() {
+            Winding::CounterClockwise => hole.reversed(),
+            _ => hole,
+        });
+    }
+
+    /// Remove the hole at `index` from the [`PolygonWithHoles`], returning it, or `None` if there is no hole
+    /// at `index`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 1., y: 2. }, Vec2 { x: 2., y: 2. }]);
+    /// let mut polygon = PolygonWithHoles::new(outer, vec![hole.clone()]);
+    ///
+    /// assert_eq!(polygon.remove_hole(0), Some(hole));
+    /// assert_eq!(polygon.remove_hole(0), None);
+    /// assert_eq!(polygon.area(), 16.);
+    /// ```
+    pub fn remove_hole(&mut self, index: usize) -> Option<Polygon> {
+        (index < self.holes.len()).then(|| self.holes.remove(index))
+    }
+}
+
+// ##########
+// Attributes
+// ##########
+impl PolygonWithHoles {
+    /// Get the area of the [`PolygonWithHoles`], the area of the `outer` ring less the area of its holes
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 1., y: 3. }]);
+    ///
+    /// assert_eq!(PolygonWithHoles::new(outer, vec![hole]).area(), 12.);
+    /// ```
+    pub fn area(&self) -> f32 {
+        self.holes
+            .iter()
+            .fold(self.outer.area(), |area, hole| area - hole.area())
+    }
+
+    /// Get the perimeter of the [`PolygonWithHoles`], the length around the `outer` ring and every hole
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 1., y: 3. }]);
+    ///
+    /// assert_eq!(PolygonWithHoles::new(outer, vec![hole]).perimeter(), 24.);
+    /// ```
+    pub fn perimeter(&self) -> f32 {
+        self.holes
+            .iter()
+            .fold(self.outer.perimeter(), |perimeter, hole| {
+                perimeter + hole.perimeter()
+            })
+    }
+
+    /// Get the center of mass of the [`PolygonWithHoles`], or `None` if the `outer` ring has no coordinates
+    ///
+    /// Without any area left, this is the centroid of the `outer` ring.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO);
+    /// // a hole right of the center pushes the centroid left
+    /// let hole = Polygon::new(vec![Vec2 { x: 2., y: 1. }, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 2., y: 3. }]);
+    /// let polygon = PolygonWithHoles::new(outer, vec![hole]);
+    ///
+    /// let centroid = polygon.centroid().unwrap();
+    /// assert_abs_diff_eq!(centroid, Vec2 { x: 27. / 14., y: 2. }, epsilon = 1e-6);
+    ///
+    /// assert_eq!(PolygonWithHoles::new(Polygon::new(Vec::new()), Vec::new()).centroid(), None);
+    /// ```
+    pub fn centroid(&self) -> Option<Vec2> {
+        let outer = self.outer.centroid()?;
+        let area = self.area();
+        if area <= 0. {
+            return Some(outer);
+        }
+        let weighted = self
+            .holes
+            .iter()
+            .fold(outer * self.outer.area(), |weighted, hole| {
+                weighted - hole.centroid().unwrap_or(Vec2::ZERO) * hole.area()
+            });
+        Some(weighted / area)
+    }
+
+    /// Get the smallest axis aligned [`Rectangle`] containing the [`PolygonWithHoles`], or `None` if the `outer`
+    /// ring has no coordinates
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 2. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 1.5, y: 1. }, Vec2 { x: 1.5, y: 1.5 }]);
+    /// let rect = PolygonWithHoles::new(outer, vec![hole]).bounding_box().unwrap();
+    ///
+    /// assert_eq!(rect.min(), Vec2::ZERO);
+    /// assert_eq!(rect.max(), Vec2 { x: 4., y: 2. });
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Option<Rectangle> {
+        self.outer.bounding_box()
+    }
+
+    /// Check that every hole is inside of the `outer` ring, and that no two holes overlap
+    ///
+    /// Holes may touch the `outer` ring or each other along their edges. Returns every defect found.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{HoleDefect, Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO);
+    /// let inside = Polygon::new(vec![Vec2::ONE, Vec2 { x: 2., y: 1. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 1., y: 2. }]);
+    /// let beside = inside.translated(Vec2 { x: 1., y: 0. });
+    /// let overlapping = inside.translated(Vec2 { x: 0.5, y: 0.5 });
+    /// let crossing = inside.translated(Vec2 { x: 2.5, y: 0. });
+    /// let outside = inside.translated(Vec2 { x: 5., y: 5. });
+    ///
+    /// assert_eq!(PolygonWithHoles::new(outer.clone(), vec![inside.clone(), beside]).validate(), Ok(()));
+    /// assert_eq!(
+    ///     PolygonWithHoles::new(outer.clone(), vec![inside.clone(), overlapping]).validate(),
+    ///     Err(vec![HoleDefect::Overlapping(0, 1)])
+    /// );
+    /// assert_eq!(
+    ///     PolygonWithHoles::new(outer, vec![crossing, inside, outside]).validate(),
+    ///     Err(vec![HoleDefect::OutsideOuter(0), HoleDefect::OutsideOuter(2)])
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<HoleDefect>> {
+        let mut defects = Vec::new();
+        for (index, hole) in self.holes.iter().enumerate() {
+            if hole
+                .coordinates()
+                .iter()
+                .any(|coordinate| !self.outer.contains_point(*coordinate))
+                || rings_cross(hole, &self.outer)
+            {
+                defects.push(HoleDefect::OutsideOuter(index));
+            }
+        }
+        for (a_index, a) in self.holes.iter().enumerate() {
+            for (b_index, b) in self.holes.iter().enumerate().skip(a_index + 1) {
+                if rings_overlap(a, b) {
+                    defects.push(HoleDefect::Overlapping(a_index, b_index));
+                }
+            }
+        }
+
+        if defects.is_empty() {
+            Ok(())
+        } else {
+            Err(defects)
+        }
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl PolygonWithHoles {
+    /// Checks if a `point` is inside of the `outer` ring and not inside of any hole
+    ///
+    /// Points on the edge of the `outer` ring or of a hole are on the boundary, and are contained.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 1., y: 3. }]);
+    /// let frame = PolygonWithHoles::new(outer, vec![hole]);
+    ///
+    /// assert!(frame.contains_point(Vec2 { x: 3.5, y: 0.5 }));
+    /// // in the hole
+    /// assert!(!frame.contains_point(Vec2 { x: 2., y: 2.5 }));
+    /// // on the edge of the hole
+    /// assert!(frame.contains_point(Vec2 { x: 3., y: 2. }));
+    /// assert!(!frame.contains_point(Vec2 { x: 5., y: 2. }));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.outer.contains_point(point)
+            && self.holes.iter().all(|hole| {
+                !hole.contains_point(point)
+                    || hole
+                        .edge_pairs()
+                        .any(|(start, end)| Polygon::is_point_on_segment(point, start, end))
+            })
+    }
+
+    /// Get the point on the edge of the `outer` ring or of a hole closest to `point`, or `None` if there are no
+    /// coordinates
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 6., y: 6. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2 { x: 2., y: 2. }, Vec2 { x: 4., y: 2. }, Vec2 { x: 4., y: 4. }, Vec2 { x: 2., y: 4. }]);
+    /// let frame = PolygonWithHoles::new(outer, vec![hole]);
+    ///
+    /// assert_eq!(frame.closest_boundary_point(Vec2 { x: 3., y: 2.5 }), Some(Vec2 { x: 3., y: 2. }));
+    /// assert_eq!(frame.closest_boundary_point(Vec2 { x: 3., y: 0.5 }), Some(Vec2 { x: 3., y: 0. }));
+    /// ```
+    pub fn closest_boundary_point(&self, point: Vec2) -> Option<Vec2> {
+        std::iter::once(&self.outer)
+            .chain(&self.holes)
+            .filter_map(|ring| ring.closest_boundary_point(point))
+            .min_by(|a, b| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+    }
+
+    /// Get the signed distance from `point` to the boundary of the [`PolygonWithHoles`]
+    ///
+    /// The distance is negative inside of the [`PolygonWithHoles`] and positive outside, including inside of a
+    /// hole.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 6., y: 6. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2 { x: 2., y: 2. }, Vec2 { x: 4., y: 2. }, Vec2 { x: 4., y: 4. }, Vec2 { x: 2., y: 4. }]);
+    /// let frame = PolygonWithHoles::new(outer, vec![hole]);
+    ///
+    /// assert_eq!(frame.signed_distance(Vec2 { x: 1., y: 3. }), -1.);
+    /// assert_eq!(frame.signed_distance(Vec2 { x: 3., y: 3. }), 1.);
+    /// assert_eq!(frame.signed_distance(Vec2 { x: 8., y: 3. }), 2.);
+    /// ```
+    pub fn signed_distance(&self, point: Vec2) -> f32 {
+        let Some(closest) = self.closest_boundary_point(point) else {
+            return f32::INFINITY;
+        };
+        let distance = closest.distance(point);
+        if self.contains_point(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    /// Get counter-clockwise [`Triangle`]s covering the [`PolygonWithHoles`] and leaving its holes uncovered
+    ///
+    /// Each hole is joined to the `outer` ring along a bridge to the closest coordinate it can see, and the
+    /// single ring that makes is ear clipped. Holes which overlap the `outer` ring or each other (see
+    /// [`PolygonWithHoles::validate`]) give no meaningful triangulation.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 1., y: 3. }]);
+    /// let frame = PolygonWithHoles::new(outer, vec![hole]);
+    ///
+    /// let triangles = frame.triangulate();
+    /// assert_eq!(triangles.len(), 8);
+    ///
+    /// let area: f32 = triangles.iter().map(|triangle| triangle.area()).sum();
+    /// assert_abs_diff_eq!(area, frame.area(), epsilon = 1e-5);
+    /// // nothing covers the hole
+    /// assert!(triangles.iter().all(|triangle| !triangle.contains_point(Vec2 { x: 2., y: 2. })));
+    /// ```
+    pub fn triangulate(&self) -> Vec<Triangle> {
+        let (ring, triangles) = triangulate_polygon(&Polygon::new(self.bridged_ring()));
+        triangles
+            .iter()
+            .map(|[a, b, c]| Triangle::new(ring[*a], ring[*b], ring[*c]))
+            .collect()
+    }
+
+    /// Creates a [`Mesh`] covering the [`PolygonWithHoles`], from the same triangles as
+    /// [`PolygonWithHoles::triangulate`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let outer = Polygon::default().scaled_about(Vec2 { x: 4., y: 4. }, Vec2::ZERO);
+    /// let hole = Polygon::new(vec![Vec2::ONE, Vec2 { x: 3., y: 1. }, Vec2 { x: 3., y: 3. }, Vec2 { x: 1., y: 3. }]);
+    /// let mesh = PolygonWithHoles::new(outer, vec![hole]).to_mesh();
+    ///
+    /// assert_eq!(mesh.triangle_count(), 8);
+    /// assert_abs_diff_eq!(mesh.area(), 12., epsilon = 1e-5);
+    /// assert!(!mesh.contains_point(Vec2 { x: 2., y: 2. }));
+    /// ```
+    #[cfg(feature = "mesh")]
+    pub fn to_mesh(&self) -> Mesh {
+        Mesh::from_polygon(&Polygon::new(self.bridged_ring()))
+    }
+
+    // Joins every hole into the `outer` ring along a bridge to the closest coordinate it can see, giving one
+    // ring which walks each bridge once in each direction
+    fn bridged_ring(&self) -> Vec<Vec2> {
+        let mut ring = self.outer.coordinates().to_vec();
+        let mut holes: Vec<&Polygon> = self
+            .holes
+            .iter()
+            .filter(|hole| hole.winding() == Winding::Clockwise)
+            .collect();
+        // Bridging from the rightmost holes first keeps the bridges short
+        holes.sort_by(|a, b| {
+            rightmost(b.coordinates())
+                .1
+                .x
+                .total_cmp(&rightmost(a.coordinates()).1.x)
+        });
+
+        for (index, hole) in holes.iter().enumerate() {
+            let coordinates = hole.coordinates();
+            let (start, from) = rightmost(coordinates);
+            let others = &holes[index + 1..];
+            let edges = ring_edges(&ring)
+                .chain(hole.edge_pairs())
+                .chain(others.iter().flat_map(|other| other.edge_pairs()));
+            let edges: Vec<(Vec2, Vec2)> = edges.collect();
+
+            let is_visible = |to: Vec2| {
+                edges.iter().all(|(a, b)| {
+                    [*a, *b].iter().any(|end| *end == from || *end == to)
+                        || !segments_touch(from, to, *a, *b)
+                }) && contains_ring(&ring, from.lerp(to, 0.5))
+            };
+            let Some(target) = (0..ring.len())
+                .filter(|target| is_visible(ring[*target]))
+                .min_by(|a, b| {
+                    ring[*a]
+                        .distance_squared(from)
+                        .total_cmp(&ring[*b].distance_squared(from))
+                })
+            else {
+                continue;
+            };
+
+            let mut joined = Vec::with_capacity(ring.len() + coordinates.len() + 2);
+            joined.extend_from_slice(&ring[..=target]);
+            joined.extend_from_slice(&coordinates[start..]);
+            joined.extend_from_slice(&coordinates[..=start]);
+            joined.extend_from_slice(&ring[target..]);
+            ring = joined;
+        }
+        ring
+    }
+}
+
+// ##########
+// From impls
+// ##########
+impl From<Polygon> for PolygonWithHoles {
+    /// Creates a [`PolygonWithHoles`] without any holes
+    ///
+    /// ```
+    /// use shapes2d::prelude::{Polygon, PolygonWithHoles};
+    ///
+    /// let polygon = PolygonWithHoles::from(Polygon::default());
+    ///
+    /// assert!(polygon.holes().is_empty());
+    /// assert_eq!(polygon.area(), 1.);
+    /// ```
+    fn from(polygon: Polygon) -> Self {
+        Self::new(polygon, Vec::new())
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `outer` [`Polygon`] and then each hole in order within `epsilon`, see the [`Polygon`] comparison
+    ///
+    /// [`PolygonWithHoles`] with a different number of holes are never close.
+    PolygonWithHoles { outer, holes[..] }
+);
+
+// ##########
+// Display impl
+// ##########
+impl Display for PolygonWithHoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PolygonWithHoles {{ outer: {}, holes: [", self.outer())?;
+        for (index, hole) in self.holes().iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", hole)?;
+        }
+        write!(f, "] }}")
+    }
+}
+
+// ##########
+// Helpers
+// ##########
+// The index and coordinate furthest right, the lowest one on ties
+fn rightmost(coordinates: &[Vec2]) -> (usize, Vec2) {
+    coordinates
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.x.total_cmp(&b.x).then(b.y.total_cmp(&a.y)))
+        .unwrap_or((0, Vec2::ZERO))
+}
+
+fn ring_edges(ring: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    ring.iter()
+        .copied()
+        .zip(ring.iter().cycle().skip(1).copied())
+}
+
+// Checks if two segments cross or touch anywhere, including at their ends
+fn segments_touch(a_start: Vec2, a_end: Vec2, b_start: Vec2, b_end: Vec2) -> bool {
+    let side = |start: Vec2, end: Vec2, point: Vec2| (end - start).perp_dot(point - start);
+    let within = |point: Vec2, start: Vec2, end: Vec2| {
+        point.cmpge(start.min(end)).all() && point.cmple(start.max(end)).all()
+    };
+    let (a1, a2) = (side(b_start, b_end, a_start), side(b_start, b_end, a_end));
+    let (b1, b2) = (side(a_start, a_end, b_start), side(a_start, a_end, b_end));
+    if a1 * a2 < 0. && b1 * b2 < 0. {
+        return true;
+    }
+    (a1 == 0. && within(a_start, b_start, b_end))
+        || (a2 == 0. && within(a_end, b_start, b_end))
+        || (b1 == 0. && within(b_start, a_start, a_end))
+        || (b2 == 0. && within(b_end, a_start, a_end))
+}
+
+// Checks if an edge of one ring crosses an edge of the other, rather than only touching it
+fn rings_cross(a: &Polygon, b: &Polygon) -> bool {
+    let side = |start: Vec2, end: Vec2, point: Vec2| (end - start).perp_dot(point - start);
+    a.edge_pairs().any(|(a_start, a_end)| {
+        b.edge_pairs().any(|(b_start, b_end)| {
+            side(b_start, b_end, a_start) * side(b_start, b_end, a_end) < 0.
+                && side(a_start, a_end, b_start) * side(a_start, a_end, b_end) < 0.
+        })
+    })
+}
+
+// Checks if two rings share any area, either by crossing or by a coordinate of one inside of the other
+fn rings_overlap(a: &Polygon, b: &Polygon) -> bool {
+    let is_inside = |ring: &Polygon, point: Vec2| {
+        ring.contains_point(point)
+            && !ring
+                .edge_pairs()
+                .any(|(start, end)| Polygon::is_point_on_segment(point, start, end))
+    };
+    rings_cross(a, b)
+        || a.coordinates().iter().any(|point| is_inside(b, *point))
+        || b.coordinates().iter().any(|point| is_inside(a, *point))
+}
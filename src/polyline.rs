@@ -0,0 +1,483 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+use crate::{
+    polygon::simplify_chain,
+    prelude::{Line, Polygon},
+    traits::closest_point_on_segment,
+};
+
+/// Represents a single [`Polyline`] in 2d space, an open chain of segments joining its coordinates in order
+///
+/// Unlike a [`Polygon`], the last coordinate is not joined back to the first. A [`Polyline`] may have any
+/// number of coordinates, one with fewer than two coordinates has no segments and no length.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::Polyline;
+///
+/// let path = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2 { x: 3., y: 4. }]);
+///
+/// assert_eq!(path.length(), 7.);
+/// assert_eq!(path.point_at_distance(5.), Some(Vec2 { x: 3., y: 2. }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    coordinates: Vec<Vec2>,
+}
+
+// ##########
+// Constructors
+// ##########
+impl Polyline {
+    /// Creates a new [`Polyline`] joining the `coordinates` in order
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2::ONE]);
+    ///
+    /// assert_eq!(polyline.len(), 2);
+    /// ```
+    pub fn new(coordinates: Vec<Vec2>) -> Self {
+        Self { coordinates }
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl Polyline {
+    /// Get the coordinates for the [`Polyline`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2::ONE]);
+    ///
+    /// assert_eq!(polyline.coordinates(), &[Vec2::ZERO, Vec2::ONE]);
+    /// ```
+    pub fn coordinates(&self) -> &[Vec2] {
+        &self.coordinates
+    }
+
+    /// Get the coordinate at `index` for the [`Polyline`], or `None` if `index` is out of bounds
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2::ONE]);
+    ///
+    /// assert_eq!(polyline.coordinate(1), Some(Vec2::ONE));
+    /// assert_eq!(polyline.coordinate(2), None);
+    /// ```
+    pub fn coordinate(&self, index: usize) -> Option<Vec2> {
+        self.coordinates.get(index).copied()
+    }
+
+    /// Get an iterator over the segments of the [`Polyline`], in order
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+    /// let segments: Vec<_> = polyline.segments().collect();
+    ///
+    /// assert_eq!(segments.len(), 2);
+    /// assert_eq!(segments[0].end(), segments[1].origin());
+    /// assert_eq!(Polyline::new(vec![Vec2::ONE]).segments().count(), 0);
+    /// ```
+    pub fn segments(&self) -> impl Iterator<Item = Line> + '_ {
+        self.coordinates
+            .windows(2)
+            .map(|pair| Line::new(pair[0], pair[1]))
+    }
+
+    /// Adds a `coordinate` to the end of the [`Polyline`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let mut polyline = Polyline::new(Vec::new());
+    /// polyline.push(Vec2::ZERO);
+    /// polyline.push(Vec2::ONE);
+    ///
+    /// assert_eq!(polyline.coordinates(), &[Vec2::ZERO, Vec2::ONE]);
+    /// ```
+    pub fn push(&mut self, coordinate: Vec2) {
+        self.coordinates.push(coordinate);
+    }
+
+    /// Reverses the order of the coordinates of the [`Polyline`], so it runs from its end to its start
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let mut polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+    /// polyline.reverse();
+    ///
+    /// assert_eq!(polyline.coordinates(), &[Vec2::ONE, Vec2 { x: 1., y: 0. }, Vec2::ZERO]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.coordinates.reverse();
+    }
+}
+
+// ##########
+// Attributes
+// ##########
+impl Polyline {
+    /// Get the number of coordinates in the [`Polyline`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// assert_eq!(Polyline::new(vec![Vec2::ZERO, Vec2::ONE]).len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    /// Checks if the [`Polyline`] has no coordinates
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// assert!(Polyline::new(Vec::new()).is_empty());
+    /// assert!(!Polyline::new(vec![Vec2::ZERO]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.coordinates.is_empty()
+    }
+
+    /// Get the `length` of the [`Polyline`], the sum of the lengths of its segments
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 3., y: 4. }, Vec2 { x: 3., y: 0. }]);
+    ///
+    /// assert_eq!(polyline.length(), 9.);
+    /// assert_eq!(Polyline::new(vec![Vec2::ONE]).length(), 0.);
+    /// ```
+    pub fn length(&self) -> f32 {
+        self.coordinates
+            .windows(2)
+            .map(|pair| pair[0].distance(pair[1]))
+            .sum()
+    }
+
+    /// Get the smallest [`Rectangle`] holding every coordinate of the [`Polyline`], or `None` if it is empty
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2 { x: -1., y: 2. }, Vec2::ZERO, Vec2 { x: 3., y: 1. }]);
+    /// let rect = polyline.bounding_box().unwrap();
+    ///
+    /// assert_eq!(rect.min(), Vec2 { x: -1., y: 0. });
+    /// assert_eq!(rect.max(), Vec2 { x: 3., y: 2. });
+    /// assert!(Polyline::new(Vec::new()).bounding_box().is_none());
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Option<Rectangle> {
+        let first = *self.coordinates.first()?;
+        let (min, max) = self
+            .coordinates
+            .iter()
+            .fold((first, first), |(min, max), coordinate| {
+                (min.min(*coordinate), max.max(*coordinate))
+            });
+        Some(Rectangle::new_coordinates(min, max))
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl Polyline {
+    /// Get the point `distance` along the [`Polyline`] from its first coordinate, or `None` if it is empty
+    ///
+    /// The `distance` is clamped between `0.` and the `length` of the [`Polyline`].
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 3. }]);
+    ///
+    /// // either side of the joint, and right on it
+    /// assert_eq!(polyline.point_at_distance(1.5), Some(Vec2 { x: 1.5, y: 0. }));
+    /// assert_eq!(polyline.point_at_distance(2.), Some(Vec2 { x: 2., y: 0. }));
+    /// assert_eq!(polyline.point_at_distance(2.5), Some(Vec2 { x: 2., y: 0.5 }));
+    ///
+    /// assert_eq!(polyline.point_at_distance(-1.), Some(Vec2::ZERO));
+    /// assert_eq!(polyline.point_at_distance(9.), Some(Vec2 { x: 2., y: 3. }));
+    /// assert_eq!(Polyline::new(vec![Vec2::ONE]).point_at_distance(1.), Some(Vec2::ONE));
+    /// assert_eq!(Polyline::new(Vec::new()).point_at_distance(1.), None);
+    /// ```
+    pub fn point_at_distance(&self, distance: f32) -> Option<Vec2> {
+        let mut remaining = distance.max(0.);
+        for pair in self.coordinates.windows(2) {
+            let length = pair[0].distance(pair[1]);
+            if remaining <= length {
+                if length == 0. {
+                    return Some(pair[0]);
+                }
+                return Some(pair[0].lerp(pair[1], remaining / length));
+            }
+            remaining -= length;
+        }
+        self.coordinates.last().copied()
+    }
+
+    /// Get the point a fraction `t` of the way along the [`Polyline`], or `None` if it is empty
+    ///
+    /// Equal steps of `t` cover equal lengths of the [`Polyline`], however long each segment is. The fraction is
+    /// clamped between `0.` and `1.`.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2 { x: 1., y: 3. }]);
+    ///
+    /// assert_eq!(polyline.point_at_fraction(0.25), Some(Vec2 { x: 1., y: 0. }));
+    /// assert_eq!(polyline.point_at_fraction(0.5), Some(Vec2 { x: 1., y: 1. }));
+    /// assert_eq!(polyline.point_at_fraction(1.), Some(Vec2 { x: 1., y: 3. }));
+    /// ```
+    pub fn point_at_fraction(&self, t: f32) -> Option<Vec2> {
+        self.point_at_distance(t.clamp(0., 1.) * self.length())
+    }
+
+    /// Get the point of the [`Polyline`] closest to `point`, or `None` if it is empty
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 4. }]);
+    ///
+    /// assert_eq!(polyline.closest_point(Vec2 { x: 2., y: 1. }), Some(Vec2 { x: 2., y: 0. }));
+    /// assert_eq!(polyline.closest_point(Vec2 { x: 6., y: 3. }), Some(Vec2 { x: 4., y: 3. }));
+    /// assert_eq!(Polyline::new(vec![Vec2::ONE]).closest_point(Vec2::ZERO), Some(Vec2::ONE));
+    /// assert_eq!(Polyline::new(Vec::new()).closest_point(Vec2::ZERO), None);
+    /// ```
+    pub fn closest_point(&self, point: Vec2) -> Option<Vec2> {
+        if self.coordinates.len() == 1 {
+            return Some(self.coordinates[0]);
+        }
+        self.coordinates
+            .windows(2)
+            .map(|pair| closest_point_on_segment(point, pair[0], pair[1]))
+            .min_by(|a, b| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+    }
+
+    /// Get the distance from `point` to the closest point of the [`Polyline`], or `None` if it is empty
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 4. }]);
+    ///
+    /// assert_eq!(polyline.distance_to_point(Vec2 { x: 1., y: 3. }), Some(3.));
+    /// assert_eq!(polyline.distance_to_point(Vec2 { x: 2., y: 0. }), Some(0.));
+    /// assert_eq!(Polyline::new(Vec::new()).distance_to_point(Vec2::ZERO), None);
+    /// ```
+    pub fn distance_to_point(&self, point: Vec2) -> Option<f32> {
+        self.closest_point(point)
+            .map(|closest| closest.distance(point))
+    }
+
+    /// Creates a new [`Polyline`] with a coordinate every `spacing` along the [`Polyline`]
+    ///
+    /// The first and last coordinates are kept, so the last step is shorter when the `length` is not a whole
+    /// number of `spacing`. The new coordinates cut across corners, which shortens the [`Polyline`] by at most
+    /// `spacing` at each corner. A [`Polyline`] with fewer than 2 coordinates, or a `spacing` that is not
+    /// positive, is returned unchanged.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 2.5, y: 0. }]);
+    /// let resampled = polyline.resample(1.);
+    /// assert_eq!(resampled.coordinates(), &[Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2 { x: 2., y: 0. }, Vec2 { x: 2.5, y: 0. }]);
+    ///
+    /// // a fine spacing keeps the length of a path with corners
+    /// let path = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2 { x: 3., y: 4. }, Vec2 { x: 7., y: 1. }]);
+    /// let resampled = path.resample(0.01);
+    /// assert_abs_diff_eq!(resampled.length(), path.length(), epsilon = 0.01);
+    /// for pair in resampled.coordinates().windows(2) {
+    ///     assert!(pair[0].distance(pair[1]) <= 0.01 + 1e-5);
+    /// }
+    ///
+    /// assert_eq!(Polyline::new(vec![Vec2::ONE]).resample(1.).len(), 1);
+    /// ```
+    pub fn resample(&self, spacing: f32) -> Polyline {
+        if self.coordinates.len() < 2 || spacing.is_nan() || spacing <= 0. {
+            return self.clone();
+        }
+        let total = self.length();
+
+        let mut coordinates = vec![self.coordinates[0]];
+        let mut step = 1;
+        let mut travelled = 0.;
+        for pair in self.coordinates.windows(2) {
+            let length = pair[0].distance(pair[1]);
+            // stop short of the end, which is always kept exactly
+            loop {
+                let distance = step as f32 * spacing;
+                if distance > travelled + length || distance >= total - spacing * 1e-3 {
+                    break;
+                }
+                coordinates.push(pair[0].lerp(pair[1], (distance - travelled) / length));
+                step += 1;
+            }
+            travelled += length;
+        }
+        coordinates.push(self.coordinates[self.coordinates.len() - 1]);
+        Polyline::new(coordinates)
+    }
+
+    /// Creates a new [`Polyline`] with fewer coordinates, staying within `tolerance` of the original, using the
+    /// Ramer-Douglas-Peucker algorithm
+    ///
+    /// The first and last coordinates are always kept.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![
+    ///     Vec2::ZERO,
+    ///     Vec2 { x: 1., y: 0.05 },
+    ///     Vec2 { x: 2., y: 0. },
+    ///     Vec2 { x: 2., y: 2. },
+    /// ]);
+    ///
+    /// assert_eq!(polyline.simplified(0.1).coordinates(), &[Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }]);
+    /// assert_eq!(polyline.simplified(0.01), polyline);
+    /// assert!(Polyline::new(Vec::new()).simplified(1.).is_empty());
+    /// ```
+    pub fn simplified(&self, tolerance: f32) -> Polyline {
+        simplify_chain(&self.coordinates, tolerance)
+            .into_iter()
+            .map(|index| self.coordinates[index])
+            .collect()
+    }
+
+    /// Creates a [`Polygon`] from the coordinates of the [`Polyline`], closing it from the last coordinate back to
+    /// the first
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline = Polyline::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }]);
+    /// let polygon = polyline.to_polygon();
+    ///
+    /// assert_eq!(polygon.coordinates(), polyline.coordinates());
+    /// assert_eq!(polygon.area(), 2.);
+    /// ```
+    pub fn to_polygon(&self) -> Polygon {
+        Polygon::new(self.coordinates.clone())
+    }
+}
+
+// ##########
+// FromIterator impl
+// ##########
+impl FromIterator<Vec2> for Polyline {
+    /// Creates a new [`Polyline`] from an iterator of coordinates
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let polyline: Polyline = (0..4).map(|i| Vec2 { x: i as f32, y: 0. }).collect();
+    ///
+    /// assert_eq!(polyline.length(), 3.);
+    /// ```
+    fn from_iter<T: IntoIterator<Item = Vec2>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+// ##########
+// Extend impl
+// ##########
+impl Extend<Vec2> for Polyline {
+    /// Adds every coordinate of an iterator to the end of the [`Polyline`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Polyline;
+    ///
+    /// let mut polyline = Polyline::new(vec![Vec2::ZERO]);
+    /// polyline.extend([Vec2 { x: 1., y: 0. }, Vec2::ONE]);
+    ///
+    /// assert_eq!(polyline.len(), 3);
+    /// assert_eq!(polyline.length(), 2.);
+    /// ```
+    fn extend<T: IntoIterator<Item = Vec2>>(&mut self, iter: T) {
+        self.coordinates.extend(iter);
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the coordinates of two [`Polyline`]s in order within `epsilon`, so a reversed [`Polyline`], or one
+    /// with a different number of coordinates, is never close
+    Polyline { coordinates[..] }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for Polyline {
+    fn default() -> Self {
+        Self {
+            coordinates: vec![Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2::ONE],
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for Polyline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Polyline {{ coordinates: [")?;
+        for (index, coordinate) in self.coordinates().iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", coordinate)?;
+        }
+        write!(f, "] }}")
+    }
+}
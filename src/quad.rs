@@ -0,0 +1,431 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+use crate::prelude::{Triangle, Winding};
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single [`Quad`] in 2d space, any four sided shape
+///
+/// The four vertices are kept in order around the edge, and are also the corners of [`Quad::point_at`] for
+/// mapping a texture. Unlike a [`Rectangle`](crate::prelude::Rectangle) a [`Quad`] may be turned, sheared or
+/// tapered, and unlike a [`Polygon`](crate::prelude::Polygon) it never allocates.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::Quad;
+///
+/// // a trapezoid
+/// let quad = Quad::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 3., y: 2. }, Vec2 { x: 1., y: 2. });
+///
+/// assert_eq!(quad.area(), 6.);
+/// assert!(quad.is_convex());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quad {
+    vertices: [Vec2; 4],
+}
+
+// ##########
+// Constructors
+// ##########
+impl Quad {
+    /// Creates a new [`Quad`] from its four vertices, in order around its edge
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// let quad = Quad::new(Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y);
+    ///
+    /// assert_eq!(quad.vertices(), [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y]);
+    /// ```
+    pub fn new(vertex1: Vec2, vertex2: Vec2, vertex3: Vec2, vertex4: Vec2) -> Self {
+        Self {
+            vertices: [vertex1, vertex2, vertex3, vertex4],
+        }
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl Quad {
+    /// Get the four vertices of the [`Quad`], in order around its edge
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// let quad = Quad::new(Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y);
+    ///
+    /// assert_eq!(quad.vertices()[2], Vec2::ONE);
+    /// ```
+    pub fn vertices(&self) -> [Vec2; 4] {
+        self.vertices
+    }
+
+    /// Set the four vertices of the [`Quad`], in order around its edge
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// let mut quad = Quad::new(Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y);
+    /// quad.set_vertices([Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    ///
+    /// assert_eq!(quad.area(), 4.);
+    /// ```
+    pub fn set_vertices(&mut self, vertices: [Vec2; 4]) {
+        self.vertices = vertices;
+    }
+
+    /// Get the four edges of the [`Quad`], each from a vertex to the next, and the last back to the first
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Line, Quad};
+    ///
+    /// let quad = Quad::new(Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y);
+    /// let edges = quad.edges();
+    ///
+    /// assert_eq!(edges[0], Line::new(Vec2::ZERO, Vec2::X));
+    /// assert_eq!(edges[3], Line::new(Vec2::Y, Vec2::ZERO));
+    /// ```
+    #[cfg(feature = "line")]
+    pub fn edges(&self) -> [Line; 4] {
+        let [a, b, c, d] = self.vertices;
+        [
+            Line::new(a, b),
+            Line::new(b, c),
+            Line::new(c, d),
+            Line::new(d, a),
+        ]
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl Quad {
+    /// Get the signed area of the [`Quad`], positive when its vertices wind counter-clockwise
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// assert_eq!(Quad::new(Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y).signed_area(), 1.);
+    /// assert_eq!(Quad::new(Vec2::ZERO, Vec2::Y, Vec2::ONE, Vec2::X).signed_area(), -1.);
+    /// ```
+    pub fn signed_area(&self) -> f32 {
+        let [a, b, c, d] = self.vertices;
+        // the diagonals of any quad span twice its area
+        (c - a).perp_dot(d - b) / 2.
+    }
+
+    /// Get the `area` of the [`Quad`], the sum of the areas of the [`Triangle`]s from [`Quad::to_triangles`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// // an arrowhead, pointing up with a notch at `(0., 0.)`
+    /// let arrowhead = Quad::new(Vec2::ZERO, Vec2 { x: 2., y: -2. }, Vec2 { x: 0., y: 3. }, Vec2 { x: -2., y: -2. });
+    ///
+    /// assert_eq!(arrowhead.area(), 6.);
+    /// assert_eq!(arrowhead.area(), arrowhead.signed_area());
+    /// ```
+    pub fn area(&self) -> f32 {
+        let [first, second] = self.to_triangles();
+        first.area() + second.area()
+    }
+
+    /// Get the `perimeter` of the [`Quad`], the total length of its edges
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// let quad = Quad::new(Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2 { x: 3., y: 4. }, Vec2 { x: 0., y: 4. });
+    ///
+    /// assert_eq!(quad.perimeter(), 14.);
+    /// ```
+    pub fn perimeter(&self) -> f32 {
+        let [a, b, c, d] = self.vertices;
+        a.distance(b) + b.distance(c) + c.distance(d) + d.distance(a)
+    }
+
+    /// Get the [`Winding`] of the vertices of the [`Quad`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Quad, Winding};
+    ///
+    /// assert_eq!(Quad::new(Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y).winding(), Winding::CounterClockwise);
+    /// ```
+    pub fn winding(&self) -> Winding {
+        Winding::from_signed_area(self.signed_area())
+    }
+
+    /// Checks if the [`Quad`] is convex, turning the same way at every vertex
+    ///
+    /// A [`Quad`] with three vertices in a line is still convex, but one with every vertex in a line is not.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// assert!(Quad::new(Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y).is_convex());
+    /// assert!(Quad::new(Vec2::ZERO, Vec2::X, Vec2 { x: 2., y: 0. }, Vec2::Y).is_convex());
+    ///
+    /// // an arrowhead, and a bow tie crossing over itself
+    /// assert!(!Quad::new(Vec2::ZERO, Vec2 { x: 2., y: -2. }, Vec2 { x: 0., y: 3. }, Vec2 { x: -2., y: -2. }).is_convex());
+    /// assert!(!Quad::new(Vec2::ZERO, Vec2::ONE, Vec2::X, Vec2::Y).is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let turns = self.turns();
+        (turns.iter().all(|turn| *turn >= 0.) || turns.iter().all(|turn| *turn <= 0.))
+            && turns.iter().any(|turn| *turn != 0.)
+    }
+
+    /// Splits the [`Quad`] into two [`Triangle`]s along one of its diagonals
+    ///
+    /// The diagonal from the first vertex to the third is used when the other two vertices are on either side
+    /// of it, otherwise the diagonal from the second vertex to the fourth. For a non-convex [`Quad`] this is the
+    /// diagonal inside of it, so the [`Triangle`]s never overlap, and a vertex lying on the first diagonal never
+    /// leaves a flat [`Triangle`]. Both [`Triangle`]s wind the same way as the [`Quad`].
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Quad, Triangle};
+    ///
+    /// let square = Quad::new(Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y);
+    /// assert_eq!(
+    ///     square.to_triangles(),
+    ///     [Triangle::new(Vec2::ZERO, Vec2::X, Vec2::ONE), Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2::Y)]
+    /// );
+    ///
+    /// // an arrowhead notched at its first vertex can't be split from its second vertex
+    /// let arrowhead = Quad::new(Vec2::ZERO, Vec2 { x: 2., y: -2. }, Vec2 { x: 0., y: 3. }, Vec2 { x: -2., y: -2. });
+    /// let [first, second] = arrowhead.to_triangles();
+    /// assert_eq!(first.area() + second.area(), 6.);
+    ///
+    /// // but one notched at its second vertex is split from there
+    /// let arrowhead = Quad::new(Vec2 { x: -2., y: -2. }, Vec2::ZERO, Vec2 { x: 2., y: -2. }, Vec2 { x: 0., y: 3. });
+    /// let [first, second] = arrowhead.to_triangles();
+    /// assert_eq!(first.coordinate1(), Vec2::ZERO);
+    /// assert_eq!(first.area() + second.area(), 6.);
+    /// ```
+    pub fn to_triangles(&self) -> [Triangle; 2] {
+        let [a, b, c, d] = self.vertices;
+        let diagonal = c - a;
+        if diagonal.perp_dot(b - a) * diagonal.perp_dot(d - a) < 0. {
+            [Triangle::new(a, b, c), Triangle::new(a, c, d)]
+        } else {
+            [Triangle::new(b, c, d), Triangle::new(b, d, a)]
+        }
+    }
+
+    /// Get the `centroid` of the [`Quad`], its center of mass
+    ///
+    /// A [`Quad`] with no area has the average of its vertices as its `centroid`.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// let square = Quad::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. });
+    /// assert_eq!(square.centroid(), Vec2::ONE);
+    ///
+    /// // the notch moves the centroid of the arrowhead towards its tip, past the average of its vertices
+    /// let arrowhead = Quad::new(Vec2::ZERO, Vec2 { x: 2., y: -2. }, Vec2 { x: 0., y: 3. }, Vec2 { x: -2., y: -2. });
+    /// assert_abs_diff_eq!(arrowhead.centroid(), Vec2 { x: 0., y: 1. / 3. }, epsilon = 1e-6);
+    ///
+    /// let flat = Quad::new(Vec2::ZERO, Vec2::X, Vec2 { x: 2., y: 0. }, Vec2 { x: 3., y: 0. });
+    /// assert_eq!(flat.centroid(), Vec2 { x: 1.5, y: 0. });
+    /// ```
+    pub fn centroid(&self) -> Vec2 {
+        let [first, second] = self.to_triangles();
+        let (first_area, second_area) = (first.area(), second.area());
+        let area = first_area + second_area;
+        if area == 0. {
+            return self.vertices.iter().sum::<Vec2>() / 4.;
+        }
+        (first.centroid() * first_area + second.centroid() * second_area) / area
+    }
+
+    /// Checks if a `point` is inside of the [`Quad`], including its edges
+    ///
+    /// The [`Quad`] may be non-convex, as long as its edges don't cross.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// // an arrowhead, pointing up with a notch at `(0., 0.)`
+    /// let arrowhead = Quad::new(Vec2::ZERO, Vec2 { x: 2., y: -2. }, Vec2 { x: 0., y: 3. }, Vec2 { x: -2., y: -2. });
+    ///
+    /// assert!(arrowhead.contains_point(Vec2 { x: 0., y: 1. }));
+    /// assert!(arrowhead.contains_point(Vec2 { x: 1., y: -0.5 }));
+    /// assert!(arrowhead.contains_point(Vec2::ZERO));
+    /// // inside of the notch, and so outside of the arrowhead
+    /// assert!(!arrowhead.contains_point(Vec2 { x: 0., y: -1. }));
+    /// assert!(!arrowhead.contains_point(Vec2 { x: 0., y: 3.5 }));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let [first, second] = self.to_triangles();
+        first.contains_point(point) || second.contains_point(point)
+    }
+
+    /// Get the point at `(u, v)` across the [`Quad`], by bilinear interpolation of its vertices
+    ///
+    /// The first vertex is at `(0., 0.)`, the second at `(1., 0.)`, the third at `(1., 1.)` and the fourth at
+    /// `(0., 1.)`, matching texture coordinates to the vertices. Both `u` and `v` are clamped between `0.` and
+    /// `1.`.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// let quad = Quad::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 3., y: 2. }, Vec2 { x: 1., y: 4. });
+    ///
+    /// // the corners
+    /// assert_eq!(quad.point_at(0., 0.), Vec2::ZERO);
+    /// assert_eq!(quad.point_at(1., 0.), Vec2 { x: 4., y: 0. });
+    /// assert_eq!(quad.point_at(1., 1.), Vec2 { x: 3., y: 2. });
+    /// assert_eq!(quad.point_at(0., 1.), Vec2 { x: 1., y: 4. });
+    ///
+    /// // the center is the average of the corners, and the edges run straight between them
+    /// assert_eq!(quad.point_at(0.5, 0.5), Vec2 { x: 2., y: 1.5 });
+    /// assert_eq!(quad.point_at(0.5, 0.), Vec2 { x: 2., y: 0. });
+    /// assert_eq!(quad.point_at(0., 0.5), Vec2 { x: 0.5, y: 2. });
+    ///
+    /// assert_eq!(quad.point_at(-1., 2.), quad.point_at(0., 1.));
+    /// ```
+    pub fn point_at(&self, u: f32, v: f32) -> Vec2 {
+        let (u, v) = (u.clamp(0., 1.), v.clamp(0., 1.));
+        let [a, b, c, d] = self.vertices;
+        a.lerp(b, u).lerp(d.lerp(c, u), v)
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`Quad`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// let quad = Quad::new(Vec2::ZERO, Vec2 { x: 4., y: -1. }, Vec2 { x: 3., y: 2. }, Vec2 { x: -1., y: 4. });
+    /// let rect = quad.bounding_box();
+    ///
+    /// assert_eq!(rect.min(), Vec2 { x: -1., y: -1. });
+    /// assert_eq!(rect.max(), Vec2 { x: 4., y: 4. });
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let [a, b, c, d] = self.vertices;
+        Rectangle::new_coordinates(a.min(b).min(c.min(d)), a.max(b).max(c.max(d)))
+    }
+
+    /// Creates a [`Polygon`] from the vertices of the [`Quad`], in the same order
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Quad;
+    ///
+    /// let quad = Quad::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. });
+    /// let polygon = quad.to_polygon();
+    ///
+    /// assert_eq!(polygon.coordinates(), &quad.vertices());
+    /// assert_eq!(polygon.area(), quad.area());
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn to_polygon(&self) -> Polygon {
+        Polygon::new(self.vertices.to_vec())
+    }
+}
+
+// ##########
+// From impls
+// ##########
+#[cfg(feature = "rectangle")]
+impl From<Rectangle> for Quad {
+    /// Creates a [`Quad`] from the four corners of a [`Rectangle`], wound counter-clockwise from the minimum
+    /// corner
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Quad, Rectangle, Winding};
+    ///
+    /// let quad = Quad::from(Rectangle::new(0., 0., 2., 1.));
+    ///
+    /// assert_eq!(quad.vertices(), [Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 1. }, Vec2 { x: 0., y: 1. }]);
+    /// assert_eq!(quad.winding(), Winding::CounterClockwise);
+    /// assert_eq!(Quad::from(Rectangle::new(2., 1., 0., 0.)), quad);
+    /// ```
+    fn from(rectangle: Rectangle) -> Self {
+        let min = rectangle.min().min(rectangle.max());
+        let max = rectangle.min().max(rectangle.max());
+        Self::new(
+            min,
+            Vec2 { x: max.x, y: min.y },
+            max,
+            Vec2 { x: min.x, y: max.y },
+        )
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the four vertices of two [`Quad`]s in order within `epsilon`, so the same [`Quad`] starting from
+    /// another vertex is not close
+    Quad { vertices[..] }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for Quad {
+    fn default() -> Self {
+        Self {
+            vertices: [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y],
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for Quad {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b, c, d] = self.vertices;
+        write!(f, "Quad {{ vertices: [{}, {}, {}, {}] }}", a, b, c, d)
+    }
+}
+
+// ##########
+// Helpers
+// ##########
+impl Quad {
+    // Which way the edges turn at each vertex, positive for counter-clockwise
+    fn turns(&self) -> [f32; 4] {
+        let [a, b, c, d] = self.vertices;
+        let edges = [b - a, c - b, d - c, a - d];
+        [
+            edges[3].perp_dot(edges[0]),
+            edges[0].perp_dot(edges[1]),
+            edges[1].perp_dot(edges[2]),
+            edges[2].perp_dot(edges[3]),
+        ]
+    }
+}
@@ -0,0 +1,450 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "polyline")]
+use crate::prelude::Polyline;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+// The deepest a curve is halved while measuring its length
+const MAX_LENGTH_DEPTH: u32 = 16;
+// The most segments a curve is flattened into
+#[cfg(feature = "polyline")]
+const MAX_FLATTEN_SEGMENTS: f32 = 1024.;
+
+/// Represents a single quadratic Bézier curve in 2d space
+///
+/// The curve leaves `start` heading towards `control`, and arrives at `end` heading away from `control`. It is
+/// parameterized by `t` from `0.` at `start` to `1.` at `end`, which is not proportional to the distance along
+/// the curve.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::QuadraticBezier;
+///
+/// // the parabola `y = x * x` between `x = -1.` and `x = 1.`
+/// let curve = QuadraticBezier::new(Vec2 { x: -1., y: 1. }, Vec2 { x: 0., y: -1. }, Vec2::ONE);
+///
+/// assert_eq!(curve.point_at(0.5), Vec2::ZERO);
+/// assert_eq!(curve.point_at(0.75), Vec2 { x: 0.5, y: 0.25 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticBezier {
+    start: Vec2,
+    control: Vec2,
+    end: Vec2,
+}
+
+// ##########
+// Constructors
+// ##########
+impl QuadraticBezier {
+    /// Creates a new [`QuadraticBezier`] from `start` to `end`, pulled towards `control`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// let curve = QuadraticBezier::new(Vec2::ZERO, Vec2::Y, Vec2::X);
+    ///
+    /// assert_eq!(curve.point_at(0.), Vec2::ZERO);
+    /// assert_eq!(curve.point_at(1.), Vec2::X);
+    /// ```
+    pub fn new(start: Vec2, control: Vec2, end: Vec2) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl QuadraticBezier {
+    /// Get the `start` of the [`QuadraticBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// assert_eq!(QuadraticBezier::new(Vec2::ZERO, Vec2::Y, Vec2::X).start(), Vec2::ZERO);
+    /// ```
+    pub fn start(&self) -> Vec2 {
+        self.start
+    }
+
+    /// Get the `control` point of the [`QuadraticBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// assert_eq!(QuadraticBezier::new(Vec2::ZERO, Vec2::Y, Vec2::X).control(), Vec2::Y);
+    /// ```
+    pub fn control(&self) -> Vec2 {
+        self.control
+    }
+
+    /// Get the `end` of the [`QuadraticBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// assert_eq!(QuadraticBezier::new(Vec2::ZERO, Vec2::Y, Vec2::X).end(), Vec2::X);
+    /// ```
+    pub fn end(&self) -> Vec2 {
+        self.end
+    }
+
+    /// Set the `start` of the [`QuadraticBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// let mut curve = QuadraticBezier::new(Vec2::ZERO, Vec2::Y, Vec2::X);
+    /// curve.set_start(Vec2::NEG_X);
+    ///
+    /// assert_eq!(curve.point_at(0.), Vec2::NEG_X);
+    /// ```
+    pub fn set_start(&mut self, start: Vec2) {
+        self.start = start;
+    }
+
+    /// Set the `control` point of the [`QuadraticBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// let mut curve = QuadraticBezier::new(Vec2::ZERO, Vec2::Y, Vec2 { x: 2., y: 0. });
+    /// curve.set_control(Vec2 { x: 1., y: -1. });
+    ///
+    /// assert_eq!(curve.point_at(0.5), Vec2 { x: 1., y: -0.5 });
+    /// ```
+    pub fn set_control(&mut self, control: Vec2) {
+        self.control = control;
+    }
+
+    /// Set the `end` of the [`QuadraticBezier`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// let mut curve = QuadraticBezier::new(Vec2::ZERO, Vec2::Y, Vec2::X);
+    /// curve.set_end(Vec2::ONE);
+    ///
+    /// assert_eq!(curve.point_at(1.), Vec2::ONE);
+    /// ```
+    pub fn set_end(&mut self, end: Vec2) {
+        self.end = end;
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl QuadraticBezier {
+    /// Get the point at `t` along the [`QuadraticBezier`], clamping `t` between `0.` and `1.`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// let curve = QuadraticBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 2. }, Vec2 { x: 2., y: 0. });
+    ///
+    /// assert_eq!(curve.point_at(0.5), Vec2 { x: 1., y: 1. });
+    /// assert_eq!(curve.point_at(-1.), curve.start());
+    /// assert_eq!(curve.point_at(2.), curve.end());
+    /// ```
+    pub fn point_at(&self, t: f32) -> Vec2 {
+        let t = t.clamp(0., 1.);
+        let s = 1. - t;
+        self.start * (s * s) + self.control * (2. * s * t) + self.end * (t * t)
+    }
+
+    /// Get the tangent at `t` along the [`QuadraticBezier`], clamping `t` between `0.` and `1.`
+    ///
+    /// The tangent is the derivative of the curve, so its length is how fast the point moves as `t` changes. It
+    /// is `(0., 0.)` where the curve stops, such as at `start` when `control` is on top of it.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// let curve = QuadraticBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 2. }, Vec2 { x: 2., y: 0. });
+    ///
+    /// assert_eq!(curve.tangent_at(0.), Vec2 { x: 2., y: 4. });
+    /// assert_eq!(curve.tangent_at(0.5), Vec2 { x: 2., y: 0. });
+    /// assert_eq!(curve.tangent_at(1.), Vec2 { x: 2., y: -4. });
+    /// ```
+    pub fn tangent_at(&self, t: f32) -> Vec2 {
+        let t = t.clamp(0., 1.);
+        (self.control - self.start) * (2. * (1. - t)) + (self.end - self.control) * (2. * t)
+    }
+
+    /// Splits the [`QuadraticBezier`] at `t` into two curves that together trace the same path, clamping `t`
+    /// between `0.` and `1.`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// let curve = QuadraticBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 3. }, Vec2 { x: 4., y: 1. });
+    /// let (first, second) = curve.split_at(0.3);
+    ///
+    /// // the pieces re-join where the curve was split, heading the same way
+    /// assert_eq!(first.start(), curve.start());
+    /// assert_eq!(first.end(), second.start());
+    /// assert_eq!(second.end(), curve.end());
+    /// assert_abs_diff_eq!(first.end(), curve.point_at(0.3), epsilon = 1e-6);
+    /// assert_abs_diff_eq!(first.tangent_at(1.).normalize(), second.tangent_at(0.).normalize(), epsilon = 1e-6);
+    ///
+    /// // and follow the original curve
+    /// for i in 0..=10 {
+    ///     let t = i as f32 / 10.;
+    ///     assert_abs_diff_eq!(first.point_at(t), curve.point_at(0.3 * t), epsilon = 1e-5);
+    ///     assert_abs_diff_eq!(second.point_at(t), curve.point_at(0.3 + 0.7 * t), epsilon = 1e-5);
+    /// }
+    /// ```
+    pub fn split_at(&self, t: f32) -> (Self, Self) {
+        let t = t.clamp(0., 1.);
+        let first_control = self.start.lerp(self.control, t);
+        let second_control = self.control.lerp(self.end, t);
+        let middle = first_control.lerp(second_control, t);
+        (
+            Self::new(self.start, first_control, middle),
+            Self::new(middle, second_control, self.end),
+        )
+    }
+
+    /// Get the length of the [`QuadraticBezier`], within `tolerance` of the true length
+    ///
+    /// The curve is halved until each piece is close enough to a straight line, to a depth of 16 halvings.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// // the parabola `y = x * x` between `x = -1.` and `x = 1.`
+    /// let curve = QuadraticBezier::new(Vec2 { x: -1., y: 1. }, Vec2 { x: 0., y: -1. }, Vec2::ONE);
+    /// let exact = 5f32.sqrt() + 2f32.asinh() / 2.;
+    ///
+    /// assert_abs_diff_eq!(curve.length(1e-3), exact, epsilon = 1e-3);
+    /// assert_abs_diff_eq!(curve.length(1e-5), exact, epsilon = 1e-5);
+    ///
+    /// // a straight curve is as long as its chord
+    /// assert_eq!(QuadraticBezier::new(Vec2::ZERO, Vec2::X, Vec2 { x: 2., y: 0. }).length(1e-3), 2.);
+    /// ```
+    pub fn length(&self, tolerance: f32) -> f32 {
+        subdivided_length(self, tolerance, MAX_LENGTH_DEPTH)
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`QuadraticBezier`]
+    ///
+    /// The curve may be well inside of the [`Rectangle`] around its `start`, `control` and `end`, as only the
+    /// turning points of the curve are used.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// // the parabola `y = x * x` between `x = -1.` and `x = 1.`
+    /// let curve = QuadraticBezier::new(Vec2 { x: -1., y: 1. }, Vec2 { x: 0., y: -1. }, Vec2::ONE);
+    /// let rect = curve.bounding_box();
+    ///
+    /// assert_eq!(rect.min(), Vec2 { x: -1., y: 0. });
+    /// assert_eq!(rect.max(), Vec2::ONE);
+    ///
+    /// // the control point reaches down to `y = -1.`, so its hull is twice as tall
+    /// assert!(rect.height() < curve.end().y - curve.control().y);
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let mut min = self.start.min(self.end);
+        let mut max = self.start.max(self.end);
+
+        // the curve only turns back where the tangent is `0.` along an axis
+        let denominator = self.start - 2. * self.control + self.end;
+        for t in [
+            (self.start.x - self.control.x) / denominator.x,
+            (self.start.y - self.control.y) / denominator.y,
+        ] {
+            if t > 0. && t < 1. {
+                let point = self.point_at(t);
+                min = min.min(point);
+                max = max.max(point);
+            }
+        }
+
+        Rectangle::new_coordinates(min, max)
+    }
+
+    /// Creates a [`Polyline`] following the [`QuadraticBezier`], never more than `tolerance` away from it
+    ///
+    /// The curve is split into equal steps of `t`, as few as keep every point of the [`Polyline`] within
+    /// `tolerance` of the curve and every point of the curve within `tolerance` of the [`Polyline`]. The curve
+    /// is never split into more than 1024 segments, which only limits a `tolerance` that is tiny next to the
+    /// size of the curve, or one that is not positive.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// let curve = QuadraticBezier::new(Vec2::ZERO, Vec2 { x: 1., y: 3. }, Vec2 { x: 4., y: 1. });
+    ///
+    /// for tolerance in [0.5, 0.1, 0.01] {
+    ///     let polyline = curve.flatten(tolerance);
+    ///
+    ///     assert_eq!(polyline.coordinate(0), Some(curve.start()));
+    ///     assert_eq!(polyline.coordinate(polyline.len() - 1), Some(curve.end()));
+    ///     for i in 0..=1000 {
+    ///         let point = curve.point_at(i as f32 / 1000.);
+    ///         assert!(polyline.distance_to_point(point).unwrap() <= tolerance + 1e-5);
+    ///     }
+    /// }
+    ///
+    /// // a straight curve needs a single segment
+    /// assert_eq!(QuadraticBezier::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. }).flatten(0.1).len(), 2);
+    /// ```
+    #[cfg(feature = "polyline")]
+    pub fn flatten(&self, tolerance: f32) -> Polyline {
+        // a piece of the curve spanning `h` of `t` strays at most `|start - 2 control + end| * h * h / 4.` from
+        // its chord, and the chord at most as far from the piece
+        let bend = (self.start - 2. * self.control + self.end).length();
+        let segments = if tolerance > 0. {
+            (bend / (4. * tolerance))
+                .sqrt()
+                .ceil()
+                .clamp(1., MAX_FLATTEN_SEGMENTS) as u32
+        } else {
+            MAX_FLATTEN_SEGMENTS as u32
+        };
+
+        let mut polyline: Polyline = (0..segments)
+            .map(|i| self.point_at(i as f32 / segments as f32))
+            .collect();
+        polyline.push(self.end);
+        polyline
+    }
+
+    /// Get the `t` of the point of the [`QuadraticBezier`] closest to `point`
+    ///
+    /// The curve is sampled to find a starting guess, which is then refined with Newton's method.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::QuadraticBezier;
+    ///
+    /// // the parabola `y = x * x` between `x = -1.` and `x = 1.`
+    /// let curve = QuadraticBezier::new(Vec2 { x: -1., y: 1. }, Vec2 { x: 0., y: -1. }, Vec2::ONE);
+    ///
+    /// assert_eq!(curve.closest_t(Vec2 { x: 0., y: -1. }), 0.5);
+    /// assert_eq!(curve.closest_t(Vec2 { x: 2., y: 1. }), 1.);
+    ///
+    /// assert_eq!(curve.closest_t(Vec2 { x: 3., y: 3. }), 1.);
+    ///
+    /// // on the curve, and off to the side where the normal at `(0.5, 0.25)` points
+    /// assert_abs_diff_eq!(curve.closest_t(Vec2 { x: 0.5, y: 0.25 }), 0.75, epsilon = 1e-5);
+    /// assert_abs_diff_eq!(curve.closest_t(Vec2 { x: 0.3, y: 0.45 }), 0.75, epsilon = 1e-5);
+    /// ```
+    pub fn closest_t(&self, point: Vec2) -> f32 {
+        const SAMPLES: u32 = 16;
+        const ITERATIONS: u32 = 8;
+
+        let distance = |t: f32| self.point_at(t).distance_squared(point);
+        let mut best = (1..=SAMPLES)
+            .map(|i| i as f32 / SAMPLES as f32)
+            .fold(0., |best, t| {
+                if distance(t) < distance(best) {
+                    t
+                } else {
+                    best
+                }
+            });
+
+        // Newton's method on the slope of the squared distance
+        let acceleration = 2. * (self.start - 2. * self.control + self.end);
+        for _ in 0..ITERATIONS {
+            let offset = self.point_at(best) - point;
+            let tangent = self.tangent_at(best);
+            let slope = tangent.length_squared() + offset.dot(acceleration);
+            if slope <= 0. {
+                break;
+            }
+            let next = (best - offset.dot(tangent) / slope).clamp(0., 1.);
+            if distance(next) > distance(best) {
+                break;
+            }
+            let converged = (next - best).abs() < 1e-7;
+            best = next;
+            if converged {
+                break;
+            }
+        }
+        best
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `start`, `control` and `end` points of two [`QuadraticBezier`] curves within `epsilon`, so a
+    /// reversed curve is not close
+    QuadraticBezier { start, control, end }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for QuadraticBezier {
+    fn default() -> Self {
+        Self {
+            start: Vec2::ZERO,
+            control: Vec2 { x: 0.5, y: 1. },
+            end: Vec2 { x: 1., y: 0. },
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for QuadraticBezier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "QuadraticBezier {{ start: {}, control: {}, end: {} }}",
+            self.start(),
+            self.control(),
+            self.end()
+        )
+    }
+}
+
+// ##########
+// Helpers
+// ##########
+// The length of `curve` within `tolerance`, which lies between its chord and the length of its control polygon
+fn subdivided_length(curve: &QuadraticBezier, tolerance: f32, depth: u32) -> f32 {
+    let chord = curve.start.distance(curve.end);
+    let hull = curve.start.distance(curve.control) + curve.control.distance(curve.end);
+    if hull - chord <= tolerance || depth == 0 {
+        return (2. * chord + hull) / 3.;
+    }
+    let (first, second) = curve.split_at(0.5);
+    subdivided_length(&first, tolerance / 2., depth - 1)
+        + subdivided_length(&second, tolerance / 2., depth - 1)
+}
@@ -1,527 +1,1048 @@
 use std::fmt::Display;
 
+#[cfg(feature = "integer")]
+use glam::IVec2;
+#[cfg(feature = "integer")]
+use glam::UVec2;
 use glam::Vec2;
 
-/// Represents a single [`Rectangle`] in 2d space
-pub struct Rectangle {
-    min: Vec2,
-    max: Vec2,
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "integer")]
+use crate::prelude::Rounding;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+// Defines the `Rectangle` for one precision, here with `f32` and in `crate::double` with `f64`, where the
+// shape and `Vec2` names in the bodies refer to the `f64` types
+macro_rules! impl_rectangle {
+    ($(#[$meta:meta])* $rectangle:ident, $sweep_hit:ident, $float:ident; $imports:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $rectangle {
+            min: Vec2,
+            max: Vec2,
+        }
+
+        /// The first contact found by [`Rectangle::sweep_against`]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $sweep_hit {
+            /// The fraction of the velocity travelled before contact, in `[0, 1]`
+            pub t: $float,
+            /// The normal of the face of the other [`Rectangle`] which was hit
+            pub normal: Vec2,
+        }
+
+        // ##########
+        // Constructors
+        // ##########
+        impl Rectangle {
+            /// Creates a new [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 1., 1.);
+            ///
+            /// assert_eq!(rect.x(), 0.);
+            /// assert_eq!(rect.y(), 0.);
+            /// assert_eq!(rect.max_x(), 1.);
+            /// assert_eq!(rect.max_y(), 1.);
+            /// ```
+            pub fn new(min_x: $float, min_y: $float, max_x: $float, max_y: $float) -> Self {
+                Self::new_coordinates(Vec2 { x: min_x, y: min_y }, Vec2 { x: max_x, y: max_y })
+            }
+
+            /// Creates a new [`Rectangle`] given `min` and `max` coordinates
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new_coordinates(Vec2::ZERO, Vec2::ONE);
+            ///
+            /// assert_eq!(rect.x(), 0.);
+            /// assert_eq!(rect.y(), 0.);
+            /// assert_eq!(rect.max_x(), 1.);
+            /// assert_eq!(rect.max_y(), 1.);
+            /// ```
+            pub fn new_coordinates(min: Vec2, max: Vec2) -> Self {
+                Self { min, max }
+            }
+
+            /// Creates a new [`Rectangle`] given a `min` coordinate and a `width` and a `height`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new_dimensions(Vec2::ZERO, 1., 1.);
+            ///
+            /// assert_eq!(rect.x(), 0.);
+            /// assert_eq!(rect.y(), 0.);
+            /// assert_eq!(rect.max_x(), 1.);
+            /// assert_eq!(rect.max_y(), 1.);
+            /// ```
+            pub fn new_dimensions(min: Vec2, width: $float, height: $float) -> Self {
+                Self::new_coordinates(min, Vec2::new(min.x + width, min.y + height))
+            }
+        }
+
+        // ##########
+        // Getters/Setters
+        // ##########
+        impl Rectangle {
+            /// Get the minimum coordinate for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 1., 1.);
+            /// let min = rect.min();
+            ///
+            /// assert_eq!(min, Vec2::ZERO);
+            /// ```
+            pub fn min(&self) -> Vec2 {
+                self.min
+            }
+
+            /// Get the maximum coordinate for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 1., 1.);
+            /// let max = rect.max();
+            ///
+            /// assert_eq!(max, Vec2::ONE);
+            /// ```
+            pub fn max(&self) -> Vec2 {
+                self.max
+            }
+
+            /// Sets the minimum coordinate for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// rect.set_min(Vec2::ONE);
+            ///
+            /// assert_eq!(rect.min(), Vec2::ONE);
+            /// ```
+            pub fn set_min(&mut self, min: Vec2) {
+                self.min = min;
+            }
+
+            /// Sets the maximum coordinate for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// rect.set_max(Vec2::ONE);
+            ///
+            /// assert_eq!(rect.max(), Vec2::ONE);
+            /// ```
+            pub fn set_max(&mut self, max: Vec2) {
+                self.max = max;
+            }
+        }
+
+        // ##########
+        // Attributes
+        // ##########
+        impl Rectangle {
+            /// Get the minimum `x` value for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let x = rect.x();
+            ///
+            /// assert_eq!(x, 0.);
+            /// ```
+            pub fn x(&self) -> $float {
+                self.min.x
+            }
+
+            /// Get the minimum `x` value for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let x = rect.min_x();
+            ///
+            /// assert_eq!(x, 0.);
+            /// ```
+            pub fn min_x(&self) -> $float {
+                self.min.x
+            }
+
+            /// Get the maximum `x` value for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let x = rect.max_x();
+            ///
+            /// assert_eq!(x, 2.);
+            /// ```
+            pub fn max_x(&self) -> $float {
+                self.max.x
+            }
+
+            /// Get the minimum `y` value for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let y = rect.y();
+            ///
+            /// assert_eq!(y, 0.);
+            /// ```
+            pub fn y(&self) -> $float {
+                self.min.y
+            }
+
+            /// Get the minimum `y` value for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let y = rect.y();
+            ///
+            /// assert_eq!(y, 0.);
+            /// ```
+            pub fn min_y(&self) -> $float {
+                self.min.y
+            }
+
+            /// Get the maximum `y` value for the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let y = rect.max_y();
+            ///
+            /// assert_eq!(y, 2.);
+            /// ```
+            pub fn max_y(&self) -> $float {
+                self.max.y
+            }
+
+            /// Get the `width` of the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let width = rect.width();
+            ///
+            /// assert_eq!(width, 2.);
+            /// ```
+            pub fn width(&self) -> $float {
+                self.max.x - self.min.x
+            }
+
+            /// Get the `height` of the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let height = rect.height();
+            ///
+            /// assert_eq!(height, 2.);
+            /// ```
+            pub fn height(&self) -> $float {
+                self.max.y - self.min.y
+            }
+
+            /// Get the `size` of the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let size = rect.size();
+            ///
+            /// assert_eq!(size.x, 2.);
+            /// assert_eq!(size.y, 2.);
+            /// ```
+            pub fn size(&self) -> Vec2 {
+                Vec2 {
+                    x: self.width(),
+                    y: self.height(),
+                }
+            }
+
+            /// Get the `position` of the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let position = rect.position();
+            ///
+            /// assert_eq!(position.x, 0.);
+            /// assert_eq!(position.y, 0.);
+            /// ```
+            pub fn position(&self) -> Vec2 {
+                self.min
+            }
+
+            /// Get the `center` of the [`Rectangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            /// let center = rect.center();
+            ///
+            /// assert_eq!(center.x, 1.);
+            /// assert_eq!(center.y, 1.);
+            /// ```
+            pub fn center(&self) -> Vec2 {
+                Vec2 {
+                    x: (self.min.x + self.max.x) * 0.5,
+                    y: (self.min.y + self.max.y) * 0.5,
+                }
+            }
+
+            /// Sets the minimum `x` coordinate for the [`Rectangle`] while maintaining the width
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// assert_eq!(rect.width(), 2.);
+            ///
+            /// rect.set_x(1.);
+            ///
+            /// assert_eq!(rect.width(), 2.);
+            /// ```
+            pub fn set_x(&mut self, x: $float) {
+                self.max.x += x - self.min.x;
+                self.min.x = x;
+            }
+
+            /// Sets the minimum `x` coordinate for the [`Rectangle`] while changing the width
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// assert_eq!(rect.width(), 2.);
+            ///
+            /// rect.set_min_x(1.);
+            ///
+            /// assert_eq!(rect.width(), 1.);
+            /// ```
+            pub fn set_min_x(&mut self, x: $float) {
+                self.min.x = x;
+            }
+
+            /// Sets the maximum `x` coordinate for the [`Rectangle`] while changing the width
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// assert_eq!(rect.width(), 2.);
+            ///
+            /// rect.set_max_x(1.);
+            ///
+            /// assert_eq!(rect.width(), 1.);
+            /// ```
+            pub fn set_max_x(&mut self, x: $float) {
+                self.max.x = x;
+            }
+
+            /// Sets the minimum `y` coordinate for the [`Rectangle`] while maintaining the height
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// assert_eq!(rect.height(), 2.);
+            ///
+            /// rect.set_y(1.);
+            ///
+            /// assert_eq!(rect.height(), 2.);
+            /// ```
+            pub fn set_y(&mut self, y: $float) {
+                self.max.y += y - self.min.y;
+                self.min.y = y;
+            }
+
+            /// Sets the minimum `y` coordinate for the [`Rectangle`] while changing the height
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// assert_eq!(rect.height(), 2.);
+            ///
+            /// rect.set_min_y(1.);
+            ///
+            /// assert_eq!(rect.height(), 1.);
+            /// ```
+            pub fn set_min_y(&mut self, y: $float) {
+                self.min.y = y;
+            }
+
+            /// Sets the maximum `y` coordinate for the [`Rectangle`] while changing the height
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// assert_eq!(rect.height(), 2.);
+            ///
+            /// rect.set_max_y(1.);
+            ///
+            /// assert_eq!(rect.height(), 1.);
+            /// ```
+            pub fn set_max_y(&mut self, y: $float) {
+                self.max.y = y;
+            }
+
+            /// Sets the maximum `x` coordinate for the [`Rectangle`] relative to the minimum `x` coordinate
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// rect.set_width(1.);
+            ///
+            /// assert_eq!(rect.max_x(), 1.);
+            /// ```
+            pub fn set_width(&mut self, width: $float) {
+                self.max.x = self.min.x + width;
+            }
+
+            /// Sets the maximum `y` coordinate for the [`Rectangle`] relative to the minimum `y` coordinate
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// rect.set_height(1.);
+            ///
+            /// assert_eq!(rect.max_y(), 1.);
+            /// ```
+            pub fn set_height(&mut self, height: $float) {
+                self.max.y = self.min.y + height;
+            }
+
+            /// Sets the maximum coordinate for the [`Rectangle`] relative to the minimum coordinate
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// rect.set_size(Vec2::ONE);
+            ///
+            /// assert_eq!(rect.max_x(), 1.);
+            /// assert_eq!(rect.max_y(), 1.);
+            /// ```
+            pub fn set_size(&mut self, size: Vec2) {
+                self.set_width(size.x);
+                self.set_height(size.y);
+            }
+
+            /// Sets the minimum coordinate for the [`Rectangle`] moving the maximum coordinate
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// rect.set_position(Vec2::ONE);
+            ///
+            /// assert_eq!(rect.max_x(), 3.);
+            /// assert_eq!(rect.max_y(), 3.);
+            /// ```
+            pub fn set_position(&mut self, position: Vec2) {
+                self.set_x(position.x);
+                self.set_y(position.y);
+            }
+
+            /// Sets the minimum and maximum coordinates for the [`Rectangle`] relative to the `center`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut rect = Rectangle::new(0., 0., 2., 2.);
+            /// rect.set_center(Vec2::ZERO);
+            ///
+            /// assert_eq!(rect.min_x(), -1.);
+            /// assert_eq!(rect.min_y(), -1.);
+            /// assert_eq!(rect.max_x(), 1.);
+            /// assert_eq!(rect.max_y(), 1.);
+            /// ```
+            pub fn set_center(&mut self, center: Vec2) {
+                let half_width = self.width() * 0.5;
+                let half_height = self.height() * 0.5;
+                self.set_position(Vec2 {
+                    x: center.x - half_width,
+                    y: center.y - half_height,
+                });
+            }
+        }
+
+        // ##########
+        // Operations
+        // ##########
+        impl Rectangle {
+            /// Get a [`Mesh`] of two counter-clockwise triangles covering the [`Rectangle`], see [`Mesh::from_rect`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mesh = Rectangle::new(0., 0., 2., 1.).to_mesh();
+            ///
+            /// assert_eq!(mesh.vertex_count(), 4);
+            /// assert_eq!(mesh.triangle_count(), 2);
+            /// ```
+            #[cfg(feature = "mesh")]
+            pub fn to_mesh(&self) -> Mesh {
+                Mesh::from_rect(self)
+            }
+
+        }
+
+        // ##########
+        // Sweeps
+        // ##########
+        impl Rectangle {
+            /// Get the first contact of the [`Rectangle`] moving by `velocity` with the stationary `other`
+            ///
+            /// Returns `None` when there is no contact before the end of `velocity`, when moving away from `other`,
+            /// and when only sliding along a touching face. When the entry times along both axes are equal, as when
+            /// hitting a corner exactly, the horizontal face is reported, with a normal along the y axis.
+            /// Rectangles which already overlap return `t = 0.` with the normal of least penetration (see
+            /// [`Penetrates`](crate::prelude::Penetrates)).
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let rect = Rectangle::new(0., 0., 2., 2.);
+            ///
+            /// // horizontal approach
+            /// let wall = Rectangle::new(4., 0., 6., 2.);
+            /// assert_eq!(rect.sweep_against(Vec2 { x: 4., y: 0. }, &wall), Some(SweepHit { t: 0.5, normal: Vec2::NEG_X }));
+            /// // not far enough, and moving away
+            /// assert_eq!(rect.sweep_against(Vec2 { x: 1., y: 0. }, &wall), None);
+            /// assert_eq!(rect.sweep_against(Vec2 { x: -4., y: 0. }, &wall), None);
+            ///
+            /// // vertical approach
+            /// let floor = Rectangle::new(-5., -4., 5., -2.);
+            /// assert_eq!(rect.sweep_against(Vec2 { x: 1., y: -8. }, &floor), Some(SweepHit { t: 0.25, normal: Vec2::Y }));
+            ///
+            /// // diagonal corner hit, reported as the horizontal face
+            /// let corner = Rectangle::new(3., 3., 5., 5.);
+            /// assert_eq!(rect.sweep_against(Vec2 { x: 2., y: 2. }, &corner), Some(SweepHit { t: 0.5, normal: Vec2::NEG_Y }));
+            ///
+            /// // sliding along a touching face
+            /// let ground = Rectangle::new(-5., -2., 5., 0.);
+            /// assert_eq!(rect.sweep_against(Vec2 { x: 3., y: 0. }, &ground), None);
+            /// assert_eq!(rect.sweep_against(Vec2 { x: 3., y: -1. }, &ground), Some(SweepHit { t: 0., normal: Vec2::Y }));
+            ///
+            /// // already overlapping without moving
+            /// let overlapping = Rectangle::new(1., 0.5, 5., 3.);
+            /// assert_eq!(rect.sweep_against(Vec2::ZERO, &overlapping), Some(SweepHit { t: 0., normal: Vec2::NEG_X }));
+            /// ```
+            pub fn sweep_against(&self, velocity: Vec2, other: &Rectangle) -> Option<$sweep_hit> {
+                let overlapping = self.min.x < other.max.x
+                    && other.min.x < self.max.x
+                    && self.min.y < other.max.y
+                    && other.min.y < self.max.y;
+                if overlapping {
+                    return self.least_penetration(other).map(|(normal, _)| $sweep_hit { t: 0., normal });
+                }
+
+                let (entry_x, exit_x) = sweep_axis((self.min.x, self.max.x), (other.min.x, other.max.x), velocity.x)?;
+                let (entry_y, exit_y) = sweep_axis((self.min.y, self.max.y), (other.min.y, other.max.y), velocity.y)?;
+
+                let entry = entry_x.max(entry_y);
+                let exit = exit_x.min(exit_y);
+                if !(0. ..=1.).contains(&entry) || entry > exit {
+                    return None;
+                }
+
+                let normal = if entry_y >= entry_x {
+                    Vec2 { x: 0., y: -velocity.y.signum() }
+                } else {
+                    Vec2 { x: -velocity.x.signum(), y: 0. }
+                };
+                Some($sweep_hit { t: entry, normal })
+            }
+
+            // The normal and depth of the shallowest way out of `other`, or `None` if they don't touch. The
+            // earliest of `+x`, `-x`, `+y`, and `-y` is preferred on ties.
+            pub(crate) fn least_penetration(&self, other: &Rectangle) -> Option<(Vec2, $float)> {
+                let sides = [
+                    (Vec2::X, other.max.x - self.min.x),
+                    (Vec2::NEG_X, self.max.x - other.min.x),
+                    (Vec2::Y, other.max.y - self.min.y),
+                    (Vec2::NEG_Y, self.max.y - other.min.y),
+                ];
+                if sides.iter().any(|(_, depth)| *depth < 0.) {
+                    return None;
+                }
+                Some(sides.into_iter().fold((Vec2::ZERO, $float::INFINITY), |shallowest, side| {
+                    if side.1 < shallowest.1 {
+                        side
+                    } else {
+                        shallowest
+                    }
+                }))
+            }
+        }
+
+        // ##########
+        // Default impl
+        // ##########
+        impl Default for Rectangle {
+            fn default() -> Self {
+                Self {
+                    min: Vec2::ZERO,
+                    max: Vec2::ONE,
+                }
+            }
+        }
+
+        // ##########
+        // Display impl
+        // ##########
+        impl Display for Rectangle {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    concat!(stringify!($rectangle), " {{ MinX: {}, MinY: {}, MaxX: {}, MaxY: {} }}"),
+                    self.min_x(),
+                    self.min_y(),
+                    self.max_x(),
+                    self.max_y(),
+                )
+            }
+        }
+        // ##########
+        // Helpers
+        // ##########
+        // The times the span `moving` starts and stops overlapping `other` along one axis, or `None` if it never
+        // does. Without any speed the span overlaps for all time or never.
+        fn sweep_axis(moving: ($float, $float), other: ($float, $float), speed: $float) -> Option<($float, $float)> {
+            if speed == 0. {
+                return (moving.1 >= other.0 && other.1 >= moving.0).then_some(($float::NEG_INFINITY, $float::INFINITY));
+            }
+            let (gap, through) = if speed > 0. {
+                (other.0 - moving.1, other.1 - moving.0)
+            } else {
+                (moving.0 - other.1, moving.1 - other.0)
+            };
+            Some((gap / speed.abs(), through / speed.abs()))
+        }
+    };
 }
-// ##########
-// Constructors
-// ##########
-impl Rectangle {
-    /// Creates a new [`Rectangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 1., 1.);
-    ///
-    /// assert_eq!(rect.x(), 0.);
-    /// assert_eq!(rect.y(), 0.);
-    /// assert_eq!(rect.max_x(), 1.);
-    /// assert_eq!(rect.max_y(), 1.);
-    /// ```
-    pub fn new(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Self {
-        Self::new_coordinates(Vec2 { x: min_x, y: min_y }, Vec2 { x: max_x, y: max_y })
-    }
+pub(crate) use impl_rectangle;
 
-    /// Creates a new [`Rectangle`] given `min` and `max` coordinates
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+impl_rectangle!(
+    /// Represents a single [`Rectangle`] in 2d space
     ///
-    /// let rect = Rectangle::new_coordinates(Vec2::ZERO, Vec2::ONE);
+    /// Like the other shapes with a fixed number of coordinates, a [`Rectangle`] is [`Copy`].
     ///
-    /// assert_eq!(rect.x(), 0.);
-    /// assert_eq!(rect.y(), 0.);
-    /// assert_eq!(rect.max_x(), 1.);
-    /// assert_eq!(rect.max_y(), 1.);
     /// ```
-    pub fn new_coordinates(min: Vec2, max: Vec2) -> Self {
-        Self { min, max }
-    }
-
-    /// Creates a new [`Rectangle`] given a `min` coordinate and a `width` and a `height`
-    ///
-    /// ```
-    /// use glam::Vec2;
     /// use shapes2d::prelude::Rectangle;
     ///
-    /// let rect = Rectangle::new_dimensions(Vec2::ZERO, 1., 1.);
+    /// fn area(rect: Rectangle) -> f32 {
+    ///     rect.width() * rect.height()
+    /// }
     ///
-    /// assert_eq!(rect.x(), 0.);
-    /// assert_eq!(rect.y(), 0.);
-    /// assert_eq!(rect.max_x(), 1.);
-    /// assert_eq!(rect.max_y(), 1.);
+    /// let rect = Rectangle::new(0., 0., 2., 3.);
+    /// assert_eq!(area(rect), 6.);
+    /// assert_eq!(area(rect), 6.);
+    /// assert_eq!(format!("{:?}", rect), "Rectangle { min: Vec2(0.0, 0.0), max: Vec2(2.0, 3.0) }");
     /// ```
-    pub fn new_dimensions(min: Vec2, width: f32, height: f32) -> Self {
-        Self::new_coordinates(min, Vec2::new(min.x + width, min.y + height))
-    }
-}
+    Rectangle,
+    SweepHit,
+    f32;
+    "use glam::Vec2;\nuse shapes2d::prelude::*;"
+);
 
 // ##########
-// Getters/Setters
+// approx impl
 // ##########
-impl Rectangle {
-    /// Get the minimum coordinate for the [`Rectangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 1., 1.);
-    /// let min = rect.min();
-    ///
-    /// assert_eq!(min, Vec2::ZERO);
-    /// ```
-    pub fn min(&self) -> Vec2 {
-        self.min
-    }
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `min` and `max` corners of two [`Rectangle`]s within `epsilon`
+    Rectangle { min, max }
+);
 
-    /// Get the maximum coordinate for the [`Rectangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 1., 1.);
-    /// let max = rect.max();
-    ///
-    /// assert_eq!(max, Vec2::ONE);
-    /// ```
-    pub fn max(&self) -> Vec2 {
-        self.max
-    }
+// ##########
+// IRect
+// ##########
+/// Represents a [`Rectangle`] of cells on an integer grid
+///
+/// The `min` cell is inside of the [`IRect`] and the `max` cell is not, so it covers the cells from `min.x`
+/// up to but excluding `max.x`, and likewise for `y`. An [`IRect`] with `max` not above `min` on either axis
+/// is empty.
+#[cfg(feature = "integer")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IRect {
+    min: IVec2,
+    max: IVec2,
+}
 
-    /// Sets the minimum coordinate for the [`Rectangle`]
+#[cfg(feature = "integer")]
+impl IRect {
+    /// Creates a new [`IRect`], excluding the cells at `max_x` and `max_y`
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// rect.set_min(Vec2::ONE);
+    /// let rect = IRect::new(0, 0, 3, 2);
     ///
-    /// assert_eq!(rect.min(), Vec2::ONE);
+    /// assert_eq!(rect.min(), IVec2::ZERO);
+    /// assert_eq!(rect.max(), IVec2 { x: 3, y: 2 });
     /// ```
-    pub fn set_min(&mut self, min: Vec2) {
-        self.min = min;
+    pub fn new(min_x: i32, min_y: i32, max_x: i32, max_y: i32) -> Self {
+        Self::new_coordinates(IVec2 { x: min_x, y: min_y }, IVec2 { x: max_x, y: max_y })
     }
 
-    /// Sets the maximum coordinate for the [`Rectangle`]
+    /// Creates a new [`IRect`] given the `min` cell and the exclusive `max` cell
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use glam::IVec2;
+    /// use glam::UVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// rect.set_max(Vec2::ONE);
+    /// let rect = IRect::new_coordinates(IVec2::ZERO, IVec2 { x: 3, y: 2 });
     ///
-    /// assert_eq!(rect.max(), Vec2::ONE);
+    /// assert_eq!(rect.size(), UVec2 { x: 3, y: 2 });
     /// ```
-    pub fn set_max(&mut self, max: Vec2) {
-        self.max = max;
+    pub fn new_coordinates(min: IVec2, max: IVec2) -> Self {
+        Self { min, max }
     }
-}
 
-// ##########
-// Attributes
-// ##########
-impl Rectangle {
-    /// Get the minimum `x` value for the [`Rectangle`]
+    /// Creates a new [`IRect`] given the `min` cell and a `width` and `height` in cells
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let x = rect.x();
+    /// let rect = IRect::new_dimensions(IVec2 { x: 1, y: 1 }, 3, 2);
     ///
-    /// assert_eq!(x, 0.);
+    /// assert_eq!(rect.max(), IVec2 { x: 4, y: 3 });
     /// ```
-    pub fn x(&self) -> f32 {
-        self.min.x
+    pub fn new_dimensions(min: IVec2, width: i32, height: i32) -> Self {
+        Self::new_coordinates(
+            min,
+            min + IVec2 {
+                x: width,
+                y: height,
+            },
+        )
     }
 
-    /// Get the minimum `x` value for the [`Rectangle`]
+    /// Get the `min` cell of the [`IRect`], the first cell inside of it
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let x = rect.min_x();
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// assert_eq!(x, 0.);
+    /// assert_eq!(IRect::new(1, 2, 3, 4).min(), IVec2 { x: 1, y: 2 });
     /// ```
-    pub fn min_x(&self) -> f32 {
-        self.min.x
+    pub fn min(&self) -> IVec2 {
+        self.min
     }
 
-    /// Get the maximum `x` value for the [`Rectangle`]
+    /// Get the `max` cell of the [`IRect`], the first cell past it
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let x = rect.max_x();
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// assert_eq!(x, 2.);
+    /// assert_eq!(IRect::new(1, 2, 3, 4).max(), IVec2 { x: 3, y: 4 });
     /// ```
-    pub fn max_x(&self) -> f32 {
-        self.max.x
+    pub fn max(&self) -> IVec2 {
+        self.max
     }
 
-    /// Get the minimum `y` value for the [`Rectangle`]
+    /// Set the `min` cell of the [`IRect`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use glam::IVec2;
+    /// use glam::UVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let y = rect.y();
+    /// let mut rect = IRect::new(1, 2, 3, 4);
+    /// rect.set_min(IVec2::ZERO);
     ///
-    /// assert_eq!(y, 0.);
+    /// assert_eq!(rect.size(), UVec2 { x: 3, y: 4 });
     /// ```
-    pub fn y(&self) -> f32 {
-        self.min.y
+    pub fn set_min(&mut self, min: IVec2) {
+        self.min = min;
     }
 
-    /// Get the minimum `y` value for the [`Rectangle`]
+    /// Set the exclusive `max` cell of the [`IRect`]
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use glam::IVec2;
+    /// use glam::UVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let y = rect.y();
+    /// let mut rect = IRect::new(1, 2, 3, 4);
+    /// rect.set_max(IVec2 { x: 5, y: 5 });
     ///
-    /// assert_eq!(y, 0.);
+    /// assert_eq!(rect.size(), UVec2 { x: 4, y: 3 });
     /// ```
-    pub fn min_y(&self) -> f32 {
-        self.min.y
+    pub fn set_max(&mut self, max: IVec2) {
+        self.max = max;
     }
 
-    /// Get the maximum `y` value for the [`Rectangle`]
+    /// Get the number of columns of cells in the [`IRect`], which is `0` when `max` is not past `min`
     ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let y = rect.max_y();
-    ///
-    /// assert_eq!(y, 2.);
-    /// ```
-    pub fn max_y(&self) -> f32 {
-        self.max.y
-    }
-
-    /// Get the `width` of the [`Rectangle`]
+    /// The width is a `u32` so that an [`IRect`] spanning every `i32` doesn't overflow.
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let width = rect.width();
-    ///
-    /// assert_eq!(width, 2.);
+    /// assert_eq!(IRect::new(1, 2, 4, 6).width(), 3);
+    /// assert_eq!(IRect::new(4, 2, 1, 6).width(), 0);
+    /// assert_eq!(IRect::new(i32::MIN, 0, i32::MAX, 1).width(), u32::MAX);
     /// ```
-    pub fn width(&self) -> f32 {
-        self.max.x - self.min.x
+    pub fn width(&self) -> u32 {
+        Self::span(self.min.x, self.max.x)
     }
 
-    /// Get the `height` of the [`Rectangle`]
+    /// Get the number of rows of cells in the [`IRect`], which is `0` when `max` is not past `min`
     ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// The height is a `u32` so that an [`IRect`] spanning every `i32` doesn't overflow.
     ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let height = rect.height();
+    /// ```
+    /// use shapes2d::prelude::IRect;
     ///
-    /// assert_eq!(height, 2.);
+    /// assert_eq!(IRect::new(1, 2, 4, 6).height(), 4);
+    /// assert_eq!(IRect::new(1, 6, 4, 2).height(), 0);
+    /// assert_eq!(IRect::new(0, i32::MIN, 1, i32::MAX).height(), u32::MAX);
     /// ```
-    pub fn height(&self) -> f32 {
-        self.max.y - self.min.y
+    pub fn height(&self) -> u32 {
+        Self::span(self.min.y, self.max.y)
     }
 
-    /// Get the `size` of the [`Rectangle`]
+    /// Get the width and height of the [`IRect`] in cells
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let size = rect.size();
+    /// use glam::UVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// assert_eq!(size.x, 2.);
-    /// assert_eq!(size.y, 2.);
+    /// assert_eq!(IRect::new(1, 2, 4, 6).size(), UVec2 { x: 3, y: 4 });
     /// ```
-    pub fn size(&self) -> Vec2 {
-        Vec2 {
+    pub fn size(&self) -> UVec2 {
+        UVec2 {
             x: self.width(),
             y: self.height(),
         }
     }
 
-    /// Get the `position` of the [`Rectangle`]
+    /// Get the number of cells in the [`IRect`], which is `0` when it is empty
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let position = rect.position();
+    /// use shapes2d::prelude::IRect;
     ///
-    /// assert_eq!(position.x, 0.);
-    /// assert_eq!(position.y, 0.);
+    /// assert_eq!(IRect::new(1, 2, 4, 6).area(), 12);
+    /// assert_eq!(IRect::new(1, 2, 1, 6).area(), 0);
+    /// assert_eq!(IRect::new(4, 2, 1, 6).area(), 0);
     /// ```
-    pub fn position(&self) -> Vec2 {
-        self.min
+    pub fn area(&self) -> u64 {
+        u64::from(self.width()) * u64::from(self.height())
     }
 
-    /// Get the `center` of the [`Rectangle`]
+    /// Checks if the [`IRect`] holds no cells
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let rect = Rectangle::new(0., 0., 2., 2.);
-    /// let center = rect.center();
+    /// use shapes2d::prelude::IRect;
     ///
-    /// assert_eq!(center.x, 1.);
-    /// assert_eq!(center.y, 1.);
+    /// assert!(!IRect::new(0, 0, 1, 1).is_empty());
+    /// assert!(IRect::new(0, 0, 0, 1).is_empty());
     /// ```
-    pub fn center(&self) -> Vec2 {
-        Vec2 {
-            x: (self.min.x + self.max.x) * 0.5,
-            y: (self.min.y + self.max.y) * 0.5,
-        }
+    pub fn is_empty(&self) -> bool {
+        self.max.x <= self.min.x || self.max.y <= self.min.y
     }
 
-    /// Sets the minimum `x` coordinate for the [`Rectangle`] while maintaining the width
+    /// Checks if the `cell` is inside of the [`IRect`], which includes the `min` cell but not the `max` cell
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// assert_eq!(rect.width(), 2.);
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// rect.set_x(1.);
+    /// let rect = IRect::new(0, 0, 3, 2);
     ///
-    /// assert_eq!(rect.width(), 2.);
+    /// assert!(rect.contains(IVec2 { x: 0, y: 0 }));
+    /// assert!(rect.contains(IVec2 { x: 2, y: 1 }));
+    /// assert!(!rect.contains(IVec2 { x: 3, y: 1 }));
+    /// assert!(!rect.contains(IVec2 { x: 2, y: 2 }));
+    /// assert!(!rect.contains(IVec2 { x: -1, y: 0 }));
     /// ```
-    pub fn set_x(&mut self, x: f32) {
-        self.max.x += x - self.min.x;
-        self.min.x = x;
+    pub fn contains(&self, cell: IVec2) -> bool {
+        cell.cmpge(self.min).all() && cell.cmplt(self.max).all()
     }
 
-    /// Sets the minimum `x` coordinate for the [`Rectangle`] while changing the width
+    /// Checks if the [`IRect`] shares any cells with `other`
     ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// Rectangles which only meet along an edge share no cells.
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// assert_eq!(rect.width(), 2.);
-    ///
-    /// rect.set_min_x(1.);
-    ///
-    /// assert_eq!(rect.width(), 1.);
     /// ```
-    pub fn set_min_x(&mut self, x: f32) {
-        self.min.x = x;
-    }
-
-    /// Sets the maximum `x` coordinate for the [`Rectangle`] while changing the width
+    /// use shapes2d::prelude::IRect;
     ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// let rect = IRect::new(0, 0, 3, 2);
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// assert_eq!(rect.width(), 2.);
-    ///
-    /// rect.set_max_x(1.);
-    ///
-    /// assert_eq!(rect.width(), 1.);
+    /// assert!(rect.intersects(&IRect::new(2, 1, 5, 5)));
+    /// assert!(!rect.intersects(&IRect::new(3, 0, 5, 2)));
     /// ```
-    pub fn set_max_x(&mut self, x: f32) {
-        self.max.x = x;
+    pub fn intersects(&self, other: &IRect) -> bool {
+        self.intersection(other).is_some()
     }
 
-    /// Sets the minimum `y` coordinate for the [`Rectangle`] while maintaining the height
+    /// Get the cells shared by the [`IRect`] and `other`, or `None` if they share none
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// assert_eq!(rect.height(), 2.);
+    /// let rect = IRect::new(0, 0, 3, 2);
     ///
-    /// rect.set_y(1.);
+    /// let shared = rect.intersection(&IRect::new(2, 1, 5, 5)).unwrap();
+    /// assert_eq!((shared.min(), shared.max()), (IVec2 { x: 2, y: 1 }, IVec2 { x: 3, y: 2 }));
+    /// assert_eq!(shared.area(), 1);
     ///
-    /// assert_eq!(rect.height(), 2.);
+    /// // meeting along an edge
+    /// assert!(rect.intersection(&IRect::new(3, 0, 5, 2)).is_none());
     /// ```
-    pub fn set_y(&mut self, y: f32) {
-        self.max.y += y - self.min.y;
-        self.min.y = y;
+    pub fn intersection(&self, other: &IRect) -> Option<IRect> {
+        let shared = IRect::new_coordinates(self.min.max(other.min), self.max.min(other.max));
+        (!shared.is_empty()).then_some(shared)
     }
 
-    /// Sets the minimum `y` coordinate for the [`Rectangle`] while changing the height
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// Get the smallest [`IRect`] holding the cells of both the [`IRect`] and `other`
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// assert_eq!(rect.height(), 2.);
+    /// An empty [`IRect`] holds no cells, so the union with it is a copy of the other.
     ///
-    /// rect.set_min_y(1.);
-    ///
-    /// assert_eq!(rect.height(), 1.);
     /// ```
-    pub fn set_min_y(&mut self, y: f32) {
-        self.min.y = y;
-    }
-
-    /// Sets the maximum `y` coordinate for the [`Rectangle`] while changing the height
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// assert_eq!(rect.height(), 2.);
+    /// let rect = IRect::new(0, 0, 3, 2);
     ///
-    /// rect.set_max_y(1.);
+    /// let union = rect.union(&IRect::new(5, 1, 6, 4));
+    /// assert_eq!((union.min(), union.max()), (IVec2 { x: 0, y: 0 }, IVec2 { x: 6, y: 4 }));
     ///
-    /// assert_eq!(rect.height(), 1.);
+    /// let union = rect.union(&IRect::new(10, 10, 10, 20));
+    /// assert_eq!((union.min(), union.max()), (rect.min(), rect.max()));
     /// ```
-    pub fn set_max_y(&mut self, y: f32) {
-        self.max.y = y;
+    pub fn union(&self, other: &IRect) -> IRect {
+        if other.is_empty() {
+            return IRect::new_coordinates(self.min, self.max);
+        }
+        if self.is_empty() {
+            return IRect::new_coordinates(other.min, other.max);
+        }
+        IRect::new_coordinates(self.min.min(other.min), self.max.max(other.max))
     }
 
-    /// Sets the maximum `x` coordinate for the [`Rectangle`] relative to the minimum `x` coordinate
+    /// Get the cells inside of the [`IRect`] row by row, from `min` to the cell before `max` on each axis
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// rect.set_width(1.);
+    /// let cells: Vec<IVec2> = IRect::new(0, 0, 3, 2).cells().collect();
     ///
-    /// assert_eq!(rect.max_x(), 1.);
+    /// assert_eq!(cells.len(), 6);
+    /// assert_eq!(cells[0], IVec2 { x: 0, y: 0 });
+    /// assert_eq!(cells[3], IVec2 { x: 0, y: 1 });
+    /// assert_eq!(cells[5], IVec2 { x: 2, y: 1 });
+    /// assert_eq!(IRect::new(0, 0, 0, 2).cells().count(), 0);
     /// ```
-    pub fn set_width(&mut self, width: f32) {
-        self.max.x = self.min.x + width;
+    pub fn cells(&self) -> impl Iterator<Item = IVec2> {
+        let (min, max) = (self.min, self.max);
+        (min.y..max.y).flat_map(move |y| (min.x..max.x).map(move |x| IVec2 { x, y }))
     }
 
-    /// Sets the maximum `y` coordinate for the [`Rectangle`] relative to the minimum `y` coordinate
+    /// Get the [`Rectangle`] covering the cells of the [`IRect`], from `min` to `max`
+    ///
+    /// The conversion is exact for coordinates within `±2^24`, beyond which `f32` can't hold every integer.
     ///
     /// ```
     /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use shapes2d::prelude::IRect;
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// rect.set_height(1.);
+    /// let rect = IRect::new(0, 0, 3, 2).to_rect();
     ///
-    /// assert_eq!(rect.max_y(), 1.);
+    /// assert_eq!((rect.min(), rect.max()), (Vec2::ZERO, Vec2 { x: 3., y: 2. }));
     /// ```
-    pub fn set_height(&mut self, height: f32) {
-        self.max.y = self.min.y + height;
+    pub fn to_rect(&self) -> Rectangle {
+        Rectangle::new_coordinates(self.min.as_vec2(), self.max.as_vec2())
     }
 
-    /// Sets the maximum coordinate for the [`Rectangle`] relative to the minimum coordinate
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// rect.set_size(Vec2::ONE);
-    ///
-    /// assert_eq!(rect.max_x(), 1.);
-    /// assert_eq!(rect.max_y(), 1.);
-    /// ```
-    pub fn set_size(&mut self, size: Vec2) {
-        self.set_width(size.x);
-        self.set_height(size.y);
+    // the cells from `min` up to `max`, computed without overflowing `i32`
+    fn span(min: i32, max: i32) -> u32 {
+        if max > min {
+            max.abs_diff(min)
+        } else {
+            0
+        }
     }
+}
 
-    /// Sets the minimum coordinate for the [`Rectangle`] moving the maximum coordinate
+#[cfg(feature = "integer")]
+impl Rectangle {
+    /// Get the [`IRect`] with `min` and `max` rounded by `rounding`
+    ///
+    /// Every cell the [`Rectangle`] touches is covered by rounding `min` with [`Rounding::Floor`] and `max` with
+    /// [`Rounding::Ceil`], see [`IRect::new_coordinates`].
     ///
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
+    /// use glam::IVec2;
+    /// use shapes2d::prelude::{IRect, Rectangle, Rounding};
     ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// rect.set_position(Vec2::ONE);
+    /// let rect = Rectangle::new(0.5, 0.2, 2.5, 1.8);
     ///
-    /// assert_eq!(rect.max_x(), 3.);
-    /// assert_eq!(rect.max_y(), 3.);
-    /// ```
-    pub fn set_position(&mut self, position: Vec2) {
-        self.set_x(position.x);
-        self.set_y(position.y);
-    }
-
-    /// Sets the minimum and maximum coordinates for the [`Rectangle`] relative to the `center`
+    /// let rounded = rect.to_irect(Rounding::Nearest);
+    /// assert_eq!((rounded.min(), rounded.max()), (IVec2 { x: 1, y: 0 }, IVec2 { x: 3, y: 2 }));
     ///
+    /// let covering = IRect::new_coordinates(Rounding::Floor.round(rect.min()), Rounding::Ceil.round(rect.max()));
+    /// assert_eq!((covering.min(), covering.max()), (IVec2 { x: 0, y: 0 }, IVec2 { x: 3, y: 2 }));
     /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Rectangle;
-    ///
-    /// let mut rect = Rectangle::new(0., 0., 2., 2.);
-    /// rect.set_center(Vec2::ZERO);
-    ///
-    /// assert_eq!(rect.min_x(), -1.);
-    /// assert_eq!(rect.min_y(), -1.);
-    /// assert_eq!(rect.max_x(), 1.);
-    /// assert_eq!(rect.max_y(), 1.);
-    /// ```
-    pub fn set_center(&mut self, center: Vec2) {
-        let half_width = self.width() * 0.5;
-        let half_height = self.height() * 0.5;
-        self.set_position(Vec2 {
-            x: center.x - half_width,
-            y: center.y - half_height,
-        });
+    pub fn to_irect(&self, rounding: Rounding) -> IRect {
+        IRect::new_coordinates(rounding.round(self.min), rounding.round(self.max))
     }
 }
 
-// ##########
-// Default impl
-// ##########
-impl Default for Rectangle {
+#[cfg(feature = "integer")]
+impl Default for IRect {
     fn default() -> Self {
         Self {
-            min: Vec2::ZERO,
-            max: Vec2::ONE,
+            min: IVec2::ZERO,
+            max: IVec2::ONE,
         }
     }
 }
 
-// ##########
-// Display impl
-// ##########
-impl Display for Rectangle {
+#[cfg(feature = "integer")]
+impl Display for IRect {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Rectangle {{ MinX: {}, MinY: {}, MaxX: {}, MaxY: {} }}",
-            self.min_x(),
-            self.min_y(),
-            self.max_x(),
-            self.max_y(),
+            "IRect {{ MinX: {}, MinY: {}, MaxX: {}, MaxY: {} }}",
+            self.min.x, self.min.y, self.max.x, self.max.y,
         )
     }
 }
@@ -0,0 +1,421 @@
+use std::{
+    f32::consts::{PI, TAU},
+    fmt::Display,
+};
+
+use glam::Vec2;
+
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single [`RegularPolygon`] in 2d space, with `sides` of equal length and equal angles between them
+///
+/// The vertices all lie on a circle of `circumradius` around the `center`. The first vertex is at the
+/// `rotation` angle, and the rest follow counter-clockwise. A [`RegularPolygon`] always has at least 3 `sides`.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use shapes2d::prelude::RegularPolygon;
+///
+/// // a hex tile with a flat top
+/// let hex = RegularPolygon::new(Vec2::ZERO, 1., 6, 0.);
+///
+/// assert_abs_diff_eq!(hex.apothem(), 3_f32.sqrt() / 2., epsilon = 1e-6);
+/// assert!(hex.contains_point(Vec2 { x: 0., y: 0.86 }));
+/// assert!(!hex.contains_point(Vec2 { x: 0., y: 0.87 }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegularPolygon {
+    center: Vec2,
+    circumradius: f32,
+    sides: u32,
+    rotation: f32,
+}
+
+// ##########
+// Constructors
+// ##########
+impl RegularPolygon {
+    /// Creates a new [`RegularPolygon`] with its first vertex at the `rotation` angle, clamping `sides` to at least 3
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let triangle = RegularPolygon::new(Vec2::ZERO, 1., 1, 0.);
+    ///
+    /// assert_eq!(triangle.sides(), 3);
+    /// ```
+    pub fn new(center: Vec2, circumradius: f32, sides: u32, rotation: f32) -> Self {
+        Self {
+            center,
+            circumradius,
+            sides: sides.max(3),
+            rotation,
+        }
+    }
+
+    /// Creates a new [`RegularPolygon`] from the `apothem`, the distance from the `center` to the middle of each side
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let square = RegularPolygon::new_apothem(Vec2::ZERO, 1., 4, std::f32::consts::FRAC_PI_4);
+    ///
+    /// assert_abs_diff_eq!(square.circumradius(), 2_f32.sqrt(), epsilon = 1e-6);
+    /// assert_abs_diff_eq!(square.side_length(), 2., epsilon = 1e-6);
+    /// ```
+    pub fn new_apothem(center: Vec2, apothem: f32, sides: u32, rotation: f32) -> Self {
+        let sides = sides.max(3);
+        Self::new(center, apothem / (PI / sides as f32).cos(), sides, rotation)
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl RegularPolygon {
+    /// Get the `center` of the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// assert_eq!(RegularPolygon::new(Vec2::ONE, 1., 5, 0.).center(), Vec2::ONE);
+    /// ```
+    pub fn center(&self) -> Vec2 {
+        self.center
+    }
+
+    /// Get the `circumradius` of the [`RegularPolygon`], the distance from its `center` to each vertex
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// assert_eq!(RegularPolygon::new(Vec2::ZERO, 2., 5, 0.).circumradius(), 2.);
+    /// ```
+    pub fn circumradius(&self) -> f32 {
+        self.circumradius
+    }
+
+    /// Get the number of `sides` of the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// assert_eq!(RegularPolygon::new(Vec2::ZERO, 1., 5, 0.).sides(), 5);
+    /// ```
+    pub fn sides(&self) -> u32 {
+        self.sides
+    }
+
+    /// Get the `rotation` of the [`RegularPolygon`], the angle from its `center` to its first vertex
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// assert_eq!(RegularPolygon::new(Vec2::ZERO, 1., 5, 0.5).rotation(), 0.5);
+    /// ```
+    pub fn rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// Set a new `center` for the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let mut polygon = RegularPolygon::new(Vec2::ZERO, 1., 5, 0.);
+    /// polygon.set_center(Vec2::ONE);
+    ///
+    /// assert_eq!(polygon.center(), Vec2::ONE);
+    /// ```
+    pub fn set_center(&mut self, center: Vec2) {
+        self.center = center;
+    }
+
+    /// Set a new `circumradius` for the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let mut polygon = RegularPolygon::new(Vec2::ZERO, 1., 5, 0.);
+    /// polygon.set_circumradius(3.);
+    ///
+    /// assert_eq!(polygon.circumradius(), 3.);
+    /// ```
+    pub fn set_circumradius(&mut self, circumradius: f32) {
+        self.circumradius = circumradius;
+    }
+
+    /// Set a new number of `sides` for the [`RegularPolygon`], clamped to at least 3
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let mut polygon = RegularPolygon::new(Vec2::ZERO, 1., 5, 0.);
+    /// polygon.set_sides(8);
+    /// assert_eq!(polygon.sides(), 8);
+    ///
+    /// polygon.set_sides(0);
+    /// assert_eq!(polygon.sides(), 3);
+    /// ```
+    pub fn set_sides(&mut self, sides: u32) {
+        self.sides = sides.max(3);
+    }
+
+    /// Set a new `rotation` for the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let mut polygon = RegularPolygon::new(Vec2::ZERO, 1., 5, 0.);
+    /// polygon.set_rotation(1.);
+    ///
+    /// assert_eq!(polygon.rotation(), 1.);
+    /// ```
+    pub fn set_rotation(&mut self, rotation: f32) {
+        self.rotation = rotation;
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl RegularPolygon {
+    /// Get the vertices of the [`RegularPolygon`], counter-clockwise from the one at the `rotation` angle
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let square = RegularPolygon::new(Vec2::ONE, 2., 4, 0.);
+    /// let vertices: Vec<Vec2> = square.vertices().collect();
+    /// assert_eq!(vertices.len(), 4);
+    /// assert_eq!(vertices[0], Vec2 { x: 3., y: 1. });
+    /// assert_abs_diff_eq!(vertices[1], Vec2 { x: 1., y: 3. }, epsilon = 1e-6);
+    ///
+    /// // rotating turns the first vertex away from angle 0. by the rotation
+    /// let turned = RegularPolygon::new(Vec2::ONE, 2., 4, FRAC_PI_2 / 2.);
+    /// assert!(turned.vertices().next().unwrap().distance(Vec2::ONE + 2_f32.sqrt()) < 1e-6);
+    /// ```
+    pub fn vertices(&self) -> impl Iterator<Item = Vec2> {
+        let Self {
+            center,
+            circumradius,
+            sides,
+            rotation,
+        } = *self;
+        (0..sides).map(move |i| {
+            center + Vec2::from_angle(rotation + i as f32 * TAU / sides as f32) * circumradius
+        })
+    }
+
+    /// Get the length of each side of the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// assert_abs_diff_eq!(RegularPolygon::new(Vec2::ZERO, 1., 6, 0.).side_length(), 1., epsilon = 1e-6);
+    /// ```
+    pub fn side_length(&self) -> f32 {
+        2. * self.circumradius * (PI / self.sides as f32).sin()
+    }
+
+    /// Get the `apothem` of the [`RegularPolygon`], the distance from its `center` to the middle of each side
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// assert_abs_diff_eq!(RegularPolygon::new(Vec2::ZERO, 2., 3, 0.).apothem(), 1., epsilon = 1e-6);
+    /// ```
+    pub fn apothem(&self) -> f32 {
+        self.circumradius * (PI / self.sides as f32).cos()
+    }
+
+    /// Get the `area` of the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, RegularPolygon};
+    ///
+    /// let hex = RegularPolygon::new(Vec2 { x: 3., y: -2. }, 2., 6, 0.3);
+    /// let shoelace = Polygon::new(hex.vertices().collect()).area();
+    ///
+    /// assert_abs_diff_eq!(hex.area(), 6. * 3_f32.sqrt(), epsilon = 1e-5);
+    /// assert_abs_diff_eq!(hex.area(), shoelace, epsilon = 1e-5);
+    /// ```
+    pub fn area(&self) -> f32 {
+        0.5 * self.sides as f32
+            * self.circumradius
+            * self.circumradius
+            * (TAU / self.sides as f32).sin()
+    }
+
+    /// Get the `perimeter` of the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// assert_abs_diff_eq!(RegularPolygon::new(Vec2::ZERO, 1., 6, 0.).perimeter(), 6., epsilon = 1e-5);
+    /// ```
+    pub fn perimeter(&self) -> f32 {
+        self.sides as f32 * self.side_length()
+    }
+
+    /// Checks if `point` is inside or on the edge of the [`RegularPolygon`]
+    ///
+    /// Only the side facing the angle of `point` is checked, instead of every side.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, RegularPolygon};
+    ///
+    /// let heptagon = RegularPolygon::new(Vec2 { x: 1., y: 2. }, 3., 7, 0.4);
+    /// let polygon = Polygon::new(heptagon.vertices().collect());
+    ///
+    /// for x in -20..=20 {
+    ///     for y in -20..=20 {
+    ///         let point = Vec2 { x: 1. + x as f32 * 0.17, y: 2. + y as f32 * 0.17 };
+    ///         assert_eq!(heptagon.contains_point(point), polygon.contains_point(point));
+    ///     }
+    /// }
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let offset = point - self.center;
+        let step = TAU / self.sides as f32;
+        let angle = (offset.y.atan2(offset.x) - self.rotation).rem_euclid(TAU);
+        let side = (angle / step).floor();
+        let normal = Vec2::from_angle(self.rotation + (side + 0.5) * step);
+        offset.dot(normal) <= self.apothem()
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`RegularPolygon`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let rect = RegularPolygon::new(Vec2::ZERO, 2., 4, 0.).bounding_box();
+    ///
+    /// assert_abs_diff_eq!(rect.min(), Vec2 { x: -2., y: -2. }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(rect.max(), Vec2 { x: 2., y: 2. }, epsilon = 1e-6);
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let (min, max) = self.vertices().fold(
+            (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+            |(min, max), vertex| (min.min(vertex), max.max(vertex)),
+        );
+        Rectangle::new_coordinates(min, max)
+    }
+
+    /// Creates a [`Polygon`] from the vertices of the [`RegularPolygon`], wound counter-clockwise
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{RegularPolygon, Winding};
+    ///
+    /// let polygon = RegularPolygon::new(Vec2::ZERO, 1., 5, 0.).to_polygon();
+    ///
+    /// assert_eq!(polygon.len(), 5);
+    /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn to_polygon(&self) -> Polygon {
+        self.vertices().collect()
+    }
+
+    /// Get a [`Mesh`] covering the [`RegularPolygon`] with a fan of triangles around its `center`
+    ///
+    /// The `center` is the first vertex, followed by the vertices of the [`RegularPolygon`].
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::RegularPolygon;
+    ///
+    /// let hex = RegularPolygon::new(Vec2::ZERO, 1., 6, 0.);
+    /// let mesh = hex.to_mesh();
+    ///
+    /// assert_eq!(mesh.vertex_count(), 7);
+    /// assert_eq!(mesh.triangle_count(), 6);
+    /// assert_abs_diff_eq!(mesh.signed_area(), hex.area(), epsilon = 1e-5);
+    /// ```
+    #[cfg(feature = "mesh")]
+    pub fn to_mesh(&self) -> Mesh {
+        let sides = self.sides;
+        let positions = std::iter::once(self.center)
+            .chain(self.vertices())
+            .collect();
+        let indices = (0..sides)
+            .flat_map(|i| [0, i + 1, (i + 1) % sides + 1])
+            .collect();
+        Mesh::new(positions, indices).unwrap_or_else(Mesh::empty)
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the `center`, `circumradius` and `rotation` of two [`RegularPolygon`]s within `epsilon`
+    ///
+    /// [`RegularPolygon`]s with a different number of `sides` are never close.
+    RegularPolygon { center, circumradius, rotation } exact { sides }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for RegularPolygon {
+    fn default() -> Self {
+        Self {
+            center: Vec2::ZERO,
+            circumradius: 1.,
+            sides: 6,
+            rotation: 0.,
+        }
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for RegularPolygon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RegularPolygon {{ center: {}, circumradius: {}, sides: {}, rotation: {} }}",
+            self.center, self.circumradius, self.sides, self.rotation
+        )
+    }
+}
@@ -0,0 +1,469 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single [`RoundedRectangle`] in 2d space, a [`Rectangle`] with each corner rounded off by a
+/// quarter circle
+///
+/// The `radii` of the corners go counter-clockwise from the corner at `min`: `min`, `(max.x, min.y)`, `max`,
+/// then `(min.x, max.y)`. Each radius is clamped between `0.` and half of the smaller side of the [`Rectangle`],
+/// so the rounded corners never overlap. A radius of `0.` leaves a square corner.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+///
+/// // a tab, rounded only along its top
+/// let tab = RoundedRectangle::new(Rectangle::new(0., 0., 4., 2.), [0., 0., 1., 1.]);
+///
+/// assert!(tab.contains_point(Vec2::ZERO));
+/// assert!(tab.contains_point(Vec2 { x: 4., y: 0. }));
+/// assert!(!tab.contains_point(Vec2 { x: 4., y: 2. }));
+/// assert!(!tab.contains_point(Vec2 { x: 0., y: 2. }));
+/// ```
+///
+/// Without rounding a [`RoundedRectangle`] acts exactly like its [`Rectangle`], and fully rounded it acts like a
+/// [`Capsule`](crate::prelude::Capsule), or a [`Circle`](crate::prelude::Circle) when it is square.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Capsule, Circle, Contains, Rectangle, RoundedRectangle, Shape2d, SignedDistance};
+///
+/// let rect = Rectangle::new(0., 0., 4., 2.);
+/// let square = RoundedRectangle::new_uniform(rect, 0.);
+/// let stadium = RoundedRectangle::new_uniform(rect, 10.);
+/// let capsule = Capsule::new(Vec2 { x: 1., y: 1. }, Vec2 { x: 3., y: 1. }, 1.);
+/// let disc = RoundedRectangle::new_uniform(Rectangle::new(0., 0., 2., 2.), 1.);
+/// let circle = Circle::new(Vec2::ONE, 1.);
+///
+/// assert_eq!(Shape2d::area(&square), Shape2d::area(&rect));
+/// assert_eq!(Shape2d::perimeter(&square), Shape2d::perimeter(&rect));
+/// assert_abs_diff_eq!(Shape2d::area(&stadium), Shape2d::area(&capsule), epsilon = 1e-5);
+/// assert_abs_diff_eq!(Shape2d::perimeter(&stadium), Shape2d::perimeter(&capsule), epsilon = 1e-5);
+/// assert_abs_diff_eq!(Shape2d::area(&disc), Shape2d::area(&circle), epsilon = 1e-5);
+/// for x in -4..=12 {
+///     for y in -4..=8 {
+///         let point = Vec2 { x: x as f32 * 0.4, y: y as f32 * 0.4 };
+///         assert_eq!(square.contains(point), rect.contains(point));
+///         assert_eq!(square.signed_distance(point), rect.signed_distance(point));
+///         assert_eq!(stadium.contains(point), capsule.contains(point));
+///         assert_abs_diff_eq!(stadium.signed_distance(point), capsule.signed_distance(point), epsilon = 1e-5);
+///         assert_eq!(disc.contains(point), circle.contains(point));
+///         assert_abs_diff_eq!(disc.signed_distance(point), circle.signed_distance(point), epsilon = 1e-5);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedRectangle {
+    rectangle: Rectangle,
+    radii: [f32; 4],
+}
+
+// ##########
+// Constructors
+// ##########
+impl RoundedRectangle {
+    /// Creates a new [`RoundedRectangle`] with a radius for each corner, clamped to fit the [`Rectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rounded = RoundedRectangle::new(Rectangle::new(0., 0., 4., 2.), [0.5, -1., 3., 1.]);
+    ///
+    /// assert_eq!(rounded.radii(), [0.5, 0., 1., 1.]);
+    /// ```
+    pub fn new(rectangle: Rectangle, radii: [f32; 4]) -> Self {
+        let limit = rectangle.width().min(rectangle.height()) * 0.5;
+        Self {
+            rectangle,
+            radii: radii.map(|radius| radius.clamp(0., limit.max(0.))),
+        }
+    }
+
+    /// Creates a new [`RoundedRectangle`] with the same `radius` for every corner
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rounded = RoundedRectangle::new_uniform(Rectangle::new(0., 0., 4., 2.), 0.25);
+    ///
+    /// assert_eq!(rounded.radii(), [0.25; 4]);
+    /// ```
+    pub fn new_uniform(rectangle: Rectangle, radius: f32) -> Self {
+        Self::new(rectangle, [radius; 4])
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl RoundedRectangle {
+    /// Get the [`Rectangle`] the [`RoundedRectangle`] rounds the corners of
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    ///
+    /// assert_eq!(RoundedRectangle::new_uniform(rect, 1.).rectangle(), rect);
+    /// ```
+    pub fn rectangle(&self) -> Rectangle {
+        self.rectangle
+    }
+
+    /// Get the `radii` of the corners, counter-clockwise from the corner at `min`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rounded = RoundedRectangle::new(Rectangle::new(0., 0., 4., 2.), [0.1, 0.2, 0.3, 0.4]);
+    ///
+    /// assert_eq!(rounded.radii(), [0.1, 0.2, 0.3, 0.4]);
+    /// ```
+    pub fn radii(&self) -> [f32; 4] {
+        self.radii
+    }
+
+    /// Set a new [`Rectangle`] for the [`RoundedRectangle`], clamping the `radii` to fit it
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let mut rounded = RoundedRectangle::new_uniform(Rectangle::new(0., 0., 4., 4.), 2.);
+    /// rounded.set_rectangle(Rectangle::new(0., 0., 2., 1.));
+    ///
+    /// assert_eq!(rounded.radii(), [0.5; 4]);
+    /// ```
+    pub fn set_rectangle(&mut self, rectangle: Rectangle) {
+        *self = Self::new(rectangle, self.radii);
+    }
+
+    /// Set new `radii` for the corners of the [`RoundedRectangle`], clamped to fit its [`Rectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let mut rounded = RoundedRectangle::new_uniform(Rectangle::new(0., 0., 4., 2.), 0.);
+    /// rounded.set_radii([1., 2., 0., 0.5]);
+    ///
+    /// assert_eq!(rounded.radii(), [1., 1., 0., 0.5]);
+    /// ```
+    pub fn set_radii(&mut self, radii: [f32; 4]) {
+        *self = Self::new(self.rectangle, radii);
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl RoundedRectangle {
+    /// Get the `area` of the [`RoundedRectangle`], the area of its [`Rectangle`] less what the corners cut off
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    ///
+    /// assert_eq!(RoundedRectangle::new_uniform(rect, 0.).area(), 8.);
+    /// // two half circles and a square
+    /// assert_abs_diff_eq!(RoundedRectangle::new_uniform(rect, 1.).area(), (PI + 4.), epsilon = 1e-6);
+    /// ```
+    pub fn area(&self) -> f32 {
+        let cut: f32 = self.radii.iter().map(|radius| radius * radius).sum();
+        self.rectangle.width() * self.rectangle.height() - (1. - std::f32::consts::FRAC_PI_4) * cut
+    }
+
+    /// Get the `perimeter` of the [`RoundedRectangle`], its straight sides plus its rounded corners
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::TAU;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    ///
+    /// assert_eq!(RoundedRectangle::new_uniform(rect, 0.).perimeter(), 12.);
+    /// assert_abs_diff_eq!(RoundedRectangle::new_uniform(rect, 1.).perimeter(), (TAU + 4.), epsilon = 1e-6);
+    /// ```
+    pub fn perimeter(&self) -> f32 {
+        let rounded: f32 = self.radii.iter().sum();
+        2. * (self.rectangle.width() + self.rectangle.height())
+            - (2. - std::f32::consts::FRAC_PI_2) * rounded
+    }
+
+    /// Get the `centroid` of the [`RoundedRectangle`], which moves away from the more rounded corners
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    /// let stadium = RoundedRectangle::new_uniform(rect, 1.).centroid();
+    /// assert_abs_diff_eq!(stadium, Vec2 { x: 2., y: 1. }, epsilon = 1e-6);
+    ///
+    /// let tab = RoundedRectangle::new(rect, [0., 0., 1., 1.]).centroid();
+    /// assert_abs_diff_eq!(tab.x, 2., epsilon = 1e-6);
+    /// assert!(tab.y < 1.);
+    ///
+    /// // compare against the centroid of a fine outline
+    /// let outline = RoundedRectangle::new(rect, [0., 0., 1., 1.]).to_polygon(256);
+    /// assert_abs_diff_eq!(outline.centroid().unwrap(), tab, epsilon = 1e-3);
+    /// ```
+    pub fn centroid(&self) -> Vec2 {
+        let center = self.rectangle.center();
+        let mut moment = center * self.rectangle.width() * self.rectangle.height();
+        for (corner, radius) in self.corners().into_iter().zip(self.radii) {
+            // the piece cut from a corner is its square less a quarter circle
+            let cut = (1. - std::f32::consts::FRAC_PI_4) * radius * radius;
+            let inset =
+                radius * (10. - 3. * std::f32::consts::PI) / (12. - 3. * std::f32::consts::PI);
+            moment -= (corner + (center - corner).signum() * inset) * cut;
+        }
+        moment / self.area()
+    }
+
+    /// Checks if `point` is inside or on the edge of the [`RoundedRectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rounded = RoundedRectangle::new_uniform(Rectangle::new(0., 0., 4., 4.), 1.);
+    ///
+    /// assert!(rounded.contains_point(Vec2 { x: 2., y: 0. }));
+    /// assert!(rounded.contains_point(Vec2 { x: 0.3, y: 0.3 }));
+    /// // inside the square corner of the rectangle, but outside the rounding
+    /// assert!(!rounded.contains_point(Vec2 { x: 0.2, y: 0.2 }));
+    /// assert!(!rounded.contains_point(Vec2 { x: 3.8, y: 3.8 }));
+    /// assert!(!rounded.contains_point(Vec2 { x: 2., y: 4.1 }));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let (min, max) = (self.rectangle.min(), self.rectangle.max());
+        if point.cmplt(min).any() || point.cmpgt(max).any() {
+            return false;
+        }
+        let (center, radius) = self.corner(point);
+        let offset = point - center;
+        // only the square beyond the corner's circle center is rounded off
+        let beyond = (offset * (point - self.rectangle.center()).signum()).cmpgt(Vec2::ZERO);
+        !beyond.all() || offset.length_squared() <= radius * radius
+    }
+
+    /// Get the signed distance from `point` to the edge of the [`RoundedRectangle`], negative inside
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rounded = RoundedRectangle::new(Rectangle::new(0., 0., 4., 4.), [1., 0., 0., 0.]);
+    ///
+    /// assert_eq!(rounded.signed_distance(Vec2 { x: 2., y: 5. }), 1.);
+    /// assert_eq!(rounded.signed_distance(Vec2 { x: 2., y: 3. }), -1.);
+    /// assert_eq!(rounded.signed_distance(Vec2 { x: 5., y: -1. }), 2_f32.sqrt());
+    /// assert_abs_diff_eq!(rounded.signed_distance(Vec2 { x: -2., y: -3. }), 4., epsilon = 1e-6);
+    /// ```
+    pub fn signed_distance(&self, point: Vec2) -> f32 {
+        let (_, radius) = self.corner(point);
+        let half_size = self.rectangle.size() * 0.5;
+        let q = (point - self.rectangle.center()).abs() - half_size + radius;
+        q.max(Vec2::ZERO).length() + q.max_element().min(0.) - radius
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`RoundedRectangle`], which is its [`Rectangle`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    ///
+    /// assert_eq!(RoundedRectangle::new_uniform(rect, 1.).bounding_box(), rect);
+    /// ```
+    pub fn bounding_box(&self) -> Rectangle {
+        self.rectangle
+    }
+
+    /// Creates a [`Polygon`] approximating the [`RoundedRectangle`] with `corner_segments` edges around each
+    /// rounded corner, wound counter-clockwise from the corner at `min`
+    ///
+    /// A square corner is a single coordinate, and coordinates where two corners meet are not repeated.
+    /// A `corner_segments` below 1 is clamped to 1.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle, Winding};
+    ///
+    /// let rounded = RoundedRectangle::new(Rectangle::new(0., 0., 4., 2.), [0., 0., 1., 1.]);
+    /// let polygon = rounded.to_polygon(16);
+    ///
+    /// // two square corners, and two rounded corners
+    /// assert_eq!(polygon.len(), 2 + 17 + 17);
+    /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+    /// assert_abs_diff_eq!(polygon.area(), rounded.area(), epsilon = 0.01);
+    ///
+    /// let square = RoundedRectangle::new_uniform(Rectangle::new(0., 0., 4., 2.), 0.).to_polygon(16);
+    /// assert_eq!(square.coordinates(), &[Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 4., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn to_polygon(&self, corner_segments: u32) -> Polygon {
+        Polygon::new(self.outline(corner_segments))
+    }
+
+    /// Get a [`Mesh`] approximating the [`RoundedRectangle`] with a fan of triangles around the center of its
+    /// [`Rectangle`]
+    ///
+    /// The center is the first vertex, followed by the coordinates of
+    /// [`to_polygon`](RoundedRectangle::to_polygon).
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, RoundedRectangle};
+    ///
+    /// let rounded = RoundedRectangle::new_uniform(Rectangle::new(0., 0., 4., 2.), 0.5);
+    /// let mesh = rounded.to_mesh(8);
+    ///
+    /// assert_eq!(mesh.vertex_count(), 37);
+    /// assert_eq!(mesh.triangle_count(), 36);
+    /// assert_abs_diff_eq!(mesh.signed_area(), rounded.area(), epsilon = 0.01);
+    /// ```
+    #[cfg(feature = "mesh")]
+    pub fn to_mesh(&self, corner_segments: u32) -> Mesh {
+        let outline = self.outline(corner_segments);
+        let count = outline.len() as u32;
+        let positions = std::iter::once(self.rectangle.center())
+            .chain(outline)
+            .collect();
+        let indices = (0..count)
+            .flat_map(|i| [0, i + 1, (i + 1) % count + 1])
+            .collect();
+        Mesh::new(positions, indices).unwrap_or_else(Mesh::empty)
+    }
+
+    // The corners of the rectangle, counter-clockwise from `min`
+    fn corners(&self) -> [Vec2; 4] {
+        let (min, max) = (self.rectangle.min(), self.rectangle.max());
+        [
+            min,
+            Vec2 { x: max.x, y: min.y },
+            max,
+            Vec2 { x: min.x, y: max.y },
+        ]
+    }
+
+    // The center of the circle rounding the corner nearest `point`, and its radius
+    fn corner(&self, point: Vec2) -> (Vec2, f32) {
+        let center = self.rectangle.center();
+        let index = match (point.x >= center.x, point.y >= center.y) {
+            (false, false) => 0,
+            (true, false) => 1,
+            (true, true) => 2,
+            (false, true) => 3,
+        };
+        let (corner, radius) = (self.corners()[index], self.radii[index]);
+        (corner - (corner - center).signum() * radius, radius)
+    }
+
+    // The coordinates around the edge, counter-clockwise from the corner at `min`
+    #[cfg(any(feature = "polygon", feature = "mesh"))]
+    fn outline(&self, corner_segments: u32) -> Vec<Vec2> {
+        let corner_segments = corner_segments.max(1);
+        // each corner turns a quarter from the side before it to the side after it
+        let sides = [-Vec2::X, -Vec2::Y, Vec2::X, Vec2::Y, -Vec2::X];
+        let tolerance = f32::EPSILON * self.rectangle.size().max_element();
+
+        let mut outline: Vec<Vec2> = Vec::new();
+        for (index, (corner, radius)) in self.corners().into_iter().zip(self.radii).enumerate() {
+            let (from, to) = (sides[index], sides[index + 1]);
+            let center = corner - (from + to) * radius;
+            let points = if radius == 0. {
+                vec![corner]
+            } else {
+                (0..=corner_segments)
+                    .map(|i| match i {
+                        0 => center + from * radius,
+                        i if i == corner_segments => center + to * radius,
+                        i => {
+                            let angle =
+                                i as f32 * std::f32::consts::FRAC_PI_2 / corner_segments as f32;
+                            center + Vec2::from_angle(angle).rotate(from) * radius
+                        }
+                    })
+                    .collect()
+            };
+            for point in points {
+                if outline
+                    .last()
+                    .is_none_or(|last| last.distance(point) > tolerance)
+                {
+                    outline.push(point);
+                }
+            }
+        }
+        if outline.len() > 1 && outline[0].distance(outline[outline.len() - 1]) <= tolerance {
+            outline.pop();
+        }
+        outline
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the [`Rectangle`] and then each corner radius of two [`RoundedRectangle`]s within `epsilon`
+    RoundedRectangle { rectangle, radii[..] }
+);
+
+// ##########
+// Default impl
+// ##########
+impl Default for RoundedRectangle {
+    fn default() -> Self {
+        Self::new_uniform(Rectangle::default(), 0.25)
+    }
+}
+
+// ##########
+// Display impl
+// ##########
+impl Display for RoundedRectangle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RoundedRectangle {{ MinX: {}, MinY: {}, MaxX: {}, MaxY: {}, Radii: {:?} }}",
+            self.rectangle.min_x(),
+            self.rectangle.min_y(),
+            self.rectangle.max_x(),
+            self.rectangle.max_y(),
+            self.radii
+        )
+    }
+}
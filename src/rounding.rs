@@ -0,0 +1,45 @@
+use glam::{IVec2, Vec2};
+
+/// Represents how coordinates are rounded when converting to integer coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity, the cell a coordinate lies in
+    Floor,
+    /// Round towards positive infinity
+    Ceil,
+    /// Round to the nearest integer, with halves rounded away from zero
+    Nearest,
+    /// Round towards zero
+    Truncate,
+}
+
+// ##########
+// Operations
+// ##########
+impl Rounding {
+    /// Rounds each component of `coordinate` to an integer
+    ///
+    /// Components outside of the range of `i32` saturate, and `NaN` becomes `0`.
+    ///
+    /// ```
+    /// use glam::{IVec2, Vec2};
+    /// use shapes2d::prelude::Rounding;
+    ///
+    /// let coordinate = Vec2 { x: -1.5, y: 2.5 };
+    ///
+    /// assert_eq!(Rounding::Floor.round(coordinate), IVec2 { x: -2, y: 2 });
+    /// assert_eq!(Rounding::Ceil.round(coordinate), IVec2 { x: -1, y: 3 });
+    /// assert_eq!(Rounding::Nearest.round(coordinate), IVec2 { x: -2, y: 3 });
+    /// assert_eq!(Rounding::Truncate.round(coordinate), IVec2 { x: -1, y: 2 });
+    /// ```
+    pub fn round(&self, coordinate: Vec2) -> IVec2 {
+        match self {
+            Self::Floor => coordinate.floor(),
+            Self::Ceil => coordinate.ceil(),
+            Self::Nearest => coordinate.round(),
+            // The conversion to integers truncates
+            Self::Truncate => coordinate,
+        }
+        .as_ivec2()
+    }
+}
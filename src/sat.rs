@@ -0,0 +1,423 @@
+use glam::Vec2;
+
+#[cfg(feature = "rectangle")]
+use crate::clip::clip_segment_against_edge;
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "point")]
+use crate::prelude::Point;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "triangle")]
+use crate::prelude::Triangle;
+#[cfg(feature = "rectangle")]
+use crate::traits::ContactManifold;
+use crate::traits::Penetration;
+
+// ##########
+// Project
+// ##########
+/// A shape which can be projected onto an axis
+///
+/// This is the projection used by [`AxisProject`] and the separating axis theorem in this module, for use by
+/// collision code of your own.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Line, Point, Polygon, Rectangle, Triangle};
+/// use shapes2d::sat::Project;
+///
+/// let shapes: Vec<Box<dyn Project>> = vec![
+///     Box::new(Point::new(Vec2 { x: 2., y: 1. })),
+///     Box::new(Line::new(Vec2 { x: -4., y: 0. }, Vec2 { x: 2., y: 2. })),
+///     Box::new(Circle::new(Vec2 { x: 1., y: 0. }, 2.)),
+///     Box::new(Rectangle::new(-1., -1., 3., 1.)),
+///     Box::new(Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 1. })),
+///     Box::new(Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }])),
+/// ];
+///
+/// let along_x: Vec<(f32, f32)> = shapes.iter().map(|shape| shape.project_onto(Vec2::X)).collect();
+/// assert_eq!(along_x, vec![(2., 2.), (-4., 2.), (-1., 3.), (-1., 3.), (0., 4.), (0., 2.)]);
+///
+/// let along_y: Vec<(f32, f32)> = shapes.iter().map(|shape| shape.project_onto(Vec2::Y)).collect();
+/// assert_eq!(along_y, vec![(1., 1.), (0., 2.), (-2., 2.), (-1., 1.), (0., 1.), (0., 2.)]);
+///
+/// // along the 45 degree diagonal, `(x, y)` projects to `(x + y) / sqrt(2)`
+/// let root_two = 2_f32.sqrt();
+/// let expected = [
+///     (3. / root_two, 3. / root_two),
+///     (-4. / root_two, 4. / root_two),
+///     (1. / root_two - 2., 1. / root_two + 2.),
+///     (-2. / root_two, 4. / root_two),
+///     (0., 4. / root_two),
+///     (0., 4. / root_two),
+/// ];
+/// for (shape, (min, max)) in shapes.iter().zip(expected) {
+///     let (projected_min, projected_max) = shape.project_onto(Vec2::ONE.normalize());
+///     assert_abs_diff_eq!(projected_min, min, epsilon = 1e-5);
+///     assert_abs_diff_eq!(projected_max, max, epsilon = 1e-5);
+///
+///     // an unnormalized axis scales the projection by its length
+///     let (scaled_min, scaled_max) = shape.project_onto(Vec2::ONE);
+///     assert_abs_diff_eq!(scaled_min, min * root_two, epsilon = 1e-5);
+///     assert_abs_diff_eq!(scaled_max, max * root_two, epsilon = 1e-5);
+/// }
+/// ```
+pub trait Project {
+    /// Projects the shape onto `axis`, returning the `(min, max)` of the dot products of the shape with `axis`
+    ///
+    /// `axis` does not need to be normalized, the projection scales with its length. So the projections are only
+    /// distances along `axis` when it is normalized. An empty [`Polygon`] projects to
+    /// `(f32::INFINITY, f32::NEG_INFINITY)`.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Circle;
+    /// use shapes2d::sat::Project;
+    ///
+    /// let circle = Circle::new(Vec2 { x: 3., y: 4. }, 1.);
+    ///
+    /// assert_eq!(circle.project_onto(Vec2::Y), (3., 5.));
+    /// assert_eq!(circle.project_onto(Vec2 { x: 0., y: 2. }), (6., 10.));
+    /// ```
+    fn project_onto(&self, axis: Vec2) -> (f32, f32);
+}
+
+#[cfg(feature = "point")]
+impl Project for Point {
+    fn project_onto(&self, axis: Vec2) -> (f32, f32) {
+        let projection = self.coordinate().dot(axis);
+        (projection, projection)
+    }
+}
+
+#[cfg(feature = "line")]
+impl Project for Line {
+    fn project_onto(&self, axis: Vec2) -> (f32, f32) {
+        project_points([self.origin(), self.end()].into_iter(), axis)
+    }
+}
+
+#[cfg(feature = "circle")]
+impl Project for Circle {
+    fn project_onto(&self, axis: Vec2) -> (f32, f32) {
+        let center = self.center().dot(axis);
+        let radius = self.radius() * axis.length();
+        (center - radius, center + radius)
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Project for Rectangle {
+    fn project_onto(&self, axis: Vec2) -> (f32, f32) {
+        let (min, max) = (self.min(), self.max());
+        project_points(
+            [
+                min,
+                Vec2 { x: max.x, y: min.y },
+                max,
+                Vec2 { x: min.x, y: max.y },
+            ]
+            .into_iter(),
+            axis,
+        )
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl Project for Triangle {
+    fn project_onto(&self, axis: Vec2) -> (f32, f32) {
+        project_points(self.vertices().into_iter(), axis)
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Project for Polygon {
+    fn project_onto(&self, axis: Vec2) -> (f32, f32) {
+        self.project_onto_axis(axis)
+    }
+}
+
+// ##########
+// AxisProject
+// ##########
+/// A convex shape which can be projected onto an axis for the separating axis theorem
+///
+/// A [`Polygon`] must be convex (see [`Polygon::is_convex`]) for [`intersects`] and [`minimum_penetration`]
+/// to be meaningful.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Line, Rectangle, Triangle};
+/// use shapes2d::sat::AxisProject;
+///
+/// let shapes: Vec<Box<dyn AxisProject>> = vec![
+///     Box::new(Circle::new(Vec2 { x: 1., y: 0. }, 2.)),
+///     Box::new(Rectangle::new(-1., -1., 3., 1.)),
+///     Box::new(Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 1. })),
+///     Box::new(Line::new(Vec2 { x: -4., y: 0. }, Vec2 { x: 2., y: 2. })),
+/// ];
+///
+/// let projections: Vec<(f32, f32)> = shapes.iter().map(|shape| shape.project(Vec2::X)).collect();
+/// assert_eq!(projections, vec![(-1., 3.), (-1., 3.), (0., 4.), (-4., 2.)]);
+/// ```
+pub trait AxisProject: Project {
+    /// Projects the shape onto `axis`, returning the `(min, max)` of the projection, see [`Project::project_onto`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Circle;
+    /// use shapes2d::sat::AxisProject;
+    ///
+    /// let circle = Circle::new(Vec2 { x: 3., y: 4. }, 1.);
+    ///
+    /// assert_eq!(circle.project(Vec2::Y), (3., 5.));
+    /// assert_eq!(circle.project(Vec2 { x: 0., y: 2. }), (6., 10.));
+    /// ```
+    fn project(&self, axis: Vec2) -> (f32, f32) {
+        self.project_onto(axis)
+    }
+
+    /// Get the axes which may separate the shape from another, the normals of its edges
+    ///
+    /// The axes are not normalized, and parallel edges may repeat an axis. A [`Circle`] has no edges and
+    /// returns no axes, so the axis towards the other shape has to be supplied separately.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Line, Rectangle};
+    /// use shapes2d::sat::AxisProject;
+    ///
+    /// assert_eq!(Rectangle::new(0., 0., 2., 1.).separating_axes(), vec![Vec2::X, Vec2::Y]);
+    /// assert_eq!(Line::new(Vec2::ZERO, Vec2 { x: 3., y: 0. }).separating_axes(), vec![Vec2 { x: 0., y: 3. }]);
+    /// assert!(Circle::new(Vec2::ZERO, 1.).separating_axes().is_empty());
+    /// ```
+    fn separating_axes(&self) -> Vec<Vec2>;
+}
+
+#[cfg(feature = "circle")]
+impl AxisProject for Circle {
+    fn separating_axes(&self) -> Vec<Vec2> {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl AxisProject for Rectangle {
+    fn separating_axes(&self) -> Vec<Vec2> {
+        vec![Vec2::X, Vec2::Y]
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl AxisProject for Triangle {
+    fn separating_axes(&self) -> Vec<Vec2> {
+        let vertices = self.vertices();
+        (0..3)
+            .map(|i| (vertices[(i + 1) % 3] - vertices[i]).perp())
+            .collect()
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl AxisProject for Polygon {
+    fn separating_axes(&self) -> Vec<Vec2> {
+        self.edge_pairs()
+            .map(|(start, end)| (end - start).perp())
+            .collect()
+    }
+}
+
+#[cfg(feature = "line")]
+impl AxisProject for Line {
+    fn separating_axes(&self) -> Vec<Vec2> {
+        vec![self.direction().perp()]
+    }
+}
+
+// ##########
+// Operations
+// ##########
+/// Checks if two convex shapes touch or overlap along every one of `axes`
+///
+/// Each axis is normalized before use, and zero length axes are skipped. The shapes are separated when their
+/// projections onto any axis do not overlap, so with no usable axes they always intersect. Only the
+/// supplied axes are tested, usually the [`AxisProject::separating_axes`] of both shapes.
+///
+/// ```
+/// use glam::Vec2;
+/// use rand::{Rng, SeedableRng};
+/// use shapes2d::prelude::{Circle, ClosestPoint, Intersects, Rectangle};
+/// use shapes2d::sat::{self, AxisProject};
+///
+/// // without usable axes nothing is separated, and shapes which only touch intersect
+/// let rect = Rectangle::new(0., 0., 1., 1.);
+/// let far = Rectangle::new(5., 5., 6., 6.);
+/// assert!(sat::intersects(&rect, &far, []));
+/// assert!(sat::intersects(&rect, &far, [Vec2::ZERO]));
+/// assert!(!sat::intersects(&rect, &far, [Vec2::X * 10.]));
+/// assert!(sat::intersects(&rect, &Rectangle::new(1., 0., 2., 1.), rect.separating_axes()));
+///
+/// // agrees with the specialized tests over random rectangles and circles
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(194);
+/// let mut point = || Vec2 { x: rng.gen_range(-2.0..2.), y: rng.gen_range(-2.0..2.) };
+/// for _ in 0..64 {
+///     let (a, b, c) = (point(), point(), point());
+///     let rect = Rectangle::new_coordinates(a.min(b), a.max(b));
+///     let other = Rectangle::new_coordinates(c, c + Vec2::splat(0.5));
+///     let circle = Circle::new(point(), c.x.abs() * 0.8);
+///
+///     let axes = rect.separating_axes().into_iter().chain(other.separating_axes());
+///     assert_eq!(sat::intersects(&rect, &other, axes), rect.intersects(&other));
+///
+///     // a circle also needs the axis towards the closest point of the rectangle
+///     let towards = rect.closest_point(circle.center()) - circle.center();
+///     let axes = rect.separating_axes().into_iter().chain([towards]);
+///     assert_eq!(sat::intersects(&rect, &circle, axes), rect.intersects(&circle));
+/// }
+/// ```
+pub fn intersects(
+    a: &(impl AxisProject + ?Sized),
+    b: &(impl AxisProject + ?Sized),
+    axes: impl IntoIterator<Item = Vec2>,
+) -> bool {
+    axes.into_iter()
+        .map(|axis| axis.normalize_or_zero())
+        .filter(|axis| *axis != Vec2::ZERO)
+        .all(|axis| {
+            let (a_min, a_max) = a.project(axis);
+            let (b_min, b_max) = b.project(axis);
+            a_max >= b_min && b_max >= a_min
+        })
+}
+
+/// Get the shallowest [`Penetration`] of `a` into `b` along `axes`, or `None` if an axis separates them
+///
+/// Each axis is normalized before use, and zero length axes are skipped. Moving `a` by `normal * depth` leaves
+/// the shapes only touching along that axis, and when both directions along an axis are equally shallow the
+/// direction of the axis is used. With no usable axes there is no direction to separate along, and `None` is
+/// returned.
+///
+/// ```
+/// use glam::Vec2;
+/// use rand::{Rng, SeedableRng};
+/// use shapes2d::prelude::{Penetrates, Penetration, Rectangle};
+/// use shapes2d::sat::{self, AxisProject};
+///
+/// let rect = Rectangle::new(0., 0., 4., 2.);
+/// let other = Rectangle::new(3., 1., 6., 5.);
+/// let axes = rect.separating_axes().into_iter().chain(other.separating_axes());
+/// assert_eq!(sat::minimum_penetration(&rect, &other, axes), Some(Penetration { normal: Vec2::NEG_X, depth: 1. }));
+/// assert!(sat::minimum_penetration(&rect, &other, []).is_none());
+///
+/// // touching along an axis has no depth, and an even overlap goes along the axis
+/// let touching = Rectangle::new(4., 0., 5., 2.);
+/// assert_eq!(sat::minimum_penetration(&rect, &touching, [Vec2::X]), Some(Penetration { normal: Vec2::NEG_X, depth: 0. }));
+/// let centered = Rectangle::new(1., 0., 3., 2.);
+/// assert_eq!(sat::minimum_penetration(&rect, &centered, [Vec2::X]), Some(Penetration { normal: Vec2::X, depth: 3. }));
+///
+/// // agrees with the specialized rectangle penetration over random rectangles
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(194);
+/// let mut point = || Vec2 { x: rng.gen_range(-2.0..2.), y: rng.gen_range(-2.0..2.) };
+/// for _ in 0..64 {
+///     let (a, b, c) = (point(), point(), point());
+///     let rect = Rectangle::new_coordinates(a.min(b), a.max(b));
+///     let other = Rectangle::new_coordinates(c, c + Vec2::splat(0.5));
+///
+///     let axes = rect.separating_axes().into_iter().chain(other.separating_axes());
+///     let generic = sat::minimum_penetration(&rect, &other, axes).map(|penetration| penetration.depth);
+///     assert_eq!(generic, rect.penetration(&other).map(|penetration| penetration.depth));
+/// }
+/// ```
+pub fn minimum_penetration(
+    a: &(impl AxisProject + ?Sized),
+    b: &(impl AxisProject + ?Sized),
+    axes: impl IntoIterator<Item = Vec2>,
+) -> Option<Penetration> {
+    let mut shallowest: Option<Penetration> = None;
+    for axis in axes.into_iter().map(|axis| axis.normalize_or_zero()) {
+        if axis == Vec2::ZERO {
+            continue;
+        }
+        let (a_min, a_max) = a.project(axis);
+        let (b_min, b_max) = b.project(axis);
+        // The distance to move along the axis, and against it, to separate
+        let forward = b_max - a_min;
+        let backward = a_max - b_min;
+        if forward < 0. || backward < 0. {
+            return None;
+        }
+        let candidate = if backward < forward {
+            Penetration {
+                normal: -axis,
+                depth: backward,
+            }
+        } else {
+            Penetration {
+                normal: axis,
+                depth: forward,
+            }
+        };
+        if shallowest.is_none_or(|shallowest| candidate.depth < shallowest.depth) {
+            shallowest = Some(candidate);
+        }
+    }
+    shallowest
+}
+
+// ##########
+// Helpers
+// ##########
+// Clips the `incident` face of one convex shape to the sides of the `reference` face of another, keeping the
+// points behind the reference face. `normal` is the outward normal of the reference face, and the contacts
+// are the points of the incident face, which move out along it by their depths.
+#[cfg(feature = "rectangle")]
+pub(crate) fn clip_faces(
+    reference: [Vec2; 2],
+    incident: [Vec2; 2],
+    normal: Vec2,
+) -> Option<ContactManifold> {
+    let tangent = (reference[1] - reference[0]).normalize_or_zero();
+    let (start, end) = (reference[0].dot(tangent), reference[1].dot(tangent));
+    let clipped = clip_segment(incident, tangent, start.max(end))?;
+    let clipped = clip_segment(clipped, -tangent, -start.min(end))?;
+
+    let surface = reference[0].dot(normal);
+    let mut manifold = ContactManifold {
+        normal,
+        points: [Vec2::ZERO; 2],
+        count: 0,
+        depths: [0.; 2],
+    };
+    for point in clipped {
+        let depth = surface - point.dot(normal);
+        let count = manifold.count as usize;
+        if depth >= 0. && !manifold.points[..count].contains(&point) {
+            manifold.points[count] = point;
+            manifold.depths[count] = depth;
+            manifold.count += 1;
+        }
+    }
+    (manifold.count > 0).then_some(manifold)
+}
+
+// The part of the segment where `point.dot(normal) <= offset`, or `None` when it lies wholly beyond
+#[cfg(feature = "rectangle")]
+fn clip_segment(segment: [Vec2; 2], normal: Vec2, offset: f32) -> Option<[Vec2; 2]> {
+    // the kept side lies to the left of an edge running along the boundary
+    let start = normal * offset;
+    clip_segment_against_edge(segment, start, start + normal.perp())
+}
+
+#[cfg(any(feature = "rectangle", feature = "triangle", feature = "line"))]
+fn project_points(points: impl Iterator<Item = Vec2>, axis: Vec2) -> (f32, f32) {
+    points.map(|point| point.dot(axis)).fold(
+        (f32::INFINITY, f32::NEG_INFINITY),
+        |(min, max), projection| (min.min(projection), max.max(projection)),
+    )
+}
@@ -0,0 +1,481 @@
+use std::fmt::Display;
+
+use glam::Vec2;
+
+use crate::prelude::Arc;
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
+
+/// Represents a single circular [`Sector`], a slice of pie, in 2d space
+///
+/// A [`Sector`] is the area between the `center` of a circle and an [`Arc`] of its edge, and follows the same
+/// rules for its angles: a positive `sweep` turns counter-clockwise from the `start_angle`, the `start_angle`
+/// is kept between `0.` and `TAU`, and the `sweep` is clamped between `-TAU` and `TAU`, a whole circle.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use std::f32::consts::{FRAC_PI_2, PI};
+///
+/// use glam::Vec2;
+/// use shapes2d::prelude::Sector;
+///
+/// // a field of view looking right, 90 degrees wide
+/// let view = Sector::new(Vec2::ZERO, 10., -FRAC_PI_2 / 2., FRAC_PI_2);
+///
+/// assert!(view.contains_point(Vec2 { x: 5., y: 0. }));
+/// assert!(view.contains_point(Vec2 { x: 5., y: 4.9 }));
+/// assert!(!view.contains_point(Vec2 { x: 5., y: 5.1 }));
+/// assert!(!view.contains_point(Vec2 { x: -5., y: 0. }));
+/// assert_abs_diff_eq!(view.area(), 25. * PI, epsilon = 1e-4);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Sector {
+    arc: Arc,
+}
+
+// ##########
+// Constructors
+// ##########
+impl Sector {
+    /// Creates a new [`Sector`] turning `sweep` radians from `start_angle`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI, TAU};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let sector = Sector::new(Vec2::ZERO, 1., -FRAC_PI_2, 3. * TAU);
+    ///
+    /// assert_eq!(sector.start_angle(), 3. * FRAC_PI_2);
+    /// assert_eq!(sector.sweep(), TAU);
+    /// assert_abs_diff_eq!(sector.area(), PI, epsilon = 1e-6);
+    /// ```
+    pub fn new(center: Vec2, radius: f32, start_angle: f32, sweep: f32) -> Self {
+        Self {
+            arc: Arc::new(center, radius, start_angle, sweep),
+        }
+    }
+
+    /// Creates a new [`Sector`] turning counter-clockwise from `start_angle` to `end_angle`, see [`Arc::new_angles`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let sector = Sector::new_angles(Vec2::ZERO, 1., 3. * FRAC_PI_2, FRAC_PI_2);
+    ///
+    /// assert_abs_diff_eq!(sector.sweep(), PI, epsilon = 1e-6);
+    /// assert!(sector.contains_point(Vec2 { x: 0.5, y: 0. }));
+    /// ```
+    pub fn new_angles(center: Vec2, radius: f32, start_angle: f32, end_angle: f32) -> Self {
+        Self {
+            arc: Arc::new_angles(center, radius, start_angle, end_angle),
+        }
+    }
+
+    /// Creates a new [`Sector`] between the center of `arc` and `arc`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Arc, Sector};
+    ///
+    /// let arc = Arc::new(Vec2::ONE, 2., 0., 1.);
+    /// let sector = Sector::new_arc(arc);
+    ///
+    /// assert_eq!(sector.arc(), arc);
+    /// ```
+    pub fn new_arc(arc: Arc) -> Self {
+        Self { arc }
+    }
+}
+
+// ##########
+// Getters/Setters
+// ##########
+impl Sector {
+    /// Get the [`Arc`] along the curved edge of the [`Sector`]
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let sector = Sector::new(Vec2::ZERO, 2., 0., PI);
+    ///
+    /// assert_eq!(sector.arc().length(), 2. * PI);
+    /// ```
+    pub fn arc(&self) -> Arc {
+        self.arc
+    }
+
+    /// Get the `center` of the [`Sector`], the tip of the slice
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let sector = Sector::new(Vec2::ONE, 1., 0., 1.);
+    ///
+    /// assert_eq!(sector.center(), Vec2::ONE);
+    /// ```
+    pub fn center(&self) -> Vec2 {
+        self.arc.center()
+    }
+
+    /// Get the `radius` of the [`Sector`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let sector = Sector::new(Vec2::ZERO, 2., 0., 1.);
+    ///
+    /// assert_eq!(sector.radius(), 2.);
+    /// ```
+    pub fn radius(&self) -> f32 {
+        self.arc.radius()
+    }
+
+    /// Get the angle the [`Sector`] starts at, between `0.` and `TAU`
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// assert_eq!(Sector::new(Vec2::ZERO, 1., -PI, 1.).start_angle(), PI);
+    /// ```
+    pub fn start_angle(&self) -> f32 {
+        self.arc.start_angle()
+    }
+
+    /// Get the angle the [`Sector`] turns through, counter-clockwise when positive
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// assert_eq!(Sector::new(Vec2::ZERO, 1., 0., -1.).sweep(), -1.);
+    /// ```
+    pub fn sweep(&self) -> f32 {
+        self.arc.sweep()
+    }
+
+    /// Get the angle the [`Sector`] ends at, between `0.` and `TAU`
+    ///
+    /// ```
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// assert_eq!(Sector::new(Vec2::ZERO, 1., PI, FRAC_PI_2).end_angle(), 3. * FRAC_PI_2);
+    /// ```
+    pub fn end_angle(&self) -> f32 {
+        self.arc.end_angle()
+    }
+
+    /// Set a new `center` for the [`Sector`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let mut sector = Sector::new(Vec2::ZERO, 1., 0., 1.);
+    /// sector.set_center(Vec2::ONE);
+    ///
+    /// assert_eq!(sector.center(), Vec2::ONE);
+    /// ```
+    pub fn set_center(&mut self, center: Vec2) {
+        self.arc.set_center(center);
+    }
+
+    /// Set a new `radius` for the [`Sector`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let mut sector = Sector::new(Vec2::ZERO, 1., 0., 1.);
+    /// sector.set_radius(3.);
+    ///
+    /// assert_eq!(sector.radius(), 3.);
+    /// ```
+    pub fn set_radius(&mut self, radius: f32) {
+        self.arc.set_radius(radius);
+    }
+
+    /// Set a new `start_angle` for the [`Sector`], keeping its `sweep`
+    ///
+    /// ```
+    /// use std::f32::consts::PI;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let mut sector = Sector::new(Vec2::ZERO, 1., 0., 1.);
+    /// sector.set_start_angle(-PI);
+    ///
+    /// assert_eq!(sector.start_angle(), PI);
+    /// assert_eq!(sector.sweep(), 1.);
+    /// ```
+    pub fn set_start_angle(&mut self, start_angle: f32) {
+        self.arc.set_start_angle(start_angle);
+    }
+
+    /// Set a new `sweep` for the [`Sector`], keeping its `start_angle`
+    ///
+    /// ```
+    /// use std::f32::consts::TAU;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let mut sector = Sector::new(Vec2::ZERO, 1., 0., 1.);
+    /// sector.set_sweep(10.);
+    ///
+    /// assert_eq!(sector.sweep(), TAU);
+    /// ```
+    pub fn set_sweep(&mut self, sweep: f32) {
+        self.arc.set_sweep(sweep);
+    }
+}
+
+// ##########
+// Operations
+// ##########
+impl Sector {
+    /// Get the `area` of the [`Sector`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let quarter = Sector::new(Vec2::ZERO, 2., 0., FRAC_PI_2);
+    /// assert_abs_diff_eq!(quarter.area(), PI * 4. / 4., epsilon = 1e-6);
+    ///
+    /// let clockwise = Sector::new(Vec2::ZERO, 2., 0., -FRAC_PI_2);
+    /// assert_eq!(clockwise.area(), quarter.area());
+    /// ```
+    pub fn area(&self) -> f32 {
+        0.5 * self.radius() * self.radius() * self.sweep().abs()
+    }
+
+    /// Get the `perimeter` of the [`Sector`], the length of its [`Arc`] and both of its straight edges
+    ///
+    /// The straight edges are counted even when the [`Sector`] is a whole circle, where they lie on top of
+    /// each other.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let quarter = Sector::new(Vec2::ZERO, 2., 0., FRAC_PI_2);
+    ///
+    /// assert_abs_diff_eq!(quarter.perimeter(), (PI + 4.), epsilon = 1e-6);
+    /// ```
+    pub fn perimeter(&self) -> f32 {
+        self.arc.length() + 2. * self.radius()
+    }
+
+    /// Checks if `point` is inside or on the edge of the [`Sector`]
+    ///
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// // straddles the seam at 0, from -45 to 45 degrees
+    /// let sector = Sector::new(Vec2::ZERO, 2., -FRAC_PI_2 / 2., FRAC_PI_2);
+    ///
+    /// assert!(sector.contains_point(Vec2::ZERO));
+    /// assert!(sector.contains_point(Vec2 { x: 1., y: 0. }));
+    /// assert!(sector.contains_point(Vec2 { x: 1., y: -0.99 }));
+    /// assert!(sector.contains_point(Vec2 { x: 1., y: 0.99 }));
+    /// assert!(!sector.contains_point(Vec2 { x: 1., y: 1.01 }));
+    /// assert!(!sector.contains_point(Vec2 { x: 1., y: -1.01 }));
+    /// assert!(!sector.contains_point(Vec2 { x: 2.01, y: 0. }));
+    /// assert!(!sector.contains_point(Vec2 { x: -1., y: 0. }));
+    /// ```
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let offset = point - self.center();
+        offset.length_squared() <= self.radius() * self.radius()
+            && (offset == Vec2::ZERO || self.arc.contains_angle(offset.y.atan2(offset.x)))
+    }
+
+    /// Get the `centroid` of the [`Sector`], its center of mass
+    ///
+    /// The centroid lies on the line halfway through the `sweep`, `2 * radius * sin(a) / (3 * a)` from the
+    /// `center` where `a` is half of the `sweep`.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{PI, TAU};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let half = Sector::new(Vec2::ZERO, 3., 0., PI);
+    /// assert_abs_diff_eq!(half.centroid(), Vec2 { x: 0., y: 4. / PI }, epsilon = 1e-6);
+    ///
+    /// assert_abs_diff_eq!(Sector::new(Vec2::ONE, 3., 0., TAU).centroid(), Vec2::ONE, epsilon = 1e-6);
+    /// ```
+    pub fn centroid(&self) -> Vec2 {
+        let half = self.sweep().abs() * 0.5;
+        let distance = if half == 0. {
+            2. * self.radius() / 3.
+        } else {
+            2. * self.radius() * half.sin() / (3. * half)
+        };
+        self.center() + Vec2::from_angle(self.start_angle() + self.sweep() * 0.5) * distance
+    }
+
+    /// Get the smallest [`Rectangle`] holding the [`Sector`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let quarter = Sector::new(Vec2::ONE, 2., PI, FRAC_PI_2).bounding_box();
+    /// assert_abs_diff_eq!(quarter.min(), Vec2 { x: -1., y: -1. }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(quarter.max(), Vec2::ONE, epsilon = 1e-6);
+    ///
+    /// // straddling the seam at 0
+    /// let seam = Sector::new(Vec2::ZERO, 2., -FRAC_PI_2 / 2., FRAC_PI_2).bounding_box();
+    /// assert_abs_diff_eq!(seam.min(), Vec2 { x: 0., y: -2_f32.sqrt() }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(seam.max(), Vec2 { x: 2., y: 2_f32.sqrt() }, epsilon = 1e-6);
+    /// ```
+    #[cfg(feature = "rectangle")]
+    pub fn bounding_box(&self) -> Rectangle {
+        let arc = self.arc.bounding_box();
+        Rectangle::new_coordinates(arc.min().min(self.center()), arc.max().max(self.center()))
+    }
+
+    /// Creates a [`Polygon`] approximating the [`Sector`] with `segments` edges along its [`Arc`], wound
+    /// counter-clockwise
+    ///
+    /// The first coordinate is the `center`, followed by `segments + 1` coordinates along the [`Arc`]. A whole
+    /// circle leaves out the `center` and is just its `segments` coordinates around the edge. A `segments`
+    /// below 1, or below 3 for a whole circle, is clamped.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Sector, Winding};
+    ///
+    /// let quarter = Sector::new(Vec2::ZERO, 1., 0., -FRAC_PI_2);
+    /// let polygon = quarter.to_polygon(1);
+    /// assert_eq!(polygon.len(), 3);
+    /// assert_eq!(polygon.winding(), Winding::CounterClockwise);
+    /// assert_abs_diff_eq!(polygon.area(), 0.5, epsilon = 1e-6);
+    ///
+    /// assert_abs_diff_eq!(quarter.to_polygon(64).area(), PI / 4., epsilon = 1e-3);
+    /// assert_eq!(Sector::new(Vec2::ZERO, 1., 0., 10.).to_polygon(16).len(), 16);
+    /// ```
+    #[cfg(feature = "polygon")]
+    pub fn to_polygon(&self, segments: u32) -> Polygon {
+        let arc = self.counter_clockwise_arc();
+        if arc.sweep() >= std::f32::consts::TAU {
+            let segments = segments.max(3);
+            return (0..segments)
+                .map(|i| arc.point_at(i as f32 / segments as f32))
+                .collect();
+        }
+        std::iter::once(self.center())
+            .chain(arc.to_polyline(segments))
+            .collect()
+    }
+
+    /// Get a [`Mesh`] approximating the [`Sector`] with a fan of `segments` triangles around its `center`
+    ///
+    /// The `center` is the first vertex, followed by `segments + 1` vertices counter-clockwise along the
+    /// [`Arc`]. A `segments` below 1 is clamped to 1.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use std::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::Sector;
+    ///
+    /// let mesh = Sector::new(Vec2::ZERO, 2., 0., -FRAC_PI_2).to_mesh(32);
+    ///
+    /// assert_eq!(mesh.vertex_count(), 34);
+    /// assert_eq!(mesh.triangle_count(), 32);
+    /// assert_abs_diff_eq!(mesh.signed_area(), PI, epsilon = 0.01);
+    /// ```
+    #[cfg(feature = "mesh")]
+    pub fn to_mesh(&self, segments: u32) -> Mesh {
+        let segments = segments.max(1);
+        let positions = std::iter::once(self.center())
+            .chain(self.counter_clockwise_arc().to_polyline(segments))
+            .collect();
+        let indices = (1..=segments).flat_map(|i| [0, i, i + 1]).collect();
+        Mesh::new(positions, indices).unwrap_or_else(Mesh::empty)
+    }
+
+    // The arc of the sector, turning counter-clockwise
+    #[cfg(any(feature = "polygon", feature = "mesh"))]
+    fn counter_clockwise_arc(&self) -> Arc {
+        if self.sweep() >= 0. {
+            self.arc
+        } else {
+            Arc::new(
+                self.center(),
+                self.radius(),
+                self.start_angle() + self.sweep(),
+                -self.sweep(),
+            )
+        }
+    }
+}
+
+// ##########
+// approx impl
+// ##########
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the [`Arc`] bounding two [`Sector`]s within `epsilon`
+    Sector { arc }
+);
+
+// ##########
+// Display impl
+// ##########
+impl Display for Sector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Sector {{ center: {}, radius: {}, start_angle: {}, sweep: {} }}",
+            self.center(),
+            self.radius(),
+            self.start_angle(),
+            self.sweep()
+        )
+    }
+}
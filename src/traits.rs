@@ -0,0 +1,4198 @@
+use std::hash::Hash;
+#[cfg(any(
+    feature = "point",
+    feature = "line",
+    feature = "ray",
+    feature = "circle",
+    feature = "ellipse",
+    feature = "rectangle",
+    feature = "triangle",
+    feature = "polygon"
+))]
+use std::ops::{Add, Mul};
+
+#[cfg(feature = "line")]
+use glam::Mat2;
+use glam::{Affine2, Vec2};
+
+#[cfg(feature = "annulus")]
+use crate::prelude::Annulus;
+#[cfg(all(feature = "arc", feature = "rectangle"))]
+use crate::prelude::Arc;
+#[cfg(feature = "capsule")]
+use crate::prelude::Capsule;
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "circular_segment")]
+use crate::prelude::CircularSegment;
+#[cfg(feature = "cubic_bezier")]
+use crate::prelude::CubicBezier;
+#[cfg(feature = "ellipse")]
+use crate::prelude::Ellipse;
+#[cfg(feature = "half_plane")]
+use crate::prelude::HalfPlane;
+#[cfg(feature = "infinite_line")]
+use crate::prelude::InfiniteLine;
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "oriented_rectangle")]
+use crate::prelude::OrientedRectangle;
+#[cfg(feature = "point")]
+use crate::prelude::Point;
+#[cfg(feature = "polygon")]
+use crate::prelude::Polygon;
+#[cfg(feature = "polygon_with_holes")]
+use crate::prelude::PolygonWithHoles;
+#[cfg(all(feature = "rectangle", feature = "polyline"))]
+use crate::prelude::Polyline;
+#[cfg(feature = "quad")]
+use crate::prelude::Quad;
+#[cfg(feature = "quadratic_bezier")]
+use crate::prelude::QuadraticBezier;
+#[cfg(feature = "ray")]
+use crate::prelude::Ray;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+#[cfg(feature = "regular_polygon")]
+use crate::prelude::RegularPolygon;
+#[cfg(feature = "rounded_rectangle")]
+use crate::prelude::RoundedRectangle;
+#[cfg(feature = "sector")]
+use crate::prelude::Sector;
+#[cfg(feature = "triangle")]
+use crate::prelude::Triangle;
+#[cfg(any(feature = "rectangle", feature = "polygon"))]
+use crate::sat;
+#[cfg(feature = "polygon")]
+use crate::sat::AxisProject;
+
+// ##########
+// Shape2d
+// ##########
+/// A closed shape enclosing an area in 2d space
+///
+/// [`Line`](crate::prelude::Line), [`Ray`](crate::prelude::Ray) and [`Point`](crate::prelude::Point)
+/// enclose no area and intentionally do not implement [`Shape2d`].
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Rectangle, Shape2d, Triangle};
+///
+/// let shapes: Vec<Box<dyn Shape2d>> = vec![
+///     Box::new(Circle::new(Vec2::ZERO, 1.)),
+///     Box::new(Rectangle::new(0., 0., 2., 3.)),
+///     Box::new(Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. })),
+/// ];
+///
+/// let total: f32 = shapes.iter().map(|shape| shape.area()).sum();
+/// assert_abs_diff_eq!(total, (std::f32::consts::PI + 6. + 6.), epsilon = 1e-5);
+/// ```
+pub trait Shape2d {
+    /// Get the `area` enclosed by the shape
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, Rectangle, Shape2d};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// let rect = Rectangle::new(0., 0., 2., 3.);
+    /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    ///
+    /// let shape: &dyn Shape2d = &rect;
+    /// assert_eq!(shape.area(), 6.);
+    /// let shape: &dyn Shape2d = &square;
+    /// assert_eq!(shape.area(), 4.);
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// let shape: &dyn Shape2d = &Ellipse::new(Vec2::ZERO, 2., 1.);
+    /// assert_eq!(shape.area(), std::f32::consts::PI * 2.);
+    /// # }
+    /// ```
+    fn area(&self) -> f32;
+
+    /// Get the length of the boundary of the shape
+    ///
+    /// The perimeter of an [`Ellipse`] is approximated with Ramanujan's second approximation.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Rectangle, Shape2d, Triangle};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 1.);
+    /// let rect = Rectangle::new(0., 0., 2., 3.);
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+    ///
+    /// let shape: &dyn Shape2d = &circle;
+    /// assert_eq!(shape.perimeter(), std::f32::consts::TAU);
+    /// let shape: &dyn Shape2d = &rect;
+    /// assert_eq!(shape.perimeter(), 10.);
+    /// let shape: &dyn Shape2d = &triangle;
+    /// assert_eq!(shape.perimeter(), 12.);
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// // an ellipse with equal radii is a circle
+    /// let shape: &dyn Shape2d = &Ellipse::new(Vec2::ZERO, 2., 2.);
+    /// assert_abs_diff_eq!(shape.perimeter(), std::f32::consts::TAU * 2., epsilon = 1e-5);
+    /// # }
+    /// ```
+    fn perimeter(&self) -> f32;
+
+    /// Get the center of mass of the area of the shape
+    ///
+    /// A [`Polygon`] enclosing no area uses the mean of its coordinates, and an empty [`Polygon`]
+    /// uses `(0., 0.)`.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, Rectangle, Shape2d, Triangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 2., 4.);
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2 { x: 0., y: 3. });
+    /// let empty = Polygon::new(Vec::new());
+    ///
+    /// let shape: &dyn Shape2d = &rect;
+    /// assert_eq!(shape.centroid(), Vec2 { x: 1., y: 2. });
+    /// let shape: &dyn Shape2d = &triangle;
+    /// assert_eq!(shape.centroid(), Vec2::ONE);
+    /// let shape: &dyn Shape2d = &empty;
+    /// assert_eq!(shape.centroid(), Vec2::ZERO);
+    /// ```
+    fn centroid(&self) -> Vec2;
+}
+
+#[cfg(feature = "annulus")]
+impl Shape2d for Annulus {
+    fn area(&self) -> f32 {
+        Annulus::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        std::f32::consts::TAU * (self.inner_radius() + self.outer_radius())
+    }
+
+    fn centroid(&self) -> Vec2 {
+        self.center()
+    }
+}
+
+#[cfg(feature = "capsule")]
+impl Shape2d for Capsule {
+    fn area(&self) -> f32 {
+        Capsule::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        Capsule::perimeter(self)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        self.center()
+    }
+}
+
+#[cfg(feature = "circle")]
+impl Shape2d for Circle {
+    fn area(&self) -> f32 {
+        std::f32::consts::PI * self.radius() * self.radius()
+    }
+
+    fn perimeter(&self) -> f32 {
+        std::f32::consts::TAU * self.radius()
+    }
+
+    fn centroid(&self) -> Vec2 {
+        self.center()
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl Shape2d for Ellipse {
+    fn area(&self) -> f32 {
+        std::f32::consts::PI * self.radius_major() * self.radius_minor()
+    }
+
+    fn perimeter(&self) -> f32 {
+        let (a, b) = (self.radius_major(), self.radius_minor());
+        std::f32::consts::PI * (3. * (a + b) - ((3. * a + b) * (a + 3. * b)).sqrt())
+    }
+
+    fn centroid(&self) -> Vec2 {
+        self.center()
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Shape2d for Rectangle {
+    fn area(&self) -> f32 {
+        self.width() * self.height()
+    }
+
+    fn perimeter(&self) -> f32 {
+        2. * (self.width() + self.height())
+    }
+
+    fn centroid(&self) -> Vec2 {
+        self.center()
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl Shape2d for Triangle {
+    fn area(&self) -> f32 {
+        Triangle::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        let [a, b, c] = self.vertices();
+        a.distance(b) + b.distance(c) + c.distance(a)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        Triangle::centroid(self)
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Shape2d for Polygon {
+    fn area(&self) -> f32 {
+        Polygon::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        Polygon::perimeter(self)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        Polygon::centroid(self).unwrap_or(Vec2::ZERO)
+    }
+}
+
+#[cfg(feature = "polygon_with_holes")]
+impl Shape2d for PolygonWithHoles {
+    fn area(&self) -> f32 {
+        PolygonWithHoles::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        PolygonWithHoles::perimeter(self)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        PolygonWithHoles::centroid(self).unwrap_or(Vec2::ZERO)
+    }
+}
+
+#[cfg(feature = "oriented_rectangle")]
+impl Shape2d for OrientedRectangle {
+    fn area(&self) -> f32 {
+        OrientedRectangle::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        OrientedRectangle::perimeter(self)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        self.center()
+    }
+}
+
+#[cfg(feature = "quad")]
+impl Shape2d for Quad {
+    fn area(&self) -> f32 {
+        Quad::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        Quad::perimeter(self)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        Quad::centroid(self)
+    }
+}
+
+#[cfg(feature = "regular_polygon")]
+impl Shape2d for RegularPolygon {
+    fn area(&self) -> f32 {
+        RegularPolygon::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        RegularPolygon::perimeter(self)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        self.center()
+    }
+}
+
+#[cfg(feature = "rounded_rectangle")]
+impl Shape2d for RoundedRectangle {
+    fn area(&self) -> f32 {
+        RoundedRectangle::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        RoundedRectangle::perimeter(self)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        RoundedRectangle::centroid(self)
+    }
+}
+
+#[cfg(feature = "sector")]
+impl Shape2d for Sector {
+    fn area(&self) -> f32 {
+        Sector::area(self)
+    }
+
+    fn perimeter(&self) -> f32 {
+        Sector::perimeter(self)
+    }
+
+    fn centroid(&self) -> Vec2 {
+        Sector::centroid(self)
+    }
+}
+
+// ##########
+// Perimeter
+// ##########
+/// A shape whose boundary can be walked at an even speed
+///
+/// [`perimeter_point`](Perimeter::perimeter_point) walks the boundary by arc length as `t` goes from `0.` to `1.`,
+/// so equal steps of `t` cover equal lengths of the boundary. Closed shapes wrap `t` around, while a
+/// [`Line`](crate::prelude::Line) clamps `t` to its ends.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Line, Perimeter, Rectangle};
+///
+/// let outlines: Vec<Box<dyn Perimeter>> = vec![
+///     Box::new(Circle::new(Vec2::ZERO, 1.)),
+///     Box::new(Rectangle::new(0., 0., 4., 2.)),
+///     Box::new(Line::new(Vec2::ZERO, Vec2 { x: 3., y: 4. })),
+/// ];
+///
+/// // move 1 unit along each outline
+/// let moved: Vec<Vec2> = outlines.iter().map(|outline| outline.perimeter_point(1. / outline.perimeter_length())).collect();
+/// assert_abs_diff_eq!(moved[0], Vec2::from_angle(1.), epsilon = 1e-5);
+/// assert_abs_diff_eq!(moved[1], Vec2 { x: 1., y: 0. }, epsilon = 1e-5);
+/// assert_abs_diff_eq!(moved[2], Vec2 { x: 0.6, y: 0.8 }, epsilon = 1e-5);
+/// ```
+pub trait Perimeter {
+    /// Get the point `t` of the way along the boundary
+    ///
+    /// - A [`Circle`](crate::prelude::Circle) and an [`Ellipse`](crate::prelude::Ellipse) start at their
+    ///   center offset along `+x` and head towards `+y`.
+    /// - A [`Rectangle`](crate::prelude::Rectangle) starts at its min corner and heads along `+x`.
+    /// - A [`Triangle`](crate::prelude::Triangle) and a [`Polygon`](crate::prelude::Polygon) start at their first
+    ///   vertex and follow their vertices in order. An empty [`Polygon`](crate::prelude::Polygon) is always `(0., 0.)`.
+    /// - A [`Line`](crate::prelude::Line) starts at its origin.
+    ///
+    /// The arc length of an [`Ellipse`](crate::prelude::Ellipse) is approximated, placing the point within 0.05%
+    /// of [`perimeter_length`](Perimeter::perimeter_length) of the exact point along the boundary.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Line, Perimeter, Rectangle};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    /// assert_eq!(rect.perimeter_length(), 12.);
+    ///
+    /// // equally spaced `t` are equally spaced along the boundary
+    /// let expected = [
+    ///     (0., 0.), (1., 0.), (2., 0.), (3., 0.), (4., 0.), (4., 1.),
+    ///     (4., 2.), (3., 2.), (2., 2.), (1., 2.), (0., 2.), (0., 1.),
+    /// ];
+    /// for (i, (x, y)) in expected.into_iter().enumerate() {
+    ///     assert_abs_diff_eq!(rect.perimeter_point(i as f32 / 12.), Vec2 { x, y }, epsilon = 1e-5);
+    /// }
+    ///
+    /// // closed shapes wrap around
+    /// assert_abs_diff_eq!(rect.perimeter_point(1.25), rect.perimeter_point(0.25), epsilon = 1e-5);
+    /// assert_abs_diff_eq!(rect.perimeter_point(-0.25), rect.perimeter_point(0.75), epsilon = 1e-5);
+    ///
+    /// // lines clamp to their ends
+    /// let line = Line::new(Vec2::ZERO, Vec2 { x: 2., y: 0. });
+    /// assert_eq!(line.perimeter_point(1.5), Vec2 { x: 2., y: 0. });
+    /// assert_eq!(line.perimeter_point(-1.), Vec2::ZERO);
+    ///
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// // each quarter of an ellipse has the same length
+    /// let ellipse = Ellipse::new(Vec2::ZERO, 4., 1.);
+    /// assert_abs_diff_eq!(ellipse.perimeter_point(0.25), Vec2 { x: 0., y: 1. }, epsilon = 1e-3);
+    /// assert_abs_diff_eq!(ellipse.perimeter_point(0.5), Vec2 { x: -4., y: 0. }, epsilon = 1e-3);
+    /// # }
+    /// ```
+    fn perimeter_point(&self, t: f32) -> Vec2;
+
+    /// Get the length of the boundary walked by [`perimeter_point`](Perimeter::perimeter_point)
+    ///
+    /// The length of an [`Ellipse`](crate::prelude::Ellipse) is approximated the same as [`Shape2d::perimeter`].
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Line, Perimeter, Triangle};
+    ///
+    /// assert_eq!(Circle::new(Vec2::ZERO, 1.).perimeter_length(), std::f32::consts::TAU);
+    /// assert_eq!(Line::new(Vec2::ZERO, Vec2 { x: 3., y: 4. }).perimeter_length(), 5.);
+    /// assert_eq!(Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. }).perimeter_length(), 12.);
+    /// ```
+    fn perimeter_length(&self) -> f32;
+}
+
+#[cfg(feature = "circle")]
+impl Perimeter for Circle {
+    fn perimeter_point(&self, t: f32) -> Vec2 {
+        self.center() + Vec2::from_angle(t.rem_euclid(1.) * std::f32::consts::TAU) * self.radius()
+    }
+
+    fn perimeter_length(&self) -> f32 {
+        std::f32::consts::TAU * self.radius()
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl Perimeter for Ellipse {
+    fn perimeter_point(&self, t: f32) -> Vec2 {
+        // Walks chords between evenly spaced angles, then spreads the remaining length evenly across the
+        // angles of the chord it lands in
+        const SEGMENTS: u32 = 64;
+        let (a, b) = (self.radius_major(), self.radius_minor());
+        let step = std::f32::consts::TAU / SEGMENTS as f32;
+        let at = |angle: f32| Vec2 {
+            x: a * angle.cos(),
+            y: b * angle.sin(),
+        };
+        let chord = |i: u32| at(i as f32 * step).distance(at((i + 1) as f32 * step));
+
+        let mut remaining = t.rem_euclid(1.) * (0..SEGMENTS).map(chord).sum::<f32>();
+        for i in 0..SEGMENTS {
+            let length = chord(i);
+            if remaining < length {
+                return ellipse_world_point(self, at((i as f32 + remaining / length) * step));
+            }
+            remaining -= length;
+        }
+        ellipse_world_point(self, at(0.))
+    }
+
+    fn perimeter_length(&self) -> f32 {
+        Shape2d::perimeter(self)
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Perimeter for Rectangle {
+    fn perimeter_point(&self, t: f32) -> Vec2 {
+        let (min, max) = (self.min(), self.max());
+        closed_path_point(
+            &[
+                min,
+                Vec2 { x: max.x, y: min.y },
+                max,
+                Vec2 { x: min.x, y: max.y },
+            ],
+            t,
+        )
+    }
+
+    fn perimeter_length(&self) -> f32 {
+        Shape2d::perimeter(self)
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl Perimeter for Triangle {
+    fn perimeter_point(&self, t: f32) -> Vec2 {
+        closed_path_point(&self.vertices(), t)
+    }
+
+    fn perimeter_length(&self) -> f32 {
+        Shape2d::perimeter(self)
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Perimeter for Polygon {
+    fn perimeter_point(&self, t: f32) -> Vec2 {
+        closed_path_point(self.coordinates(), t)
+    }
+
+    fn perimeter_length(&self) -> f32 {
+        Polygon::perimeter(self)
+    }
+}
+
+#[cfg(feature = "line")]
+impl Perimeter for Line {
+    fn perimeter_point(&self, t: f32) -> Vec2 {
+        self.origin() + self.direction() * t.clamp(0., 1.)
+    }
+
+    fn perimeter_length(&self) -> f32 {
+        self.length()
+    }
+}
+
+// ##########
+// BoundaryNormal
+// ##########
+/// A closed shape which can find the outward normal of its boundary
+///
+/// Normals are always unit length and point out of the shape, whichever way its vertices wind. At a corner
+/// the normal is the average of the normals of the two edges meeting there, which points out along the
+/// corner's diagonal on a [`Rectangle`](crate::prelude::Rectangle).
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use shapes2d::prelude::{BoundaryNormal, Perimeter, Polygon, Rectangle, Shape2d};
+///
+/// let rect = Rectangle::new(0., 0., 4., 2.);
+/// assert_eq!(rect.normal_at_point(Vec2 { x: 2., y: 0. }), Vec2 { x: 0., y: -1. });
+/// assert_eq!(rect.normal_at_point(Vec2 { x: 4., y: 1. }), Vec2::X);
+/// assert_eq!(rect.normal_at_point(Vec2 { x: 4., y: 2. }), Vec2::ONE.normalize());
+///
+/// // a clockwise triangle still has outward normals
+/// let clockwise = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 0., y: 3. }, Vec2 { x: 4., y: 0. }]);
+/// assert_eq!(clockwise.normal_at_point(Vec2 { x: 2., y: 0. }), Vec2 { x: 0., y: -1. });
+///
+/// // normals point away from the centroid and across the edge they are on
+/// let edges = [(0.1, Vec2::X), (0.4, Vec2::Y), (0.6, Vec2::X), (0.9, Vec2::Y)];
+/// for (t, edge) in edges {
+///     let normal = rect.normal_at(t);
+///     assert_abs_diff_eq!(normal.length(), 1., epsilon = 1e-6);
+///     assert!(normal.dot(rect.perimeter_point(t) - rect.centroid()) > 0.);
+///     assert_eq!(normal.dot(edge), 0.);
+/// }
+/// ```
+pub trait BoundaryNormal: Perimeter {
+    /// Get the outward normal at the point `t` of the way along the boundary, see [`Perimeter::perimeter_point`]
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{BoundaryNormal, Circle, Perimeter};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// let circle = Circle::new(Vec2::ONE, 2.);
+    /// assert_eq!(circle.normal_at(0.), Vec2::X);
+    /// assert_abs_diff_eq!(circle.normal_at(0.25), Vec2::Y, epsilon = 1e-6);
+    ///
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// let ellipse = Ellipse::new(Vec2::ZERO, 4., 1.);
+    /// for i in 0..16 {
+    ///     let t = i as f32 / 16.;
+    ///     let point = ellipse.perimeter_point(t);
+    ///     let normal = ellipse.normal_at(t);
+    ///     assert_abs_diff_eq!(normal.length(), 1., epsilon = 1e-6);
+    ///     assert!(normal.dot(point) > 0.);
+    ///
+    ///     // across the boundary, which heads towards the next point
+    ///     let along = (ellipse.perimeter_point(t + 1e-3) - ellipse.perimeter_point(t - 1e-3)).normalize();
+    ///     assert!(normal.dot(along).abs() < 1e-2);
+    /// }
+    /// # }
+    /// ```
+    fn normal_at(&self, t: f32) -> Vec2 {
+        self.normal_at_point(self.perimeter_point(t))
+    }
+
+    /// Get the outward normal at `boundary_point`
+    ///
+    /// A point off the boundary gets the normal of the nearest part of the boundary. Shapes without a boundary to
+    /// find the normal of, such as a [`Circle`](crate::prelude::Circle) at its center or an empty
+    /// [`Polygon`](crate::prelude::Polygon), return [`Vec2::X`].
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{BoundaryNormal, Circle, Triangle};
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 4. });
+    /// assert_eq!(triangle.normal_at_point(Vec2 { x: 2., y: 2. }), Vec2::ONE.normalize());
+    /// assert_eq!(triangle.normal_at_point(Vec2 { x: 0., y: 1. }), Vec2 { x: -1., y: 0. });
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 2.);
+    /// assert_eq!(circle.normal_at_point(Vec2 { x: 0., y: -5. }), Vec2 { x: 0., y: -1. });
+    /// assert_eq!(circle.normal_at_point(Vec2::ZERO), Vec2::X);
+    /// ```
+    fn normal_at_point(&self, boundary_point: Vec2) -> Vec2;
+}
+
+#[cfg(feature = "circle")]
+impl BoundaryNormal for Circle {
+    fn normal_at_point(&self, boundary_point: Vec2) -> Vec2 {
+        (boundary_point - self.center())
+            .try_normalize()
+            .unwrap_or(Vec2::X)
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl BoundaryNormal for Ellipse {
+    fn normal_at_point(&self, boundary_point: Vec2) -> Vec2 {
+        // The gradient of `(x / a)^2 + (y / b)^2` along the axes of the ellipse
+        let offset = ellipse_local_point(self, boundary_point);
+        let [major, minor] = self.axes();
+        (major * offset.x / self.radius_major().powi(2)
+            + minor * offset.y / self.radius_minor().powi(2))
+        .try_normalize()
+        .unwrap_or(major)
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl BoundaryNormal for Rectangle {
+    fn normal_at_point(&self, boundary_point: Vec2) -> Vec2 {
+        let (min, max) = (self.min(), self.max());
+        closed_path_normal(
+            &[
+                min,
+                Vec2 { x: max.x, y: min.y },
+                max,
+                Vec2 { x: min.x, y: max.y },
+            ],
+            boundary_point,
+        )
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl BoundaryNormal for Triangle {
+    fn normal_at_point(&self, boundary_point: Vec2) -> Vec2 {
+        closed_path_normal(&self.vertices(), boundary_point)
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl BoundaryNormal for Polygon {
+    fn normal_at_point(&self, boundary_point: Vec2) -> Vec2 {
+        closed_path_normal(self.coordinates(), boundary_point)
+    }
+}
+
+// ##########
+// Bounded
+// ##########
+/// A shape with a finite extent which fits inside of an axis aligned [`Rectangle`]
+///
+/// [`Ray`](crate::prelude::Ray) extends forever and intentionally does not implement [`Bounded`].
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Bounded, Circle, Line, Rectangle};
+///
+/// let shapes: Vec<Box<dyn Bounded>> = vec![
+///     Box::new(Circle::new(Vec2::ZERO, 1.)),
+///     Box::new(Line::new(Vec2 { x: 3., y: 2. }, Vec2 { x: 2., y: -2. })),
+///     Box::new(Rectangle::new(-1., 0., 0., 4.)),
+/// ];
+///
+/// let (min, max) = shapes.iter().map(|shape| shape.bounding_box()).fold(
+///     (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)),
+///     |(min, max), rect| (min.min(rect.min()), max.max(rect.max())),
+/// );
+/// assert_eq!(min, Vec2 { x: -1., y: -2. });
+/// assert_eq!(max, Vec2 { x: 3., y: 4. });
+/// ```
+#[cfg(feature = "rectangle")]
+pub trait Bounded {
+    /// Get the smallest axis aligned [`Rectangle`] containing the shape
+    ///
+    /// A [`Polygon`] or [`Mesh`] without any coordinates is bounded by an empty [`Rectangle`] at `(0., 0.)`.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Bounded, Circle, Line, Mesh, Polygon, Rectangle, Triangle};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// fn contains(rect: &Rectangle, point: Vec2) -> bool {
+    ///     point.cmpge(rect.min() - 1e-5).all() && point.cmple(rect.max() + 1e-5).all()
+    /// }
+    ///
+    /// let circle = Circle::new(Vec2 { x: 1., y: 2. }, 3.);
+    /// let rect = circle.bounding_box();
+    /// assert_eq!((rect.min(), rect.max()), (Vec2 { x: -2., y: -1. }, Vec2 { x: 4., y: 5. }));
+    /// assert!(circle.to_polygon(64).coordinates().iter().all(|c| contains(&rect, *c)));
+    ///
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// let ellipse = Ellipse::new(Vec2::ZERO, 2., 1.);
+    /// let rect = ellipse.bounding_box();
+    /// assert_eq!((rect.min(), rect.max()), (Vec2 { x: -2., y: -1. }, Vec2 { x: 2., y: 1. }));
+    /// assert!(ellipse.to_polygon(64).coordinates().iter().all(|c| contains(&rect, *c)));
+    ///
+    /// // a quarter turn swaps the extents
+    /// let ellipse = Ellipse::new_rotated(Vec2::ZERO, 2., 1., std::f32::consts::FRAC_PI_2);
+    /// let rect = ellipse.bounding_box();
+    /// assert!(rect.min().distance(Vec2 { x: -1., y: -2. }) < 1e-5 && rect.max().distance(Vec2 { x: 1., y: 2. }) < 1e-5);
+    /// # }
+    ///
+    /// let line = Line::new(Vec2 { x: 3., y: -1. }, Vec2 { x: 1., y: 2. });
+    /// let rect = line.bounding_box();
+    /// assert_eq!((rect.min(), rect.max()), (Vec2 { x: 1., y: -1. }, Vec2 { x: 3., y: 2. }));
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 1. }, Vec2 { x: -1., y: 3. });
+    /// let rect = Bounded::bounding_box(&triangle);
+    /// assert_eq!((rect.min(), rect.max()), (Vec2 { x: -1., y: 0. }, Vec2 { x: 4., y: 3. }));
+    ///
+    /// let polygon = Polygon::new(vec![Vec2 { x: -2., y: -1. }, Vec2 { x: 3., y: 0.5 }, Vec2 { x: -2., y: 2. }]);
+    /// let rect = Bounded::bounding_box(&polygon);
+    /// assert_eq!((rect.min(), rect.max()), (Vec2 { x: -2., y: -1. }, Vec2 { x: 3., y: 2. }));
+    /// let rect = Bounded::bounding_box(&Polygon::new(Vec::new()));
+    /// assert_eq!((rect.min(), rect.max()), (Vec2::ZERO, Vec2::ZERO));
+    ///
+    /// let mesh = Mesh::from_triangle(&triangle);
+    /// let rect = Bounded::bounding_box(&mesh);
+    /// assert_eq!((rect.min(), rect.max()), (Vec2 { x: -1., y: 0. }, Vec2 { x: 4., y: 3. }));
+    ///
+    /// let rect = Rectangle::new(1., 2., 3., 4.).bounding_box();
+    /// assert_eq!((rect.min(), rect.max()), (Vec2 { x: 1., y: 2. }, Vec2 { x: 3., y: 4. }));
+    /// ```
+    fn bounding_box(&self) -> Rectangle;
+}
+
+#[cfg(all(feature = "rectangle", feature = "annulus"))]
+impl Bounded for Annulus {
+    fn bounding_box(&self) -> Rectangle {
+        Annulus::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "arc"))]
+impl Bounded for Arc {
+    fn bounding_box(&self) -> Rectangle {
+        Arc::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "capsule"))]
+impl Bounded for Capsule {
+    fn bounding_box(&self) -> Rectangle {
+        Capsule::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "circle"))]
+impl Bounded for Circle {
+    fn bounding_box(&self) -> Rectangle {
+        let radius = Vec2::splat(self.radius());
+        Rectangle::new_coordinates(self.center() - radius, self.center() + radius)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "circular_segment"))]
+impl Bounded for CircularSegment {
+    fn bounding_box(&self) -> Rectangle {
+        CircularSegment::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "ellipse"))]
+impl Bounded for Ellipse {
+    fn bounding_box(&self) -> Rectangle {
+        // The furthest the turned axes reach along `x` and `y`
+        let [major, minor] = self.axes();
+        let (major, minor) = (major * self.radius_major(), minor * self.radius_minor());
+        let radii = Vec2 {
+            x: major.x.hypot(minor.x),
+            y: major.y.hypot(minor.y),
+        };
+        Rectangle::new_coordinates(self.center() - radii, self.center() + radii)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "line"))]
+impl Bounded for Line {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new_coordinates(self.origin().min(self.end()), self.origin().max(self.end()))
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "triangle"))]
+impl Bounded for Triangle {
+    fn bounding_box(&self) -> Rectangle {
+        Triangle::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "polygon"))]
+impl Bounded for Polygon {
+    fn bounding_box(&self) -> Rectangle {
+        Polygon::bounding_box(self)
+            .unwrap_or_else(|| Rectangle::new_coordinates(Vec2::ZERO, Vec2::ZERO))
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "polygon_with_holes"))]
+impl Bounded for PolygonWithHoles {
+    fn bounding_box(&self) -> Rectangle {
+        PolygonWithHoles::bounding_box(self)
+            .unwrap_or_else(|| Rectangle::new_coordinates(Vec2::ZERO, Vec2::ZERO))
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "oriented_rectangle"))]
+impl Bounded for OrientedRectangle {
+    fn bounding_box(&self) -> Rectangle {
+        OrientedRectangle::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "polyline"))]
+impl Bounded for Polyline {
+    fn bounding_box(&self) -> Rectangle {
+        Polyline::bounding_box(self)
+            .unwrap_or_else(|| Rectangle::new_coordinates(Vec2::ZERO, Vec2::ZERO))
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "quadratic_bezier"))]
+impl Bounded for QuadraticBezier {
+    fn bounding_box(&self) -> Rectangle {
+        QuadraticBezier::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "cubic_bezier"))]
+impl Bounded for CubicBezier {
+    fn bounding_box(&self) -> Rectangle {
+        CubicBezier::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "mesh"))]
+impl Bounded for Mesh {
+    fn bounding_box(&self) -> Rectangle {
+        Mesh::bounding_box(self)
+            .unwrap_or_else(|| Rectangle::new_coordinates(Vec2::ZERO, Vec2::ZERO))
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "point"))]
+impl Bounded for Point {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new_coordinates(self.coordinate(), self.coordinate())
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Bounded for Rectangle {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new_coordinates(self.min(), self.max())
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "quad"))]
+impl Bounded for Quad {
+    fn bounding_box(&self) -> Rectangle {
+        Quad::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "regular_polygon"))]
+impl Bounded for RegularPolygon {
+    fn bounding_box(&self) -> Rectangle {
+        RegularPolygon::bounding_box(self)
+    }
+}
+
+#[cfg(feature = "rounded_rectangle")]
+impl Bounded for RoundedRectangle {
+    fn bounding_box(&self) -> Rectangle {
+        RoundedRectangle::bounding_box(self)
+    }
+}
+
+#[cfg(all(feature = "rectangle", feature = "sector"))]
+impl Bounded for Sector {
+    fn bounding_box(&self) -> Rectangle {
+        Sector::bounding_box(self)
+    }
+}
+
+// ##########
+// Contains
+// ##########
+/// A shape which can check if a point lies inside of it
+///
+/// Points exactly on the boundary of the shape are considered inside.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Contains, Mesh, Polygon, Rectangle, Triangle};
+/// # #[cfg(feature = "ellipse")]
+/// use shapes2d::prelude::Ellipse;
+///
+/// let square = vec![Vec2 { x: -1., y: -1. }, Vec2 { x: 1., y: -1. }, Vec2::ONE, Vec2 { x: -1., y: 1. }];
+/// let triangle = Triangle::new(Vec2 { x: -1., y: -1. }, Vec2 { x: 1., y: -1. }, Vec2 { x: -1., y: 1. });
+///
+/// // each shape with a point inside, a point on the boundary, and a point outside
+/// let mut battery: Vec<(Box<dyn Contains>, [Vec2; 3])> = vec![
+///     (Box::new(Circle::new(Vec2::ZERO, 1.)), [Vec2::ZERO, Vec2 { x: 0., y: -1. }, Vec2::ONE]),
+///     (Box::new(Rectangle::new(-1., -1., 1., 1.)), [Vec2::ZERO, Vec2 { x: 1., y: 0.5 }, Vec2 { x: 1.5, y: 0. }]),
+///     (Box::new(Polygon::new(square)), [Vec2::ZERO, Vec2::ONE, Vec2 { x: 0., y: 1.5 }]),
+///     (Box::new(Mesh::from_triangle(&triangle)), [Vec2 { x: -0.5, y: -0.5 }, Vec2::ZERO, Vec2 { x: 0.5, y: 0.5 }]),
+///     (Box::new(triangle), [Vec2 { x: -0.5, y: -0.5 }, Vec2 { x: -1., y: 0. }, Vec2 { x: 0.5, y: 0.5 }]),
+/// ];
+/// # #[cfg(feature = "ellipse")]
+/// battery.push((Box::new(Ellipse::new(Vec2::ZERO, 2., 1.)), [Vec2 { x: 1.5, y: 0. }, Vec2 { x: -2., y: 0. }, Vec2::ONE]));
+/// # #[cfg(feature = "ellipse")]
+/// battery.push((
+///     Box::new(Ellipse::new_rotated(Vec2::ZERO, 2., 1., std::f32::consts::FRAC_PI_2)),
+///     [Vec2 { x: 0., y: 1.5 }, Vec2 { x: 0., y: -1.999 }, Vec2 { x: 1.5, y: 0. }],
+/// ));
+///
+/// for (shape, [inside, boundary, outside]) in &battery {
+///     assert!(shape.contains(*inside));
+///     assert!(shape.contains(*boundary));
+///     assert!(!shape.contains(*outside));
+/// }
+/// ```
+pub trait Contains {
+    /// Checks if a `point` is inside of the shape, including its boundary
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Contains};
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 1.);
+    ///
+    /// assert!(circle.contains(Vec2 { x: 0.5, y: 0.5 }));
+    /// assert!(circle.contains(Vec2 { x: 1., y: 0. }));
+    /// assert!(!circle.contains(Vec2::ONE));
+    /// ```
+    fn contains(&self, point: Vec2) -> bool;
+}
+
+#[cfg(feature = "annulus")]
+impl Contains for Annulus {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "capsule")]
+impl Contains for Capsule {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "circle")]
+impl Contains for Circle {
+    fn contains(&self, point: Vec2) -> bool {
+        point.distance_squared(self.center()) <= self.radius() * self.radius()
+    }
+}
+
+#[cfg(feature = "circular_segment")]
+impl Contains for CircularSegment {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl Contains for Ellipse {
+    fn contains(&self, point: Vec2) -> bool {
+        let offset = ellipse_local_point(self, point);
+        let x = offset.x / self.radius_major();
+        let y = offset.y / self.radius_minor();
+        x * x + y * y <= 1.
+    }
+}
+
+#[cfg(feature = "half_plane")]
+impl Contains for HalfPlane {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Contains for Rectangle {
+    fn contains(&self, point: Vec2) -> bool {
+        point.cmpge(self.min()).all() && point.cmple(self.max()).all()
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl Contains for Triangle {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Contains for Polygon {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "polygon_with_holes")]
+impl Contains for PolygonWithHoles {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "oriented_rectangle")]
+impl Contains for OrientedRectangle {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "mesh")]
+impl Contains for Mesh {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "quad")]
+impl Contains for Quad {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "regular_polygon")]
+impl Contains for RegularPolygon {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "rounded_rectangle")]
+impl Contains for RoundedRectangle {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+#[cfg(feature = "sector")]
+impl Contains for Sector {
+    fn contains(&self, point: Vec2) -> bool {
+        self.contains_point(point)
+    }
+}
+
+// ##########
+// Intersects
+// ##########
+/// A shape which can check if it overlaps a shape of type `Rhs`
+///
+/// Shapes which only touch along their boundaries intersect. Every pair of different shapes is implemented in
+/// both directions, so `a.intersects(&b)` always agrees with `b.intersects(&a)`.
+///
+/// ```
+/// use glam::Vec2;
+/// use rand::{Rng, SeedableRng};
+/// use shapes2d::prelude::{Circle, Intersects, Line, Polygon, Rectangle, Triangle};
+///
+/// // shapes which only touch intersect in both orders
+/// let rect = Rectangle::new(0., 0., 1., 1.);
+/// let circle = Circle::new(Vec2 { x: 2., y: 0.5 }, 1.);
+/// assert!(rect.intersects(&circle) && circle.intersects(&rect));
+/// let line = Line::new(Vec2 { x: 3., y: 0.5 }, Vec2 { x: 5., y: 0.5 });
+/// assert!(circle.intersects(&line) && line.intersects(&circle));
+/// let triangle = Triangle::new(Vec2 { x: 3., y: 0.5 }, Vec2 { x: 4., y: 0.5 }, Vec2 { x: 3., y: 1.5 });
+/// assert!(circle.intersects(&triangle) && triangle.intersects(&circle));
+///
+/// // a circle with no radius is a point
+/// let dot = Circle::new(Vec2::splat(0.5), 0.);
+/// assert!(dot.intersects(&rect) && rect.intersects(&dot));
+/// assert!(!dot.intersects(&circle) && !circle.intersects(&dot));
+///
+/// // random shapes around the origin agree in either order
+/// fn point(rng: &mut impl Rng) -> Vec2 {
+///     Vec2 { x: rng.gen_range(-2.0..2.), y: rng.gen_range(-2.0..2.) }
+/// }
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(186);
+/// let mut agreed = (0, 0);
+/// for _ in 0..64 {
+///     let (a, b) = (point(&mut rng), point(&mut rng));
+///     let rect = Rectangle::new_coordinates(a.min(b), a.max(b));
+///     let circle = Circle::new(point(&mut rng), rng.gen_range(0.0..1.6));
+///     let line = Line::new(point(&mut rng), point(&mut rng));
+///     let triangle = Triangle::new(point(&mut rng), point(&mut rng), point(&mut rng));
+///     let polygon = Polygon::new(vec![a, Vec2 { x: b.x, y: a.y }, b]);
+///
+///     assert_eq!(rect.intersects(&circle), circle.intersects(&rect));
+///     assert_eq!(rect.intersects(&line), line.intersects(&rect));
+///     assert_eq!(circle.intersects(&line), line.intersects(&circle));
+///     assert_eq!(triangle.intersects(&circle), circle.intersects(&triangle));
+///     assert_eq!(polygon.intersects(&circle), circle.intersects(&polygon));
+///
+///     // the same type in either order
+///     let other = Triangle::new(point(&mut rng), point(&mut rng), point(&mut rng));
+///     assert_eq!(triangle.intersects(&other), other.intersects(&triangle));
+///     let other = Line::new(point(&mut rng), point(&mut rng));
+///     assert_eq!(line.intersects(&other), other.intersects(&line));
+///
+///     if rect.intersects(&circle) {
+///         agreed.0 += 1;
+///     } else {
+///         agreed.1 += 1;
+///     }
+/// }
+/// // both outcomes were exercised
+/// assert!(agreed.0 > 0 && agreed.1 > 0);
+/// ```
+pub trait Intersects<Rhs = Self> {
+    /// Checks if the shape overlaps or touches `other`
+    ///
+    /// [`Polygon`]s are only checked against each other with the separating axis theorem, so both must
+    /// be convex (see [`Polygon::intersects_convex`]).
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Intersects, Line, Polygon, Rectangle, Triangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 2., 2.);
+    /// let circle = Circle::new(Vec2 { x: 3., y: 1. }, 1.);
+    /// let line = Line::new(Vec2 { x: -1., y: 3. }, Vec2 { x: 3., y: -1. });
+    /// let triangle = Triangle::new(Vec2 { x: 3., y: 3. }, Vec2 { x: 5., y: 3. }, Vec2 { x: 3., y: 5. });
+    ///
+    /// // touching at the right edge of the rectangle
+    /// assert!(rect.intersects(&circle));
+    /// assert!(circle.intersects(&rect));
+    /// // crossing through the rectangle without an end inside
+    /// assert!(rect.intersects(&line));
+    /// assert!(!circle.intersects(&line));
+    /// assert!(!rect.intersects(&Rectangle::new(2.5, 0., 4., 2.)));
+    /// assert!(!triangle.intersects(&circle));
+    /// assert!(triangle.intersects(&Circle::new(Vec2 { x: 2., y: 2. }, 1.5)));
+    ///
+    /// // a triangle inside of another triangle
+    /// let inner = Triangle::new(Vec2 { x: 3.5, y: 3.5 }, Vec2 { x: 4., y: 3.5 }, Vec2 { x: 3.5, y: 4. });
+    /// assert!(triangle.intersects(&inner));
+    /// assert!(inner.intersects(&triangle));
+    ///
+    /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    /// let diamond = Polygon::new(vec![Vec2 { x: 3., y: 0. }, Vec2 { x: 4., y: 1. }, Vec2 { x: 3., y: 2. }, Vec2 { x: 2., y: 1. }]);
+    /// assert!(square.intersects(&diamond));
+    /// assert!(diamond.intersects(&circle));
+    ///
+    /// let crossing = Line::new(Vec2 { x: 0., y: -1. }, Vec2 { x: 2., y: 3. });
+    /// assert!(line.intersects(&crossing));
+    /// assert!(!line.intersects(&Line::new(Vec2 { x: 2., y: 2. }, Vec2 { x: 4., y: 0. })));
+    /// ```
+    fn intersects(&self, other: &Rhs) -> bool;
+}
+
+// Implements `AbsDiffEq` and `RelativeEq` with an `f32` epsilon by comparing the listed fields in order.
+// A field followed by `[..]` is compared as a slice, so lengths must match, and the fields after `exact`
+// must be equal
+#[cfg(feature = "approx")]
+#[allow(unused_macros)]
+macro_rules! impl_approx {
+    ($(#[$meta:meta])* $shape:ident { $($field:ident $([$slice:tt])?),+ } $(exact { $($exact:ident),+ })?) => {
+        $(#[$meta])*
+        impl approx::AbsDiffEq for $shape {
+            type Epsilon = f32;
+
+            fn default_epsilon() -> f32 {
+                f32::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                $($(self.$exact == other.$exact &&)+)?
+                $(approx::AbsDiffEq::abs_diff_eq(
+                    &self.$field$([$slice])?,
+                    &other.$field$([$slice])?,
+                    epsilon,
+                ))&&+
+            }
+        }
+
+        impl approx::RelativeEq for $shape {
+            fn default_max_relative() -> f32 {
+                f32::EPSILON
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                $($(self.$exact == other.$exact &&)+)?
+                $(approx::RelativeEq::relative_eq(
+                    &self.$field$([$slice])?,
+                    &other.$field$([$slice])?,
+                    epsilon,
+                    max_relative,
+                ))&&+
+            }
+        }
+    };
+}
+#[cfg(feature = "approx")]
+#[allow(unused_imports)]
+pub(crate) use impl_approx;
+
+// Implements `$trait` between a shape and itself, or between two different shapes in both directions
+// with the reverse direction forwarding to the first so that both always agree
+macro_rules! impl_symmetric {
+    ($trait:ident::$method:ident -> $output:ty; $(#[$meta:meta])* $shape:ty, |$a:ident, $b:ident| $body:expr) => {
+        $(#[$meta])*
+        impl $trait for $shape {
+            fn $method(&self, other: &$shape) -> $output {
+                let ($a, $b) = (self, other);
+                $body
+            }
+        }
+    };
+    ($trait:ident::$method:ident -> $output:ty; $(#[$meta:meta])* $lhs:ty, $rhs:ty, |$a:ident, $b:ident| $body:expr) => {
+        $(#[$meta])*
+        impl $trait<$rhs> for $lhs {
+            fn $method(&self, other: &$rhs) -> $output {
+                let ($a, $b) = (self, other);
+                $body
+            }
+        }
+
+        $(#[$meta])*
+        impl $trait<$lhs> for $rhs {
+            fn $method(&self, other: &$lhs) -> $output {
+                other.$method(self)
+            }
+        }
+    };
+}
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(feature = "rectangle")]
+    Rectangle,
+    |a, b| a.min().cmple(b.max()).all() && b.min().cmple(a.max()).all()
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(all(feature = "rectangle", feature = "circle"))]
+    Rectangle,
+    Circle,
+    |rect, circle| {
+        let closest = circle.center().clamp(rect.min(), rect.max());
+        closest.distance_squared(circle.center()) <= circle.radius() * circle.radius()
+    }
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(all(feature = "rectangle", feature = "line"))]
+    Rectangle,
+    Line,
+    |rect, line| {
+        let (min, max) = (rect.min(), rect.max());
+        let corners = [
+            min,
+            Vec2 { x: max.x, y: min.y },
+            max,
+            Vec2 { x: min.x, y: max.y },
+        ];
+        rect.contains(line.origin())
+            || (0..4).any(|i| {
+                segments_intersect(line.origin(), line.end(), corners[i], corners[(i + 1) % 4])
+            })
+    }
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(feature = "circle")]
+    Circle,
+    |a, b| {
+        let radii = a.radius() + b.radius();
+        a.center().distance_squared(b.center()) <= radii * radii
+    }
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(all(feature = "circle", feature = "line"))]
+    Circle,
+    Line,
+    |circle, line| {
+        let closest = closest_point_on_segment(circle.center(), line.origin(), line.end());
+        closest.distance_squared(circle.center()) <= circle.radius() * circle.radius()
+    }
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(feature = "triangle")]
+    Triangle,
+    |a, b| {
+        let (a_vertices, b_vertices) = (a.vertices(), b.vertices());
+        (0..3).any(|i| {
+            (0..3).any(|j| {
+                segments_intersect(
+                    a_vertices[i],
+                    a_vertices[(i + 1) % 3],
+                    b_vertices[j],
+                    b_vertices[(j + 1) % 3],
+                )
+            })
+        }) || a.contains_point(b_vertices[0])
+            || b.contains_point(a_vertices[0])
+    }
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(all(feature = "triangle", feature = "circle"))]
+    Triangle,
+    Circle,
+    |triangle, circle| {
+        let vertices = triangle.vertices();
+        triangle.contains_point(circle.center())
+            || (0..3).any(|i| {
+                let closest =
+                    closest_point_on_segment(circle.center(), vertices[i], vertices[(i + 1) % 3]);
+                closest.distance_squared(circle.center()) <= circle.radius() * circle.radius()
+            })
+    }
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(feature = "polygon")]
+    Polygon,
+    |a, b| a.intersects_convex(b)
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(all(feature = "polygon", feature = "circle"))]
+    Polygon,
+    Circle,
+    |polygon, circle| polygon.intersects_circle(circle)
+);
+
+impl_symmetric!(
+    Intersects::intersects -> bool;
+    #[cfg(feature = "line")]
+    Line,
+    |a, b| segments_intersect(a.origin(), a.end(), b.origin(), b.end())
+);
+
+// ##########
+// ClosestPoint
+// ##########
+/// A shape which can find its closest point to another point
+///
+/// A `point` inside of a solid shape is its own closest point. Use the `closest_boundary_point`
+/// methods of the concrete shapes, such as [`Polygon::closest_boundary_point`], to find the closest point
+/// on the boundary instead.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, ClosestPoint, Line, Rectangle};
+///
+/// let shapes: Vec<Box<dyn ClosestPoint>> = vec![
+///     Box::new(Circle::new(Vec2 { x: 5., y: 0. }, 1.)),
+///     Box::new(Line::new(Vec2 { x: -4., y: 2. }, Vec2 { x: 4., y: 2. })),
+///     Box::new(Rectangle::new(-3., -6., 3., -4.)),
+/// ];
+///
+/// let nearest = shapes
+///     .iter()
+///     .map(|shape| shape.distance_to(Vec2::ZERO))
+///     .fold(f32::INFINITY, f32::min);
+/// assert_eq!(nearest, 2.);
+/// ```
+pub trait ClosestPoint {
+    /// Get the point of the shape closest to `point`, which is `point` itself when it is inside of the shape
+    ///
+    /// An empty [`Polygon`] has no points, and returns `point` itself.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, ClosestPoint, Line, Polygon, Ray, Rectangle, Triangle};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 2.);
+    /// assert_eq!(circle.closest_point(Vec2 { x: 0., y: 5. }), Vec2 { x: 0., y: 2. });
+    /// assert_eq!(circle.closest_point(Vec2::ONE), Vec2::ONE);
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    /// assert_eq!(rect.closest_point(Vec2 { x: 6., y: -1. }), Vec2 { x: 4., y: 0. });
+    ///
+    /// let line = Line::new(Vec2::ZERO, Vec2 { x: 4., y: 0. });
+    /// assert_eq!(line.closest_point(Vec2 { x: 1., y: 3. }), Vec2 { x: 1., y: 0. });
+    /// assert_eq!(line.closest_point(Vec2 { x: 6., y: 3. }), Vec2 { x: 4., y: 0. });
+    ///
+    /// let ray = Ray::new_direction(Vec2::ZERO, Vec2::X);
+    /// assert_eq!(ray.closest_point(Vec2 { x: 6., y: 3. }), Vec2 { x: 6., y: 0. });
+    /// assert_eq!(ray.closest_point(Vec2 { x: -6., y: 3. }), Vec2::ZERO);
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 4. });
+    /// assert_eq!(triangle.closest_point(Vec2 { x: 3., y: 3. }), Vec2 { x: 2., y: 2. });
+    /// assert_eq!(triangle.closest_point(Vec2::ONE), Vec2::ONE);
+    ///
+    /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    /// assert_eq!(square.closest_point(Vec2 { x: 1., y: -3. }), Vec2 { x: 1., y: 0. });
+    /// assert_eq!(Polygon::new(Vec::new()).closest_point(Vec2::ONE), Vec2::ONE);
+    ///
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// let ellipse = Ellipse::new(Vec2::ZERO, 4., 2.);
+    /// assert_abs_diff_eq!(ellipse.closest_point(Vec2 { x: 0., y: 5. }), Vec2 { x: 0., y: 2. }, epsilon = 1e-5);
+    /// assert_abs_diff_eq!(ellipse.closest_point(Vec2 { x: -7., y: 0. }), Vec2 { x: -4., y: 0. }, epsilon = 1e-5);
+    /// let closest = ellipse.closest_point(Vec2 { x: 4., y: 3. });
+    /// let on_boundary = (closest.x / 4.).powi(2) + (closest.y / 2.).powi(2);
+    /// assert_abs_diff_eq!(on_boundary, 1., epsilon = 1e-4);
+    /// # }
+    /// ```
+    fn closest_point(&self, point: Vec2) -> Vec2;
+
+    /// Get the distance from `point` to the closest point of the shape, which is `0.` inside of the shape
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, ClosestPoint, Line, Rectangle, Triangle};
+    ///
+    /// let point = Vec2 { x: 6., y: 8. };
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 4.);
+    /// assert_eq!(circle.distance_to(point), point.length() - 4.);
+    ///
+    /// let rect = Rectangle::new(0., 0., 3., 4.);
+    /// assert_eq!(rect.distance_to(point), (point - Vec2 { x: 3., y: 4. }).length());
+    /// assert_eq!(rect.distance_to(Vec2::ONE), 0.);
+    ///
+    /// let line = Line::new(Vec2 { x: -10., y: 2. }, Vec2 { x: 10., y: 2. });
+    /// assert_eq!(line.distance_to(point), 6.);
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 6., y: 0. }, Vec2 { x: 6., y: 5. });
+    /// assert_eq!(triangle.distance_to(point), 3.);
+    /// ```
+    fn distance_to(&self, point: Vec2) -> f32 {
+        point.distance(self.closest_point(point))
+    }
+}
+
+#[cfg(feature = "annulus")]
+impl ClosestPoint for Annulus {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        let offset = point - self.center();
+        let distance_squared = offset.length_squared();
+        if distance_squared > self.outer_radius() * self.outer_radius() {
+            return self.center() + offset.normalize() * self.outer_radius();
+        }
+        if distance_squared < self.inner_radius() * self.inner_radius() {
+            // every point of the hole's edge is as close to the center, so pick the one at angle 0.
+            let direction = offset.try_normalize().unwrap_or(Vec2::X);
+            return self.center() + direction * self.inner_radius();
+        }
+        point
+    }
+}
+
+#[cfg(feature = "capsule")]
+impl ClosestPoint for Capsule {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        let axis = self.closest_point_on_segment(point);
+        let offset = point - axis;
+        if offset.length_squared() <= self.radius() * self.radius() {
+            return point;
+        }
+        axis + offset.normalize() * self.radius()
+    }
+}
+
+#[cfg(feature = "circle")]
+impl ClosestPoint for Circle {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        let offset = point - self.center();
+        if offset.length_squared() <= self.radius() * self.radius() {
+            return point;
+        }
+        self.center() + offset.normalize() * self.radius()
+    }
+}
+
+#[cfg(feature = "half_plane")]
+impl ClosestPoint for HalfPlane {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        point - self.normal() * self.signed_distance(point).max(0.)
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl ClosestPoint for Ellipse {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        if Contains::contains(self, point) {
+            return point;
+        }
+
+        // Iteratively walk the parameter of a point on the ellipse towards the query, working along the axes
+        // of the ellipse in the first quadrant and mirroring the result back
+        let (a, b) = (self.radius_major(), self.radius_minor());
+        let offset = ellipse_local_point(self, point);
+        let p = offset.abs();
+        let mut t = Vec2::splat(std::f32::consts::FRAC_1_SQRT_2);
+        for _ in 0..4 {
+            let on_ellipse = Vec2 {
+                x: a * t.x,
+                y: b * t.y,
+            };
+            let evolute = Vec2 {
+                x: (a * a - b * b) * t.x.powi(3) / a,
+                y: (b * b - a * a) * t.y.powi(3) / b,
+            };
+            let r = (on_ellipse - evolute).length();
+            let q = p - evolute;
+            let next =
+                ((q * r / q.length() + evolute) / Vec2 { x: a, y: b }).clamp(Vec2::ZERO, Vec2::ONE);
+            t = next.normalize_or_zero();
+        }
+        ellipse_world_point(
+            self,
+            Vec2 {
+                x: a * t.x,
+                y: b * t.y,
+            } * offset.signum(),
+        )
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl ClosestPoint for Rectangle {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        point.clamp(self.min(), self.max())
+    }
+}
+
+#[cfg(feature = "line")]
+impl ClosestPoint for Line {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        closest_point_on_segment(point, self.origin(), self.end())
+    }
+}
+
+#[cfg(feature = "infinite_line")]
+impl ClosestPoint for InfiniteLine {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        self.project(point)
+    }
+}
+
+#[cfg(feature = "ray")]
+impl ClosestPoint for Ray {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        let distance = (point - self.origin()).dot(self.direction()).max(0.);
+        self.origin() + self.direction() * distance
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl ClosestPoint for Triangle {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.contains_point(point) {
+            return point;
+        }
+        let vertices = self.vertices();
+        (0..3)
+            .map(|i| closest_point_on_segment(point, vertices[i], vertices[(i + 1) % 3]))
+            .min_by(|a, b| {
+                a.distance_squared(point)
+                    .total_cmp(&b.distance_squared(point))
+            })
+            .unwrap_or(point)
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl ClosestPoint for Polygon {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.contains_point(point) {
+            return point;
+        }
+        self.closest_boundary_point(point).unwrap_or(point)
+    }
+}
+
+#[cfg(feature = "polygon_with_holes")]
+impl ClosestPoint for PolygonWithHoles {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        if self.contains_point(point) {
+            return point;
+        }
+        self.closest_boundary_point(point).unwrap_or(point)
+    }
+}
+
+#[cfg(feature = "oriented_rectangle")]
+impl ClosestPoint for OrientedRectangle {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        OrientedRectangle::closest_point(self, point)
+    }
+}
+
+#[cfg(feature = "quadratic_bezier")]
+impl ClosestPoint for QuadraticBezier {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        self.point_at(self.closest_t(point))
+    }
+}
+
+#[cfg(feature = "cubic_bezier")]
+impl ClosestPoint for CubicBezier {
+    fn closest_point(&self, point: Vec2) -> Vec2 {
+        self.point_at(self.closest_t(point))
+    }
+}
+
+// ##########
+// SignedDistance
+// ##########
+/// A shape which can measure the signed distance from a point to its boundary
+///
+/// The distance is negative inside of the shape, `0.` on its boundary, and positive outside.
+/// [`Line`], [`Ray`] and [`Point`] enclose nothing, so their distance is never negative.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Contains, Polygon, Rectangle, SignedDistance, Triangle};
+/// # #[cfg(feature = "ellipse")]
+/// use shapes2d::prelude::Ellipse;
+///
+/// fn check(shape: &(impl SignedDistance + Contains)) {
+///     for x in -20..=20 {
+///         for y in -20..=20 {
+///             let point = Vec2 { x: x as f32, y: y as f32 } * 0.25;
+///             let distance = shape.signed_distance(point);
+///             if distance < -1e-4 {
+///                 assert!(shape.contains(point), "{point} should be inside");
+///             } else if distance > 1e-4 {
+///                 assert!(!shape.contains(point), "{point} should be outside");
+///             }
+///         }
+///     }
+/// }
+///
+/// check(&Circle::new(Vec2 { x: 1., y: 0. }, 2.));
+/// # #[cfg(feature = "ellipse")]
+/// check(&Ellipse::new(Vec2::ZERO, 3., 1.5));
+/// # #[cfg(feature = "ellipse")]
+/// check(&Ellipse::new_rotated(Vec2 { x: 0.5, y: -0.5 }, 3., 1.5, 0.6));
+/// check(&Rectangle::new(-2., -1., 3., 2.5));
+/// check(&Triangle::new(Vec2 { x: -3., y: -2. }, Vec2 { x: 4., y: -1. }, Vec2 { x: 0., y: 4. }));
+/// check(&Polygon::new(vec![
+///     Vec2 { x: -3., y: -3. },
+///     Vec2 { x: 3., y: -3. },
+///     Vec2 { x: 3., y: 3. },
+///     Vec2 { x: 0., y: 0.5 },
+///     Vec2 { x: -3., y: 3. },
+/// ]));
+/// ```
+pub trait SignedDistance {
+    /// Get the signed distance from `point` to the boundary of the shape, negative inside
+    ///
+    /// The distance to an [`Ellipse`] is approximate away from its boundary, but always has the right sign
+    /// and is exact for an [`Ellipse`] with equal radii. A [`Polygon`] with no coordinates is infinitely far away.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Line, Point, Ray, Rectangle, SignedDistance, Triangle};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 2.);
+    /// assert_eq!(circle.signed_distance(Vec2 { x: 3., y: 4. }), 3.);
+    /// assert_eq!(circle.signed_distance(Vec2 { x: 0., y: 0.5 }), -1.5);
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    /// assert_eq!(rect.signed_distance(Vec2 { x: 1., y: 1.5 }), -0.5);
+    /// assert_eq!(rect.signed_distance(Vec2 { x: 7., y: 6. }), 5.);
+    /// assert_eq!(rect.signed_distance(Vec2 { x: 4., y: 1. }), 0.);
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 4. });
+    /// assert_eq!(triangle.signed_distance(Vec2 { x: 1., y: 0.5 }), -0.5);
+    /// assert_eq!(triangle.signed_distance(Vec2 { x: -3., y: 2. }), 3.);
+    ///
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// let ellipse = Ellipse::new(Vec2::ZERO, 4., 2.);
+    /// assert_eq!(ellipse.signed_distance(Vec2 { x: 4., y: 0. }), 0.);
+    /// assert_eq!(ellipse.signed_distance(Vec2 { x: 0., y: 3. }), 1.);
+    /// assert!(ellipse.signed_distance(Vec2::ZERO) < 0.);
+    /// # }
+    ///
+    /// let line = Line::new(Vec2::ZERO, Vec2 { x: 4., y: 0. });
+    /// assert_eq!(line.signed_distance(Vec2 { x: 2., y: -3. }), 3.);
+    /// assert_eq!(line.signed_distance(Vec2 { x: 7., y: 4. }), 5.);
+    ///
+    /// let point = Point::new(Vec2::ONE);
+    /// assert_eq!(point.signed_distance(Vec2 { x: 4., y: 5. }), 5.);
+    ///
+    /// let ray = Ray::new_direction(Vec2::ZERO, Vec2::Y);
+    /// assert_eq!(ray.signed_distance(Vec2 { x: 2., y: 10. }), 2.);
+    /// assert_eq!(ray.signed_distance(Vec2 { x: 3., y: -4. }), 5.);
+    /// ```
+    fn signed_distance(&self, point: Vec2) -> f32;
+}
+
+#[cfg(feature = "point")]
+impl SignedDistance for Point {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        point.distance(self.coordinate())
+    }
+}
+
+#[cfg(feature = "line")]
+impl SignedDistance for Line {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        point.distance(closest_point_on_segment(point, self.origin(), self.end()))
+    }
+}
+
+#[cfg(feature = "infinite_line")]
+impl SignedDistance for InfiniteLine {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        self.distance_to_point(point)
+    }
+}
+
+#[cfg(feature = "ray")]
+impl SignedDistance for Ray {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        self.distance_to(point)
+    }
+}
+
+#[cfg(feature = "annulus")]
+impl SignedDistance for Annulus {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let distance = point.distance(self.center());
+        // without a hole the center is inside, not on an edge
+        if self.inner_radius() <= 0. {
+            return distance - self.outer_radius();
+        }
+        (distance - self.outer_radius()).max(self.inner_radius() - distance)
+    }
+}
+
+#[cfg(feature = "capsule")]
+impl SignedDistance for Capsule {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        point.distance(self.closest_point_on_segment(point)) - self.radius()
+    }
+}
+
+#[cfg(feature = "circle")]
+impl SignedDistance for Circle {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        point.distance(self.center()) - self.radius()
+    }
+}
+
+#[cfg(feature = "half_plane")]
+impl SignedDistance for HalfPlane {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        HalfPlane::signed_distance(self, point)
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl SignedDistance for Ellipse {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let radii = Vec2 {
+            x: self.radius_major(),
+            y: self.radius_minor(),
+        };
+        let offset = ellipse_local_point(self, point);
+        // The distance from the unit circle after scaling, corrected by the gradient of the scaling
+        let k0 = (offset / radii).length();
+        let k1 = (offset / (radii * radii)).length();
+        if k1 == 0. {
+            return -radii.min_element();
+        }
+        k0 * (k0 - 1.) / k1
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl SignedDistance for Rectangle {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let half_size = self.size() * 0.5;
+        let q = (point - self.center()).abs() - half_size;
+        q.max(Vec2::ZERO).length() + q.max_element().min(0.)
+    }
+}
+
+#[cfg(feature = "rounded_rectangle")]
+impl SignedDistance for RoundedRectangle {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        RoundedRectangle::signed_distance(self, point)
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl SignedDistance for Triangle {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        let vertices = self.vertices();
+        let distance = (0..3)
+            .map(|i| {
+                point.distance(closest_point_on_segment(
+                    point,
+                    vertices[i],
+                    vertices[(i + 1) % 3],
+                ))
+            })
+            .fold(f32::INFINITY, f32::min);
+        if self.contains_point(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl SignedDistance for Polygon {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        Polygon::signed_distance(self, point)
+    }
+}
+
+#[cfg(feature = "polygon_with_holes")]
+impl SignedDistance for PolygonWithHoles {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        PolygonWithHoles::signed_distance(self, point)
+    }
+}
+
+#[cfg(feature = "oriented_rectangle")]
+impl SignedDistance for OrientedRectangle {
+    fn signed_distance(&self, point: Vec2) -> f32 {
+        OrientedRectangle::signed_distance(self, point)
+    }
+}
+
+/// Combines two signed distances into the distance to the union of both shapes
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, SignedDistance};
+/// use shapes2d::traits::sdf_union;
+///
+/// let a = Circle::new(Vec2::ZERO, 1.);
+/// let b = Circle::new(Vec2 { x: 3., y: 0. }, 1.);
+/// let point = Vec2 { x: 3., y: 0.5 };
+///
+/// assert_eq!(sdf_union(a.signed_distance(point), b.signed_distance(point)), -0.5);
+/// ```
+pub fn sdf_union(a: f32, b: f32) -> f32 {
+    a.min(b)
+}
+
+/// Combines two signed distances into the distance to the overlap of both shapes
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, SignedDistance};
+/// use shapes2d::traits::sdf_intersection;
+///
+/// let a = Circle::new(Vec2::ZERO, 2.);
+/// let b = Circle::new(Vec2 { x: 3., y: 0. }, 2.);
+///
+/// let inside = Vec2 { x: 1.5, y: 0. };
+/// assert_eq!(sdf_intersection(a.signed_distance(inside), b.signed_distance(inside)), -0.5);
+/// let outside = Vec2::ZERO;
+/// assert_eq!(sdf_intersection(a.signed_distance(outside), b.signed_distance(outside)), 1.);
+/// ```
+pub fn sdf_intersection(a: f32, b: f32) -> f32 {
+    a.max(b)
+}
+
+/// Combines two signed distances into the distance to the first shape with the second shape cut out of it
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, SignedDistance};
+/// use shapes2d::traits::sdf_subtraction;
+///
+/// let a = Circle::new(Vec2::ZERO, 2.);
+/// let b = Circle::new(Vec2 { x: 2., y: 0. }, 1.);
+///
+/// let bitten = Vec2 { x: 1.5, y: 0. };
+/// assert_eq!(sdf_subtraction(a.signed_distance(bitten), b.signed_distance(bitten)), 0.5);
+/// let kept = Vec2 { x: -1., y: 0. };
+/// assert_eq!(sdf_subtraction(a.signed_distance(kept), b.signed_distance(kept)), -1.);
+/// ```
+pub fn sdf_subtraction(a: f32, b: f32) -> f32 {
+    a.max(-b)
+}
+
+/// Combines two signed distances into the distance to the union of both shapes, rounding the seam where
+/// they meet
+///
+/// `k` is the size of the blend, and a `k` of `0.` or less is the same as [`sdf_union`]. The result is never
+/// more than [`sdf_union`] and never less than it by more than `k / 4`.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, SignedDistance};
+/// use shapes2d::traits::{sdf_smooth_union, sdf_union};
+///
+/// let a = Circle::new(Vec2::ZERO, 1.);
+/// let b = Circle::new(Vec2 { x: 3., y: 0. }, 1.);
+///
+/// // halfway between the circles the blend fills in the gap
+/// let seam = Vec2 { x: 1.5, y: 0. };
+/// let (da, db) = (a.signed_distance(seam), b.signed_distance(seam));
+/// assert_eq!(sdf_union(da, db), 0.5);
+/// assert_eq!(sdf_smooth_union(da, db, 2.), 0.);
+///
+/// // far from the seam only the closest shape matters
+/// let far = Vec2 { x: -3., y: 0. };
+/// let (da, db) = (a.signed_distance(far), b.signed_distance(far));
+/// assert_eq!(sdf_smooth_union(da, db, 2.), 2.);
+/// assert_eq!(sdf_smooth_union(da, db, 0.), sdf_union(da, db));
+/// ```
+pub fn sdf_smooth_union(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0. {
+        return sdf_union(a, b);
+    }
+    let h = (0.5 + 0.5 * (b - a) / k).clamp(0., 1.);
+    b + (a - b) * h - k * h * (1. - h)
+}
+
+// ##########
+// Transformable
+// ##########
+/// A shape which can be moved, rotated, and scaled by a [`glam::Affine2`]
+///
+/// Some shapes cannot represent every affine transform exactly:
+/// - A [`Circle`] keeps a single radius, scaled to keep the transformed area. Similarity transforms
+///   (uniform scale, rotation, and translation) are exact.
+/// - A [`Rectangle`] is axis aligned, so it grows to the bounding box of its transformed corners.
+/// - A [`Ray`] moves its origin, but its direction is only rotated and scaled (then normalized), never translated.
+///
+/// ```
+/// use glam::{Affine2, Vec2};
+/// use shapes2d::prelude::{Line, Polygon, Transformable, Triangle};
+///
+/// let close = |a: Vec2, b: Vec2| a.distance(b) < 1e-5;
+///
+/// let mut triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 1. });
+/// let mut stepped = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 1. });
+/// let mut line = Line::new(Vec2 { x: -1., y: 3. }, Vec2 { x: 4., y: 2. });
+/// let mut stepped_line = Line::new(Vec2 { x: -1., y: 3. }, Vec2 { x: 4., y: 2. });
+///
+/// // scale, then rotate, then move
+/// let composed = Affine2::from_translation(Vec2 { x: 5., y: -2. })
+///     * Affine2::from_angle(0.7)
+///     * Affine2::from_scale(Vec2 { x: 2., y: 3. });
+/// triangle.transform(composed);
+/// line.transform(composed);
+///
+/// stepped.scale_about(Vec2 { x: 2., y: 3. }, Vec2::ZERO);
+/// stepped.rotate_about(0.7, Vec2::ZERO);
+/// stepped.translate(Vec2 { x: 5., y: -2. });
+/// stepped_line.scale_about(Vec2 { x: 2., y: 3. }, Vec2::ZERO);
+/// stepped_line.rotate_about(0.7, Vec2::ZERO);
+/// stepped_line.translate(Vec2 { x: 5., y: -2. });
+///
+/// for (a, b) in triangle.vertices().into_iter().zip(stepped.vertices()) {
+///     assert!(close(a, b));
+/// }
+/// assert!(close(line.origin(), stepped_line.origin()));
+/// assert!(close(line.end(), stepped_line.end()));
+///
+/// // the same through a trait object
+/// let mut polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 1. }]);
+/// let shape: &mut dyn Transformable = &mut polygon;
+/// shape.transform(composed);
+/// for (a, b) in polygon.coordinates().iter().zip(stepped.vertices()) {
+///     assert!(close(*a, b));
+/// }
+/// ```
+pub trait Transformable {
+    /// Applies `affine` to the shape
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::{Affine2, Vec2};
+    /// use shapes2d::prelude::{Circle, Point, Ray, Rectangle, Transformable};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::{Ellipse, SignedDistance};
+    ///
+    /// let affine = Affine2::from_translation(Vec2::ONE) * Affine2::from_angle(std::f32::consts::FRAC_PI_2);
+    ///
+    /// let mut point = Point::new(Vec2 { x: 2., y: 0. });
+    /// point.transform(affine);
+    /// assert_abs_diff_eq!(point.coordinate(), Vec2 { x: 1., y: 3. }, epsilon = 1e-6);
+    ///
+    /// let mut ray = Ray::new_direction(Vec2::ZERO, Vec2::X);
+    /// ray.transform(affine);
+    /// assert_eq!(ray.origin(), Vec2::ONE);
+    /// assert_abs_diff_eq!(ray.direction(), Vec2::Y, epsilon = 1e-6);
+    ///
+    /// let mut rect = Rectangle::new(0., 0., 4., 2.);
+    /// rect.transform(affine);
+    /// assert_abs_diff_eq!(rect.min(), Vec2 { x: -1., y: 1. }, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(rect.max(), Vec2 { x: 1., y: 5. }, epsilon = 1e-6);
+    ///
+    /// let mut circle = Circle::new(Vec2::ZERO, 1.);
+    /// circle.transform(Affine2::from_scale(Vec2 { x: 2., y: 8. }));
+    /// assert_eq!(circle.radius(), 4.);
+    ///
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// // the major axis of an ellipse turns with it
+    /// let mut ellipse = Ellipse::new(Vec2::ZERO, 4., 2.);
+    /// ellipse.transform(affine);
+    /// assert_eq!(ellipse.center(), Vec2::ONE);
+    /// assert_abs_diff_eq!(ellipse.radius_major(), 4., epsilon = 1e-6);
+    /// assert_abs_diff_eq!(ellipse.radius_minor(), 2., epsilon = 1e-6);
+    /// assert_abs_diff_eq!(ellipse.rotation(), std::f32::consts::FRAC_PI_2, epsilon = 1e-6);
+    ///
+    /// // and even a shear keeps the sheared boundary on the boundary
+    /// let shear = Affine2::from_cols(Vec2::X, Vec2 { x: 1.5, y: 1. }, Vec2::ZERO);
+    /// let mut sheared = Ellipse::new(Vec2::ZERO, 4., 2.);
+    /// sheared.transform(shear);
+    /// for i in 0..16 {
+    ///     let boundary = Vec2::from_angle(i as f32 * std::f32::consts::TAU / 16.) * Vec2 { x: 4., y: 2. };
+    ///     assert_abs_diff_eq!(sheared.signed_distance(shear.transform_point2(boundary)), 0., epsilon = 1e-4);
+    /// }
+    /// # }
+    /// ```
+    fn transform(&mut self, affine: Affine2);
+
+    /// Moves the shape by `offset`
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Transformable};
+    ///
+    /// let mut circle = Circle::new(Vec2::ZERO, 1.);
+    /// circle.translate(Vec2 { x: 2., y: -1. });
+    ///
+    /// assert_eq!(circle.center(), Vec2 { x: 2., y: -1. });
+    /// assert_eq!(circle.radius(), 1.);
+    /// ```
+    fn translate(&mut self, offset: Vec2) {
+        self.transform(Affine2::from_translation(offset));
+    }
+
+    /// Rotates the shape counter-clockwise by `radians` around `anchor`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Line, Transformable};
+    ///
+    /// let mut line = Line::new(Vec2::ONE, Vec2 { x: 3., y: 1. });
+    /// line.rotate_about(std::f32::consts::PI, Vec2::ONE);
+    ///
+    /// assert_abs_diff_eq!(line.origin(), Vec2::ONE, epsilon = 1e-6);
+    /// assert_abs_diff_eq!(line.end(), Vec2 { x: -1., y: 1. }, epsilon = 1e-6);
+    /// ```
+    fn rotate_about(&mut self, radians: f32, anchor: Vec2) {
+        self.transform(
+            Affine2::from_translation(anchor)
+                * Affine2::from_angle(radians)
+                * Affine2::from_translation(-anchor),
+        );
+    }
+
+    /// Scales the shape away from `anchor` by `factor` along each axis
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Rectangle, Transformable};
+    ///
+    /// let mut rect = Rectangle::new(1., 1., 2., 3.);
+    /// rect.scale_about(Vec2 { x: 2., y: -1. }, Vec2::ONE);
+    ///
+    /// assert_eq!(rect.min(), Vec2 { x: 1., y: -1. });
+    /// assert_eq!(rect.max(), Vec2 { x: 3., y: 1. });
+    /// ```
+    fn scale_about(&mut self, factor: Vec2, anchor: Vec2) {
+        self.transform(
+            Affine2::from_translation(anchor)
+                * Affine2::from_scale(factor)
+                * Affine2::from_translation(-anchor),
+        );
+    }
+}
+
+#[cfg(feature = "point")]
+impl Transformable for Point {
+    fn transform(&mut self, affine: Affine2) {
+        self.set_coordinate(affine.transform_point2(self.coordinate()));
+    }
+}
+
+#[cfg(feature = "line")]
+impl Transformable for Line {
+    fn transform(&mut self, affine: Affine2) {
+        self.set_origin(affine.transform_point2(self.origin()));
+        self.set_end(affine.transform_point2(self.end()));
+    }
+}
+
+#[cfg(feature = "ray")]
+impl Transformable for Ray {
+    fn transform(&mut self, affine: Affine2) {
+        self.set_origin(affine.transform_point2(self.origin()));
+        self.set_direction(affine.transform_vector2(self.direction()));
+    }
+}
+
+#[cfg(feature = "circle")]
+impl Transformable for Circle {
+    fn transform(&mut self, affine: Affine2) {
+        self.set_center(affine.transform_point2(self.center()));
+        self.set_radius(self.radius() * affine.matrix2.determinant().abs().sqrt());
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl Transformable for Ellipse {
+    fn transform(&mut self, affine: Affine2) {
+        // The transformed radii along the axes are conjugate radii of the transformed ellipse, whose own axes
+        // are the eigenvectors of `major * major^T + minor * minor^T`
+        let [major, minor] = self.axes();
+        let major = affine.matrix2 * major * self.radius_major();
+        let minor = affine.matrix2 * minor * self.radius_minor();
+        let (xx, yy, xy) = (
+            major.x * major.x + minor.x * minor.x,
+            major.y * major.y + minor.y * minor.y,
+            major.x * major.y + minor.x * minor.y,
+        );
+        let (mean, spread) = ((xx + yy) * 0.5, ((xx - yy) * 0.5).hypot(xy));
+        let (longest, shortest) = ((mean + spread).sqrt(), (mean - spread).max(0.).sqrt());
+        let longest_angle = 0.5 * (2. * xy).atan2(xx - yy);
+
+        // The major radius stays on the axis nearest to where the old one was sent, turned by half turns to
+        // keep the rotation close to the direction it was sent in
+        let (rotation, radius_major, radius_minor) =
+            if major.length_squared() >= minor.length_squared() {
+                (longest_angle, longest, shortest)
+            } else {
+                (
+                    longest_angle + std::f32::consts::FRAC_PI_2,
+                    shortest,
+                    longest,
+                )
+            };
+        let sent = major.y.atan2(major.x);
+        let half_turns = ((sent - rotation) / std::f32::consts::PI).round();
+
+        self.set_center(affine.transform_point2(self.center()));
+        self.set_radius_major(radius_major);
+        self.set_radius_minor(radius_minor);
+        self.set_rotation(rotation + half_turns * std::f32::consts::PI);
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Transformable for Rectangle {
+    fn transform(&mut self, affine: Affine2) {
+        let (min, max) = (self.min(), self.max());
+        let corners = [
+            min,
+            Vec2 { x: max.x, y: min.y },
+            max,
+            Vec2 { x: min.x, y: max.y },
+        ]
+        .map(|corner| affine.transform_point2(corner));
+        let (min, max) = corners
+            .iter()
+            .fold((corners[0], corners[0]), |(min, max), corner| {
+                (min.min(*corner), max.max(*corner))
+            });
+        self.set_min(min);
+        self.set_max(max);
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl Transformable for Triangle {
+    fn transform(&mut self, affine: Affine2) {
+        self.set_vertices(
+            self.vertices()
+                .map(|vertex| affine.transform_point2(vertex)),
+        );
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Transformable for Polygon {
+    fn transform(&mut self, affine: Affine2) {
+        *self = self
+            .coordinates()
+            .iter()
+            .map(|coordinate| affine.transform_point2(*coordinate))
+            .collect();
+    }
+}
+
+#[cfg(feature = "mesh")]
+impl Transformable for Mesh {
+    fn transform(&mut self, affine: Affine2) {
+        Mesh::transform(self, affine);
+    }
+}
+
+// ##########
+// Reflect
+// ##########
+/// A shape which can be mirrored across a [`Line`]
+///
+/// The [`Line`] is extended infinitely in both directions, and a zero length [`Line`] reflects through its
+/// origin instead. Reflecting is a [`Transformable::transform`], so shapes which cannot hold every transform
+/// exactly are limited the same way. A [`Rectangle`] is axis aligned, so it grows to the bounding box of its
+/// reflected corners. It is exact across horizontal, vertical, and 45 degree lines, and for an exact
+/// reflection across any other line, convert it to a [`Polygon`] first.
+///
+/// An [`Ellipse`] keeps its radii, and its `rotation` turns the other way around the direction of the line.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Line, Point, Polygon, Ray, Rectangle, Reflect, Triangle};
+/// # #[cfg(feature = "ellipse")]
+/// use shapes2d::prelude::Ellipse;
+///
+/// // reflecting twice gives back the shape
+/// let lines = [
+///     Line::new(Vec2::ZERO, Vec2::X),
+///     Line::new(Vec2 { x: 1., y: -2. }, Vec2 { x: 1., y: 5. }),
+///     Line::new(Vec2 { x: -1., y: 0. }, Vec2 { x: 0., y: 1. }),
+///     Line::new(Vec2 { x: 2., y: 3. }, Vec2 { x: -1., y: 0.5 }),
+///     Line::new(Vec2 { x: 0.3, y: -0.7 }, Vec2 { x: 4.1, y: 2.9 }),
+/// ];
+/// let close = |a: Vec2, b: Vec2| a.distance(b) < 1e-4;
+/// for (i, line) in lines.iter().enumerate() {
+///     let point = Point::new(Vec2 { x: 3., y: -1. });
+///     assert!(close(point.reflect_across(line).reflect_across(line).coordinate(), point.coordinate()));
+///
+///     let segment = Line::new(Vec2 { x: -2., y: 1. }, Vec2 { x: 4., y: 3. });
+///     let twice = segment.reflect_across(line).reflect_across(line);
+///     assert!(close(twice.origin(), segment.origin()) && close(twice.end(), segment.end()));
+///
+///     let ray = Ray::new_direction(Vec2 { x: 1., y: 1. }, Vec2 { x: 0.6, y: 0.8 });
+///     let twice = ray.reflect_across(line).reflect_across(line);
+///     assert!(close(twice.origin(), ray.origin()) && close(twice.direction(), ray.direction()));
+///
+///     let circle = Circle::new(Vec2 { x: -3., y: 2. }, 1.5);
+///     let twice = circle.reflect_across(line).reflect_across(line);
+///     assert!(close(twice.center(), circle.center()) && (twice.radius() - circle.radius()).abs() < 1e-5);
+///
+///     let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 1. }, Vec2 { x: 1., y: 3. });
+///     let twice = triangle.reflect_across(line).reflect_across(line);
+///     assert!(twice.vertices().into_iter().zip(triangle.vertices()).all(|(a, b)| close(a, b)));
+///
+///     let polygon = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: -1. }, Vec2 { x: 3., y: 2. }, Vec2 { x: 0., y: 4. }]);
+///     let twice = polygon.reflect_across(line).reflect_across(line);
+///     assert!(twice.coordinates().iter().zip(polygon.coordinates()).all(|(a, b)| close(*a, *b)));
+///
+/// #   #[cfg(feature = "ellipse")]
+/// #   {
+///     let ellipse = Ellipse::new_rotated(Vec2 { x: 2., y: -1. }, 3., 1., 0.4);
+///     let twice = ellipse.reflect_across(line).reflect_across(line);
+///     assert!(close(twice.center(), ellipse.center()));
+///     assert_abs_diff_eq!(twice.radius_major(), 3., epsilon = 1e-5);
+///     assert_abs_diff_eq!(twice.radius_minor(), 1., epsilon = 1e-5);
+///     assert_abs_diff_eq!(twice.rotation(), 0.4, epsilon = 1e-5);
+/// #   }
+///
+///     // the first three lines are horizontal, vertical, and diagonal
+///     if i < 3 {
+///         let rect = Rectangle::new(1., 2., 4., 3.);
+///         let twice = rect.reflect_across(line).reflect_across(line);
+///         assert!(close(twice.min(), rect.min()) && close(twice.max(), rect.max()));
+///     }
+/// }
+/// ```
+#[cfg(feature = "line")]
+pub trait Reflect {
+    /// Get the shape mirrored across `line`
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Line, Point, Rectangle, Reflect, Triangle};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::{Ellipse, Perimeter, SignedDistance};
+    ///
+    /// let vertical = Line::new(Vec2 { x: 1., y: 0. }, Vec2 { x: 1., y: 1. });
+    /// assert_eq!(Point::new(Vec2 { x: 3., y: 2. }).reflect_across(&vertical), Point::new(Vec2 { x: -1., y: 2. }));
+    /// assert_eq!(Rectangle::new(2., 0., 4., 1.).reflect_across(&vertical), Rectangle::new(-2., 0., 0., 1.));
+    ///
+    /// // the winding of a triangle is reversed
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0. }, Vec2 { x: 0., y: 1. });
+    /// assert_eq!(
+    ///     triangle.reflect_across(&vertical),
+    ///     Triangle::new(Vec2 { x: 2., y: 0. }, Vec2 { x: 1., y: 0. }, Vec2 { x: 2., y: 1. })
+    /// );
+    ///
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// // across the diagonal, the major axis of an ellipse turns to run up the y axis
+    /// let diagonal = Line::new(Vec2::ZERO, Vec2::ONE);
+    /// let ellipse = Ellipse::new(Vec2 { x: 2., y: 0. }, 3., 1.).reflect_across(&diagonal);
+    /// assert_abs_diff_eq!(ellipse.center(), Vec2 { x: 0., y: 2. }, epsilon = 1e-6);
+    /// assert_eq!((ellipse.radius_major(), ellipse.radius_minor()), (3., 1.));
+    /// assert_abs_diff_eq!(ellipse.rotation(), std::f32::consts::FRAC_PI_2, epsilon = 1e-6);
+    ///
+    /// // across a sloped line, the mirrored boundary is the boundary of the mirrored ellipse
+    /// let sloped = Line::new(Vec2 { x: 0., y: 1. }, Vec2 { x: 3., y: 2. });
+    /// let ellipse = Ellipse::new_rotated(Vec2 { x: 2., y: -1. }, 3., 1., 0.4);
+    /// let mirrored = ellipse.reflect_across(&sloped);
+    /// for i in 0..16 {
+    ///     let boundary = Point::new(ellipse.perimeter_point(i as f32 / 16.));
+    ///     assert_abs_diff_eq!(mirrored.signed_distance(boundary.reflect_across(&sloped).coordinate()), 0., epsilon = 1e-4);
+    /// }
+    /// # }
+    /// ```
+    fn reflect_across(&self, line: &Line) -> Self;
+}
+
+#[cfg(all(feature = "line", feature = "point"))]
+impl Reflect for Point {
+    fn reflect_across(&self, line: &Line) -> Self {
+        reflected(self, line)
+    }
+}
+
+#[cfg(feature = "line")]
+impl Reflect for Line {
+    fn reflect_across(&self, line: &Line) -> Self {
+        reflected(self, line)
+    }
+}
+
+#[cfg(all(feature = "line", feature = "ray"))]
+impl Reflect for Ray {
+    fn reflect_across(&self, line: &Line) -> Self {
+        reflected(self, line)
+    }
+}
+
+#[cfg(all(feature = "line", feature = "circle"))]
+impl Reflect for Circle {
+    fn reflect_across(&self, line: &Line) -> Self {
+        reflected(self, line)
+    }
+}
+
+#[cfg(all(feature = "line", feature = "ellipse"))]
+impl Reflect for Ellipse {
+    fn reflect_across(&self, line: &Line) -> Self {
+        // The major axis turns the other way around the direction of the line, and reflecting through a
+        // point only turns it a half turn, which leaves it along the same axis
+        let rotation = match line.direction().try_normalize() {
+            Some(direction) => 2. * direction.y.atan2(direction.x) - self.rotation(),
+            None => self.rotation(),
+        };
+        Ellipse::new_rotated(
+            reflected(self, line).center(),
+            self.radius_major(),
+            self.radius_minor(),
+            rotation,
+        )
+    }
+}
+
+#[cfg(all(feature = "line", feature = "rectangle"))]
+impl Reflect for Rectangle {
+    fn reflect_across(&self, line: &Line) -> Self {
+        reflected(self, line)
+    }
+}
+
+#[cfg(all(feature = "line", feature = "triangle"))]
+impl Reflect for Triangle {
+    fn reflect_across(&self, line: &Line) -> Self {
+        reflected(self, line)
+    }
+}
+
+#[cfg(all(feature = "line", feature = "polygon"))]
+impl Reflect for Polygon {
+    fn reflect_across(&self, line: &Line) -> Self {
+        reflected(self, line)
+    }
+}
+
+// ##########
+// ShapeLerp
+// ##########
+/// A shape which can be blended into another of the same type
+///
+/// `t` of `0.` gives back the first shape and `1.` the other exactly, and values outside of `0.` to `1.`
+/// extrapolate past them.
+/// - A [`Ray`] turns its direction the short way around at an even angular speed, keeping it normalized.
+/// - An [`Ellipse`] turns its `rotation` the short way around. A half turn leaves an [`Ellipse`] unchanged, so
+///   it never turns by more than a quarter turn.
+/// - A [`Polygon`] blends each coordinate into the coordinate at the same index of the other. Polygons with
+///   different numbers of coordinates are resampled, see [`ShapeLerp::lerp`].
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Line, Ray, ShapeLerp};
+///
+/// let small = Circle::new(Vec2::ZERO, 1.);
+/// let large = Circle::new(Vec2 { x: 4., y: 2. }, 3.);
+/// assert_eq!(small.lerp(&large, 0.5), Circle::new(Vec2 { x: 2., y: 1. }, 2.));
+///
+/// // the ends are exact
+/// let line = Line::new(Vec2 { x: 0.1, y: 0.7 }, Vec2 { x: 0.3, y: 0.9 });
+/// let other = Line::new(Vec2 { x: -5.3, y: 1.1 }, Vec2 { x: 8.9, y: 0.2 });
+/// assert_eq!(line.lerp(&other, 0.), line);
+/// assert_eq!(line.lerp(&other, 1.), other);
+///
+/// // the direction of a ray stays normalized, turning a quarter of the way around at `t = 0.25`
+/// let east = Ray::new_direction(Vec2::ZERO, Vec2::X);
+/// let north = Ray::new_direction(Vec2 { x: 4., y: 0. }, Vec2::Y);
+/// for i in 0..=10 {
+///     let ray = east.lerp(&north, i as f32 / 10.);
+///     assert_abs_diff_eq!(ray.direction().length(), 1., epsilon = 1e-6);
+/// }
+/// let quarter = east.lerp(&north, 0.25);
+/// assert_eq!(quarter.origin(), Vec2 { x: 1., y: 0. });
+/// assert_abs_diff_eq!(quarter.direction(), Vec2::from_angle(std::f32::consts::FRAC_PI_8), epsilon = 1e-6);
+///
+/// // and turns the short way around, clockwise here
+/// let south_east = Ray::new_direction(Vec2::ZERO, Vec2 { x: 1., y: -1. });
+/// let halfway = north.lerp(&south_east, 0.5);
+/// assert_abs_diff_eq!(halfway.direction(), Vec2::from_angle(std::f32::consts::PI / 8.), epsilon = 1e-6);
+///
+/// # #[cfg(feature = "ellipse")]
+/// # {
+/// # use shapes2d::prelude::Ellipse;
+/// // an ellipse turns the short way around, back through `0.` here rather than forwards
+/// let turned = Ellipse::new_rotated(Vec2::ZERO, 4., 2., 0.2);
+/// let back = Ellipse::new_rotated(Vec2 { x: 2., y: 0. }, 2., 1., std::f32::consts::TAU - 0.4);
+/// let halfway = turned.lerp(&back, 0.5);
+/// assert_abs_diff_eq!(halfway.rotation(), -0.1, epsilon = 1e-5);
+/// assert_eq!((halfway.center(), halfway.radius_major(), halfway.radius_minor()), (Vec2::X, 3., 1.5));
+/// // and a half turn is the same ellipse, so turning to just past one only turns a little
+/// let flipped = Ellipse::new_rotated(Vec2::ZERO, 4., 2., 0.2 + std::f32::consts::PI + 0.1);
+/// assert_abs_diff_eq!(turned.lerp(&flipped, 0.5).rotation(), 0.25, epsilon = 1e-5);
+/// assert_eq!(turned.lerp(&flipped, 0.), turned);
+/// assert_eq!(turned.lerp(&flipped, 1.), flipped);
+/// # }
+/// ```
+pub trait ShapeLerp {
+    /// Get the shape `t` of the way from this shape to `other`
+    ///
+    /// A [`Polygon`] with fewer coordinates than `other`, or the other way around, has its edges split to
+    /// match. Splits are added to its longest edges first and every original coordinate is kept, so the
+    /// ends keep their shapes exactly while holding the larger number of coordinates. An empty
+    /// [`Polygon`] has nothing to blend, so the result is whichever polygon `t` is closer to.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, Rectangle, ShapeLerp, Triangle};
+    ///
+    /// let rect = Rectangle::new(0., 0., 2., 2.);
+    /// assert_eq!(rect.lerp(&Rectangle::new(2., 2., 6., 4.), 0.5), Rectangle::new(1., 1., 4., 3.));
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. });
+    /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    ///
+    /// // the longest edge of the triangle is split to match the square
+    /// let start = Polygon::from(triangle).lerp(&square, 0.);
+    /// assert_eq!(start.coordinates(), &[Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 1., y: 1. }, Vec2 { x: 0., y: 2. }]);
+    /// assert_eq!(square.lerp(&triangle.into(), 1.), start);
+    ///
+    /// let halfway = square.lerp(&triangle.into(), 0.5);
+    /// assert_eq!(halfway.coordinates()[2], Vec2 { x: 1.5, y: 1.5 });
+    /// ```
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+#[cfg(feature = "point")]
+impl ShapeLerp for Point {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Point::new(mix(self.coordinate(), other.coordinate(), t))
+    }
+}
+
+#[cfg(feature = "line")]
+impl ShapeLerp for Line {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Line::new(
+            mix(self.origin(), other.origin(), t),
+            mix(self.end(), other.end(), t),
+        )
+    }
+}
+
+#[cfg(feature = "ray")]
+impl ShapeLerp for Ray {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        // Renormalizing the direction could move the ends by a rounding error
+        if t == 0. {
+            return *self;
+        } else if t == 1. {
+            return *other;
+        }
+
+        let (from, to) = (self.direction(), other.direction());
+        let direction = if from == Vec2::ZERO || to == Vec2::ZERO {
+            mix(from, to, t)
+        } else {
+            Vec2::from_angle(from.angle_between(to) * t).rotate(from)
+        };
+        Ray::new_direction(mix(self.origin(), other.origin(), t), direction)
+    }
+}
+
+#[cfg(feature = "circle")]
+impl ShapeLerp for Circle {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Circle::new(
+            mix(self.center(), other.center(), t),
+            mix(self.radius(), other.radius(), t),
+        )
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl ShapeLerp for Ellipse {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        // The turn to the other rotation, wrapped to within a quarter turn since a half turn changes nothing
+        let turn = (other.rotation() - self.rotation() + std::f32::consts::FRAC_PI_2)
+            .rem_euclid(std::f32::consts::PI)
+            - std::f32::consts::FRAC_PI_2;
+        let rotation = if t == 1. {
+            other.rotation()
+        } else {
+            self.rotation() + turn * t
+        };
+        Ellipse::new_rotated(
+            mix(self.center(), other.center(), t),
+            mix(self.radius_major(), other.radius_major(), t),
+            mix(self.radius_minor(), other.radius_minor(), t),
+            rotation,
+        )
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl ShapeLerp for Rectangle {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Rectangle::new_coordinates(
+            mix(self.min(), other.min(), t),
+            mix(self.max(), other.max(), t),
+        )
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl ShapeLerp for Triangle {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let (from, to) = (self.vertices(), other.vertices());
+        Triangle::new(
+            mix(from[0], to[0], t),
+            mix(from[1], to[1], t),
+            mix(from[2], to[2], t),
+        )
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl ShapeLerp for Polygon {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        if self.coordinates().is_empty() || other.coordinates().is_empty() {
+            return if t < 0.5 { self.clone() } else { other.clone() };
+        }
+
+        let count = self.len().max(other.len());
+        let (from, to) = (
+            split_edges(self.coordinates(), count),
+            split_edges(other.coordinates(), count),
+        );
+        from.into_iter()
+            .zip(to)
+            .map(|(from, to)| mix(from, to, t))
+            .collect()
+    }
+}
+
+// ##########
+// DistanceTo
+// ##########
+/// A shape which can measure the gap between itself and a shape of type `Rhs`
+///
+/// The distance is `0.` when the shapes touch or overlap. Every pair of different shapes is implemented in
+/// both directions, so `a.distance(&b)` always agrees with `b.distance(&a)`. The method is named `distance`
+/// so that it does not collide with [`ClosestPoint::distance_to`].
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use rand::{Rng, SeedableRng};
+/// use shapes2d::prelude::{Circle, DistanceTo, Line, Point, Rectangle};
+///
+/// let wall = Line::new(Vec2 { x: 10., y: -5. }, Vec2 { x: 10., y: 5. });
+/// let player = Circle::new(Vec2 { x: 4., y: 1. }, 0.5);
+/// assert!(player.distance(&wall) <= 5.5);
+///
+/// // a circle pushed a known gap past the side of a rectangle is that far away, in either order
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(191);
+/// for _ in 0..64 {
+///     let min = Vec2 { x: rng.gen_range(-4.0..4.), y: rng.gen_range(-4.0..4.) };
+///     let rect = Rectangle::new_coordinates(min, min + Vec2 { x: rng.gen_range(0.0..4.), y: rng.gen_range(0.0..4.) });
+///     let (radius, gap) = (rng.gen_range(0.0..2.), rng.gen_range(0.0..3.));
+///     let center = Vec2 { x: rect.max().x + radius + gap, y: rng.gen_range(rect.min().y..=rect.max().y) };
+///     let circle = Circle::new(center, radius);
+///     assert_abs_diff_eq!(rect.distance(&circle), gap, epsilon = 1e-4);
+///     assert_eq!(rect.distance(&circle), circle.distance(&rect));
+///
+///     // overlapping shapes are no distance apart
+///     let point = Point::new(center);
+///     let line = Line::new(rect.center(), center);
+///     assert_eq!(line.distance(&circle), 0.);
+///     assert_eq!(point.distance(&circle), 0.);
+///     assert_abs_diff_eq!(point.distance(&rect), radius + gap, epsilon = 1e-4);
+///     assert_eq!(point.distance(&rect), rect.distance(&point));
+/// }
+/// ```
+pub trait DistanceTo<Rhs = Self> {
+    /// Get the shortest distance between the shape and `other`, which is `0.` when they touch or overlap
+    ///
+    /// A [`Polygon`] with no coordinates is infinitely far away from everything.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, DistanceTo, Line, Point, Polygon, Rectangle, Triangle};
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 1.);
+    /// let rect = Rectangle::new(0., 0., 2., 2.);
+    /// let square = |min: Vec2| Polygon::new(vec![min, min + Vec2::X, min + Vec2::ONE, min + Vec2::Y]);
+    ///
+    /// // circles
+    /// assert_eq!(circle.distance(&Circle::new(Vec2 { x: 5., y: 0. }, 2.)), 2.);
+    /// assert_eq!(circle.distance(&Circle::new(Vec2 { x: 3., y: 0. }, 2.)), 0.);
+    /// assert_eq!(circle.distance(&Circle::new(Vec2 { x: 1., y: 0. }, 2.)), 0.);
+    ///
+    /// // circle and rectangle
+    /// assert_eq!(Circle::new(Vec2 { x: 5., y: 6. }, 2.).distance(&rect), 3.);
+    /// assert_eq!(Circle::new(Vec2 { x: 3., y: 1. }, 1.).distance(&rect), 0.);
+    /// assert_eq!(circle.distance(&rect), 0.);
+    ///
+    /// // circle and line
+    /// let line = Line::new(Vec2 { x: -4., y: 3. }, Vec2 { x: 4., y: 3. });
+    /// assert_eq!(circle.distance(&line), 2.);
+    /// assert_eq!(Circle::new(Vec2::ZERO, 3.).distance(&line), 0.);
+    /// assert_eq!(Circle::new(Vec2::ZERO, 4.).distance(&line), 0.);
+    ///
+    /// // rectangles
+    /// assert_eq!(rect.distance(&Rectangle::new(5., 6., 7., 8.)), 5.);
+    /// assert_eq!(rect.distance(&Rectangle::new(2., 0., 3., 1.)), 0.);
+    /// assert_eq!(rect.distance(&Rectangle::new(1., 1., 3., 3.)), 0.);
+    ///
+    /// // lines
+    /// let crossing = Line::new(Vec2 { x: 0., y: 2. }, Vec2 { x: 0., y: 4. });
+    /// assert_eq!(line.distance(&Line::new(Vec2 { x: 5., y: 0. }, Vec2 { x: 5., y: 7. })), 1.);
+    /// assert_eq!(line.distance(&Line::new(Vec2 { x: 4., y: 3. }, Vec2 { x: 5., y: 7. })), 0.);
+    /// assert_eq!(line.distance(&crossing), 0.);
+    ///
+    /// // a point and anything
+    /// let point = Point::new(Vec2 { x: 5., y: 6. });
+    /// assert_eq!(point.distance(&rect), 5.);
+    /// assert_eq!(point.distance(&Point::new(Vec2 { x: 8., y: 10. })), 5.);
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 10., y: 0. }, Vec2 { x: 0., y: 10. });
+    /// assert_eq!(point.distance(&triangle), 0.5_f32.sqrt());
+    /// assert_eq!(Point::new(Vec2 { x: 2., y: 3. }).distance(&triangle), 0.);
+    /// assert_eq!(Point::new(Vec2 { x: 2., y: 1. }).distance(&rect), 0.);
+    ///
+    /// // circle and polygon
+    /// assert_eq!(Circle::new(Vec2 { x: 4., y: 0.5 }, 1.).distance(&square(Vec2::ZERO)), 2.);
+    /// assert_eq!(Circle::new(Vec2 { x: 2., y: 0.5 }, 1.).distance(&square(Vec2::ZERO)), 0.);
+    /// assert_eq!(Circle::new(Vec2 { x: 0.5, y: 0.5 }, 0.1).distance(&square(Vec2::ZERO)), 0.);
+    ///
+    /// // rectangle and polygon
+    /// assert_eq!(rect.distance(&square(Vec2 { x: 5., y: 6. })), 5.);
+    /// assert_eq!(rect.distance(&square(Vec2 { x: 2., y: 2. })), 0.);
+    /// assert_eq!(rect.distance(&square(Vec2 { x: 0.5, y: 0.5 })), 0.);
+    /// assert_eq!(Rectangle::new(-5., -5., 5., 5.).distance(&square(Vec2::ZERO)), 0.);
+    /// assert_eq!(rect.distance(&Polygon::new(Vec::new())), f32::INFINITY);
+    /// ```
+    fn distance(&self, other: &Rhs) -> f32;
+}
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(feature = "circle")]
+    Circle,
+    |a, b| (a.center().distance(b.center()) - a.radius() - b.radius()).max(0.)
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "circle", feature = "rectangle"))]
+    Circle,
+    Rectangle,
+    |circle, rect| (rect.distance_to(circle.center()) - circle.radius()).max(0.)
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "circle", feature = "line"))]
+    Circle,
+    Line,
+    |circle, line| (line.distance_to(circle.center()) - circle.radius()).max(0.)
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "circle", feature = "polygon"))]
+    Circle,
+    Polygon,
+    |circle, polygon| (polygon.signed_distance(circle.center()) - circle.radius()).max(0.)
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(feature = "rectangle")]
+    Rectangle,
+    |a, b| (a.min() - b.max()).max(b.min() - a.max()).max(Vec2::ZERO).length()
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "rectangle", feature = "polygon"))]
+    Rectangle,
+    Polygon,
+    |rect, polygon| {
+        if polygon.is_empty() {
+            return f32::INFINITY;
+        }
+        let (min, max) = (rect.min(), rect.max());
+        let corners = [min, Vec2 { x: max.x, y: min.y }, max, Vec2 { x: min.x, y: max.y }];
+        if rect.contains(polygon.coordinates()[0]) || polygon.contains_point(corners[0]) {
+            return 0.;
+        }
+        let coordinates = polygon.coordinates();
+        (0..coordinates.len())
+            .flat_map(|i| {
+                let (start, end) = (coordinates[i], coordinates[(i + 1) % coordinates.len()]);
+                (0..4).map(move |j| segment_distance(start, end, corners[j], corners[(j + 1) % 4]))
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(feature = "line")]
+    Line,
+    |a, b| segment_distance(a.origin(), a.end(), b.origin(), b.end())
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(feature = "point")]
+    Point,
+    |a, b| a.coordinate().distance(b.coordinate())
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "point", feature = "line"))]
+    Point,
+    Line,
+    |point, line| line.distance_to(point.coordinate())
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "point", feature = "ray"))]
+    Point,
+    Ray,
+    |point, ray| ray.distance_to(point.coordinate())
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "point", feature = "circle"))]
+    Point,
+    Circle,
+    |point, circle| circle.distance_to(point.coordinate())
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "point", feature = "ellipse"))]
+    Point,
+    Ellipse,
+    |point, ellipse| ellipse.distance_to(point.coordinate())
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "point", feature = "rectangle"))]
+    Point,
+    Rectangle,
+    |point, rect| rect.distance_to(point.coordinate())
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "point", feature = "triangle"))]
+    Point,
+    Triangle,
+    |point, triangle| triangle.distance_to(point.coordinate())
+);
+
+impl_symmetric!(
+    DistanceTo::distance -> f32;
+    #[cfg(all(feature = "point", feature = "polygon"))]
+    Point,
+    Polygon,
+    |point, polygon| polygon.signed_distance(point.coordinate()).max(0.)
+);
+
+// ##########
+// Penetrates
+// ##########
+/// The minimum translation needed to separate two overlapping shapes, see [`Penetrates`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Penetration {
+    /// The unit direction to move the first shape to separate it from the second
+    pub normal: Vec2,
+    /// How far the first shape has to move along `normal` until the shapes only touch
+    pub depth: f32,
+}
+
+/// A shape which can measure how deeply it overlaps a shape of type `Rhs`
+///
+/// Moving the shape by `normal * depth` of the returned [`Penetration`] leaves the shapes only touching.
+/// When the overlap is equally shallow in two directions, the shape is pushed along the positive axis.
+/// Two [`Circle`]s sharing a center have no direction to separate along, so the first is pushed up
+/// along `Vec2::Y`.
+///
+/// ```
+/// use glam::Vec2;
+/// use rand::{Rng, SeedableRng};
+/// use shapes2d::prelude::{Circle, Intersects, Penetrates, Penetration, Polygon, Rectangle};
+///
+/// // shapes which only touch are not pushed anywhere
+/// let rect = Rectangle::new(0., 0., 1., 1.);
+/// let touching = Circle::new(Vec2 { x: 2., y: 0.5 }, 1.);
+/// assert_eq!(touching.penetration(&rect), Some(Penetration { normal: Vec2::X, depth: 0. }));
+///
+/// // random overlaps are separated by the penetration, but not by anything shorter
+/// let pentagon = |center: Vec2, radius: f32| -> Polygon {
+///     (0..5).map(|i| center + Vec2::from_angle(i as f32 * std::f32::consts::TAU / 5.) * radius).collect()
+/// };
+/// let moved = |penetration: Penetration, extra: f32| penetration.normal * (penetration.depth + extra);
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(192);
+/// for _ in 0..64 {
+///     let center = Vec2 { x: rng.gen_range(-4.0..4.), y: rng.gen_range(-4.0..4.) };
+///     let (radius, other_radius) = (rng.gen_range(0.2..2.), rng.gen_range(0.2..2.));
+///     let near = center + Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(0.1..0.9) * (radius + other_radius);
+///
+///     let circle = Circle::new(center, radius);
+///     let other = Circle::new(near, other_radius);
+///     let penetration = circle.penetration(&other).unwrap();
+///     assert!(penetration.depth > 0. && (penetration.normal.length() - 1.).abs() < 1e-4);
+///     assert!(!Circle::new(center + moved(penetration, 1e-3), radius).intersects(&other));
+///     assert!(Circle::new(center + moved(penetration, -1e-2), radius).intersects(&other));
+///
+///     let rect = Rectangle::new_coordinates(near - other_radius, near + other_radius);
+///     let penetration = circle.penetration(&rect).unwrap();
+///     assert!(!Circle::new(center + moved(penetration, 1e-3), radius).intersects(&rect));
+///     let penetration = rect.penetration(&circle).unwrap();
+///     let rect_moved = |offset: Vec2| Rectangle::new_coordinates(rect.min() + offset, rect.max() + offset);
+///     assert!(!rect_moved(moved(penetration, 1e-3)).intersects(&circle));
+///     assert!(rect_moved(moved(penetration, -1e-2)).intersects(&circle));
+///
+///     let (polygon, other_polygon) = (pentagon(center, radius), pentagon(near, other_radius));
+///     if let Some(penetration) = polygon.penetration(&other_polygon) {
+///         assert!(!polygon.translated(moved(penetration, 1e-3)).intersects(&other_polygon));
+///         assert!(polygon.translated(moved(penetration, -1e-2)).intersects(&other_polygon));
+///     }
+/// }
+/// ```
+pub trait Penetrates<Rhs = Self> {
+    /// Get the [`Penetration`] of the shape into `other`, or `None` if they do not intersect
+    ///
+    /// Shapes which only touch have a `depth` of `0.`. [`Polygon`]s must be convex
+    /// (see [`Polygon::is_convex`]), and an empty [`Polygon`] penetrates nothing.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Penetrates, Penetration, Polygon, Rectangle};
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 2.);
+    /// let penetration = circle.penetration(&Circle::new(Vec2 { x: 3., y: 0. }, 2.)).unwrap();
+    /// assert_eq!(penetration, Penetration { normal: Vec2::NEG_X, depth: 1. });
+    /// assert!(circle.penetration(&Circle::new(Vec2 { x: 5., y: 0. }, 2.)).is_none());
+    /// // sharing a center
+    /// let penetration = circle.penetration(&Circle::new(Vec2::ZERO, 1.)).unwrap();
+    /// assert_eq!(penetration, Penetration { normal: Vec2::Y, depth: 3. });
+    ///
+    /// let rect = Rectangle::new(0., 0., 4., 2.);
+    /// let penetration = Circle::new(Vec2 { x: 2., y: 2.5 }, 1.).penetration(&rect).unwrap();
+    /// assert_eq!(penetration, Penetration { normal: Vec2::Y, depth: 0.5 });
+    /// // the center inside of the rectangle, closest to the bottom
+    /// let penetration = Circle::new(Vec2 { x: 2., y: 0.5 }, 1.).penetration(&rect).unwrap();
+    /// assert_eq!(penetration, Penetration { normal: Vec2::NEG_Y, depth: 1.5 });
+    /// let penetration = rect.penetration(&Circle::new(Vec2 { x: 2., y: 0.5 }, 1.)).unwrap();
+    /// assert_eq!(penetration, Penetration { normal: Vec2::Y, depth: 1.5 });
+    ///
+    /// let penetration = rect.penetration(&Rectangle::new(3., 1., 6., 5.)).unwrap();
+    /// assert_eq!(penetration, Penetration { normal: Vec2::NEG_X, depth: 1. });
+    /// assert!(rect.penetration(&Rectangle::new(5., 1., 6., 5.)).is_none());
+    ///
+    /// let square = |min: Vec2| Polygon::new(vec![min, min + Vec2::X, min + Vec2::ONE, min + Vec2::Y]);
+    /// let penetration = square(Vec2::ZERO).penetration(&square(Vec2 { x: 0.25, y: 0.75 })).unwrap();
+    /// assert_eq!(penetration, Penetration { normal: Vec2::NEG_Y, depth: 0.25 });
+    /// assert!(square(Vec2::ZERO).penetration(&square(Vec2 { x: 2., y: 0. })).is_none());
+    /// ```
+    fn penetration(&self, other: &Rhs) -> Option<Penetration>;
+}
+
+#[cfg(feature = "circle")]
+impl Penetrates for Circle {
+    fn penetration(&self, other: &Circle) -> Option<Penetration> {
+        let offset = self.center() - other.center();
+        let distance = offset.length();
+        let depth = self.radius() + other.radius() - distance;
+        if depth < 0. {
+            return None;
+        }
+        let normal = if distance > 0. {
+            offset / distance
+        } else {
+            Vec2::Y
+        };
+        Some(Penetration { normal, depth })
+    }
+}
+
+#[cfg(all(feature = "circle", feature = "rectangle"))]
+impl Penetrates<Rectangle> for Circle {
+    fn penetration(&self, other: &Rectangle) -> Option<Penetration> {
+        let center = self.center();
+        let closest = other.closest_point(center);
+        let offset = center - closest;
+        let distance = offset.length();
+        if distance > self.radius() {
+            return None;
+        }
+        if distance > 0. {
+            return Some(Penetration {
+                normal: offset / distance,
+                depth: self.radius() - distance,
+            });
+        }
+
+        // The center is inside of the rectangle, so leave through the closest side
+        let (min, max) = (other.min(), other.max());
+        let (normal, depth) = shallowest([
+            (Vec2::X, max.x - center.x),
+            (Vec2::NEG_X, center.x - min.x),
+            (Vec2::Y, max.y - center.y),
+            (Vec2::NEG_Y, center.y - min.y),
+        ]);
+        Some(Penetration {
+            normal,
+            depth: depth + self.radius(),
+        })
+    }
+}
+
+#[cfg(all(feature = "circle", feature = "rectangle"))]
+impl Penetrates<Circle> for Rectangle {
+    fn penetration(&self, other: &Circle) -> Option<Penetration> {
+        other.penetration(self).map(|penetration| Penetration {
+            normal: -penetration.normal,
+            ..penetration
+        })
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Penetrates for Rectangle {
+    fn penetration(&self, other: &Rectangle) -> Option<Penetration> {
+        self.least_penetration(other)
+            .map(|(normal, depth)| Penetration { normal, depth })
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Penetrates for Polygon {
+    fn penetration(&self, other: &Polygon) -> Option<Penetration> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+
+        let axes = self
+            .separating_axes()
+            .into_iter()
+            .chain(other.separating_axes());
+        sat::minimum_penetration(self, other, axes)
+    }
+}
+
+// ##########
+// Contacts
+// ##########
+/// The points where two overlapping shapes touch, see [`Contacts`]
+///
+/// Only the first `count` of `points` and `depths` are used, the rest are `(0., 0.)` and `0.`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContactManifold {
+    /// The unit direction to move the first shape to separate it from the second
+    pub normal: Vec2,
+    /// The points of the first shape deepest inside of the second
+    pub points: [Vec2; 2],
+    /// How many of `points` are in contact, `1` or `2`
+    pub count: u8,
+    /// How far each point has to move along `normal` to reach the surface of the second shape
+    pub depths: [f32; 2],
+}
+
+impl ContactManifold {
+    /// Get the `(point, depth)` of each contact
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Contacts, Rectangle};
+    ///
+    /// let ground = Rectangle::new(0., 0., 4., 2.);
+    /// let manifold = Rectangle::new(3., 1.5, 5., 2.5).contact_manifold(&ground).unwrap();
+    ///
+    /// let contacts: Vec<(Vec2, f32)> = manifold.contacts().collect();
+    /// assert_eq!(contacts, vec![(Vec2 { x: 3., y: 1.5 }, 0.5), (Vec2 { x: 4., y: 1.5 }, 0.5)]);
+    /// ```
+    pub fn contacts(&self) -> impl Iterator<Item = (Vec2, f32)> + '_ {
+        self.points
+            .iter()
+            .copied()
+            .zip(self.depths.iter().copied())
+            .take(self.count as usize)
+    }
+
+    /// Get the [`ContactManifold`] seen from the second shape
+    ///
+    /// The normal is reversed, and each point moves by its depth onto the surface of the second shape, where
+    /// it is the point of the second shape deepest inside of the first.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{ContactManifold, Contacts, Rectangle};
+    ///
+    /// let ground = Rectangle::new(0., 0., 4., 2.);
+    /// let manifold = Rectangle::new(3., 1.5, 5., 2.5).contact_manifold(&ground).unwrap();
+    ///
+    /// assert_eq!(
+    ///     manifold.flipped(),
+    ///     ContactManifold { normal: Vec2::NEG_Y, points: [Vec2 { x: 3., y: 2. }, Vec2 { x: 4., y: 2. }], count: 2, depths: [0.5, 0.5] },
+    /// );
+    /// ```
+    pub fn flipped(&self) -> ContactManifold {
+        let mut points = self.points;
+        for (point, depth) in points.iter_mut().zip(self.depths).take(self.count as usize) {
+            *point += self.normal * depth;
+        }
+        ContactManifold {
+            normal: -self.normal,
+            points,
+            ..*self
+        }
+    }
+}
+
+/// A shape which can find the points where it touches a shape of type `Rhs`
+///
+/// The `normal` and deepest `depths` of the returned [`ContactManifold`] match the [`Penetration`] of the
+/// shapes (see [`Penetrates`]). Overlapping [`Rectangle`]s touch along a face at two points, found by clipping
+/// the face of the first against the face of the second it is pushed out through. Shapes which only touch
+/// at a corner, and any pair with a [`Circle`], touch at a single point.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use rand::{Rng, SeedableRng};
+/// use shapes2d::prelude::{Circle, Contacts, Rectangle};
+///
+/// // random boxes and balls sunk into the ground
+/// let ground = Rectangle::new(0., 0., 8., 2.);
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(197);
+/// for _ in 0..64 {
+///     let (x, width, sink) = (rng.gen_range(0.5..5.5), rng.gen_range(0.5..2.), rng.gen_range(0.01..0.4));
+///
+///     // a box stacked face to face touches at both of its bottom corners
+///     let stacked = Rectangle::new(x, 2. - sink, x + width, 3. - sink);
+///     let manifold = stacked.contact_manifold(&ground).unwrap();
+///     assert_eq!((manifold.count, manifold.normal), (2, Vec2::Y));
+///     for (point, depth) in manifold.contacts() {
+///         assert_abs_diff_eq!(depth, sink, epsilon = 1e-5);
+///         assert_eq!(point.y, stacked.min().y);
+///     }
+///     assert_eq!((manifold.points[0].x.min(manifold.points[1].x), manifold.points[0].x.max(manifold.points[1].x)), (x, x + width));
+///
+///     // a box resting on the corner of the ground touches at that corner only
+///     let corner = Rectangle::new(8., 2., 8. + width, 2. + width);
+///     let manifold = corner.contact_manifold(&ground).unwrap();
+///     assert_eq!((manifold.count, manifold.points[0], manifold.depths[0]), (1, Vec2 { x: 8., y: 2. }, 0.));
+///
+///     // a ball always touches at a single point
+///     let ball = Circle::new(Vec2 { x: x + width / 2., y: 2. + width / 2. - sink }, width / 2.);
+///     let manifold = ball.contact_manifold(&ground).unwrap();
+///     assert_eq!((manifold.count, manifold.normal), (1, Vec2::Y));
+///     assert_abs_diff_eq!(manifold.depths[0], sink, epsilon = 1e-5);
+/// }
+/// ```
+pub trait Contacts<Rhs = Self> {
+    /// Get the [`ContactManifold`] of the shape against `other`, or `None` if they do not intersect
+    ///
+    /// Shapes which only touch have contacts with a depth of `0.`.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, ContactManifold, Contacts, Rectangle};
+    ///
+    /// // a box stacked face to face on the ground
+    /// let ground = Rectangle::new(0., 0., 4., 2.);
+    /// let manifold = Rectangle::new(0.5, 1.75, 1.5, 2.75).contact_manifold(&ground).unwrap();
+    /// assert_eq!(
+    ///     manifold,
+    ///     ContactManifold { normal: Vec2::Y, points: [Vec2 { x: 0.5, y: 1.75 }, Vec2 { x: 1.5, y: 1.75 }], count: 2, depths: [0.25, 0.25] },
+    /// );
+    /// assert!(Rectangle::new(0.5, 2.5, 1.5, 3.5).contact_manifold(&ground).is_none());
+    ///
+    /// // rectangles touching at a corner
+    /// let manifold = Rectangle::new(4., 2., 5., 3.).contact_manifold(&ground).unwrap();
+    /// assert_eq!((manifold.count, manifold.points[0], manifold.depths[0]), (1, Vec2 { x: 4., y: 2. }, 0.));
+    ///
+    /// let circle = Circle::new(Vec2::ZERO, 2.);
+    /// let manifold = circle.contact_manifold(&Circle::new(Vec2 { x: 3., y: 0. }, 2.)).unwrap();
+    /// assert_eq!(
+    ///     manifold,
+    ///     ContactManifold { normal: Vec2::NEG_X, points: [Vec2 { x: 2., y: 0. }, Vec2::ZERO], count: 1, depths: [1., 0.] },
+    /// );
+    ///
+    /// // a circle on the corner of the ground
+    /// let circle = Circle::new(Vec2 { x: 7., y: 6. }, 5.5);
+    /// let manifold = circle.contact_manifold(&ground).unwrap();
+    /// assert_eq!(manifold.count, 1);
+    /// assert_abs_diff_eq!(manifold.normal, Vec2 { x: 0.6, y: 0.8 }, epsilon = 1e-5);
+    /// assert_abs_diff_eq!(manifold.points[0], Vec2 { x: 3.7, y: 1.6 }, epsilon = 1e-5);
+    /// assert_abs_diff_eq!(manifold.depths[0], 0.5, epsilon = 1e-5);
+    /// // and the ground on the circle, at the corner
+    /// let manifold = ground.contact_manifold(&circle).unwrap();
+    /// assert_abs_diff_eq!(manifold.points[0], Vec2 { x: 4., y: 2. }, epsilon = 1e-5);
+    /// ```
+    fn contact_manifold(&self, other: &Rhs) -> Option<ContactManifold>;
+}
+
+#[cfg(feature = "circle")]
+impl Contacts for Circle {
+    fn contact_manifold(&self, other: &Circle) -> Option<ContactManifold> {
+        self.penetration(other)
+            .map(|penetration| single_contact(self, penetration))
+    }
+}
+
+#[cfg(all(feature = "circle", feature = "rectangle"))]
+impl Contacts<Rectangle> for Circle {
+    fn contact_manifold(&self, other: &Rectangle) -> Option<ContactManifold> {
+        self.penetration(other)
+            .map(|penetration| single_contact(self, penetration))
+    }
+}
+
+#[cfg(all(feature = "circle", feature = "rectangle"))]
+impl Contacts<Circle> for Rectangle {
+    fn contact_manifold(&self, other: &Circle) -> Option<ContactManifold> {
+        other
+            .contact_manifold(self)
+            .map(|manifold| manifold.flipped())
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Contacts for Rectangle {
+    fn contact_manifold(&self, other: &Rectangle) -> Option<ContactManifold> {
+        let Penetration { normal, .. } = self.penetration(other)?;
+        sat::clip_faces(
+            rectangle_face(other, normal),
+            rectangle_face(self, -normal),
+            normal,
+        )
+    }
+}
+
+// ##########
+// SampleShape
+// ##########
+/// A shape which can pick uniformly random points inside of it and on its boundary
+///
+/// Points inside are uniform over the area of the shape, and points on the boundary are uniform over its
+/// length. A [`Line`](crate::prelude::Line) encloses no area, so its inside is the line itself.
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use glam::Vec2;
+/// use rand::SeedableRng;
+/// use shapes2d::prelude::{Circle, Contains, Rectangle, SampleShape, Triangle};
+///
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(11);
+///
+/// // the quadrants of a circle are hit equally often
+/// let circle = Circle::new(Vec2 { x: 3., y: -2. }, 2.);
+/// let mut quadrants = [0; 4];
+/// for _ in 0..8000 {
+///     let sample = circle.sample_interior(&mut rng);
+///     assert!(circle.contains(sample));
+///     let offset = sample - circle.center();
+///     quadrants[(offset.x < 0.) as usize + 2 * (offset.y < 0.) as usize] += 1;
+/// }
+/// assert!(quadrants.iter().all(|count| (*count as f32 / 2000. - 1.).abs() < 0.08));
+///
+/// // and a quarter of the points are within half of the radius, not half of them
+/// let near = (0..8000).filter(|_| circle.sample_interior(&mut rng).distance(circle.center()) < 1.).count();
+/// assert_abs_diff_eq!(near as f32 / 8000., 0.25, epsilon = 0.02);
+///
+/// // the mean of many points approaches the centroid
+/// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 6., y: 0. }, Vec2 { x: 0., y: 3. });
+/// let mean = (0..8000).map(|_| triangle.sample_interior(&mut rng)).sum::<Vec2>() / 8000.;
+/// assert_abs_diff_eq!(mean, Vec2 { x: 2., y: 1. }, epsilon = 0.05);
+///
+/// // the sides of a rectangle are hit in proportion to their lengths
+/// let rect = Rectangle::new(0., 0., 3., 1.);
+/// let bottom = (0..8000).filter(|_| rect.sample_boundary(&mut rng).y == 0.).count();
+/// assert_abs_diff_eq!(bottom as f32 / 8000., 3. / 8., epsilon = 0.02);
+/// ```
+#[cfg(feature = "rand")]
+pub trait SampleShape {
+    /// Picks a uniformly random point inside of the shape
+    ///
+    /// - A [`Circle`](crate::prelude::Circle) takes the square root of a random fraction of its radius, so
+    ///   the points don't bunch up around its center.
+    /// - A [`Triangle`](crate::prelude::Triangle) folds points from the other half of its parallelogram back
+    ///   inside of it.
+    /// - An [`Ellipse`](crate::prelude::Ellipse) stretches a point inside of a unit circle.
+    /// - A [`Polygon`](crate::prelude::Polygon) picks one of its triangles weighted by area, see
+    ///   [`Polygon::sampler`](crate::prelude::Polygon::sampler). A [`Polygon`](crate::prelude::Polygon) without
+    ///   any area picks a point on its boundary instead, and an empty one always picks `(0., 0.)`.
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use rand::SeedableRng;
+    /// use shapes2d::prelude::{Contains, Ellipse, Polygon, SampleShape};
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+    ///
+    /// let ellipse = Ellipse::new(Vec2::ONE, 4., 1.);
+    /// let samples: Vec<Vec2> = (0..4000).map(|_| ellipse.sample_interior(&mut rng)).collect();
+    /// assert!(samples.iter().all(|sample| ellipse.contains(*sample)));
+    /// assert_abs_diff_eq!((samples.iter().sum::<Vec2>() / 4000.), Vec2::ONE, epsilon = 0.1);
+    ///
+    /// // `Polygon::sample_interior` returns an `Option`, so call through the trait
+    /// let square = Polygon::new(vec![Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+    /// let mean = (0..4000).map(|_| SampleShape::sample_interior(&square, &mut rng)).sum::<Vec2>() / 4000.;
+    /// assert_abs_diff_eq!(mean, Vec2::ONE, epsilon = 0.05);
+    /// ```
+    fn sample_interior<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2;
+
+    /// Picks a uniformly random point on the boundary of the shape
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use glam::Vec2;
+    /// use rand::SeedableRng;
+    /// use shapes2d::prelude::{Ellipse, Line, SampleShape, SignedDistance};
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(9);
+    ///
+    /// let line = Line::new(Vec2::ZERO, Vec2 { x: 4., y: 0. });
+    /// let mean = (0..4000).map(|_| line.sample_boundary(&mut rng)).sum::<Vec2>() / 4000.;
+    /// assert_abs_diff_eq!(mean, Vec2 { x: 2., y: 0. }, epsilon = 0.1);
+    ///
+    /// // a flat ellipse is almost two lines, so half of its boundary is within half of its width of the center,
+    /// // where picking random angles would only put a third of the points
+    /// let ellipse = Ellipse::new(Vec2::ZERO, 10., 0.01);
+    /// let samples: Vec<Vec2> = (0..8000).map(|_| ellipse.sample_boundary(&mut rng)).collect();
+    /// assert!(samples.iter().all(|sample| ellipse.signed_distance(*sample).abs() < 1e-3));
+    /// let middle = samples.iter().filter(|sample| sample.x.abs() < 5.).count();
+    /// assert_abs_diff_eq!(middle as f32 / 8000., 0.5, epsilon = 0.02);
+    /// ```
+    fn sample_boundary<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2;
+}
+
+#[cfg(all(feature = "rand", feature = "circle"))]
+impl SampleShape for Circle {
+    fn sample_interior<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+        self.center() + Vec2::from_angle(angle) * self.radius() * rng.gen::<f32>().sqrt()
+    }
+
+    fn sample_boundary<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.perimeter_point(rng.gen())
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "ellipse"))]
+impl SampleShape for Ellipse {
+    fn sample_interior<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+        let radii = Vec2 {
+            x: self.radius_major(),
+            y: self.radius_minor(),
+        };
+        ellipse_world_point(
+            self,
+            Vec2::from_angle(angle) * radii * rng.gen::<f32>().sqrt(),
+        )
+    }
+
+    fn sample_boundary<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        // Random angles crowd the flatter parts of the boundary, so they are kept with a chance in proportion
+        // to the length of boundary around them
+        let (a, b) = (self.radius_major(), self.radius_minor());
+        let longest = a.abs().max(b.abs());
+        if longest == 0. {
+            return self.center();
+        }
+        loop {
+            let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+            let (sin, cos) = angle.sin_cos();
+            if rng.gen::<f32>() * longest <= (a * sin).hypot(b * cos) {
+                return ellipse_world_point(
+                    self,
+                    Vec2 {
+                        x: a * cos,
+                        y: b * sin,
+                    },
+                );
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "rectangle"))]
+impl SampleShape for Rectangle {
+    fn sample_interior<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.min()
+            + (self.max() - self.min())
+                * Vec2 {
+                    x: rng.gen(),
+                    y: rng.gen(),
+                }
+    }
+
+    fn sample_boundary<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.perimeter_point(rng.gen())
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "triangle"))]
+impl SampleShape for Triangle {
+    fn sample_interior<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let [a, b, c] = self.vertices();
+        let (mut u, mut v) = (rng.gen::<f32>(), rng.gen::<f32>());
+        if u + v > 1. {
+            (u, v) = (1. - u, 1. - v);
+        }
+        a + (b - a) * u + (c - a) * v
+    }
+
+    fn sample_boundary<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.perimeter_point(rng.gen())
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "polygon"))]
+impl SampleShape for Polygon {
+    fn sample_interior<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        match self.sampler() {
+            Some(sampler) => sampler.sample(rng),
+            None => self.sample_boundary(rng),
+        }
+    }
+
+    fn sample_boundary<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.perimeter_point(rng.gen())
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "line"))]
+impl SampleShape for Line {
+    fn sample_interior<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.sample_boundary(rng)
+    }
+
+    fn sample_boundary<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        self.perimeter_point(rng.gen())
+    }
+}
+
+// ##########
+// ToBitsKey
+// ##########
+/// A shape which can give a hashable key made from the bits of its values
+///
+/// `f32` is neither [`Eq`] nor [`Hash`], so shapes can't be the keys of a `HashMap` themselves. The key
+/// holds each `f32` of the shape as its [`f32::to_bits`], normalized so that shapes which are equal have
+/// equal keys: `-0.` has the key of `0.`, and every NaN has the key of [`f32::NAN`]. A shape holding a NaN
+/// is never equal to itself, but its key is.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Rectangle, ToBitsKey};
+///
+/// let mut names = HashMap::new();
+/// names.insert(Rectangle::new(0., 0., 2., 1.).to_bits_key(), "door");
+/// names.insert(Rectangle::new(5., 0., 6., 3.).to_bits_key(), "window");
+/// assert_eq!(names.get(&Rectangle::new(0., 0., 2., 1.).to_bits_key()), Some(&"door"));
+///
+/// // negative zero is normalized, as `-0. == 0.`
+/// assert_eq!(Rectangle::new(-0., 0., 2., 1.), Rectangle::new(0., 0., 2., 1.));
+/// assert_eq!(names.get(&Rectangle::new(-0., 0., 2., 1.).to_bits_key()), Some(&"door"));
+///
+/// // every NaN shares a key, although NaN is never equal to itself
+/// let broken = Circle::new(Vec2::ZERO, f32::NAN);
+/// assert!(broken != broken);
+/// assert_eq!(broken.to_bits_key(), Circle::new(Vec2::ZERO, -f32::NAN).to_bits_key());
+/// ```
+pub trait ToBitsKey {
+    /// The hashable key of the shape
+    type Key: Eq + Hash;
+
+    /// Get the hashable key of the shape, which is equal for shapes which are equal
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Polygon, ToBitsKey, Triangle};
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2::X, Vec2::Y);
+    /// assert_eq!(triangle.to_bits_key(), Triangle::new(Vec2::ZERO, Vec2::X, Vec2::Y).to_bits_key());
+    /// assert_ne!(triangle.to_bits_key(), Triangle::new(Vec2::X, Vec2::Y, Vec2::ZERO).to_bits_key());
+    ///
+    /// let polygon = Polygon::new(vec![Vec2::ZERO, Vec2::X, Vec2::Y]);
+    /// assert_eq!(polygon.to_bits_key(), vec![0., 0., 1., 0., 0., 1.].iter().map(|value: &f32| value.to_bits()).collect::<Vec<_>>());
+    /// ```
+    fn to_bits_key(&self) -> Self::Key;
+}
+
+#[cfg(feature = "point")]
+impl ToBitsKey for Point {
+    type Key = [u32; 2];
+
+    fn to_bits_key(&self) -> [u32; 2] {
+        vec2_bits(self.coordinate())
+    }
+}
+
+#[cfg(feature = "line")]
+impl ToBitsKey for Line {
+    type Key = [u32; 4];
+
+    fn to_bits_key(&self) -> [u32; 4] {
+        let ([x1, y1], [x2, y2]) = (vec2_bits(self.origin()), vec2_bits(self.end()));
+        [x1, y1, x2, y2]
+    }
+}
+
+#[cfg(feature = "ray")]
+impl ToBitsKey for Ray {
+    type Key = [u32; 4];
+
+    fn to_bits_key(&self) -> [u32; 4] {
+        let ([x1, y1], [x2, y2]) = (vec2_bits(self.origin()), vec2_bits(self.direction()));
+        [x1, y1, x2, y2]
+    }
+}
+
+#[cfg(feature = "circle")]
+impl ToBitsKey for Circle {
+    type Key = [u32; 3];
+
+    fn to_bits_key(&self) -> [u32; 3] {
+        let [x, y] = vec2_bits(self.center());
+        [x, y, f32_bits(self.radius())]
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl ToBitsKey for Ellipse {
+    type Key = [u32; 5];
+
+    fn to_bits_key(&self) -> [u32; 5] {
+        let [x, y] = vec2_bits(self.center());
+        [
+            x,
+            y,
+            f32_bits(self.radius_major()),
+            f32_bits(self.radius_minor()),
+            f32_bits(self.rotation()),
+        ]
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl ToBitsKey for Rectangle {
+    type Key = [u32; 4];
+
+    fn to_bits_key(&self) -> [u32; 4] {
+        let ([x1, y1], [x2, y2]) = (vec2_bits(self.min()), vec2_bits(self.max()));
+        [x1, y1, x2, y2]
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl ToBitsKey for Triangle {
+    type Key = [u32; 6];
+
+    fn to_bits_key(&self) -> [u32; 6] {
+        let [[x1, y1], [x2, y2], [x3, y3]] = self.vertices().map(vec2_bits);
+        [x1, y1, x2, y2, x3, y3]
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl ToBitsKey for Polygon {
+    type Key = Vec<u32>;
+
+    fn to_bits_key(&self) -> Vec<u32> {
+        self.coordinates()
+            .iter()
+            .flat_map(|coordinate| vec2_bits(*coordinate))
+            .collect()
+    }
+}
+
+#[cfg(feature = "mesh")]
+impl ToBitsKey for Mesh {
+    type Key = (Vec<u32>, Vec<u32>, Option<Vec<u32>>);
+
+    fn to_bits_key(&self) -> Self::Key {
+        let positions = self
+            .positions()
+            .iter()
+            .flat_map(|position| vec2_bits(*position))
+            .collect();
+        let colors = self.colors().map(|colors| {
+            colors
+                .iter()
+                .flat_map(|color| color.map(f32_bits))
+                .collect()
+        });
+        (positions, self.indices().to_vec(), colors)
+    }
+}
+
+// ##########
+// Validity
+// ##########
+/// A reason a shape isn't geometrically sane, reported by [`Validity::validate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShapeDefect {
+    /// A coordinate or size is NaN or infinite
+    NonFinite,
+    /// A shape without area has no length, such as a [`Line`](crate::prelude::Line) with both ends together
+    ZeroLength,
+    /// A shape which should enclose an area encloses none
+    ZeroArea,
+    /// A size is negative, such as a [`Rectangle`](crate::prelude::Rectangle) with its max below its min or a
+    /// [`Circle`](crate::prelude::Circle) with a negative radius
+    InvertedBounds,
+    /// A shape made of vertices has too few of them to enclose an area
+    TooFewVertices,
+}
+
+/// A shape which can check that it is geometrically sane before being used
+///
+/// NaN and infinite values pass silently through every calculation, and break every comparison made with
+/// them, so they are checked before anything else.
+///
+/// ```
+/// use glam::Vec2;
+/// use shapes2d::prelude::{Circle, Line, Polygon, Rectangle, Shape, ShapeDefect, Validity};
+///
+/// let scene: Vec<Shape> = vec![
+///     Rectangle::new(0., 0., 4., 2.).into(),
+///     Rectangle::new(f32::NAN, 0., 4., 2.).into(),
+///     Circle::new(Vec2::ONE, 0.).into(),
+///     Line::new(Vec2::ONE, Vec2::ONE).into(),
+///     Polygon::new(vec![Vec2::ZERO, Vec2::ONE]).into(),
+/// ];
+///
+/// let defects: Vec<Result<(), ShapeDefect>> = scene.iter().map(|shape| shape.validate(1e-6)).collect();
+/// assert_eq!(
+///     defects,
+///     vec![
+///         Ok(()),
+///         Err(ShapeDefect::NonFinite),
+///         Err(ShapeDefect::ZeroArea),
+///         Err(ShapeDefect::ZeroLength),
+///         Err(ShapeDefect::TooFewVertices),
+///     ]
+/// );
+/// ```
+pub trait Validity {
+    /// Checks if the shape is not geometrically sane, see [`Validity::validate`]
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Validity};
+    ///
+    /// assert!(!Circle::new(Vec2::ZERO, 1.).is_degenerate(1e-6));
+    /// assert!(Circle::new(Vec2::ZERO, 1e-4).is_degenerate(1e-6));
+    /// assert!(Circle::new(Vec2 { x: f32::INFINITY, y: 0. }, 1.).is_degenerate(1e-6));
+    /// ```
+    fn is_degenerate(&self, epsilon: f32) -> bool {
+        self.validate(epsilon).is_err()
+    }
+
+    /// Checks the shape for the first [`ShapeDefect`] found
+    ///
+    /// The checks run in order, stopping at the first defect:
+    /// - [`ShapeDefect::NonFinite`] when any coordinate or size is NaN or infinite
+    /// - [`ShapeDefect::TooFewVertices`] when a [`Polygon`](crate::prelude::Polygon) has fewer than 3
+    ///   coordinates, or a [`Mesh`](crate::prelude::Mesh) has no triangles
+    /// - [`ShapeDefect::InvertedBounds`] when a size is negative
+    /// - [`ShapeDefect::ZeroLength`] when a [`Line`](crate::prelude::Line) is no longer than `epsilon`, or a
+    ///   [`Ray`](crate::prelude::Ray) has no direction
+    /// - [`ShapeDefect::ZeroArea`] when a shape enclosing an area encloses no more than `epsilon`, the same
+    ///   as [`Polygon::validate`](crate::prelude::Polygon::validate)
+    ///
+    /// [`Polygon::validate`](crate::prelude::Polygon::validate) and [`Mesh::validate`](crate::prelude::Mesh::validate)
+    /// report every problem they find, including ones which aren't a [`ShapeDefect`], and are called instead of
+    /// this when called as a method.
+    ///
+    /// ```
+    /// use glam::Vec2;
+    /// use shapes2d::prelude::{Circle, Line, Mesh, Point, Polygon, Ray, Rectangle, ShapeDefect, Triangle, Validity};
+    /// # #[cfg(feature = "ellipse")]
+    /// use shapes2d::prelude::Ellipse;
+    ///
+    /// let epsilon = 1e-6;
+    ///
+    /// assert_eq!(Point::new(Vec2::ONE).validate(epsilon), Ok(()));
+    /// assert_eq!(Point::new(Vec2 { x: f32::NAN, y: 0. }).validate(epsilon), Err(ShapeDefect::NonFinite));
+    ///
+    /// assert_eq!(Line::new(Vec2::ZERO, Vec2::ONE).validate(epsilon), Ok(()));
+    /// assert_eq!(Line::new(Vec2::ZERO, Vec2 { x: f32::INFINITY, y: 0. }).validate(epsilon), Err(ShapeDefect::NonFinite));
+    /// assert_eq!(Line::new(Vec2::ONE, Vec2::ONE).validate(epsilon), Err(ShapeDefect::ZeroLength));
+    ///
+    /// assert_eq!(Ray::new_direction(Vec2::ZERO, Vec2::X).validate(epsilon), Ok(()));
+    /// assert_eq!(Ray::new_direction(Vec2::NAN, Vec2::X).validate(epsilon), Err(ShapeDefect::NonFinite));
+    /// assert_eq!(Ray::new_direction(Vec2::ZERO, Vec2::ZERO).validate(epsilon), Err(ShapeDefect::ZeroLength));
+    ///
+    /// assert_eq!(Circle::new(Vec2::ZERO, 1.).validate(epsilon), Ok(()));
+    /// assert_eq!(Circle::new(Vec2::ZERO, f32::NAN).validate(epsilon), Err(ShapeDefect::NonFinite));
+    /// assert_eq!(Circle::new(Vec2::ZERO, -1.).validate(epsilon), Err(ShapeDefect::InvertedBounds));
+    /// assert_eq!(Circle::new(Vec2::ZERO, 0.).validate(epsilon), Err(ShapeDefect::ZeroArea));
+    ///
+    /// # #[cfg(feature = "ellipse")]
+    /// # {
+    /// assert_eq!(Ellipse::new(Vec2::ZERO, 2., 1.).validate(epsilon), Ok(()));
+    /// assert_eq!(Ellipse::new(Vec2::ZERO, 2., f32::INFINITY).validate(epsilon), Err(ShapeDefect::NonFinite));
+    /// assert_eq!(Ellipse::new(Vec2::ZERO, 2., -1.).validate(epsilon), Err(ShapeDefect::InvertedBounds));
+    /// assert_eq!(Ellipse::new(Vec2::ZERO, 2., 0.).validate(epsilon), Err(ShapeDefect::ZeroArea));
+    /// # }
+    ///
+    /// assert_eq!(Rectangle::new(0., 0., 2., 1.).validate(epsilon), Ok(()));
+    /// assert_eq!(Rectangle::new(f32::NAN, 0., 2., 1.).validate(epsilon), Err(ShapeDefect::NonFinite));
+    /// assert_eq!(Rectangle::new(2., 0., 0., 1.).validate(epsilon), Err(ShapeDefect::InvertedBounds));
+    /// assert_eq!(Rectangle::new(0., 1., 2., 1.).validate(epsilon), Err(ShapeDefect::ZeroArea));
+    ///
+    /// let triangle = Triangle::new(Vec2::ZERO, Vec2::X, Vec2::Y);
+    /// assert_eq!(triangle.validate(epsilon), Ok(()));
+    /// assert_eq!(Triangle::new(Vec2::ZERO, Vec2::X, Vec2::NAN).validate(epsilon), Err(ShapeDefect::NonFinite));
+    /// assert_eq!(Triangle::new(Vec2::ZERO, Vec2::X, Vec2 { x: 2., y: 0. }).validate(epsilon), Err(ShapeDefect::ZeroArea));
+    ///
+    /// // `Polygon` and `Mesh` have their own, more detailed, `validate`, so call through the trait
+    /// assert_eq!(Validity::validate(&Polygon::from(triangle), epsilon), Ok(()));
+    /// let infinite = Polygon::new(vec![Vec2::ZERO, Vec2::X, Vec2::splat(f32::INFINITY)]);
+    /// assert_eq!(Validity::validate(&infinite, epsilon), Err(ShapeDefect::NonFinite));
+    /// let two = Polygon::new(vec![Vec2::ZERO, Vec2::X]);
+    /// assert_eq!(Validity::validate(&two, epsilon), Err(ShapeDefect::TooFewVertices));
+    /// let flat = Polygon::new(vec![Vec2::ZERO, Vec2::X, Vec2::X * 2.]);
+    /// assert_eq!(Validity::validate(&flat, epsilon), Err(ShapeDefect::ZeroArea));
+    ///
+    /// assert_eq!(Validity::validate(&Mesh::from_triangle(&triangle), epsilon), Ok(()));
+    /// let broken = Mesh::from_triangle(&Triangle::new(Vec2::ZERO, Vec2::X, Vec2::NAN));
+    /// assert_eq!(Validity::validate(&broken, epsilon), Err(ShapeDefect::NonFinite));
+    /// assert_eq!(Validity::validate(&Mesh::empty(), epsilon), Err(ShapeDefect::TooFewVertices));
+    /// let flat = Mesh::from_triangle(&Triangle::new(Vec2::ZERO, Vec2::X, Vec2::X * 2.));
+    /// assert_eq!(Validity::validate(&flat, epsilon), Err(ShapeDefect::ZeroArea));
+    /// ```
+    fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect>;
+}
+
+#[cfg(feature = "point")]
+impl Validity for Point {
+    fn validate(&self, _epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(&[self.coordinate()], &[])
+    }
+}
+
+#[cfg(feature = "line")]
+impl Validity for Line {
+    fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(&[self.origin(), self.end()], &[])?;
+        if self.length() <= epsilon {
+            return Err(ShapeDefect::ZeroLength);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ray")]
+impl Validity for Ray {
+    fn validate(&self, _epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(&[self.origin(), self.direction()], &[])?;
+        if self.direction() == Vec2::ZERO {
+            return Err(ShapeDefect::ZeroLength);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "circle")]
+impl Validity for Circle {
+    fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(&[self.center()], &[self.radius()])?;
+        if self.radius() < 0. {
+            return Err(ShapeDefect::InvertedBounds);
+        }
+        if Shape2d::area(self) <= epsilon {
+            return Err(ShapeDefect::ZeroArea);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ellipse")]
+impl Validity for Ellipse {
+    fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(
+            &[self.center()],
+            &[self.radius_major(), self.radius_minor(), self.rotation()],
+        )?;
+        if self.radius_major() < 0. || self.radius_minor() < 0. {
+            return Err(ShapeDefect::InvertedBounds);
+        }
+        if Shape2d::area(self) <= epsilon {
+            return Err(ShapeDefect::ZeroArea);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rectangle")]
+impl Validity for Rectangle {
+    fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(&[self.min(), self.max()], &[])?;
+        if self.width() < 0. || self.height() < 0. {
+            return Err(ShapeDefect::InvertedBounds);
+        }
+        if Shape2d::area(self) <= epsilon {
+            return Err(ShapeDefect::ZeroArea);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "triangle")]
+impl Validity for Triangle {
+    fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(&self.vertices(), &[])?;
+        if Shape2d::area(self) <= epsilon {
+            return Err(ShapeDefect::ZeroArea);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "polygon")]
+impl Validity for Polygon {
+    fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(self.coordinates(), &[])?;
+        if self.len() < 3 {
+            return Err(ShapeDefect::TooFewVertices);
+        }
+        if Polygon::area(self) <= epsilon {
+            return Err(ShapeDefect::ZeroArea);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mesh")]
+impl Validity for Mesh {
+    fn validate(&self, epsilon: f32) -> Result<(), ShapeDefect> {
+        finite(self.positions(), &[])?;
+        if self.triangle_count() == 0 {
+            return Err(ShapeDefect::TooFewVertices);
+        }
+        if self.area() <= epsilon {
+            return Err(ShapeDefect::ZeroArea);
+        }
+        Ok(())
+    }
+}
+
+// ##########
+// Helpers
+// ##########
+// The closest point to `point` on the segment from `start` to `end`
+#[cfg(any(
+    feature = "line",
+    feature = "triangle",
+    feature = "capsule",
+    all(feature = "rectangle", feature = "polygon")
+))]
+pub(crate) fn closest_point_on_segment(point: Vec2, start: Vec2, end: Vec2) -> Vec2 {
+    let direction = end - start;
+    let length_squared = direction.length_squared();
+    if length_squared == 0. {
+        return start;
+    }
+    let t = ((point - start).dot(direction) / length_squared).clamp(0., 1.);
+    start + direction * t
+}
+
+// Checks if the segments `a_start -> a_end` and `b_start -> b_end` touch or cross
+#[cfg(any(
+    feature = "line",
+    feature = "triangle",
+    feature = "capsule",
+    all(feature = "rectangle", feature = "polygon")
+))]
+pub(crate) fn segments_intersect(a_start: Vec2, a_end: Vec2, b_start: Vec2, b_end: Vec2) -> bool {
+    // `point` is known to be collinear with the segment, so it only has to be within its bounds
+    let within = |point: Vec2, start: Vec2, end: Vec2| {
+        point.cmpge(start.min(end)).all() && point.cmple(start.max(end)).all()
+    };
+
+    let d1 = (b_end - b_start).perp_dot(a_start - b_start);
+    let d2 = (b_end - b_start).perp_dot(a_end - b_start);
+    let d3 = (a_end - a_start).perp_dot(b_start - a_start);
+    let d4 = (a_end - a_start).perp_dot(b_end - a_start);
+
+    if ((d1 > 0. && d2 < 0.) || (d1 < 0. && d2 > 0.))
+        && ((d3 > 0. && d4 < 0.) || (d3 < 0. && d4 > 0.))
+    {
+        return true;
+    }
+
+    (d1 == 0. && within(a_start, b_start, b_end))
+        || (d2 == 0. && within(a_end, b_start, b_end))
+        || (d3 == 0. && within(b_start, a_start, a_end))
+        || (d4 == 0. && within(b_end, a_start, a_end))
+}
+
+// The shortest distance between the segments `a_start -> a_end` and `b_start -> b_end`
+#[cfg(any(
+    feature = "line",
+    feature = "capsule",
+    all(feature = "rectangle", feature = "polygon")
+))]
+pub(crate) fn segment_distance(a_start: Vec2, a_end: Vec2, b_start: Vec2, b_end: Vec2) -> f32 {
+    if segments_intersect(a_start, a_end, b_start, b_end) {
+        return 0.;
+    }
+    [
+        a_start.distance(closest_point_on_segment(a_start, b_start, b_end)),
+        a_end.distance(closest_point_on_segment(a_end, b_start, b_end)),
+        b_start.distance(closest_point_on_segment(b_start, a_start, a_end)),
+        b_end.distance(closest_point_on_segment(b_end, a_start, a_end)),
+    ]
+    .into_iter()
+    .fold(f32::INFINITY, f32::min)
+}
+
+// The contact of the point of `circle` deepest inside of the other shape
+#[cfg(feature = "circle")]
+fn single_contact(circle: &Circle, penetration: Penetration) -> ContactManifold {
+    ContactManifold {
+        normal: penetration.normal,
+        points: [
+            circle.center() - penetration.normal * circle.radius(),
+            Vec2::ZERO,
+        ],
+        count: 1,
+        depths: [penetration.depth, 0.],
+    }
+}
+
+// The face of `rect` facing along the axis aligned `normal`
+#[cfg(feature = "rectangle")]
+fn rectangle_face(rect: &Rectangle, normal: Vec2) -> [Vec2; 2] {
+    let (min, max) = (rect.min(), rect.max());
+    if normal == Vec2::X {
+        [Vec2 { x: max.x, y: min.y }, max]
+    } else if normal == Vec2::NEG_X {
+        [Vec2 { x: min.x, y: max.y }, min]
+    } else if normal == Vec2::Y {
+        [max, Vec2 { x: min.x, y: max.y }]
+    } else {
+        [min, Vec2 { x: max.x, y: min.y }]
+    }
+}
+
+// The point `t` of the way along the closed path through `vertices` by length, wrapping `t` around
+#[cfg(any(feature = "rectangle", feature = "triangle", feature = "polygon"))]
+fn closed_path_point(vertices: &[Vec2], t: f32) -> Vec2 {
+    let edges = || vertices.iter().zip(vertices.iter().cycle().skip(1));
+    let total: f32 = edges().map(|(start, end)| start.distance(*end)).sum();
+
+    let mut remaining = t.rem_euclid(1.) * total;
+    for (start, end) in edges() {
+        let length = start.distance(*end);
+        if remaining < length {
+            return start.lerp(*end, remaining / length);
+        }
+        remaining -= length;
+    }
+    vertices.first().copied().unwrap_or(Vec2::ZERO)
+}
+
+// The outward normal of the edge of the closed path through `vertices` nearest to `point`, or the average of
+// the normals of both edges at a vertex when `point` is nearest to that vertex
+#[cfg(any(feature = "rectangle", feature = "triangle", feature = "polygon"))]
+fn closed_path_normal(vertices: &[Vec2], point: Vec2) -> Vec2 {
+    let edges = || vertices.iter().zip(vertices.iter().cycle().skip(1));
+
+    // Turning the edges clockwise points out of a counter-clockwise path, and into a clockwise one
+    let twice_area: f32 = edges().map(|(start, end)| start.perp_dot(*end)).sum();
+    let outward = if twice_area < 0. { -1. } else { 1. };
+    let normal = |i: usize| {
+        let direction = vertices[(i + 1) % vertices.len()] - vertices[i];
+        (Vec2 {
+            x: direction.y,
+            y: -direction.x,
+        } * outward)
+            .normalize_or_zero()
+    };
+
+    // The nearest edge, and where along it the nearest point is
+    let nearest = edges()
+        .enumerate()
+        .filter(|(_, (start, end))| start != end)
+        .map(|(i, (start, end))| {
+            let direction = *end - *start;
+            let t = ((point - *start).dot(direction) / direction.length_squared()).clamp(0., 1.);
+            (i, t, point.distance_squared(*start + direction * t))
+        })
+        .min_by(|a, b| a.2.total_cmp(&b.2));
+
+    let Some((i, t, _)) = nearest else {
+        return Vec2::X;
+    };
+    // At a vertex, the edge on its other side skipping over repeated vertices
+    let len = vertices.len();
+    let is_edge = |j: usize| vertices[j] != vertices[(j + 1) % len];
+    let corner = if t == 0. {
+        (1..len)
+            .map(|offset| (i + len - offset) % len)
+            .find(|&j| is_edge(j))
+    } else if t == 1. {
+        (1..len)
+            .map(|offset| (i + offset) % len)
+            .find(|&j| is_edge(j))
+    } else {
+        None
+    };
+    match corner {
+        Some(other) => (normal(i) + normal(other))
+            .try_normalize()
+            .unwrap_or(normal(i)),
+        None => normal(i),
+    }
+}
+
+// A copy of `shape` mirrored across `line`, or through the origin of `line` when it has no length
+#[cfg(feature = "line")]
+fn reflected<T: Transformable + Clone>(shape: &T, line: &Line) -> T {
+    let matrix = match line.direction().try_normalize() {
+        Some(direction) => Mat2::from_cols(
+            Vec2 {
+                x: 2. * direction.x * direction.x - 1.,
+                y: 2. * direction.x * direction.y,
+            },
+            Vec2 {
+                x: 2. * direction.x * direction.y,
+                y: 2. * direction.y * direction.y - 1.,
+            },
+        ),
+        None => -Mat2::IDENTITY,
+    };
+    let origin = line.origin();
+
+    let mut reflected = shape.clone();
+    reflected.transform(Affine2::from_mat2_translation(
+        matrix,
+        origin - matrix * origin,
+    ));
+    reflected
+}
+
+// The blend `t` of the way from `from` to `to`, which is exact at `t = 0.` and `t = 1.`
+#[cfg(any(
+    feature = "point",
+    feature = "line",
+    feature = "ray",
+    feature = "circle",
+    feature = "ellipse",
+    feature = "rectangle",
+    feature = "triangle",
+    feature = "polygon"
+))]
+fn mix<T: Add<Output = T> + Mul<f32, Output = T>>(from: T, to: T, t: f32) -> T {
+    from * (1. - t) + to * t
+}
+
+// The closed path through `vertices` with its edges split into `count` coordinates, splitting whichever edge
+// has the longest pieces each time so the splits spread out along the path
+#[cfg(feature = "polygon")]
+fn split_edges(vertices: &[Vec2], count: usize) -> Vec<Vec2> {
+    let lengths: Vec<f32> = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(start, end)| start.distance(*end))
+        .collect();
+    let mut pieces = vec![1_usize; vertices.len()];
+    for _ in vertices.len()..count {
+        let longest = (0..pieces.len())
+            .max_by(|a, b| {
+                (lengths[*a] / pieces[*a] as f32).total_cmp(&(lengths[*b] / pieces[*b] as f32))
+            })
+            .unwrap_or(0);
+        pieces[longest] += 1;
+    }
+
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .zip(pieces)
+        .flat_map(|((start, end), pieces)| {
+            (0..pieces).map(move |piece| start.lerp(*end, piece as f32 / pieces as f32))
+        })
+        .collect()
+}
+
+// `Err(ShapeDefect::NonFinite)` if any of the `coordinates` or `values` are NaN or infinite
+#[cfg(any(
+    feature = "point",
+    feature = "line",
+    feature = "ray",
+    feature = "circle",
+    feature = "ellipse",
+    feature = "rectangle",
+    feature = "triangle",
+    feature = "polygon",
+    feature = "mesh"
+))]
+fn finite(coordinates: &[Vec2], values: &[f32]) -> Result<(), ShapeDefect> {
+    if coordinates.iter().all(|coordinate| coordinate.is_finite())
+        && values.iter().all(|value| value.is_finite())
+    {
+        Ok(())
+    } else {
+        Err(ShapeDefect::NonFinite)
+    }
+}
+
+// The `point` along each of the axes of `ellipse` from its center
+#[cfg(feature = "ellipse")]
+fn ellipse_local_point(ellipse: &Ellipse, point: Vec2) -> Vec2 {
+    let [major, minor] = ellipse.axes();
+    let offset = point - ellipse.center();
+    Vec2 {
+        x: offset.dot(major),
+        y: offset.dot(minor),
+    }
+}
+
+// The point `local` along each of the axes of `ellipse` from its center
+#[cfg(feature = "ellipse")]
+fn ellipse_world_point(ellipse: &Ellipse, local: Vec2) -> Vec2 {
+    let [major, minor] = ellipse.axes();
+    ellipse.center() + major * local.x + minor * local.y
+}
+
+// The bits of `value`, with `-0.` as `0.` and every NaN as `f32::NAN`
+#[cfg(any(
+    feature = "point",
+    feature = "line",
+    feature = "ray",
+    feature = "circle",
+    feature = "ellipse",
+    feature = "rectangle",
+    feature = "triangle",
+    feature = "polygon",
+    feature = "mesh"
+))]
+fn f32_bits(value: f32) -> u32 {
+    if value.is_nan() {
+        f32::NAN.to_bits()
+    } else {
+        (value + 0.).to_bits()
+    }
+}
+
+// The bits of each component of `value`, see `f32_bits`
+#[cfg(any(
+    feature = "point",
+    feature = "line",
+    feature = "ray",
+    feature = "circle",
+    feature = "ellipse",
+    feature = "rectangle",
+    feature = "triangle",
+    feature = "polygon",
+    feature = "mesh"
+))]
+fn vec2_bits(value: Vec2) -> [u32; 2] {
+    [f32_bits(value.x), f32_bits(value.y)]
+}
+
+// The `(normal, depth)` with the least depth, preferring the earliest on ties
+#[cfg(all(feature = "circle", feature = "rectangle"))]
+fn shallowest(sides: [(Vec2, f32); 4]) -> (Vec2, f32) {
+    sides
+        .into_iter()
+        .fold((Vec2::ZERO, f32::INFINITY), |shallowest, side| {
+            if side.1 < shallowest.1 {
+                side
+            } else {
+                shallowest
+            }
+        })
+}
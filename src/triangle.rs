@@ -1,163 +1,1245 @@
 use std::fmt::Display;
 
-use glam::Vec2;
+use glam::{Vec2, Vec3};
 
-/// Represents a single [`Triangle`] in 2d space
-pub struct Triangle {
-    coordinate1: Vec2,
-    coordinate2: Vec2,
-    coordinate3: Vec2,
-}
+#[cfg(feature = "circle")]
+use crate::prelude::Circle;
+#[cfg(feature = "line")]
+use crate::prelude::Line;
+#[cfg(feature = "mesh")]
+use crate::prelude::Mesh;
+#[cfg(feature = "rectangle")]
+use crate::prelude::Rectangle;
+use crate::prelude::Winding;
+#[cfg(feature = "approx")]
+use crate::traits::impl_approx;
 
-// ##########
-// Constructors
-// ##########
-impl Triangle {
-    /// Creates a new [`Triangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Triangle;
-    ///
-    /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1.0, y: 0.0 });
-    ///
-    /// assert_eq!(triangle.coordinate1(), Vec2::ONE);
-    /// assert_eq!(triangle.coordinate2(), Vec2::ZERO);
-    /// assert_eq!(triangle.coordinate3(), Vec2 { x: 1.0, y: 0.0 });
-    /// ```
-    pub fn new(coordinate1: Vec2, coordinate2: Vec2, coordinate3: Vec2) -> Self {
-        Self {
-            coordinate1,
-            coordinate2,
-            coordinate3,
+// Defines the `Triangle` for one precision, here with `f32` and in `crate::double` with `f64`, where the
+// shape and `Vec2` names in the bodies refer to the `f64` types
+macro_rules! impl_triangle {
+    ($(#[$meta:meta])* $triangle:ident, $float:ident; $imports:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $triangle {
+            coordinate1: Vec2,
+            coordinate2: Vec2,
+            coordinate3: Vec2,
         }
-    }
-}
 
-// ##########
-// Getters/Setters
-// ##########
-impl Triangle {
-    /// Get the first coordinate for the [`Triangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Triangle;
-    ///
-    /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
-    /// let coordinate = triangle.coordinate1();
-    ///
-    /// assert_eq!(coordinate, Vec2::ONE);
-    /// ```
-    pub fn coordinate1(&self) -> Vec2 {
-        self.coordinate1
-    }
-
-    /// Get the second coordinate for the [`Triangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Triangle;
-    ///
-    /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
-    /// let coordinate = triangle.coordinate2();
-    ///
-    /// assert_eq!(coordinate, Vec2::ZERO);
-    /// ```
-    pub fn coordinate2(&self) -> Vec2 {
-        self.coordinate2
-    }
-
-    /// Get the third coordinate for the [`Triangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Triangle;
-    ///
-    /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
-    /// let coordinate = triangle.coordinate3();
-    ///
-    /// assert_eq!(coordinate, Vec2 { x: 1., y: 0. });
-    /// ```
-    pub fn coordinate3(&self) -> Vec2 {
-        self.coordinate3
-    }
-
-    /// Set the first coordinate for the [`Triangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Triangle;
-    ///
-    /// let mut triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
-    /// triangle.set_coordinate1( Vec2 { x: 2., y: 1. });
-    ///
-    /// assert_eq!(triangle.coordinate1(), Vec2 { x: 2., y: 1. });
-    /// ```
-    pub fn set_coordinate1(&mut self, coordinate1: Vec2) {
-        self.coordinate1 = coordinate1;
-    }
-
-    /// Set the second coordinate for the [`Triangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Triangle;
-    ///
-    /// let mut triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
-    /// triangle.set_coordinate2( Vec2 { x: 2., y: 1. });
-    ///
-    /// assert_eq!(triangle.coordinate2(), Vec2 { x: 2., y: 1. });
-    /// ```
-    pub fn set_coordinate2(&mut self, coordinate2: Vec2) {
-        self.coordinate2 = coordinate2;
-    }
-
-    /// Set the third coordinate for the [`Triangle`]
-    ///
-    /// ```
-    /// use glam::Vec2;
-    /// use shapes2d::prelude::Triangle;
-    ///
-    /// let mut triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
-    /// triangle.set_coordinate3( Vec2 { x: 2., y: 1. });
-    ///
-    /// assert_eq!(triangle.coordinate3(), Vec2 { x: 2., y: 1. });
-    /// ```
-    pub fn set_coordinate3(&mut self, coordinate3: Vec2) {
-        self.coordinate3 = coordinate3;
-    }
-}
+        // ##########
+        // Constructors
+        // ##########
+        impl Triangle {
+            /// Creates a new [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1.0, y: 0.0 });
+            ///
+            /// assert_eq!(triangle.coordinate1(), Vec2::ONE);
+            /// assert_eq!(triangle.coordinate2(), Vec2::ZERO);
+            /// assert_eq!(triangle.coordinate3(), Vec2 { x: 1.0, y: 0.0 });
+            /// ```
+            pub fn new(coordinate1: Vec2, coordinate2: Vec2, coordinate3: Vec2) -> Self {
+                Self {
+                    coordinate1,
+                    coordinate2,
+                    coordinate3,
+                }
+            }
 
-// ##########
-// Attributes
-// ##########
-impl Triangle {}
+            /// Creates a new equilateral [`Triangle`] centered on `center` with every coordinate `circumradius` away from it
+            ///
+            /// `coordinate1` points straight up from the `center`, and the coordinates are ordered counter-clockwise.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let triangle = Triangle::equilateral(Vec2::ONE, 2.);
+            ///
+            /// assert!(triangle.coordinate1().abs_diff_eq(Vec2 { x: 1., y: 3. }, 1e-6));
+            /// assert_eq!(triangle.winding(), Winding::CounterClockwise);
+            /// assert!(triangle.is_equilateral(1e-5));
+            /// assert!(triangle.centroid().abs_diff_eq(Vec2::ONE, 1e-6));
+            /// for angle in triangle.angles() {
+            ///     assert_abs_diff_eq!(angle, FRAC_PI_3, epsilon = 1e-5);
+            /// }
+            /// ```
+            pub fn equilateral(center: Vec2, circumradius: $float) -> Self {
+                let vertex = |angle: $float| center + Vec2::from_angle(angle) * circumradius;
+                let up = std::$float::consts::FRAC_PI_2;
+                let third = std::$float::consts::TAU / 3.;
+                Self::new(vertex(up), vertex(up + third), vertex(up + third * 2.))
+            }
 
-// ##########
-// Default impl
-// ##########
-impl Default for Triangle {
-    fn default() -> Self {
-        Self {
-            coordinate1: Vec2::ONE,
-            coordinate2: Vec2::ZERO,
-            coordinate3: Vec2 { x: 1., y: 0. },
+            /// Creates a new right [`Triangle`] with the right angle at `origin`
+            ///
+            /// The coordinates are `origin`, `origin + (width, 0)`, and `origin + (0, height)`, which is
+            /// counter-clockwise when `width` and `height` have the same sign.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::right(Vec2::ONE, 4., 3.);
+            ///
+            /// assert_eq!(triangle.coordinate1(), Vec2::ONE);
+            /// assert_eq!(triangle.coordinate2(), Vec2 { x: 5., y: 1. });
+            /// assert_eq!(triangle.coordinate3(), Vec2 { x: 1., y: 4. });
+            /// assert_eq!(triangle.angles()[0], FRAC_PI_2);
+            /// assert_eq!(triangle.winding(), Winding::CounterClockwise);
+            /// ```
+            pub fn right(origin: Vec2, width: $float, height: $float) -> Self {
+                Self::new(
+                    origin,
+                    origin + Vec2 { x: width, y: 0. },
+                    origin + Vec2 { x: 0., y: height },
+                )
+            }
+
+            /// Creates a new isosceles [`Triangle`] with a horizontal base centered on `base_center`
+            ///
+            /// The coordinates are the left end of the base, the right end of the base, and the apex `height` above
+            /// the `base_center`, which is counter-clockwise when `base_width` and `height` have the same sign.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::isosceles(Vec2::ZERO, 6., 4.);
+            ///
+            /// assert_eq!(triangle.coordinate1(), Vec2 { x: -3., y: 0. });
+            /// assert_eq!(triangle.coordinate2(), Vec2 { x: 3., y: 0. });
+            /// assert_eq!(triangle.coordinate3(), Vec2 { x: 0., y: 4. });
+            /// assert_eq!(triangle.coordinate1().distance(triangle.coordinate3()), 5.);
+            /// assert_eq!(triangle.coordinate2().distance(triangle.coordinate3()), 5.);
+            /// assert_eq!(triangle.winding(), Winding::CounterClockwise);
+            /// ```
+            pub fn isosceles(base_center: Vec2, base_width: $float, height: $float) -> Self {
+                let half_width = base_width * 0.5;
+                Self::new(
+                    base_center
+                        - Vec2 {
+                            x: half_width,
+                            y: 0.,
+                        },
+                    base_center
+                        + Vec2 {
+                            x: half_width,
+                            y: 0.,
+                        },
+                    base_center + Vec2 { x: 0., y: height },
+                )
+            }
+
+            /// Creates a new [`Triangle`] from a `base` [`Line`] and an `apex`
+            ///
+            /// The coordinates are the `base` origin, the `base` end, and the `apex`, which is counter-clockwise
+            /// when the `apex` is to the left of the `base` direction.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let base = Line::new(Vec2::ZERO, Vec2 { x: 4., y: 0. });
+            /// let triangle = Triangle::from_base_apex(base, Vec2 { x: 0., y: 3. });
+            ///
+            /// assert_eq!(triangle.coordinate1(), Vec2::ZERO);
+            /// assert_eq!(triangle.coordinate2(), Vec2 { x: 4., y: 0. });
+            /// assert_eq!(triangle.coordinate3(), Vec2 { x: 0., y: 3. });
+            /// assert_eq!(triangle.winding(), Winding::CounterClockwise);
+            ///
+            /// let base = Line::new(Vec2::ZERO, Vec2 { x: 4., y: 0. });
+            /// let triangle = Triangle::from_base_apex(base, Vec2 { x: 0., y: -3. });
+            ///
+            /// assert_eq!(triangle.winding(), Winding::Clockwise);
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn from_base_apex(base: Line, apex: Vec2) -> Self {
+                Self::new(base.origin(), base.end(), apex)
+            }
+        }
+
+        // ##########
+        // Getters/Setters
+        // ##########
+        impl Triangle {
+            /// Get the first coordinate for the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            /// let coordinate = triangle.coordinate1();
+            ///
+            /// assert_eq!(coordinate, Vec2::ONE);
+            /// ```
+            pub fn coordinate1(&self) -> Vec2 {
+                self.coordinate1
+            }
+
+            /// Get the second coordinate for the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            /// let coordinate = triangle.coordinate2();
+            ///
+            /// assert_eq!(coordinate, Vec2::ZERO);
+            /// ```
+            pub fn coordinate2(&self) -> Vec2 {
+                self.coordinate2
+            }
+
+            /// Get the third coordinate for the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            /// let coordinate = triangle.coordinate3();
+            ///
+            /// assert_eq!(coordinate, Vec2 { x: 1., y: 0. });
+            /// ```
+            pub fn coordinate3(&self) -> Vec2 {
+                self.coordinate3
+            }
+
+            /// Set the first coordinate for the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            /// triangle.set_coordinate1( Vec2 { x: 2., y: 1. });
+            ///
+            /// assert_eq!(triangle.coordinate1(), Vec2 { x: 2., y: 1. });
+            /// ```
+            pub fn set_coordinate1(&mut self, coordinate1: Vec2) {
+                self.coordinate1 = coordinate1;
+            }
+
+            /// Set the second coordinate for the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            /// triangle.set_coordinate2( Vec2 { x: 2., y: 1. });
+            ///
+            /// assert_eq!(triangle.coordinate2(), Vec2 { x: 2., y: 1. });
+            /// ```
+            pub fn set_coordinate2(&mut self, coordinate2: Vec2) {
+                self.coordinate2 = coordinate2;
+            }
+
+            /// Set the third coordinate for the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            /// triangle.set_coordinate3( Vec2 { x: 2., y: 1. });
+            ///
+            /// assert_eq!(triangle.coordinate3(), Vec2 { x: 2., y: 1. });
+            /// ```
+            pub fn set_coordinate3(&mut self, coordinate3: Vec2) {
+                self.coordinate3 = coordinate3;
+            }
+
+            /// Get all three coordinates for the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            /// let vertices = triangle.vertices();
+            ///
+            /// assert_eq!(vertices, [Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. }]);
+            /// ```
+            pub fn vertices(&self) -> [Vec2; 3] {
+                [self.coordinate1, self.coordinate2, self.coordinate3]
+            }
+
+            /// Set all three coordinates for the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut triangle = Triangle::new(Vec2::ONE, Vec2::ZERO, Vec2 { x: 1., y: 0. });
+            /// triangle.set_vertices([Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. }]);
+            ///
+            /// assert_eq!(triangle.coordinate1(), Vec2::ZERO);
+            /// assert_eq!(triangle.coordinate2(), Vec2 { x: 2., y: 0. });
+            /// assert_eq!(triangle.coordinate3(), Vec2 { x: 0., y: 2. });
+            /// ```
+            pub fn set_vertices(&mut self, vertices: [Vec2; 3]) {
+                let [coordinate1, coordinate2, coordinate3] = vertices;
+                self.coordinate1 = coordinate1;
+                self.coordinate2 = coordinate2;
+                self.coordinate3 = coordinate3;
+            }
+
+            /// Get an iterator over the three coordinates of the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::default();
+            /// let sum: Vec2 = triangle.iter_vertices().sum();
+            ///
+            /// assert_eq!(sum, Vec2 { x: 2., y: 1. });
+            ///
+            /// for (vertex, coordinate) in (&triangle).into_iter().zip(triangle.vertices()) {
+            ///     assert_eq!(vertex, coordinate);
+            /// }
+            /// ```
+            pub fn iter_vertices(&self) -> std::array::IntoIter<Vec2, 3> {
+                self.vertices().into_iter()
+            }
+
+            /// Get the three edges of the [`Triangle`]
+            ///
+            /// The edges are ordered `coordinate1 -> coordinate2`, `coordinate2 -> coordinate3`, `coordinate3 -> coordinate1`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// let edges = triangle.edges();
+            ///
+            /// assert_eq!(edges[0].origin(), triangle.coordinate1());
+            /// for i in 0..3 {
+            ///     assert_eq!(edges[i].end(), edges[(i + 1) % 3].origin());
+            /// }
+            ///
+            /// let perimeter = edges.iter().fold(0., |perimeter, edge| perimeter + edge.direction().length());
+            /// assert_eq!(perimeter, 12.);
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn edges(&self) -> [Line; 3] {
+                [
+                    Line::new(self.coordinate1, self.coordinate2),
+                    Line::new(self.coordinate2, self.coordinate3),
+                    Line::new(self.coordinate3, self.coordinate1),
+                ]
+            }
+
+            /// Get the three medians of the [`Triangle`]
+            ///
+            /// Each median runs from a coordinate to the midpoint of the opposite side, ordered
+            /// from `coordinate1`, `coordinate2`, and `coordinate3` respectively.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: -1., y: 2. }, Vec2 { x: 3., y: 0.5 }, Vec2 { x: 1., y: 4. });
+            /// let medians = triangle.medians();
+            ///
+            /// assert_eq!(medians[0].origin(), triangle.coordinate1());
+            /// assert_eq!(medians[0].end(), (triangle.coordinate2() + triangle.coordinate3()) * 0.5);
+            ///
+            /// // the medians meet at the centroid, two thirds of the way along each median
+            /// for median in &medians {
+            ///     let point = median.origin() + median.direction() * (2. / 3.);
+            ///     assert!(point.abs_diff_eq(triangle.centroid(), 1e-5));
+            /// }
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn medians(&self) -> [Line; 3] {
+                let [a, b, c] = self.vertices();
+                [
+                    Line::new(a, (b + c) * 0.5),
+                    Line::new(b, (c + a) * 0.5),
+                    Line::new(c, (a + b) * 0.5),
+                ]
+            }
+
+            /// Get the three altitudes of the [`Triangle`]
+            ///
+            /// Each altitude runs from a coordinate to the foot of the perpendicular on the line through the
+            /// opposite side, ordered from `coordinate1`, `coordinate2`, and `coordinate3` respectively.
+            /// The foot lies on the infinite line through the side, and falls outside of the side itself when
+            /// the [`Triangle`] is obtuse.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: 0., y: 3. }, Vec2::ZERO, Vec2 { x: 4., y: 0. });
+            /// let altitudes = triangle.altitudes();
+            ///
+            /// assert_eq!(altitudes[0].end(), Vec2::ZERO);
+            /// assert_eq!(altitudes[1].origin(), Vec2::ZERO);
+            ///
+            /// // obtuse triangles have feet outside of the opposite side
+            /// let triangle = Triangle::new(Vec2 { x: -1., y: 1. }, Vec2::ZERO, Vec2 { x: 4., y: 0. });
+            /// let altitudes = triangle.altitudes();
+            ///
+            /// assert_eq!(altitudes[0].end(), Vec2 { x: -1., y: 0. });
+            ///
+            /// // each altitude is perpendicular to the opposite side
+            /// let [a, b, c] = triangle.vertices();
+            /// for (altitude, side) in altitudes.iter().zip([c - b, a - c, b - a]) {
+            ///     assert_abs_diff_eq!(altitude.direction().dot(side), 0., epsilon = 1e-5);
+            /// }
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn altitudes(&self) -> [Line; 3] {
+                let [a, b, c] = self.vertices();
+                [
+                    Line::new(a, Self::perpendicular_foot(a, b, c)),
+                    Line::new(b, Self::perpendicular_foot(b, c, a)),
+                    Line::new(c, Self::perpendicular_foot(c, a, b)),
+                ]
+            }
+
+            /// Get the three perpendicular bisectors of the sides of the [`Triangle`]
+            ///
+            /// Each bisector starts at the midpoint of the side opposite `coordinate1`, `coordinate2`, and
+            /// `coordinate3` respectively, and ends one side length away in the direction of the side rotated
+            /// 90 degrees counter-clockwise, which points into the [`Triangle`] when it is counter-clockwise.
+            /// The bisectors meet at the circumcenter when extended as infinite lines.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: -1., y: 2. }, Vec2 { x: 3., y: 0.5 }, Vec2 { x: 1., y: 4. });
+            /// let bisectors = triangle.perpendicular_bisectors();
+            /// let circumcenter = triangle.circumcircle().unwrap().center();
+            ///
+            /// assert_eq!(bisectors[0].origin(), (triangle.coordinate2() + triangle.coordinate3()) * 0.5);
+            /// for bisector in &bisectors {
+            ///     let direction = bisector.direction();
+            ///     let offset = circumcenter - bisector.origin();
+            ///     assert!(direction.perp_dot(offset).abs() / direction.length() < 1e-5);
+            /// }
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn perpendicular_bisectors(&self) -> [Line; 3] {
+                let [a, b, c] = self.vertices();
+                let bisector = |start: Vec2, end: Vec2| {
+                    let midpoint = (start + end) * 0.5;
+                    Line::new(midpoint, midpoint + (end - start).perp())
+                };
+                [bisector(b, c), bisector(c, a), bisector(a, b)]
+            }
+        }
+
+        // ##########
+        // Attributes
+        // ##########
+        impl Triangle {
+            /// Get the `centroid` of the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::default();
+            /// let centroid = triangle.centroid();
+            ///
+            /// assert_eq!(centroid, Vec2 { x: 2. / 3., y: 1. / 3. });
+            /// ```
+            pub fn centroid(&self) -> Vec2 {
+                (self.coordinate1 + self.coordinate2 + self.coordinate3) / 3.
+            }
+
+            /// Get the signed `area` of the [`Triangle`]
+            ///
+            /// The area is positive when the coordinates are counter-clockwise and negative when clockwise.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. });
+            /// assert_eq!(triangle.signed_area(), 2.);
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 0. });
+            /// assert_eq!(triangle.signed_area(), -2.);
+            /// ```
+            pub fn signed_area(&self) -> $float {
+                (self.coordinate2 - self.coordinate1).perp_dot(self.coordinate3 - self.coordinate1) * 0.5
+            }
+
+            /// Get the `area` of the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 0. });
+            /// let area = triangle.area();
+            ///
+            /// assert_eq!(area, 2.);
+            /// ```
+            pub fn area(&self) -> $float {
+                self.signed_area().abs()
+            }
+
+            /// Get the [`Winding`] order of the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. });
+            /// assert_eq!(triangle.winding(), Winding::CounterClockwise);
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 0. });
+            /// assert_eq!(triangle.winding(), Winding::Clockwise);
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert_eq!(triangle.winding(), Winding::Degenerate);
+            /// ```
+            pub fn winding(&self) -> Winding {
+                Winding::from_signed_area(self.signed_area())
+            }
+
+            /// Checks if the coordinates of the [`Triangle`] are ordered clockwise
+            ///
+            /// A degenerate [`Triangle`] is neither clockwise nor counter-clockwise and returns `false`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 0. });
+            ///
+            /// assert!(triangle.is_clockwise());
+            /// ```
+            pub fn is_clockwise(&self) -> bool {
+                self.winding() == Winding::Clockwise
+            }
+
+            /// Checks if the [`Triangle`] is degenerate within an `epsilon`
+            ///
+            /// A [`Triangle`] is degenerate when its area is below `epsilon`, or when any two of its
+            /// coordinates are within `epsilon` of each other.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// // collinear
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert!(triangle.is_degenerate(0.));
+            ///
+            /// // nearly collinear
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 1., y: 0.0001 }, Vec2 { x: 2., y: 0. });
+            /// assert!(triangle.is_degenerate(0.001));
+            /// assert!(!triangle.is_degenerate(0.));
+            ///
+            /// // duplicate coordinates
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2::ZERO, Vec2 { x: 2., y: 0. });
+            /// assert!(triangle.is_degenerate(0.));
+            ///
+            /// let triangle = Triangle::default();
+            /// assert!(!triangle.is_degenerate(0.001));
+            /// ```
+            pub fn is_degenerate(&self, epsilon: $float) -> bool {
+                let [a, b, c] = self.vertices();
+                self.area() <= epsilon
+                    || a.distance(b) <= epsilon
+                    || b.distance(c) <= epsilon
+                    || c.distance(a) <= epsilon
+            }
+
+            /// Get the interior angles of the [`Triangle`] in radians
+            ///
+            /// The angles are at `coordinate1`, `coordinate2`, and `coordinate3` respectively.
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// let angles = triangle.angles();
+            ///
+            /// assert_eq!(angles[0], FRAC_PI_2);
+            /// assert_abs_diff_eq!(angles[0] + angles[1] + angles[2], PI, epsilon = 1e-6);
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2::from_angle(FRAC_PI_3) * 2.);
+            /// for angle in triangle.angles() {
+            ///     assert_abs_diff_eq!(angle, FRAC_PI_3, epsilon = 1e-6);
+            /// }
+            /// ```
+            pub fn angles(&self) -> [$float; 3] {
+                let [a, b, c] = self.vertices();
+                [
+                    Self::angle_between(b - a, c - a),
+                    Self::angle_between(c - b, a - b),
+                    Self::angle_between(a - c, b - c),
+                ]
+            }
+
+            /// Checks if the [`Triangle`] has a right angle within `epsilon` radians
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// assert!(triangle.is_right(1e-6));
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 1., y: 3. });
+            /// assert!(!triangle.is_right(1e-6));
+            /// ```
+            pub fn is_right(&self, epsilon: $float) -> bool {
+                self.angles()
+                    .iter()
+                    .any(|angle| (angle - std::$float::consts::FRAC_PI_2).abs() <= epsilon)
+            }
+
+            /// Checks if all three sides of the [`Triangle`] have the same length within `epsilon`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2::from_angle(FRAC_PI_3) * 2.);
+            /// assert!(triangle.is_equilateral(1e-6));
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 1., y: 3. });
+            /// assert!(!triangle.is_equilateral(1e-6));
+            /// ```
+            pub fn is_equilateral(&self, epsilon: $float) -> bool {
+                let [ab, bc, ca] = self.side_lengths();
+                (ab - bc).abs() <= epsilon && (bc - ca).abs() <= epsilon && (ca - ab).abs() <= epsilon
+            }
+
+            /// Checks if at least two sides of the [`Triangle`] have the same length within `epsilon`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 1., y: 3. });
+            /// assert!(triangle.is_isosceles(1e-6));
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// assert!(!triangle.is_isosceles(1e-6));
+            /// ```
+            pub fn is_isosceles(&self, epsilon: $float) -> bool {
+                let [ab, bc, ca] = self.side_lengths();
+                (ab - bc).abs() <= epsilon || (bc - ca).abs() <= epsilon || (ca - ab).abs() <= epsilon
+            }
+
+            fn side_lengths(&self) -> [$float; 3] {
+                [
+                    self.coordinate1.distance(self.coordinate2),
+                    self.coordinate2.distance(self.coordinate3),
+                    self.coordinate3.distance(self.coordinate1),
+                ]
+            }
+
+            // The closest point to `point` on the infinite line through `start` and `end`
+            #[cfg(feature = "line")]
+            fn perpendicular_foot(point: Vec2, start: Vec2, end: Vec2) -> Vec2 {
+                let direction = end - start;
+                let length_squared = direction.length_squared();
+                if length_squared == 0. {
+                    return start;
+                }
+                start + direction * ((point - start).dot(direction) / length_squared)
+            }
+
+            // atan2 never produces NaN, even for zero length sides
+            fn angle_between(from: Vec2, to: Vec2) -> $float {
+                from.perp_dot(to).abs().atan2(from.dot(to))
+            }
         }
-    }
+
+        // ##########
+        // Operations
+        // ##########
+        impl Triangle {
+            /// Get a [`Mesh`] containing the [`Triangle`]
+            ///
+            /// The [`Mesh`] is always wound counter-clockwise, even if the [`Triangle`] is clockwise.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 2. }, Vec2 { x: 2., y: 0. });
+            /// let mesh = triangle.to_mesh();
+            ///
+            /// assert_eq!(mesh.indices(), &[0, 1, 2]);
+            /// assert_eq!(mesh.positions(), &triangle.reversed().vertices());
+            /// ```
+            #[cfg(feature = "mesh")]
+            pub fn to_mesh(&self) -> Mesh {
+                Mesh::from_triangle(self)
+            }
+
+            /// Splits the [`Triangle`] into four by connecting the midpoints of its edges
+            ///
+            /// The three corner triangles at `coordinate1`, `coordinate2`, and `coordinate3` come first,
+            /// followed by the central triangle. Every triangle keeps the winding order of the original.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 4. });
+            /// let triangles = triangle.subdivide();
+            ///
+            /// assert_eq!(triangles[0].vertices(), [Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. }]);
+            /// assert_eq!(triangles[3].vertices(), [Vec2 { x: 2., y: 0. }, Vec2 { x: 2., y: 2. }, Vec2 { x: 0., y: 2. }]);
+            /// for subdivided in &triangles {
+            ///     assert_eq!(subdivided.winding(), triangle.winding());
+            ///     assert_eq!(subdivided.area(), triangle.area() / 4.);
+            /// }
+            /// ```
+            pub fn subdivide(&self) -> [Triangle; 4] {
+                let [a, b, c] = self.vertices();
+                let ab = (a + b) * 0.5;
+                let bc = (b + c) * 0.5;
+                let ca = (c + a) * 0.5;
+                [
+                    Triangle::new(a, ab, ca),
+                    Triangle::new(ab, b, bc),
+                    Triangle::new(ca, bc, c),
+                    Triangle::new(ab, bc, ca),
+                ]
+            }
+
+            /// Reverses the winding order of the [`Triangle`] by swapping `coordinate2` and `coordinate3`
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let mut triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. });
+            /// assert_eq!(triangle.winding(), Winding::CounterClockwise);
+            ///
+            /// triangle.reverse();
+            ///
+            /// assert_eq!(triangle.winding(), Winding::Clockwise);
+            /// assert_eq!(triangle.area(), 2.);
+            /// ```
+            pub fn reverse(&mut self) {
+                std::mem::swap(&mut self.coordinate2, &mut self.coordinate3);
+            }
+
+            /// Get a copy of the [`Triangle`] with the winding order reversed
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. });
+            /// let reversed = triangle.reversed();
+            ///
+            /// assert!(!triangle.is_clockwise());
+            /// assert!(reversed.is_clockwise());
+            /// assert_eq!(reversed.signed_area(), -triangle.signed_area());
+            /// assert_eq!(reversed.area(), triangle.area());
+            /// ```
+            pub fn reversed(&self) -> Triangle {
+                Triangle::new(self.coordinate1, self.coordinate3, self.coordinate2)
+            }
+
+            /// Recursively splits the [`Triangle`] `levels` times, producing `4^levels` triangles
+            ///
+            /// See [`Triangle::subdivide`].
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: -1., y: 2. }, Vec2 { x: 3., y: 0.5 }, Vec2 { x: 1., y: 4. });
+            ///
+            /// assert_eq!(triangle.subdivide_n(0).len(), 1);
+            /// for levels in 1..5 {
+            ///     let triangles = triangle.subdivide_n(levels);
+            ///     let area = triangles.iter().fold(0., |area, triangle| area + triangle.area());
+            ///
+            ///     assert_eq!(triangles.len(), 4_usize.pow(levels));
+            ///     assert_abs_diff_eq!(area, triangle.area(), epsilon = 1e-4);
+            /// }
+            /// ```
+            pub fn subdivide_n(&self, levels: u32) -> Vec<Triangle> {
+                let mut triangles = vec![Triangle::new(
+                    self.coordinate1,
+                    self.coordinate2,
+                    self.coordinate3,
+                )];
+                for _ in 0..levels {
+                    triangles = triangles
+                        .iter()
+                        .flat_map(|triangle| triangle.subdivide())
+                        .collect();
+                }
+                triangles
+            }
+
+            /// Linearly interpolates each coordinate of the [`Triangle`] towards the matching coordinate of `other`
+            ///
+            /// `t = 0` returns a copy of the [`Triangle`] and `t = 1` returns a copy of `other`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let from = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. });
+            /// let to = Triangle::new(Vec2 { x: 2., y: 2. }, Vec2 { x: 4., y: 2. }, Vec2 { x: 2., y: 4. });
+            /// let triangle = from.lerp(&to, 0.5);
+            ///
+            /// assert_eq!(triangle.vertices(), [Vec2::ONE, Vec2 { x: 3., y: 1. }, Vec2 { x: 1., y: 3. }]);
+            /// ```
+            pub fn lerp(&self, other: &Triangle, t: $float) -> Triangle {
+                Triangle::new(
+                    self.coordinate1.lerp(other.coordinate1, t),
+                    self.coordinate2.lerp(other.coordinate2, t),
+                    self.coordinate3.lerp(other.coordinate3, t),
+                )
+            }
+
+            /// Interpolates towards `other` like [`Triangle::lerp`], after matching up the coordinates
+            ///
+            /// Every cyclic ordering of the coordinates of `other`, in both winding orders, is considered and
+            /// the ordering with the least total squared distance travelled is used. Ties keep the earliest
+            /// ordering, starting with the coordinates of `other` as they are. At `t = 1` the result has the
+            /// same coordinates as `other`, possibly in a different order.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let from = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 4. });
+            ///
+            /// // the same triangle listed in a rotated order doesn't move at all
+            /// let to = Triangle::new(Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 4. }, Vec2::ZERO);
+            /// assert_eq!(from.lerp(&to, 0.5).area(), 2.);
+            /// assert_eq!(from.lerp_matched(&to, 0.5).vertices(), from.vertices());
+            ///
+            /// // the same triangle listed in the other winding order doesn't collapse halfway through
+            /// let to = Triangle::new(Vec2::ZERO, Vec2 { x: 0., y: 4. }, Vec2 { x: 4., y: 0. });
+            /// assert_eq!(from.lerp(&to, 0.5).area(), 0.);
+            /// assert_eq!(from.lerp_matched(&to, 0.5).area(), 8.);
+            ///
+            /// // a moved triangle listed in a rotated order
+            /// let to = Triangle::new(Vec2 { x: 14., y: 1. }, Vec2 { x: 10., y: 5. }, Vec2 { x: 10., y: 1. });
+            /// let triangle = from.lerp_matched(&to, 0.5);
+            /// assert_eq!(triangle.vertices(), [Vec2 { x: 5., y: 0.5 }, Vec2 { x: 9., y: 0.5 }, Vec2 { x: 5., y: 4.5 }]);
+            /// ```
+            pub fn lerp_matched(&self, other: &Triangle, t: $float) -> Triangle {
+                let [a, b, c] = other.vertices();
+                let candidates = [
+                    [a, b, c],
+                    [b, c, a],
+                    [c, a, b],
+                    [a, c, b],
+                    [c, b, a],
+                    [b, a, c],
+                ];
+
+                let from = self.vertices();
+                let travel = |to: &[Vec2; 3]| -> $float {
+                    from.iter()
+                        .zip(to)
+                        .map(|(from, to)| from.distance_squared(*to))
+                        .sum()
+                };
+
+                let mut best = candidates[0];
+                let mut best_travel = travel(&best);
+                for candidate in &candidates[1..] {
+                    let candidate_travel = travel(candidate);
+                    if candidate_travel < best_travel {
+                        best = *candidate;
+                        best_travel = candidate_travel;
+                    }
+                }
+
+                let [a, b, c] = best;
+                self.lerp(&Triangle::new(a, b, c), t)
+            }
+
+            /// Checks if a `point` is inside of the [`Triangle`]
+            ///
+            /// Works for either winding order, and points on the boundary are considered inside.
+            /// A degenerate [`Triangle`] (all three coordinates collinear) has no inside and always returns `false`,
+            /// see [`Triangle::is_degenerate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let a = Vec2::ZERO;
+            /// let b = Vec2 { x: 4., y: 0. };
+            /// let c = Vec2 { x: 0., y: 4. };
+            ///
+            /// for triangle in [Triangle::new(a, b, c), Triangle::new(a, c, b)] {
+            ///     // interior
+            ///     assert!(triangle.contains_point(Vec2::ONE));
+            ///     // edge midpoints
+            ///     assert!(triangle.contains_point((a + b) * 0.5));
+            ///     assert!(triangle.contains_point((b + c) * 0.5));
+            ///     assert!(triangle.contains_point((c + a) * 0.5));
+            ///     // vertices
+            ///     assert!(triangle.contains_point(a));
+            ///     assert!(triangle.contains_point(b));
+            ///     assert!(triangle.contains_point(c));
+            ///     // exterior, just past the hypotenuse
+            ///     assert!(!triangle.contains_point(Vec2 { x: 2.01, y: 2. }));
+            ///     assert!(!triangle.contains_point(Vec2 { x: 1., y: -0.01 }));
+            /// }
+            ///
+            /// let degenerate = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert!(!degenerate.contains_point(Vec2::ONE));
+            /// ```
+            pub fn contains_point(&self, point: Vec2) -> bool {
+                let [a, b, c] = self.vertices();
+                if (b - a).perp_dot(c - a) == 0. {
+                    return false;
+                }
+
+                let d1 = (b - a).perp_dot(point - a);
+                let d2 = (c - b).perp_dot(point - b);
+                let d3 = (a - c).perp_dot(point - c);
+
+                let has_negative = d1 < 0. || d2 < 0. || d3 < 0.;
+                let has_positive = d1 > 0. || d2 > 0. || d3 > 0.;
+
+                !(has_negative && has_positive)
+            }
+
+            /// Get the barycentric weights of a `point` relative to the [`Triangle`]
+            ///
+            /// The weights correspond to `coordinate1`, `coordinate2`, and `coordinate3` and always sum to `1`.
+            /// Points outside of the [`Triangle`] have weights outside of `[0, 1]`.
+            /// Returns `None` for a degenerate [`Triangle`] (all three coordinates collinear),
+            /// and nearly degenerate triangles produce very large weights, see [`Triangle::is_degenerate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 3., y: 0. }, Vec2 { x: 0., y: 3. });
+            ///
+            /// // centroid
+            /// let weights = triangle.barycentric(triangle.centroid()).unwrap();
+            /// assert!(weights.abs_diff_eq(Vec3::splat(1. / 3.), 1e-6));
+            /// assert!(triangle.from_barycentric(weights).abs_diff_eq(triangle.centroid(), 1e-6));
+            ///
+            /// // vertices
+            /// assert_eq!(triangle.barycentric(triangle.coordinate1()), Some(Vec3::X));
+            /// assert_eq!(triangle.barycentric(triangle.coordinate2()), Some(Vec3::Y));
+            /// assert_eq!(triangle.barycentric(triangle.coordinate3()), Some(Vec3::Z));
+            /// assert_eq!(triangle.from_barycentric(Vec3::Y), triangle.coordinate2());
+            ///
+            /// // exterior
+            /// let point = Vec2 { x: 4., y: 2. };
+            /// let weights = triangle.barycentric(point).unwrap();
+            /// assert!(weights.x < 0.);
+            /// assert_abs_diff_eq!(weights.x + weights.y + weights.z, 1., epsilon = 1e-6);
+            /// assert!(triangle.from_barycentric(weights).abs_diff_eq(point, 1e-6));
+            ///
+            /// // degenerate
+            /// let degenerate = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert_eq!(degenerate.barycentric(Vec2::ONE), None);
+            /// ```
+            pub fn barycentric(&self, point: Vec2) -> Option<Vec3> {
+                let [a, b, c] = self.vertices();
+                let v0 = b - a;
+                let v1 = c - a;
+                let v2 = point - a;
+
+                let denominator = v0.perp_dot(v1);
+                if denominator == 0. {
+                    return None;
+                }
+
+                let v = v2.perp_dot(v1) / denominator;
+                let w = v0.perp_dot(v2) / denominator;
+                Some(Vec3 {
+                    x: 1. - v - w,
+                    y: v,
+                    z: w,
+                })
+            }
+
+            /// Get the point described by barycentric `weights` relative to the [`Triangle`]
+            ///
+            /// The weights correspond to `coordinate1`, `coordinate2`, and `coordinate3` and are expected to sum to `1`.
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 2., y: 0. }, Vec2 { x: 0., y: 2. });
+            /// let point = triangle.from_barycentric(Vec3 { x: 0., y: 0.5, z: 0.5 });
+            ///
+            /// assert_eq!(point, Vec2::ONE);
+            /// ```
+            pub fn from_barycentric(&self, weights: Vec3) -> Vec2 {
+                self.coordinate1 * weights.x + self.coordinate2 * weights.y + self.coordinate3 * weights.z
+            }
+
+            /// Get the circumcenter of the [`Triangle`], the point equidistant from all three coordinates
+            ///
+            /// Returns `None` for a degenerate [`Triangle`] (all three coordinates collinear),
+            /// see [`Triangle::is_degenerate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// assert_eq!(triangle.circumcenter(), Some(Vec2 { x: 2., y: 1.5 }));
+            ///
+            /// let degenerate = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert_eq!(degenerate.circumcenter(), None);
+            /// ```
+            pub fn circumcenter(&self) -> Option<Vec2> {
+                // Solve relative to `coordinate1` to keep the magnitudes small
+                let a = self.coordinate1;
+                let b = self.coordinate2 - a;
+                let c = self.coordinate3 - a;
+
+                let denominator = 2. * b.perp_dot(c);
+                if denominator == 0. {
+                    return None;
+                }
+
+                let b_length_squared = b.length_squared();
+                let c_length_squared = c.length_squared();
+                let offset = Vec2 {
+                    x: (c.y * b_length_squared - b.y * c_length_squared) / denominator,
+                    y: (b.x * c_length_squared - c.x * b_length_squared) / denominator,
+                };
+
+                Some(a + offset)
+            }
+
+            /// Get the incenter of the [`Triangle`], the point equidistant from all three sides
+            ///
+            /// Returns `None` for a degenerate [`Triangle`] (all three coordinates collinear),
+            /// see [`Triangle::is_degenerate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// assert_eq!(triangle.incenter(), Some(Vec2::ONE));
+            ///
+            /// let degenerate = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert_eq!(degenerate.incenter(), None);
+            /// ```
+            pub fn incenter(&self) -> Option<Vec2> {
+                if self.signed_area() == 0. {
+                    return None;
+                }
+
+                // Each coordinate is weighted by the length of the opposite side
+                let [a, b, c] = self.vertices();
+                let [length_c, length_a, length_b] = self.side_lengths();
+                let perimeter = length_a + length_b + length_c;
+
+                Some((a * length_a + b * length_b + c * length_c) / perimeter)
+            }
+
+            /// Get the orthocenter of the [`Triangle`], the point where the altitudes meet
+            ///
+            /// Returns `None` for a degenerate [`Triangle`] (all three coordinates collinear),
+            /// see [`Triangle::is_degenerate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// // the orthocenter of a right triangle is the right angled coordinate
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// assert_eq!(triangle.orthocenter(), Some(Vec2::ZERO));
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: -1., y: 2. }, Vec2 { x: 3., y: 0.5 }, Vec2 { x: 1., y: 4. });
+            /// let orthocenter = triangle.orthocenter().unwrap();
+            /// let [a, b, c] = triangle.vertices();
+            /// assert_abs_diff_eq!((orthocenter - a).dot(c - b), 0., epsilon = 1e-4);
+            /// assert_abs_diff_eq!((orthocenter - b).dot(a - c), 0., epsilon = 1e-4);
+            /// assert_abs_diff_eq!((orthocenter - c).dot(b - a), 0., epsilon = 1e-4);
+            ///
+            /// let degenerate = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert_eq!(degenerate.orthocenter(), None);
+            /// ```
+            pub fn orthocenter(&self) -> Option<Vec2> {
+                // The centroid divides the segment from the circumcenter to the orthocenter 1:2
+                let circumcenter = self.circumcenter()?;
+                Some(self.centroid() * 3. - circumcenter * 2.)
+            }
+
+            /// Get the Euler line of the [`Triangle`], running from the circumcenter to the orthocenter
+            ///
+            /// The centroid also lies on the Euler line, a third of the way from the circumcenter.
+            /// Returns `None` for a degenerate [`Triangle`], or when the circumcenter and orthocenter
+            /// coincide (within floating point error relative to the size of the [`Triangle`]), which
+            /// happens for an equilateral [`Triangle`].
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: -1., y: 2. }, Vec2 { x: 3., y: 0.5 }, Vec2 { x: 1., y: 4. });
+            /// let line = triangle.euler_line().unwrap();
+            ///
+            /// assert_eq!(line.origin(), triangle.circumcenter().unwrap());
+            /// assert!(line.end().abs_diff_eq(triangle.orthocenter().unwrap(), 1e-6));
+            ///
+            /// // the centroid is collinear and divides the line 1:2
+            /// let centroid = triangle.centroid();
+            /// assert_abs_diff_eq!(line.direction().perp_dot(centroid - line.origin()), 0., epsilon = 1e-4);
+            /// assert!((line.origin() + line.direction() / 3.).abs_diff_eq(centroid, 1e-5));
+            ///
+            /// assert!(Triangle::equilateral(Vec2::ONE, 2.).euler_line().is_none());
+            /// ```
+            #[cfg(feature = "line")]
+            pub fn euler_line(&self) -> Option<Line> {
+                let circumcenter = self.circumcenter()?;
+                let orthocenter = self.orthocenter()?;
+                let tolerance = circumcenter.distance(self.coordinate1) * 1e-5;
+                if circumcenter.distance(orthocenter) <= tolerance {
+                    return None;
+                }
+                Some(Line::new(circumcenter, orthocenter))
+            }
+
+            /// Get the circumcircle of the [`Triangle`], the [`Circle`] passing through all three coordinates
+            ///
+            /// Returns `None` for a degenerate [`Triangle`] (all three coordinates collinear),
+            /// and nearly degenerate triangles produce a very large [`Circle`], see [`Triangle::is_degenerate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: -1., y: 2. }, Vec2 { x: 3., y: 0.5 }, Vec2 { x: 1., y: 4. });
+            /// let circle = triangle.circumcircle().unwrap();
+            ///
+            /// for vertex in triangle.vertices() {
+            ///     assert_abs_diff_eq!(vertex.distance(circle.center()), circle.radius(), epsilon = 1e-5);
+            /// }
+            ///
+            /// // the circumcenter of a right triangle is the midpoint of the hypotenuse
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// let circle = triangle.circumcircle().unwrap();
+            ///
+            /// assert_eq!(circle.center(), Vec2 { x: 2., y: 1.5 });
+            /// assert_eq!(circle.radius(), 2.5);
+            ///
+            /// let degenerate = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert!(degenerate.circumcircle().is_none());
+            /// ```
+            #[cfg(feature = "circle")]
+            pub fn circumcircle(&self) -> Option<Circle> {
+                let center = self.circumcenter()?;
+                Some(Circle::new(center, center.distance(self.coordinate1)))
+            }
+
+            /// Get the incircle of the [`Triangle`], the largest [`Circle`] that fits inside of it
+            ///
+            /// Returns `None` for a degenerate [`Triangle`] (all three coordinates collinear),
+            /// see [`Triangle::is_degenerate`].
+            ///
+            /// ```
+            #[doc = $imports]
+            /// use approx::assert_abs_diff_eq;
+            ///
+            /// // a 3-4-5 right triangle has an inradius of 1
+            /// let triangle = Triangle::new(Vec2::ZERO, Vec2 { x: 4., y: 0. }, Vec2 { x: 0., y: 3. });
+            /// let circle = triangle.incircle().unwrap();
+            ///
+            /// assert_eq!(circle.center(), Vec2::ONE);
+            /// assert_eq!(circle.radius(), 1.);
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: -1., y: 2. }, Vec2 { x: 3., y: 0.5 }, Vec2 { x: 1., y: 4. });
+            /// let circle = triangle.incircle().unwrap();
+            /// assert!(triangle.contains_point(circle.center()));
+            ///
+            /// let [a, b, c] = triangle.vertices();
+            /// for (start, end) in [(a, b), (b, c), (c, a)] {
+            ///     let edge = end - start;
+            ///     let distance = edge.perp_dot(circle.center() - start).abs() / edge.length();
+            ///     assert_abs_diff_eq!(distance, circle.radius(), epsilon = 1e-5);
+            /// }
+            ///
+            /// let degenerate = Triangle::new(Vec2::ZERO, Vec2::ONE, Vec2 { x: 2., y: 2. });
+            /// assert!(degenerate.incircle().is_none());
+            /// ```
+            #[cfg(feature = "circle")]
+            pub fn incircle(&self) -> Option<Circle> {
+                let center = self.incenter()?;
+                let perimeter: $float = self.side_lengths().iter().sum();
+                Some(Circle::new(center, self.area() * 2. / perimeter))
+            }
+
+            /// Get the smallest axis aligned [`Rectangle`] containing the [`Triangle`]
+            ///
+            /// ```
+            #[doc = $imports]
+            ///
+            /// let triangle = Triangle::default();
+            /// let rect = triangle.bounding_box();
+            ///
+            /// assert_eq!(rect.min(), Vec2::ZERO);
+            /// assert_eq!(rect.max(), Vec2::ONE);
+            ///
+            /// let triangle = Triangle::new(Vec2 { x: -3., y: 1. }, Vec2 { x: 2., y: -4. }, Vec2 { x: -1., y: 5. });
+            /// let rect = triangle.bounding_box();
+            ///
+            /// assert_eq!(rect.min(), Vec2 { x: -3., y: -4. });
+            /// assert_eq!(rect.max(), Vec2 { x: 2., y: 5. });
+            /// ```
+            #[cfg(feature = "rectangle")]
+            pub fn bounding_box(&self) -> Rectangle {
+                Rectangle::new_coordinates(
+                    self.coordinate1.min(self.coordinate2).min(self.coordinate3),
+                    self.coordinate1.max(self.coordinate2).max(self.coordinate3),
+                )
+            }
+        }
+
+        // ##########
+        // IntoIterator impl
+        // ##########
+        impl IntoIterator for &Triangle {
+            type Item = Vec2;
+            type IntoIter = std::array::IntoIter<Vec2, 3>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_vertices()
+            }
+        }
+
+        // ##########
+        // Default impl
+        // ##########
+        impl Default for Triangle {
+            fn default() -> Self {
+                Self {
+                    coordinate1: Vec2::ONE,
+                    coordinate2: Vec2::ZERO,
+                    coordinate3: Vec2 { x: 1., y: 0. },
+                }
+            }
+        }
+
+        // ##########
+        // Display impl
+        // ##########
+        impl Display for Triangle {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    concat!(stringify!($triangle), " {{ coordinate1: {}, coordinate2: {}, coordinate3: {} }}"),
+                    self.coordinate1(),
+                    self.coordinate2(),
+                    self.coordinate3()
+                )
+            }
+        }
+    };
 }
+pub(crate) use impl_triangle;
+
+impl_triangle!(
+    /// Represents a single [`Triangle`] in 2d space
+    Triangle,
+    f32;
+    "use glam::{Vec2, Vec3};\nuse shapes2d::prelude::*;\n# use std::f32::consts::{FRAC_PI_2, FRAC_PI_3, PI};"
+);
 
 // ##########
-// Display impl
+// approx impl
 // ##########
-impl Display for Triangle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Triangle {{ coordinate1: {}, coordinate2: {}, coordinate3: {} }}",
-            self.coordinate1(),
-            self.coordinate2(),
-            self.coordinate3()
-        )
-    }
-}
+#[cfg(feature = "approx")]
+impl_approx!(
+    /// Compares the coordinates of two [`Triangle`]s in order within `epsilon`, so the same [`Triangle`] starting from
+    /// another coordinate is not close
+    Triangle { coordinate1, coordinate2, coordinate3 }
+);
@@ -0,0 +1,38 @@
+/// Represents the winding order of the coordinates of a shape in 2d space
+///
+/// The winding is determined by the sign of the signed area, with the `y` axis pointing up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Winding {
+    /// The coordinates are ordered clockwise (negative signed area)
+    Clockwise,
+    /// The coordinates are ordered counter-clockwise (positive signed area)
+    CounterClockwise,
+    /// The coordinates enclose no area, so there is no winding order
+    Degenerate,
+}
+
+// ##########
+// Constructors
+// ##########
+impl Winding {
+    /// Get the [`Winding`] described by a `signed_area`, of either precision
+    ///
+    /// ```
+    /// use shapes2d::prelude::Winding;
+    ///
+    /// assert_eq!(Winding::from_signed_area(1.), Winding::CounterClockwise);
+    /// assert_eq!(Winding::from_signed_area(-1.), Winding::Clockwise);
+    /// assert_eq!(Winding::from_signed_area(0.), Winding::Degenerate);
+    /// assert_eq!(Winding::from_signed_area(-1e-300_f64), Winding::Clockwise);
+    /// ```
+    pub fn from_signed_area(signed_area: impl Into<f64>) -> Self {
+        let signed_area = signed_area.into();
+        if signed_area > 0. {
+            Self::CounterClockwise
+        } else if signed_area < 0. {
+            Self::Clockwise
+        } else {
+            Self::Degenerate
+        }
+    }
+}